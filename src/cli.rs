@@ -3,8 +3,9 @@ use std::error::Error;
 use std::io::{Read, Write};
 use std::process;
 use std::{thread, time};
-use trello::Renderable;
-use trello::{Card, CardContents, TrelloClient, TrelloError, TrelloObject};
+use trello::{
+    header, Card, CardContents, CardPatch, Renderable, TrelloClient, TrelloError, TrelloObject,
+};
 
 pub fn multiselect_trello_object<T: TrelloObject + Renderable + PartialEq>(
     objects: &[T],
@@ -69,7 +70,13 @@ pub fn edit_card(client: &TrelloClient, card: &Card) -> Result<(), Box<dyn Error
     debug!("Using editor: {}", editor_env);
     debug!("Editing card: {:?}", card);
 
-    writeln!(file, "{}", card.render())?;
+    // Only the contents which can be parsed back into CardContents are written
+    writeln!(file, "{}", header(&card.name, "="))?;
+    #[cfg(feature = "human-dates")]
+    if let Some(due) = &card.due {
+        writeln!(file, "{}", trello::dates::due_line(due))?;
+    }
+    writeln!(file, "{}", card.desc)?;
 
     let mut new_card = card.clone();
 
@@ -90,7 +97,15 @@ pub fn edit_card(client: &TrelloClient, card: &Card) -> Result<(), Box<dyn Error
             file.reopen()?.read_to_string(&mut buf)?;
 
             // Trim end because a lot of editors will auto add new lines at the end of the file
-            let contents: CardContents = match buf.trim_end().parse() {
+            let parsed = buf.trim_end().parse::<CardContents>();
+            #[cfg(feature = "human-dates")]
+            let parsed = parsed.and_then(|mut contents| {
+                let due = trello::dates::take_due(&mut contents)?;
+                Ok((contents, due))
+            });
+            #[cfg(not(feature = "human-dates"))]
+            let parsed = parsed.map(|contents| (contents, new_card.due));
+            let (contents, due) = match parsed {
                 Ok(c) => c,
                 Err(e) => {
                     debug!("Unable to parse Card Contents: {}", e);
@@ -112,12 +127,18 @@ pub fn edit_card(client: &TrelloClient, card: &Card) -> Result<(), Box<dyn Error
                 || result.as_ref().unwrap().is_err()
                 || new_card.name != contents.name
                 || new_card.desc != contents.desc
+                || new_card.due != due
             {
+                let mut patch = CardPatch::new().name(&contents.name).desc(&contents.desc);
+                if new_card.due != due {
+                    patch = patch.due(due);
+                }
                 new_card.name = contents.name;
                 new_card.desc = contents.desc;
+                new_card.due = due;
 
                 debug!("Updating card: {:?}", new_card);
-                result = Some(Card::update(client, &new_card));
+                result = Some(Card::patch(client, &new_card.id, &patch));
 
                 match &result {
                     Some(Ok(_)) => debug!("Updated card"),
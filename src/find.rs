@@ -32,7 +32,7 @@ pub fn get_object_by_name<'a, T: TrelloObject>(
 
     let mut objects = objects
         .iter()
-        .filter(|o| re.is_match(&o.get_name()))
+        .filter(|o| re.is_match(o.get_name()))
         .collect::<Vec<&T>>();
 
     match objects.len().cmp(&1) {
@@ -97,8 +97,8 @@ pub fn get_trello_object(
             })
         }
     };
-    let boards = Board::get_all(&client)?;
-    let mut board = get_object_by_name(&boards, &board_name, params.ignore_case)?.clone();
+    let boards = Board::get_all(client)?;
+    let mut board = get_object_by_name(&boards, board_name, params.ignore_case)?.clone();
 
     // This should retrieve everything at once
     // This means better performance as it's less HTTP requests. But it does
@@ -108,14 +108,13 @@ pub fn get_trello_object(
     if let Some("-") = params.list_name {
         if let Some(card_name) = params.card_name {
             let board_out = board.clone();
-            let lists = board.lists.unwrap();
+            let lists = board.lists.into_vec();
 
             let cards = lists
                 .into_iter()
-                .map(|l| l.cards.unwrap())
-                .flatten()
+                .flat_map(|l| l.cards.into_vec())
                 .collect::<Vec<Card>>();
-            let card = get_object_by_name(&cards, &card_name, params.ignore_case)?;
+            let card = get_object_by_name(&cards, card_name, params.ignore_case)?;
 
             return Ok(TrelloResult {
                 board: Some(board_out),
@@ -128,13 +127,13 @@ pub fn get_trello_object(
             )))
         }
     } else if let Some(list_name) = params.list_name {
-        let lists = &board.lists.as_ref().unwrap();
-        let list = get_object_by_name(lists, &list_name, params.ignore_case)?.clone();
+        let lists = &board.lists.get().unwrap();
+        let list = get_object_by_name(lists, list_name, params.ignore_case)?.clone();
 
         if let Some(card_name) = params.card_name {
-            let cards = &list.cards.as_ref().unwrap();
+            let cards = &list.cards.get().unwrap();
 
-            let card = get_object_by_name(&cards, &card_name, params.ignore_case)?.clone();
+            let card = get_object_by_name(cards, card_name, params.ignore_case)?.clone();
             return Ok(TrelloResult {
                 board: Some(board),
                 list: Some(list),
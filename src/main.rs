@@ -15,7 +15,7 @@ mod find;
 mod subcommands;
 
 use colored::*;
-use simplelog::{CombinedLogger, Config, LevelFilter, TermLogger, TerminalMode};
+use simplelog::{CombinedLogger, ConfigBuilder, LevelFilter, TermLogger, TerminalMode};
 use std::env;
 use std::error::Error;
 use std::process;
@@ -150,9 +150,15 @@ https://help.trello.com/article/808-searching-for-cards-all-boards")
         unknown => panic!("Unknown log level '{}'", unknown),
     };
 
+    // Requests are logged by the client with their credentials redacted, whereas
+    // reqwest would include them in the urls it logs
+    let log_config = ConfigBuilder::new()
+        .add_filter_ignore_str("reqwest")
+        .build();
+
     CombinedLogger::init(vec![TermLogger::new(
         log_level,
-        Config::default(),
+        log_config,
         TerminalMode::Mixed,
     )
     .unwrap()])
@@ -186,27 +192,27 @@ https://help.trello.com/article/808-searching-for-cards-all-boards")
     if matches.subcommand_matches("version").is_some() {
         eprintln!(env!("CARGO_PKG_VERSION"));
     } else if let Some(matches) = matches.subcommand_matches("me") {
-        subcommands::me_subcommand(&client, &matches)?;
+        subcommands::me_subcommand(&client, matches)?;
     } else if let Some(matches) = matches.subcommand_matches("show") {
-        subcommands::show_subcommand(&client, &matches)?;
+        subcommands::show_subcommand(&client, matches)?;
     } else if let Some(matches) = matches.subcommand_matches("move") {
-        subcommands::move_subcommand(&client, &matches)?;
+        subcommands::move_subcommand(&client, matches)?;
     } else if let Some(matches) = matches.subcommand_matches("search") {
-        subcommands::search_subcommand(&client, &matches)?;
+        subcommands::search_subcommand(&client, matches)?;
     } else if let Some(matches) = matches.subcommand_matches("attach") {
-        subcommands::attach_subcommand(&client, &matches)?;
+        subcommands::attach_subcommand(&client, matches)?;
     } else if let Some(matches) = matches.subcommand_matches("attachments") {
-        subcommands::attachments_subcommand(&client, &matches)?;
+        subcommands::attachments_subcommand(&client, matches)?;
     } else if let Some(matches) = matches.subcommand_matches("label") {
-        subcommands::label_subcommand(&client, &matches)?;
+        subcommands::label_subcommand(&client, matches)?;
     } else if let Some(matches) = matches.subcommand_matches("url") {
-        subcommands::url_subcommand(&client, &matches)?;
+        subcommands::url_subcommand(&client, matches)?;
     } else if let Some(matches) = matches.subcommand_matches("close") {
-        subcommands::close_subcommand(&client, &matches)?;
+        subcommands::close_subcommand(&client, matches)?;
     } else if let Some(matches) = matches.subcommand_matches("open") {
-        subcommands::open_subcommand(&client, &matches)?;
+        subcommands::open_subcommand(&client, matches)?;
     } else if let Some(matches) = matches.subcommand_matches("create") {
-        subcommands::create_subcommand(&client, &matches)?;
+        subcommands::create_subcommand(&client, matches)?;
     } else {
         println!("{}", matches.usage());
     }
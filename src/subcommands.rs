@@ -25,17 +25,13 @@ pub fn setup_subcommand(matches: &ArgMatches) -> Result<()> {
     let key = cli::get_input("Enter Developer API Key: ")?;
     let token = cli::get_input("Enter Token: ")?;
 
-    let config = ClientConfig {
-        host: ClientConfig::default_host(),
-        key,
-        token,
-    };
+    let config = ClientConfig::new(&ClientConfig::default_host(), &token, &key);
 
     let client = TrelloClient::new(config);
 
     println!();
 
-    match Member::me(&client) {
+    match client.validate() {
         Ok(member) => {
             client.config.save_config()?;
             println!(
@@ -43,11 +39,12 @@ pub fn setup_subcommand(matches: &ArgMatches) -> Result<()> {
                 member.username.green()
             );
         }
-        Err(_) => {
+        Err(e) => {
             println!(
                 "{}",
                 "Unable to validate credentials. Please re-check and try again".red()
             );
+            println!("{}", e);
         }
     };
 
@@ -153,11 +150,11 @@ pub fn move_subcommand(client: &TrelloClient, matches: &ArgMatches) -> Result<()
         .list
         .ok_or("Unable to retrieve list. Wildcards are currently unsupported with move")?;
 
-    let board_lists = board.lists.as_ref().ok_or("Missing target board lists")?;
+    let board_lists = board.lists.get().ok_or("Missing target board lists")?;
 
     let new_list = find::get_object_by_name(board_lists, new_list_name, true)?;
 
-    Card::change_list(client, &card.id, &new_list.id)?;
+    Card::move_to_list(client, &card.id, &new_list.id)?;
 
     println!(
         "Moved '{}' from '{}' to '{}'",
@@ -177,19 +174,19 @@ pub fn open_subcommand(client: &TrelloClient, matches: &ArgMatches) -> Result<()
 
     if object_type == "board" {
         debug!("Re-opening board with id {}", &id);
-        let board = Board::open(client, &id)?;
+        let board = Board::open(client, id)?;
 
         eprintln!("Opened board: {}", &board.name.green());
         eprintln!("id: {}", &board.id);
     } else if object_type == "list" {
         debug!("Re-opening list with id {}", &id);
-        let list = List::open(client, &id)?;
+        let list = List::open(client, id)?;
 
         eprintln!("Opened list: {}", &list.name.green());
         eprintln!("id: {}", &list.id);
     } else if object_type == "card" {
         debug!("Re-openning card with id {}", &id);
-        let card = Card::open(client, &id)?;
+        let card = Card::open(client, id)?;
 
         eprintln!("Opened card: {}", &card.name.green());
         eprintln!("id: {}", &card.id);
@@ -288,7 +285,7 @@ pub fn create_subcommand(client: &TrelloClient, matches: &ArgMatches) -> Result<
         let labels_to_apply = if let Some(label_names) = matches.values_of("label") {
             let mut target_labels = vec![];
             let labels =
-                Label::get_all(&client, &result.board.ok_or("Unable to retrieve board")?.id)?;
+                Label::get_all(client, &result.board.ok_or("Unable to retrieve board")?.id)?;
 
             for name in label_names {
                 match find::get_object_by_name(&labels, name, true) {
@@ -313,7 +310,7 @@ pub fn create_subcommand(client: &TrelloClient, matches: &ArgMatches) -> Result<
         let card = Card::create(client, &list.id, &Card::new("", &name, "", None, "", None))?;
 
         for label in labels_to_apply {
-            match Label::apply(client, &card.id, &label.id) {
+            match Label::add_to_card(client, &card.id, &label.id) {
                 Ok(_) => eprintln!("Applied {} label", &label.simple_render(),),
                 Err(e) => eprintln!("Unable to apply {} label: {}", &label.simple_render(), e),
             };
@@ -328,7 +325,7 @@ pub fn create_subcommand(client: &TrelloClient, matches: &ArgMatches) -> Result<
             None => cli::get_input("List name: ")?,
         };
 
-        List::create(client, &board.id, &name)?;
+        List::create(client, &board.id, &name, None)?;
     } else {
         let name = match matches.value_of("name") {
             Some(n) => String::from(n),
@@ -410,7 +407,7 @@ pub fn search_subcommand(client: &TrelloClient, matches: &ArgMatches) -> Result<
     let query = matches
         .values_of("query")
         .ok_or("Missing query value")?
-        .map(|s| replace_negative_prefix(s))
+        .map(replace_negative_prefix)
         .collect::<Vec<String>>()
         .join(" ");
     let partial = matches.is_present("partial");
@@ -450,7 +447,7 @@ pub fn search_subcommand(client: &TrelloClient, matches: &ArgMatches) -> Result<
 }
 
 fn delete_label(client: &TrelloClient, card: &Card, label: &Label) -> Result<()> {
-    Label::remove(client, &card.id, &label.id)?;
+    Label::remove_from_card(client, &card.id, &label.id)?;
 
     eprintln!(
         "Removed {} label from '{}'",
@@ -462,7 +459,7 @@ fn delete_label(client: &TrelloClient, card: &Card, label: &Label) -> Result<()>
 }
 
 fn apply_label(client: &TrelloClient, card: &Card, label: &Label) -> Result<()> {
-    Label::apply(client, &card.id, &label.id)?;
+    Label::add_to_card(client, &card.id, &label.id)?;
 
     eprintln!(
         "Applied {} label to '{}'",
@@ -491,7 +488,7 @@ pub fn label_subcommand(client: &TrelloClient, matches: &ArgMatches) -> Result<(
         let label_names = label_names.ok_or("Label names must be specified")?;
 
         for name in label_names {
-            let label = match find::get_object_by_name(&labels, name, true) {
+            let label = match find::get_object_by_name(labels, name, true) {
                 Ok(l) => l,
                 Err(e) => {
                     eprintln!("{}", e);
@@ -499,11 +496,11 @@ pub fn label_subcommand(client: &TrelloClient, matches: &ArgMatches) -> Result<(
                 }
             };
 
-            delete_label(client, &card, &label)?;
+            delete_label(client, &card, label)?;
         }
     } else {
         let board = result.board.ok_or("Unable to retrieve board")?;
-        let mut labels = Label::get_all(&client, &board.id)?;
+        let mut labels = Label::get_all(client, &board.id)?;
         labels.sort_by_cached_key(|l| l.name.clone());
 
         if interactive {
@@ -539,7 +536,7 @@ pub fn label_subcommand(client: &TrelloClient, matches: &ArgMatches) -> Result<(
                     }
                 };
 
-                apply_label(client, &card, &label)?;
+                apply_label(client, &card, label)?;
             }
         }
     }
@@ -6,7 +6,6 @@ type TestResult = Result<(), Box<dyn Error>>;
 
 mod test_get_trello_object {
     use super::*;
-    use mockito;
     use serde_json::json;
 
     #[test]
@@ -34,7 +33,7 @@ mod test_get_trello_object {
     fn test_correct_output() -> TestResult {
         let _m1 = mockito::mock(
             "GET",
-            "/1/members/me/boards/?key=key&token=token&filter=open&fields=id%2Cname%2Cclosed%2Curl",
+            "/1/members/me/boards/?key=key&token=token&filter=open&fields=id%2Cname%2Cclosed%2Curl%2Csubscribed",
         )
         .with_status(200)
         .with_body(
@@ -48,12 +47,12 @@ mod test_get_trello_object {
 
         let _m2 = mockito::mock(
             "GET",
-            "/1/boards/abc-def/lists?key=key&token=token&fields=id%2Cname%2Cclosed&cards=open",
+            "/1/boards/abc-def/lists?key=key&token=token&fields=id%2Cname%2Cclosed%2CsoftLimit&cards=open",
         )
         .with_status(200)
         .with_body(
             json!([
-                {"name": "Backlog", "id": "bcklg", "closed": false, "cards": [], "url": ""},
+                {"name": "Backlog", "id": "bcklg", "closed": false, "cards": []},
             ])
             .to_string(),
         )
@@ -0,0 +1,137 @@
+//! Reports derived from the contents of a board, such as which cards are
+//! blocked by unfinished dependencies (see the `dependencies` module).
+use crate::board::Board;
+use crate::dependencies::CardNode;
+use crate::trello_object::{RenderOptions, Renderable};
+
+use colored::*;
+use petgraph::algo::tarjan_scc;
+
+/// Cards in lists with this name (ignoring case) are considered finished
+const DONE_LIST: &str = "done";
+
+/// An unfinished card whose dependencies are not all finished
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockedCard {
+    pub card: CardNode,
+    pub waiting_on: Vec<CardNode>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockedReport {
+    pub name: String,
+    pub blocked: Vec<BlockedCard>,
+    /// Groups of cards which depend on each other in a circle, so can
+    /// never be finished
+    pub cycles: Vec<Vec<CardNode>>,
+}
+
+impl BlockedReport {
+    pub fn has_cycles(&self) -> bool {
+        !self.cycles.is_empty()
+    }
+}
+
+fn is_done(node: &CardNode) -> bool {
+    node.list_name.eq_ignore_ascii_case(DONE_LIST)
+}
+
+/// Lists the cards on the board whose dependencies are not in a "Done" list,
+/// along with any circular dependencies. The board must have its lists and
+/// cards retrieved beforehand, see `Board::retrieve_nested`.
+pub fn blocked_cards(board: &Board) -> BlockedReport {
+    let graph = board.dependency_graph();
+
+    let blocked = graph
+        .graph
+        .node_indices()
+        .filter(|n| !is_done(&graph.graph[*n]))
+        .filter_map(|n| {
+            let waiting_on = graph
+                .dependencies_of(n)
+                .into_iter()
+                .filter(|d| !is_done(d))
+                .cloned()
+                .collect::<Vec<CardNode>>();
+
+            if waiting_on.is_empty() {
+                None
+            } else {
+                Some(BlockedCard {
+                    card: graph.graph[n].clone(),
+                    waiting_on,
+                })
+            }
+        })
+        .collect();
+
+    let cycles = tarjan_scc(&graph.graph)
+        .into_iter()
+        .filter(|component| match component.as_slice() {
+            [single] => graph.graph.contains_edge(*single, *single),
+            _ => true,
+        })
+        .map(|mut component| {
+            // tarjan_scc returns each component in reverse order
+            component.reverse();
+            component
+                .into_iter()
+                .map(|n| graph.graph[n].clone())
+                .collect()
+        })
+        .collect();
+
+    BlockedReport {
+        name: board.name.clone(),
+        blocked,
+        cycles,
+    }
+}
+
+impl Renderable for BlockedReport {
+    fn render_with(&self, _options: &RenderOptions) -> String {
+        let mut result = vec![self.name.bold().to_string()];
+
+        for cycle in &self.cycles {
+            let names = cycle
+                .iter()
+                .chain(cycle.first())
+                .map(|n| n.card.name.as_str())
+                .collect::<Vec<&str>>();
+            result.push(
+                format!("Error: circular dependency {}", names.join(" -> "))
+                    .red()
+                    .to_string(),
+            );
+        }
+
+        if self.blocked.is_empty() {
+            result.push(String::from("No blocked cards"));
+        }
+
+        for blocked in &self.blocked {
+            let waiting_on = blocked
+                .waiting_on
+                .iter()
+                .map(|n| format!("{} ({})", n.card.name, n.list_name))
+                .collect::<Vec<String>>();
+            result.push(format!(
+                "* {} {} waiting on {}",
+                blocked.card.card.name,
+                format!("({})", blocked.card.list_name).dimmed(),
+                waiting_on.join(", ")
+            ));
+        }
+
+        result.join("\n")
+    }
+
+    fn simple_render(&self) -> String {
+        format!(
+            "{}: {} blocked cards, {} circular dependencies",
+            self.name,
+            self.blocked.len(),
+            self.cycles.len()
+        )
+    }
+}
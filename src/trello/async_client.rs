@@ -0,0 +1,156 @@
+//! Asynchronous client for browser based tools such as dashboards. When
+//! compiled to `wasm32`, requests are made with the `fetch` API through
+//! reqwest's wasm backend, so the models, rendering and diffing of this crate
+//! can be shared with the command line client. The same client can be used
+//! natively from any async runtime.
+use crate::board::Board;
+use crate::card::{Card, CardPatch};
+use crate::client::ClientConfig;
+use crate::list::List;
+use crate::loaded::Loaded;
+use crate::trello_error::TrelloError;
+use crate::trello_object::TrelloObject;
+
+use chrono::{DateTime, Utc};
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+type Result<T> = std::result::Result<T, TrelloError>;
+
+/// The asynchronous counterpart of `TrelloClient`, covering the requests
+/// needed to display and edit boards
+#[derive(Debug)]
+pub struct AsyncTrelloClient {
+    pub config: ClientConfig,
+    pub client: reqwest::Client,
+}
+
+impl AsyncTrelloClient {
+    pub fn new(config: ClientConfig) -> Self {
+        AsyncTrelloClient {
+            config,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Retrieves the given path and deserializes the body of the response
+    pub async fn get_json<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        params: &[(&str, &str)],
+    ) -> Result<T> {
+        let url = self.config.get_trello_url(path, params)?;
+        let response = self.client.get(url).send().await?;
+
+        Ok(response.error_for_status()?.json().await?)
+    }
+
+    async fn send_form<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        form: &[(&str, String)],
+    ) -> Result<T> {
+        let url = self.config.get_trello_url(path, &[])?;
+        let response = self.client.request(method, url).form(form).send().await?;
+
+        Ok(response.error_for_status()?.json().await?)
+    }
+
+    /// Retrieves the open boards of the authenticated member
+    pub async fn get_boards(&self) -> Result<Vec<Board>> {
+        self.get_json(
+            "/1/members/me/boards/",
+            &[
+                ("filter", "open"),
+                ("fields", &Board::get_fields().join(",")),
+            ],
+        )
+        .await
+    }
+
+    pub async fn get_board(&self, board_id: &str) -> Result<Board> {
+        self.get_json(
+            &format!("/1/boards/{}", board_id),
+            &[("fields", &Board::get_fields().join(","))],
+        )
+        .await
+    }
+
+    /// Retrieves the open lists of a board, along with their open cards
+    /// when `cards` is set
+    pub async fn get_lists(&self, board_id: &str, cards: bool) -> Result<Vec<List>> {
+        let fields = List::get_fields().join(",");
+        let mut params = vec![("fields", fields.as_str())];
+
+        if cards {
+            params.push(("cards", "open"));
+        }
+
+        self.get_json(&format!("/1/boards/{}/lists", board_id), &params)
+            .await
+    }
+
+    pub async fn get_cards(&self, list_id: &str) -> Result<Vec<Card>> {
+        self.get_json(
+            &format!("/1/lists/{}/cards/", list_id),
+            &[("fields", &Card::get_fields().join(","))],
+        )
+        .await
+    }
+
+    /// Retrieves the lists and cards of the board, if not already retrieved.
+    /// See `Board::retrieve_nested`.
+    pub async fn retrieve_nested(&self, board: &mut Board) -> Result<()> {
+        if !board.lists.is_fetched() {
+            debug!("Retrieving nested data for board: {}", board.id);
+            board.lists = Loaded::Fetched(self.get_lists(&board.id, true).await?);
+        }
+        Ok(())
+    }
+
+    pub async fn create_card(&self, list_id: &str, card: &Card) -> Result<Card> {
+        let form = [
+            ("name", card.name.clone()),
+            ("desc", card.desc.clone()),
+            ("idList", String::from(list_id)),
+        ];
+
+        self.send_form(Method::POST, "/1/cards/", &form).await
+    }
+
+    /// Updates only the fields set in the patch. See `Card::patch`.
+    pub async fn patch_card(&self, card_id: &str, patch: &CardPatch) -> Result<Card> {
+        if let Some(expected) = patch.last_activity {
+            self.check_last_activity(card_id, expected).await?;
+        }
+
+        let form = patch.params();
+        self.send_form(Method::PUT, &format!("/1/cards/{}/", card_id), &form)
+            .await
+    }
+
+    async fn check_last_activity(&self, card_id: &str, expected: DateTime<Utc>) -> Result<()> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct CardActivity {
+            date_last_activity: DateTime<Utc>,
+        }
+
+        let activity: CardActivity = self
+            .get_json(
+                &format!("/1/cards/{}", card_id),
+                &[("fields", "dateLastActivity")],
+            )
+            .await?;
+
+        if activity.date_last_activity != expected {
+            return Err(TrelloError::Conflict {
+                expected,
+                actual: activity.date_last_activity,
+            });
+        }
+        Ok(())
+    }
+}
@@ -1,44 +1,249 @@
+#[cfg(feature = "http")]
 use crate::client::TrelloClient;
 use crate::formatting::header;
+#[cfg(feature = "http")]
 use crate::trello_error::TrelloError;
-use crate::trello_object::{Renderable, TrelloObject};
+use crate::trello_object::{Detail, RenderOptions, Renderable, TrelloObject};
 
-use serde::Deserialize;
+use chrono::{DateTime, Utc};
+use colored::*;
+#[cfg(feature = "http")]
+use reqwest::blocking::multipart::{Form, Part};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "http")]
+use std::io::Read;
+#[cfg(feature = "http")]
+use url::Url;
 
+#[cfg(feature = "http")]
 type Result<T> = std::result::Result<T, TrelloError>;
 
-#[derive(Deserialize, Debug, Eq, PartialEq, Clone)]
+/// Attachments larger than this are not downloaded by `fetch_text`
+pub const MAX_TEXT_BYTES: u64 = 1024 * 1024;
+
+/// Extensions of attachments which are treated as text when their mime type
+/// is not known
+const TEXT_EXTENSIONS: [&str; 4] = [".txt", ".md", ".markdown", ".csv"];
+
+/// Callback invoked with the number of bytes sent so far and the
+/// total number of bytes to send (if known)
+pub type ProgressCallback = Box<dyn FnMut(u64, Option<u64>) + Send>;
+
+#[derive(Default)]
+pub struct UploadOptions {
+    /// Size of the upload in bytes. When not specified, the upload is sent
+    /// using chunked transfer encoding
+    pub length: Option<u64>,
+    /// Overrides the mime type Trello would otherwise infer from the file name
+    pub mime_type: Option<String>,
+    pub progress: Option<ProgressCallback>,
+}
+
+/// Wraps a reader to report progress as it is read by the request body
+#[cfg(feature = "http")]
+struct ProgressReader<R: Read> {
+    inner: R,
+    sent: u64,
+    total: Option<u64>,
+    callback: ProgressCallback,
+}
+
+#[cfg(feature = "http")]
+impl<R: Read> Read for ProgressReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let count = self.inner.read(buf)?;
+        self.sent += count as u64;
+        (self.callback)(self.sent, self.total);
+
+        Ok(count)
+    }
+}
+
+// https://developer.atlassian.com/cloud/trello/guides/rest-api/object-definitions/#attachment-object
+#[derive(Deserialize, Serialize, Debug, Eq, PartialEq, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Attachment {
     pub id: String,
     pub name: String,
     pub url: String,
+    pub bytes: Option<u64>,
+    pub date: Option<DateTime<Utc>>,
+    pub mime_type: Option<String>,
+    #[serde(default)]
+    pub is_upload: bool,
+    #[serde(default)]
+    pub previews: Vec<Preview>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Scaled preview of an uploaded image
+#[derive(Deserialize, Serialize, Debug, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Preview {
+    pub id: String,
+    pub url: String,
+    #[serde(default)]
+    pub width: u32,
+    #[serde(default)]
+    pub height: u32,
+    #[serde(default)]
+    pub bytes: u64,
+    #[serde(default)]
+    pub scaled: bool,
 }
 
 impl Attachment {
+    pub fn new(id: &str, name: &str, url: &str) -> Attachment {
+        Attachment {
+            id: String::from(id),
+            name: String::from(name),
+            url: String::from(url),
+            bytes: None,
+            date: None,
+            mime_type: None,
+            is_upload: false,
+            previews: vec![],
+            #[cfg(feature = "extra-fields")]
+            extra: serde_json::Map::new(),
+        }
+    }
+
+    /// Short description of the size and type of the attachment.
+    /// ```
+    /// let mut attachment = trello::Attachment::new("1", "cat.png", "https://example.com/cat.png");
+    /// assert_eq!(attachment.summary(), "link");
+    ///
+    /// attachment.is_upload = true;
+    /// attachment.bytes = Some(2_500_000);
+    /// attachment.mime_type = Some(String::from("image/png"));
+    /// assert_eq!(attachment.summary(), "image/png, 2.4 MB");
+    /// ```
+    pub fn summary(&self) -> String {
+        if !self.is_upload {
+            return String::from("link");
+        }
+
+        let mime_type = match self.mime_type.as_deref() {
+            Some("") | None => "unknown type",
+            Some(m) => m,
+        };
+
+        match self.bytes {
+            Some(bytes) => format!("{}, {}", mime_type, format_bytes(bytes)),
+            None => String::from(mime_type),
+        }
+    }
+
+    /// Whether the attachment is a plain text or Markdown document, judged by
+    /// its mime type or otherwise by its file name
+    pub fn is_text(&self) -> bool {
+        match self.mime_type.as_deref() {
+            Some(m) if !m.is_empty() => m.starts_with("text/"),
+            _ => {
+                let name = self.name.to_lowercase();
+                TEXT_EXTENSIONS.iter().any(|e| name.ends_with(e))
+            }
+        }
+    }
+
+    /// Downloads the contents of a text attachment (see `is_text`). Returns
+    /// `None` for other attachments, for links to other sites and for
+    /// uploads larger than `MAX_TEXT_BYTES`, whether or not Trello gave their
+    /// size. Fails with `TrelloError::UnsupportedUrl` for uploads which are
+    /// not served by Trello over https, as the credentials would be sent
+    /// along.
+    #[cfg(feature = "http")]
+    pub fn fetch_text(&self, client: &TrelloClient) -> Result<Option<String>> {
+        if !self.is_upload || !self.is_text() || self.bytes.unwrap_or(0) > MAX_TEXT_BYTES {
+            return Ok(None);
+        }
+
+        let url = Url::parse(&self.url)?;
+        if !is_trello_url(&url, client) {
+            return Err(TrelloError::UnsupportedUrl(self.url.clone()));
+        }
+
+        // uploads are only served to requests authorized in a header
+        let authorization = format!(
+            "OAuth oauth_consumer_key=\"{}\", oauth_token=\"{}\"",
+            client.config.key, client.config.token
+        );
+        let request = client.get(url).header("Authorization", authorization);
+
+        // the size is not always given, so never read more than the limit
+        let mut body = vec![];
+        client
+            .send(request)?
+            .take(MAX_TEXT_BYTES + 1)
+            .read_to_end(&mut body)?;
+        if body.len() as u64 > MAX_TEXT_BYTES {
+            return Ok(None);
+        }
+
+        Ok(Some(String::from_utf8_lossy(&body).into_owned()))
+    }
+
+    #[cfg(feature = "http")]
     pub fn get_all(client: &TrelloClient, card_id: &str) -> Result<Vec<Attachment>> {
         let url = client.config.get_trello_url(
             &format!("/1/cards/{}/attachments", card_id),
             &[("fields", &Attachment::get_fields().join(","))],
         )?;
 
-        Ok(client.client.get(url).send()?.error_for_status()?.json()?)
+        Ok(client.send(client.get(url))?.json()?)
     }
 
+    #[cfg(feature = "http")]
     pub fn apply(client: &TrelloClient, card_id: &str, file: &str) -> Result<Attachment> {
         let url = client
             .config
             .get_trello_url(&format!("/1/cards/{}/attachments", card_id), &[])?;
 
-        let form = reqwest::blocking::multipart::Form::new().file("file", file)?;
+        let form = Form::new().file("file", file)?;
 
-        Ok(client
-            .client
-            .post(url)
-            .multipart(form)
-            .send()?
-            .error_for_status()?
-            .json()?)
+        Ok(client.send(client.post(url).multipart(form))?.json()?)
+    }
+
+    /// Streams the contents of the given reader to Trello as an attachment with
+    /// the specified file name. Unlike `apply`, the contents are never entirely
+    /// loaded into memory, which makes this suitable for large uploads.
+    #[cfg(feature = "http")]
+    pub fn apply_reader<R: Read + Send + 'static>(
+        client: &TrelloClient,
+        card_id: &str,
+        name: &str,
+        reader: R,
+        options: UploadOptions,
+    ) -> Result<Attachment> {
+        let url = client
+            .config
+            .get_trello_url(&format!("/1/cards/{}/attachments", card_id), &[])?;
+
+        let reader: Box<dyn Read + Send> = match options.progress {
+            Some(callback) => Box::new(ProgressReader {
+                inner: reader,
+                sent: 0,
+                total: options.length,
+                callback,
+            }),
+            None => Box::new(reader),
+        };
+
+        let part = match options.length {
+            Some(length) => Part::reader_with_length(reader, length),
+            None => Part::reader(reader),
+        };
+
+        let mut part = part.file_name(name.to_string());
+        if let Some(mime_type) = &options.mime_type {
+            part = part.mime_str(mime_type)?;
+        }
+
+        let form = Form::new().part("file", part);
+
+        Ok(client.send(client.post(url).multipart(form))?.json()?)
     }
 }
 
@@ -52,16 +257,66 @@ impl TrelloObject for Attachment {
     }
 
     fn get_fields() -> &'static [&'static str] {
-        &["id", "name", "url"]
+        &[
+            "id", "name", "url", "bytes", "date", "mimeType", "isUpload", "previews",
+        ]
     }
 }
 
 impl Renderable for Attachment {
-    fn render(&self) -> String {
-        [header(&self.name, "-").as_str(), &self.url].join("\n")
+    fn render_with(&self, options: &RenderOptions) -> String {
+        if options.detail == Detail::Minimal {
+            return self.simple_render();
+        }
+
+        [
+            header(&self.name, "-").as_str(),
+            &self.url,
+            &self.summary().dimmed().to_string(),
+        ]
+        .join("\n")
     }
 
     fn simple_render(&self) -> String {
-        self.name.clone()
+        format!("{} {}", self.name, format!("({})", self.summary()).dimmed())
+    }
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
     }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = UNITS[0];
+    for u in &UNITS[1..] {
+        if size < 1024.0 {
+            break;
+        }
+        size /= 1024.0;
+        unit = u;
+    }
+
+    format!("{:.1} {}", size, unit)
+}
+
+/// Whether the url is served by Trello over https (or by the host the client
+/// is configured with), so that the credentials of the client can be sent to
+/// it. Uploads stored elsewhere, e.g. on S3, are never sent the credentials.
+#[cfg(feature = "http")]
+fn is_trello_url(url: &Url, client: &TrelloClient) -> bool {
+    let host = match url.host_str() {
+        Some(host) => host,
+        None => return false,
+    };
+    let configured = Url::parse(&client.config.host).ok();
+
+    (url.scheme() == "https" && (host == "trello.com" || host == "api.trello.com"))
+        || configured.is_some_and(|c| {
+            c.scheme() == url.scheme()
+                && c.host_str() == Some(host)
+                && c.port_or_known_default() == url.port_or_known_default()
+        })
 }
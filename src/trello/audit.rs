@@ -0,0 +1,288 @@
+//! Checks for workspace admins, such as who can see and administer each
+//! board of an organization, or which boards are no longer used.
+#[cfg(feature = "http")]
+use crate::board::{Board, BoardPatch};
+#[cfg(feature = "http")]
+use crate::client::TrelloClient;
+use crate::formatting::pad;
+use crate::ids::BoardId;
+#[cfg(feature = "http")]
+use crate::member::Member;
+#[cfg(feature = "http")]
+use crate::trello_error::TrelloError;
+use crate::trello_object::{RenderOptions, Renderable};
+
+#[cfg(feature = "http")]
+use chrono::Duration;
+use chrono::{DateTime, Utc};
+use colored::*;
+use serde::Deserialize;
+use unicode_width::UnicodeWidthStr;
+
+#[cfg(feature = "http")]
+type Result<T> = std::result::Result<T, TrelloError>;
+
+/// The boards to audit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuditTarget {
+    /// Every board of the organization (workspace) with the given id or name
+    Organization(String),
+    Boards(Vec<String>),
+}
+
+#[cfg(feature = "http")]
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+struct BoardPrefs {
+    /// "private", "org", "enterprise" or "public"
+    permission_level: String,
+}
+
+#[cfg(feature = "http")]
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+struct BoardWithPrefs {
+    id: BoardId,
+    name: String,
+    prefs: BoardPrefs,
+}
+
+#[cfg(feature = "http")]
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+struct Membership {
+    /// "admin", "normal" or "observer"
+    member_type: String,
+    member: Member,
+}
+
+/// Who can see and administer a board
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoardPermissions {
+    pub board_id: BoardId,
+    pub name: String,
+    /// "private", "org", "enterprise" or "public"
+    pub visibility: String,
+    /// The usernames of the board admins
+    pub admins: Vec<String>,
+    /// The usernames of the members who can only observe the board
+    pub observers: Vec<String>,
+}
+
+impl BoardPermissions {
+    pub fn is_public(&self) -> bool {
+        self.visibility == "public"
+    }
+
+    /// Whether anyone outside the regular members of the board can see it
+    pub fn is_exposed(&self) -> bool {
+        self.is_public() || !self.observers.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PermissionsReport {
+    pub boards: Vec<BoardPermissions>,
+}
+
+impl PermissionsReport {
+    /// The boards which are public or have observers
+    pub fn exposed(&self) -> Vec<&BoardPermissions> {
+        self.boards.iter().filter(|b| b.is_exposed()).collect()
+    }
+}
+
+const HEADERS: [&str; 4] = ["Board", "Visibility", "Admins", "Observers"];
+
+impl Renderable for PermissionsReport {
+    /// Renders a table with a row per board. Exposed boards are highlighted.
+    fn render_with(&self, _options: &RenderOptions) -> String {
+        let rows = self
+            .boards
+            .iter()
+            .map(|b| {
+                [
+                    b.name.clone(),
+                    b.visibility.clone(),
+                    b.admins.join(", "),
+                    b.observers.join(", "),
+                ]
+            })
+            .collect::<Vec<[String; 4]>>();
+
+        let mut widths = HEADERS.map(UnicodeWidthStr::width);
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(UnicodeWidthStr::width(cell.as_str()));
+            }
+        }
+
+        let format_row = |cells: &[&str]| {
+            cells
+                .iter()
+                .zip(widths)
+                .map(|(cell, width)| pad(cell, width))
+                .collect::<Vec<String>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        };
+
+        let mut result = vec![format_row(&HEADERS).bold().to_string()];
+        for (board, row) in self.boards.iter().zip(&rows) {
+            let line = format_row(&row.iter().map(String::as_str).collect::<Vec<&str>>());
+            if board.is_exposed() {
+                result.push(line.yellow().to_string());
+            } else {
+                result.push(line);
+            }
+        }
+
+        result.join("\n")
+    }
+
+    fn simple_render(&self) -> String {
+        format!(
+            "{} boards, {} public or observed",
+            self.boards.len(),
+            self.exposed().len()
+        )
+    }
+}
+
+/// Reports the visibility, admins and observers of each board of the target
+#[cfg(feature = "http")]
+pub fn permissions(client: &TrelloClient, target: &AuditTarget) -> Result<PermissionsReport> {
+    let fields = [("fields", "id,name,prefs")];
+
+    let boards: Vec<BoardWithPrefs> = match target {
+        AuditTarget::Organization(org) => {
+            let url = client
+                .config
+                .get_trello_url(&format!("/1/organizations/{}/boards", org), &fields)?;
+            client.send(client.get(url))?.json()?
+        }
+        AuditTarget::Boards(board_ids) => board_ids
+            .iter()
+            .map(|board_id| {
+                let url = client
+                    .config
+                    .get_trello_url(&format!("/1/boards/{}", board_id), &fields)?;
+                Ok(client.send(client.get(url))?.json()?)
+            })
+            .collect::<Result<_>>()?,
+    };
+
+    let mut report = PermissionsReport { boards: vec![] };
+    for board in boards {
+        let url = client.config.get_trello_url(
+            &format!("/1/boards/{}/memberships", board.id),
+            &[("member", "true"), ("member_fields", "fullName,username")],
+        )?;
+        let memberships: Vec<Membership> = client.send(client.get(url))?.json()?;
+
+        let usernames = |member_type: &str| {
+            memberships
+                .iter()
+                .filter(|m| m.member_type == member_type)
+                .map(|m| m.member.username.clone())
+                .collect::<Vec<String>>()
+        };
+
+        report.boards.push(BoardPermissions {
+            board_id: board.id,
+            name: board.name,
+            visibility: board.prefs.permission_level,
+            admins: usernames("admin"),
+            observers: usernames("observer"),
+        });
+    }
+
+    Ok(report)
+}
+
+/// An open board without recent activity
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct StaleBoard {
+    pub id: BoardId,
+    pub name: String,
+    #[serde(default)]
+    pub url: String,
+    /// None for boards which were never active
+    pub date_last_activity: Option<DateTime<Utc>>,
+}
+
+impl StaleBoard {
+    /// The number of whole days since the last activity on the board
+    pub fn idle_days(&self, now: DateTime<Utc>) -> Option<i64> {
+        self.date_last_activity
+            .map(|last_activity| (now - last_activity).num_days())
+    }
+}
+
+impl Renderable for StaleBoard {
+    fn render_with(&self, options: &RenderOptions) -> String {
+        let activity = match self.date_last_activity {
+            Some(last_activity) => format!(
+                "last active {}, {} days ago",
+                options.format_date(&last_activity, "%Y-%m-%d"),
+                (options.now() - last_activity).num_days()
+            ),
+            None => String::from("never active"),
+        };
+        format!("{} {}", self.name, format!("({})", activity).dimmed())
+    }
+
+    fn simple_render(&self) -> String {
+        self.name.clone()
+    }
+}
+
+/// The open boards of the organization without any activity in the last
+/// `days` days, least recently active first
+#[cfg(feature = "http")]
+pub fn stale_boards(client: &TrelloClient, org: &str, days: i64) -> Result<Vec<StaleBoard>> {
+    stale_boards_at(client, org, days, Utc::now())
+}
+
+#[cfg(feature = "http")]
+pub fn stale_boards_at(
+    client: &TrelloClient,
+    org: &str,
+    days: i64,
+    now: DateTime<Utc>,
+) -> Result<Vec<StaleBoard>> {
+    let url = client.config.get_trello_url(
+        &format!("/1/organizations/{}/boards", org),
+        &[
+            ("filter", "open"),
+            ("fields", "id,name,url,dateLastActivity"),
+        ],
+    )?;
+    let boards: Vec<StaleBoard> = client.send(client.get(url))?.json()?;
+
+    let cutoff = now - Duration::days(days);
+    let mut stale = boards
+        .into_iter()
+        .filter(|b| b.date_last_activity.map_or(true, |d| d < cutoff))
+        .collect::<Vec<StaleBoard>>();
+    stale.sort_by_key(|b| b.date_last_activity);
+
+    Ok(stale)
+}
+
+/// Closes (archives) the given boards, returning the number of boards
+/// closed. When `dry_run` is set, nothing is changed in Trello and the
+/// number of boards which would be closed is returned.
+#[cfg(feature = "http")]
+pub fn close_boards(client: &TrelloClient, boards: &[StaleBoard], dry_run: bool) -> Result<usize> {
+    for board in boards {
+        debug!("Closing board {} ({})", board.name, board.id);
+        if !dry_run {
+            Board::patch(client, &board.id, &BoardPatch::new().closed(true))?;
+        }
+    }
+
+    Ok(boards.len())
+}
@@ -0,0 +1,211 @@
+//! Append-only log of every change made through a client. Each create,
+//! update or delete request is written to the log file as a single line
+//! of JSON (see `AuditEntry`) once Trello has responded to it.
+#[cfg(feature = "http")]
+use crate::response::BodySize;
+#[cfg(feature = "http")]
+use crate::transfer;
+use crate::trello_error::TrelloError;
+
+use chrono::{DateTime, Utc};
+#[cfg(feature = "http")]
+use reqwest::blocking::{Client, Request, Response};
+#[cfg(feature = "http")]
+use reqwest::header::{HeaderValue, ACCEPT_ENCODING};
+#[cfg(feature = "http")]
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "http")]
+use serde_json::Map;
+use serde_json::Value;
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+type Result<T> = std::result::Result<T, TrelloError>;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AuditEntry {
+    pub timestamp: DateTime<Utc>,
+    pub actor: String,
+    pub method: String,
+    /// Path of the request. Credentials are never included.
+    pub endpoint: String,
+    pub status: u16,
+    /// The id, name and changed fields of the object before the change.
+    /// Only available for updates and deletions when the object could be fetched.
+    pub before: Option<Value>,
+    /// The id, name and changed fields of the object returned by Trello
+    pub after: Option<Value>,
+}
+
+#[derive(Debug)]
+pub struct AuditLog {
+    path: PathBuf,
+    actor: String,
+    lock: Mutex<()>,
+}
+
+impl AuditLog {
+    /// Creates an audit log writing to the file at the given path. The actor
+    /// defaults to the name of the current system user.
+    pub fn new<P: AsRef<Path>>(path: P) -> AuditLog {
+        let actor = env::var("USER")
+            .or_else(|_| env::var("USERNAME"))
+            .unwrap_or_else(|_| String::from("unknown"));
+
+        AuditLog {
+            path: path.as_ref().to_path_buf(),
+            actor,
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// Sets who the changes are attributed to, e.g. the name of a script
+    pub fn actor(mut self, actor: &str) -> AuditLog {
+        self.actor = String::from(actor);
+        self
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn append(&self, entry: &AuditEntry) -> Result<()> {
+        let line = serde_json::to_string(entry).map_err(io::Error::from)?;
+
+        let _guard = self.lock.lock().unwrap();
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", line)?;
+
+        Ok(())
+    }
+
+    /// Reads all the entries written to the log file so far
+    pub fn entries(&self) -> Result<Vec<AuditEntry>> {
+        fs::read_to_string(&self.path)?
+            .lines()
+            .map(|l| {
+                serde_json::from_str(l).map_err(|source| TrelloError::Deserialize {
+                    source,
+                    body: l.to_string(),
+                })
+            })
+            .collect()
+    }
+
+    /// Executes a mutating request and records it in the log. Failing to write
+    /// to the log does not fail the request, as the change has already been made.
+    /// Like any other response, a successful one is decompressed and its size
+    /// is returned; error responses are returned as they are.
+    #[cfg(feature = "http")]
+    pub(crate) fn execute(
+        &self,
+        client: &Client,
+        mut request: Request,
+    ) -> Result<(Response, Option<BodySize>)> {
+        let method = request.method().clone();
+        let url = request.url().clone();
+        let fields = request
+            .body()
+            .and_then(|b| b.as_bytes())
+            .map(|b| {
+                url::form_urlencoded::parse(b)
+                    .map(|(k, _)| k.into_owned())
+                    .collect::<Vec<String>>()
+            })
+            .unwrap_or_default();
+
+        let before = match method {
+            Method::PUT if !fields.is_empty() => fetch(client, &url, &fields),
+            Method::DELETE => fetch(client, &url, &[]),
+            _ => None,
+        };
+
+        request.headers_mut().insert(
+            ACCEPT_ENCODING,
+            HeaderValue::from_static(transfer::ACCEPT_ENCODING),
+        );
+        let response = client.execute(request)?;
+        let status = response.status();
+
+        // the body of an error response is left unread, so that the error
+        // still names the url of the request
+        let (response, size, after) = if status.is_success() {
+            let (response, size) = transfer::decode(response)?;
+            let version = response.version();
+            let headers = response.headers().clone();
+            let body = response.bytes()?.to_vec();
+
+            let after = serde_json::from_slice(&body)
+                .ok()
+                .and_then(|v| summarize(&v, &fields));
+            let response = transfer::rebuild(status, version, headers, body);
+            (response, Some(size), after)
+        } else {
+            (response, None, None)
+        };
+
+        let entry = AuditEntry {
+            timestamp: Utc::now(),
+            actor: self.actor.clone(),
+            method: method.to_string(),
+            endpoint: url.path().to_string(),
+            status: status.as_u16(),
+            before,
+            after,
+        };
+
+        if let Err(e) = self.append(&entry) {
+            warn!("Unable to write to audit log {:?}: {}", self.path, e);
+        }
+
+        Ok((response, size))
+    }
+}
+
+/// Retrieves the current state of the object at the url, limited to the given fields
+#[cfg(feature = "http")]
+fn fetch(client: &Client, url: &url::Url, fields: &[String]) -> Option<Value> {
+    let mut url = url.clone();
+    let mut requested = vec![String::from("id"), String::from("name")];
+    for field in fields {
+        if !requested.contains(field) {
+            requested.push(field.clone());
+        }
+    }
+    url.query_pairs_mut()
+        .append_pair("fields", &requested.join(","));
+
+    let value = client
+        .get(url)
+        .send()
+        .and_then(|r| r.error_for_status())
+        .and_then(|r| r.json::<Value>());
+
+    match value {
+        Ok(v) => summarize(&v, fields),
+        Err(e) => {
+            debug!("Unable to fetch object state for audit log: {}", e);
+            None
+        }
+    }
+}
+
+#[cfg(feature = "http")]
+fn summarize(value: &Value, fields: &[String]) -> Option<Value> {
+    let object = value.as_object()?;
+
+    let summary = object
+        .iter()
+        .filter(|(k, _)| *k == "id" || *k == "name" || fields.contains(k))
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect::<Map<String, Value>>();
+
+    Some(Value::Object(summary))
+}
@@ -0,0 +1,121 @@
+//! Storage of Trello credentials in the operating system keyring
+//! (macOS Keychain, Secret Service or Windows Credential Manager).
+use crate::client::{ClientConfig, TrelloClient};
+use crate::member::Member;
+use crate::trello_error::TrelloError;
+
+use std::io::{self, BufRead, Write};
+use std::process;
+
+type Result<T> = std::result::Result<T, TrelloError>;
+
+const SERVICE: &str = "tro";
+const AUTHORIZE_URL: &str = "https://trello.com/1/authorize";
+
+fn entry(key: &str) -> Result<keyring::Entry> {
+    Ok(keyring::Entry::new(SERVICE, key)?)
+}
+
+/// Stores the token associated with the given developer key in the
+/// keyring. Any token previously stored for the key is replaced.
+pub fn store_token(key: &str, token: &str) -> Result<()> {
+    debug!("Storing token for key {} in keyring", key);
+    entry(key)?.set_password(token)?;
+
+    Ok(())
+}
+
+/// Retrieves the token associated with the given developer key from
+/// the keyring.
+pub fn get_token(key: &str) -> Result<String> {
+    debug!("Retrieving token for key {} from keyring", key);
+    Ok(entry(key)?.get_password()?)
+}
+
+/// Removes the token associated with the given developer key from
+/// the keyring.
+pub fn delete_token(key: &str) -> Result<()> {
+    debug!("Deleting token for key {} from keyring", key);
+    entry(key)?.delete_password()?;
+
+    Ok(())
+}
+
+/// Builds a ClientConfig for the given host and developer key using
+/// the token stored in the keyring.
+pub fn load_config(host: &str, key: &str) -> Result<ClientConfig> {
+    let token = get_token(key)?;
+
+    Ok(ClientConfig::new(host, &token, key))
+}
+
+/// Gets the URL a user needs to visit in order to grant the given developer
+/// key read and write access to their account.
+/// ```
+/// # fn main() -> Result<(), url::ParseError> {
+/// let url = trello::auth::authorize_url("some-key", "tro")?;
+/// assert_eq!(
+///     url.to_string(),
+///     "https://trello.com/1/authorize?expiration=never&name=tro&scope=read%2Cwrite&response_type=token&key=some-key",
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn authorize_url(key: &str, app_name: &str) -> std::result::Result<url::Url, url::ParseError> {
+    url::Url::parse_with_params(
+        AUTHORIZE_URL,
+        &[
+            ("expiration", "never"),
+            ("name", app_name),
+            ("scope", "read,write"),
+            ("response_type", "token"),
+            ("key", key),
+        ],
+    )
+}
+
+/// Attempts to open the given url using the default browser of the platform.
+/// Failure to do so is not an error as the url is also displayed to the user.
+fn open_browser(url: &str) {
+    let result = if cfg!(target_os = "macos") {
+        process::Command::new("open").arg(url).spawn()
+    } else if cfg!(target_os = "windows") {
+        process::Command::new("cmd")
+            .args(["/C", "start", "", url])
+            .spawn()
+    } else {
+        process::Command::new("xdg-open").arg(url).spawn()
+    };
+
+    if let Err(e) = result {
+        debug!("Unable to open browser: {}", e);
+    }
+}
+
+/// Walks the user through generating a token for the given developer key.
+/// Trello's authorization page is opened in a browser and the user is prompted
+/// to paste back the token it displays. The token is validated against the
+/// authenticated member endpoint before a ready to use client is returned.
+pub fn authorize_interactive(key: &str) -> Result<TrelloClient> {
+    let url = authorize_url(key, SERVICE)?;
+
+    eprintln!("Visit the following url to authorize access to your account:");
+    eprintln!();
+    eprintln!("{}", url);
+    eprintln!();
+    open_browser(url.as_str());
+
+    eprint!("Paste the token displayed by Trello: ");
+    io::stderr().flush()?;
+
+    let mut token = String::new();
+    io::stdin().lock().read_line(&mut token)?;
+
+    let config = ClientConfig::new(&ClientConfig::default_host(), token.trim(), key);
+    let client = TrelloClient::new(config);
+
+    let member = Member::me(&client)?;
+    debug!("Authorized as {}", member.username);
+
+    Ok(client)
+}
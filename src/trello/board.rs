@@ -1,23 +1,99 @@
+use crate::card::Card;
+#[cfg(feature = "http")]
+use crate::card::CardPatch;
+use crate::checklist::Checklist;
+#[cfg(feature = "http")]
 use crate::client::TrelloClient;
-use crate::formatting::title;
+#[cfg(feature = "http")]
+use crate::diff::{BoardDiff, Change};
+#[cfg(feature = "http")]
+use crate::expand::Expand;
+use crate::formatting::{escape_html, pad, title, unique_prefix_len, wrap};
+use crate::ids::BoardId;
+use crate::label::Label;
 use crate::list::List;
+use crate::loaded::Loaded;
+use crate::member::Member;
+#[cfg(feature = "http")]
+use crate::progress::RunOptions;
+#[cfg(feature = "http")]
+use crate::response::Response;
+#[cfg(feature = "http")]
+use crate::safety::{ConfirmationToken, Deletion};
 use crate::trello_error::TrelloError;
-use crate::trello_object::{Renderable, TrelloObject};
+use crate::trello_object::{RenderOptions, Renderable, TrelloObject};
 
+use chrono::{DateTime, NaiveTime, Utc};
 use colored::*;
-use serde::Deserialize;
+#[cfg(feature = "http")]
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fs;
+use std::path::Path;
+#[cfg(feature = "http")]
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+#[cfg(feature = "http")]
+use std::sync::mpsc;
+#[cfg(feature = "http")]
+use std::thread;
 
 type Result<T> = std::result::Result<T, TrelloError>;
 
+#[cfg(feature = "http")]
+const GET_MANY_PHASE: &str = "Retrieving boards";
+
+/// The number of boards `Board::get_many_nested` retrieves at the same time,
+/// as each of them makes several requests which count towards the rate limit
+#[cfg(feature = "http")]
+const GET_MANY_WORKERS: usize = 4;
+
+/// Width of each column in `Board::render_columns`
+const COLUMN_WIDTH: usize = 24;
+/// Spaces between columns in `Board::render_columns`
+const COLUMN_GAP: usize = 2;
+
 // https://developer.atlassian.com/cloud/trello/guides/rest-api/object-definitions/#board-object
-#[derive(Deserialize, Debug, Eq, PartialEq, Clone)]
+#[derive(Deserialize, Serialize, Debug, Eq, PartialEq, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Board {
-    pub id: String,
+    pub id: BoardId,
     pub name: String,
     pub closed: bool,
     pub url: String,
-    pub lists: Option<Vec<List>>,
+    #[serde(default)]
+    pub subscribed: bool,
+    #[serde(default)]
+    pub lists: Loaded<List>,
+    /// Only present when requested, see `Expand`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<Label>>,
+    /// Only present when requested, see `Expand`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members: Option<Vec<Member>>,
+    /// Only present when requested, see `Expand`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checklists: Option<Vec<Checklist>>,
+    /// The custom field definitions of the board. Only present when
+    /// requested, see `Expand`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_fields: Option<Vec<serde_json::Value>>,
+    /// Only present when requested, see `BoardField`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub desc: Option<String>,
+    /// The board preferences such as its visibility and background. Only
+    /// present when requested, see `BoardField`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefs: Option<serde_json::Map<String, serde_json::Value>>,
+    /// Only present when requested, see `BoardField`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date_last_activity: Option<DateTime<Utc>>,
+    /// Only present when requested, see `BoardField`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id_organization: Option<String>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 impl TrelloObject for Board {
@@ -30,17 +106,27 @@ impl TrelloObject for Board {
     }
 
     fn get_fields() -> &'static [&'static str] {
-        &["id", "name", "closed", "url"]
+        &["id", "name", "closed", "url", "subscribed"]
     }
 }
 
 impl Renderable for Board {
-    fn render(&self) -> String {
+    fn render_with(&self, options: &RenderOptions) -> String {
         let mut result = vec![title(&self.name).bold().to_string()];
-        if let Some(lists) = &self.lists {
+        if options.show_ids {
+            result.push(self.id.dimmed().to_string());
+        }
+        if options.show_urls && !self.url.is_empty() {
+            result.push(self.url.dimmed().to_string());
+        }
+        let mut options = *options;
+        if let Some(min_len) = options.id_prefix {
+            options.id_prefix = Some(self.card_id_prefix_len(min_len));
+        }
+        if let Loaded::Fetched(lists) = &self.lists {
             for list in lists {
                 result.push(String::from(""));
-                result.push(list.render());
+                result.push(list.render_with(&options));
             }
         }
         result.join("\n")
@@ -51,56 +137,571 @@ impl Renderable for Board {
     }
 }
 
+// The subset of the document produced by Trello's "Export as JSON" which
+// is needed to build the nested Board model
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Export {
+    id: String,
+    name: String,
+    closed: bool,
+    #[serde(default)]
+    url: String,
+    #[serde(default)]
+    lists: Vec<ExportList>,
+    #[serde(default)]
+    cards: Vec<ExportCard>,
+}
+
+#[derive(Deserialize)]
+struct ExportList {
+    id: String,
+    name: String,
+    closed: bool,
+    #[serde(default)]
+    pos: f64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ExportCard {
+    id: String,
+    name: String,
+    #[serde(default)]
+    desc: String,
+    closed: bool,
+    #[serde(default)]
+    url: String,
+    id_list: String,
+    #[serde(default)]
+    labels: Vec<ExportLabel>,
+    due: Option<DateTime<Utc>>,
+    date_last_activity: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pos: f64,
+}
+
+#[derive(Deserialize)]
+struct ExportLabel {
+    id: String,
+    #[serde(default)]
+    name: String,
+    // labels without a color are exported as null
+    color: Option<String>,
+}
+
+/// Org mode tags may only contain alphanumeric characters, '_', '@', '#' and '%'
+fn org_tag(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_alphanumeric() || "_@#%".contains(c) {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+fn org_timestamp(date: &DateTime<Utc>) -> String {
+    if date.time() == NaiveTime::from_hms(0, 0, 0) {
+        date.format("<%Y-%m-%d %a>").to_string()
+    } else {
+        date.format("<%Y-%m-%d %a %H:%M>").to_string()
+    }
+}
+
+// Trello label colors which have no CSS color of the same name
+// Only the colors Trello gives labels are used, anything else could escape
+// the style attribute. The dark and light shades are shown as the base color.
+fn html_color(color: &str) -> &'static str {
+    let base = color
+        .strip_suffix("_dark")
+        .or_else(|| color.strip_suffix("_light"))
+        .unwrap_or(color);
+    match base {
+        "green" => "green",
+        "yellow" => "goldenrod",
+        "orange" => "orange",
+        "red" => "red",
+        "purple" => "purple",
+        "blue" => "blue",
+        "sky" => "deepskyblue",
+        "lime" => "limegreen",
+        "pink" => "hotpink",
+        "black" => "black",
+        _ => "gray",
+    }
+}
+
+fn html_link(url: &str, text: &str) -> String {
+    if url.is_empty() {
+        escape_html(text)
+    } else {
+        format!("<a href=\"{}\">{}</a>", escape_html(url), escape_html(text))
+    }
+}
+
+const HTML_STYLE: &str = "\
+body { font-family: sans-serif; background: #f4f5f7; margin: 1em; }
+.lists { display: flex; align-items: flex-start; gap: 1em; overflow-x: auto; }
+.list { background: #ebecf0; border-radius: 4px; padding: 0.5em; min-width: 16em; max-width: 16em; }
+.list h2 { font-size: 1em; margin: 0.25em 0 0.5em; }
+.card { background: white; border-radius: 4px; padding: 0.5em; margin-bottom: 0.5em; box-shadow: 0 1px 0 #ccc; }
+.card a { color: inherit; text-decoration: none; }
+.label { display: inline-block; color: white; border-radius: 3px; padding: 0 0.4em; margin-right: 0.25em; font-size: 0.8em; }
+.desc { white-space: pre-wrap; color: #5e6c84; font-size: 0.9em; margin: 0.5em 0 0; }
+.due { color: #5e6c84; font-size: 0.8em; }";
+
+fn by_pos(a: f64, b: f64) -> Ordering {
+    a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+}
+
+/// Fields of a board which are only retrieved when asked for, as they can
+/// make board listings considerably larger
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoardField {
+    Desc,
+    Prefs,
+    DateLastActivity,
+    IdOrganization,
+}
+
+impl BoardField {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BoardField::Desc => "desc",
+            BoardField::Prefs => "prefs",
+            BoardField::DateLastActivity => "dateLastActivity",
+            BoardField::IdOrganization => "idOrganization",
+        }
+    }
+
+    /// The fields every board is retrieved with along with the given fields
+    #[cfg(feature = "http")]
+    fn with_defaults(fields: &[BoardField]) -> String {
+        let mut result = Board::get_fields().to_vec();
+        result.extend(fields.iter().map(|f| f.as_str()));
+        result.join(",")
+    }
+}
+
+/// A partial update of a board. Only the fields which have been explicitly
+/// set are sent to Trello.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BoardPatch {
+    name: Option<String>,
+    desc: Option<String>,
+    closed: Option<bool>,
+}
+
+impl BoardPatch {
+    pub fn new() -> BoardPatch {
+        BoardPatch::default()
+    }
+
+    pub fn name(mut self, name: &str) -> BoardPatch {
+        self.name = Some(String::from(name));
+        self
+    }
+
+    pub fn desc(mut self, desc: &str) -> BoardPatch {
+        self.desc = Some(String::from(desc));
+        self
+    }
+
+    pub fn closed(mut self, closed: bool) -> BoardPatch {
+        self.closed = Some(closed);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self == &BoardPatch::default()
+    }
+
+    pub fn params(&self) -> Vec<(&'static str, String)> {
+        let mut params = vec![];
+
+        if let Some(name) = &self.name {
+            params.push(("name", name.clone()));
+        }
+        if let Some(desc) = &self.desc {
+            params.push(("desc", desc.clone()));
+        }
+        if let Some(closed) = self.closed {
+            params.push(("closed", closed.to_string()));
+        }
+
+        params
+    }
+}
+
 impl Board {
     pub fn new(id: &str, name: &str, lists: Option<Vec<List>>, url: &str) -> Board {
         Board {
-            id: String::from(id),
+            id: BoardId::new(id),
             name: String::from(name),
             url: String::from(url),
-            lists,
+            lists: lists.into(),
             closed: false,
+            subscribed: false,
+            labels: None,
+            members: None,
+            checklists: None,
+            custom_fields: None,
+            desc: None,
+            prefs: None,
+            date_last_activity: None,
+            id_organization: None,
+            #[cfg(feature = "extra-fields")]
+            extra: serde_json::Map::new(),
+        }
+    }
+
+    /// Reads a board from a file produced by Trello's "Export as JSON" feature.
+    /// Lists and cards are nested in the order they appear on the board, and
+    /// archived lists and cards are included.
+    pub fn from_trello_export<P: AsRef<Path>>(path: P) -> Result<Board> {
+        let contents = fs::read_to_string(path)?;
+        let mut export: Export =
+            serde_json::from_str(&contents).map_err(|source| TrelloError::Deserialize {
+                source,
+                body: String::new(),
+            })?;
+
+        export.lists.sort_by(|a, b| by_pos(a.pos, b.pos));
+        export.cards.sort_by(|a, b| by_pos(a.pos, b.pos));
+
+        let mut lists = export
+            .lists
+            .into_iter()
+            .map(|l| {
+                let mut list = List::new(&l.id, &l.name, Some(vec![]));
+                list.closed = l.closed;
+                list
+            })
+            .collect::<Vec<List>>();
+
+        for c in export.cards {
+            let list = match lists.iter_mut().find(|l| l.id == c.id_list) {
+                Some(list) => list,
+                None => {
+                    debug!("Skipping card {} in unknown list {}", c.id, c.id_list);
+                    continue;
+                }
+            };
+
+            let labels = c
+                .labels
+                .into_iter()
+                .map(|l| Label::new(&l.id, &l.name, &l.color.unwrap_or_default()))
+                .collect();
+
+            let mut card = Card::new(&c.id, &c.name, &c.desc, Some(labels), &c.url, c.due);
+            card.closed = c.closed;
+            card.date_last_activity = c.date_last_activity;
+
+            list.cards.get_or_insert_empty().push(card);
+        }
+
+        let mut board = Board::new(&export.id, &export.name, Some(lists), &export.url);
+        board.closed = export.closed;
+
+        Ok(board)
+    }
+
+    /// Creates a new board from an imported board (see the `import` module),
+    /// including all its lists and cards in the same order. Returns the created board.
+    #[cfg(feature = "http")]
+    pub fn apply_import(client: &TrelloClient, import: &Board) -> Result<Board> {
+        let url = client.config.get_trello_url("/1/boards/", &[])?;
+        let params = [("name", import.name.as_str()), ("defaultLists", "false")];
+        let mut board: Board = client.send(client.post(url).form(&params))?.json()?;
+
+        let mut lists = vec![];
+        for list in import.lists.iter() {
+            let url = client.config.get_trello_url("/1/lists/", &[])?;
+            let params = [
+                ("name", list.name.as_str()),
+                ("idBoard", &board.id),
+                ("pos", "bottom"),
+            ];
+            let mut created: List = client.send(client.post(url).form(&params))?.json()?;
+
+            let mut cards = vec![];
+            for card in list.cards.iter() {
+                cards.push(Card::create(client, &created.id, card)?);
+            }
+            created.cards = Loaded::Fetched(cards);
+            lists.push(created);
+        }
+
+        board.lists = Loaded::Fetched(lists);
+        Ok(board)
+    }
+
+    /// The length of card id prefixes (at least `min_len`) which is needed for
+    /// every card id prefix to be unique within the board
+    pub fn card_id_prefix_len(&self, min_len: usize) -> usize {
+        let ids = self
+            .lists
+            .iter()
+            .flat_map(|l| l.cards.iter())
+            .map(|c| c.id.as_str());
+
+        unique_prefix_len(ids, min_len)
+    }
+
+    /// Renders the board as an Emacs org-mode document. Lists become top level
+    /// headings and cards become TODO (or DONE when archived) items below them,
+    /// tagged with their labels and scheduled on their due date.
+    pub fn render_org(&self) -> String {
+        let mut result = vec![format!("#+TITLE: {}", self.name)];
+
+        for list in self.lists.iter() {
+            result.push(format!("* {}", list.name));
+
+            for card in list.cards.iter() {
+                let keyword = if card.closed { "DONE" } else { "TODO" };
+                let mut heading = format!("** {} {}", keyword, card.name);
+
+                let tags = card
+                    .labels
+                    .iter()
+                    .flatten()
+                    .filter(|l| !l.name.is_empty())
+                    .map(|l| org_tag(&l.name))
+                    .collect::<Vec<String>>();
+                if !tags.is_empty() {
+                    heading.push_str(&format!(" :{}:", tags.join(":")));
+                }
+                result.push(heading);
+
+                if let Some(due) = &card.due {
+                    result.push(format!("   SCHEDULED: {}", org_timestamp(due)));
+                }
+                // indented so that lines starting with '*' are not read as headings
+                for line in card.desc.lines() {
+                    result.push(format!("   {}", line).trim_end().to_string());
+                }
+            }
+        }
+
+        result.join("\n")
+    }
+
+    /// Renders a read-only snapshot of the board as a standalone HTML page,
+    /// with the open lists as columns and the open cards as links back to
+    /// Trello, along with their labels, due dates and descriptions.
+    pub fn render_html(&self) -> String {
+        let mut result = vec![
+            String::from("<!DOCTYPE html>"),
+            String::from("<html>"),
+            String::from("<head>"),
+            String::from("<meta charset=\"utf-8\">"),
+            format!("<title>{}</title>", escape_html(&self.name)),
+            format!("<style>\n{}\n</style>", HTML_STYLE),
+            String::from("</head>"),
+            String::from("<body>"),
+            format!("<h1>{}</h1>", html_link(&self.url, &self.name)),
+            String::from("<div class=\"lists\">"),
+        ];
+
+        for list in self.lists.iter().filter(|l| !l.closed) {
+            result.push(String::from("<section class=\"list\">"));
+            result.push(format!("<h2>{}</h2>", escape_html(&list.name)));
+
+            for card in list.cards.iter().filter(|c| !c.closed) {
+                result.push(String::from("<article class=\"card\">"));
+                for label in card.labels.iter().flatten() {
+                    result.push(format!(
+                        "<span class=\"label\" style=\"background: {}\">{}</span>",
+                        html_color(&label.color),
+                        escape_html(&label.name)
+                    ));
+                }
+                result.push(format!("<h3>{}</h3>", html_link(&card.url, &card.name)));
+                if let Some(due) = &card.due {
+                    result.push(format!(
+                        "<div class=\"due\">Due {}</div>",
+                        due.format("%Y-%m-%d %H:%M")
+                    ));
+                }
+                if !card.desc.trim().is_empty() {
+                    result.push(format!(
+                        "<p class=\"desc\">{}</p>",
+                        escape_html(card.desc.trim())
+                    ));
+                }
+                result.push(String::from("</article>"));
+            }
+
+            result.push(String::from("</section>"));
+        }
+
+        result.push(String::from("</div>"));
+        result.push(String::from("</body>"));
+        result.push(String::from("</html>"));
+        result.join("\n")
+    }
+
+    /// Renders the open lists side by side as columns, like a kanban wall.
+    /// As many columns as fit within `width` are placed next to each other,
+    /// with the remaining lists wrapped onto further rows of columns. Card
+    /// names are wrapped to the width of their column.
+    pub fn render_columns(&self, width: usize) -> String {
+        let column_width = COLUMN_WIDTH.min(width.max(1));
+        let per_row = ((width + COLUMN_GAP) / (column_width + COLUMN_GAP)).max(1);
+
+        let columns = self
+            .lists
+            .iter()
+            .filter(|l| !l.closed)
+            .map(|list| {
+                let mut lines = vec![(list.name.clone(), true)];
+                lines.push(("─".repeat(column_width), false));
+                for card in list.cards.iter().filter(|c| !c.closed) {
+                    let wrapped = wrap(&card.name, column_width.saturating_sub(2));
+                    for (index, line) in wrapped.into_iter().enumerate() {
+                        let bullet = if index == 0 { "• " } else { "  " };
+                        lines.push((format!("{}{}", bullet, line), false));
+                    }
+                }
+                lines
+            })
+            .collect::<Vec<Vec<(String, bool)>>>();
+
+        let mut result = vec![title(&self.name).bold().to_string()];
+
+        for row in columns.chunks(per_row) {
+            result.push(String::new());
+            let height = row.iter().map(|c| c.len()).max().unwrap_or(0);
+            for index in 0..height {
+                let line = row
+                    .iter()
+                    .map(|column| match column.get(index) {
+                        Some((text, true)) => {
+                            let text = wrap(text, column_width)
+                                .into_iter()
+                                .next()
+                                .unwrap_or_default();
+                            // styled after padding, the pad would survive the trim
+                            let padded = pad(&text, column_width);
+                            format!("{}{}", text.bold(), &padded[text.len()..])
+                        }
+                        Some((text, false)) => pad(text, column_width),
+                        None => " ".repeat(column_width),
+                    })
+                    .collect::<Vec<String>>()
+                    .join(&" ".repeat(COLUMN_GAP));
+                result.push(line.trim_end().to_string());
+            }
         }
+
+        result.join("\n")
     }
 
     pub fn filter(&self, filter_name: &str) -> Board {
         let mut result = self.clone();
 
-        result.lists = if let Some(lists) = result.lists {
-            Some(lists.into_iter().map(|l| l.filter(filter_name)).collect())
-        } else {
-            None
-        };
+        result.lists = result
+            .lists
+            .map(|lists| lists.into_iter().map(|l| l.filter(filter_name)).collect());
         result
     }
 
+    /// The open lists of the board along with their cards, retrieving them on
+    /// first access (see `Board::retrieve_nested`)
+    #[cfg(feature = "http")]
+    pub fn lists(&mut self, client: &TrelloClient) -> Result<&[List]> {
+        self.retrieve_nested(client)?;
+        Ok(self.lists.as_slice())
+    }
+
     /// Retrieves any missing nested content for the given board. This potentially
     /// means one or more network requests in order to retrieve the data. The Board
     /// will be mutated to include all its associated lists. The lists will also in turn
     /// contain the associated card resources.
+    #[cfg(feature = "http")]
     pub fn retrieve_nested(&mut self, client: &TrelloClient) -> Result<()> {
-        if self.lists.is_none() {
+        self.retrieve_nested_with(client, RunOptions::new())
+    }
+
+    /// Like `retrieve_nested`, failing with `TrelloError::Cancelled` instead
+    /// when the options are cancelled. The lists and cards are retrieved with
+    /// a single request, see `Board::get_many_nested_with` for the progress
+    /// of retrieving several boards.
+    #[cfg(feature = "http")]
+    pub fn retrieve_nested_with(
+        &mut self,
+        client: &TrelloClient,
+        options: RunOptions,
+    ) -> Result<()> {
+        if !self.lists.is_fetched() {
+            options.check(0)?;
             debug!("Retrieving nested data for board: {}", self.id);
-            self.lists = Some(List::get_all(client, &self.id, true)?);
+            self.lists = Loaded::Fetched(List::get_all(client, &self.id, true)?);
         } else {
             debug!("No need to retrieve nested data");
         }
         Ok(())
     }
 
+    /// Applies a regular expression substitution to the names of all open cards
+    /// on the board, e.g. `rename_cards(client, r"^PROJ-\d+: ", "", false)` strips
+    /// ticket prefixes. The replacement may refer to capture groups as `$1`.
+    /// Returns the renamed cards as a diff. When `dry_run` is set, nothing is
+    /// changed in Trello or in the board.
+    #[cfg(feature = "http")]
+    pub fn rename_cards(
+        &mut self,
+        client: &TrelloClient,
+        pattern: &str,
+        replacement: &str,
+        dry_run: bool,
+    ) -> Result<BoardDiff> {
+        let re = Regex::new(pattern)?;
+        self.retrieve_nested(client)?;
+
+        let mut renamed = vec![];
+        let cards = self
+            .lists
+            .iter_mut()
+            .flat_map(|l| l.cards.iter_mut())
+            .filter(|c| !c.closed);
+
+        for card in cards {
+            let name = re.replace_all(&card.name, replacement).into_owned();
+            if name == card.name {
+                continue;
+            }
+
+            let from = card.name.clone();
+            if !dry_run {
+                debug!("Renaming card {}: '{}' -> '{}'", card.id, from, name);
+                *card = Card::patch(client, &card.id, &CardPatch::new().name(&name))?;
+            }
+
+            renamed.push(Change::Renamed { from, to: name });
+        }
+
+        Ok(BoardDiff {
+            name: self.name.clone(),
+            lists: vec![],
+            cards: renamed,
+        })
+    }
+
+    #[cfg(feature = "http")]
     pub fn create(client: &TrelloClient, name: &str) -> Result<Board> {
         let url = client.config.get_trello_url("/1/boards/", &[])?;
 
         let params = [("name", name)];
 
-        Ok(client
-            .client
-            .post(url)
-            .form(&params)
-            .send()?
-            .error_for_status()?
-            .json()?)
+        Ok(client.send(client.post(url).form(&params))?.json()?)
     }
 
+    #[cfg(feature = "http")]
     pub fn open(client: &TrelloClient, board_id: &str) -> Result<Board> {
         let url = client
             .config
@@ -108,15 +709,126 @@ impl Board {
 
         let params = [("closed", "false")];
 
-        Ok(client
-            .client
-            .put(url)
-            .form(&params)
-            .send()?
-            .error_for_status()?
-            .json()?)
+        Ok(client.send(client.put(url).form(&params))?.json()?)
+    }
+
+    /// Subscribes (watches) or unsubscribes the authenticated member
+    /// to/from the board with the specified id
+    #[cfg(feature = "http")]
+    pub fn set_subscribed(
+        client: &TrelloClient,
+        board_id: &str,
+        subscribed: bool,
+    ) -> Result<Board> {
+        let url = client
+            .config
+            .get_trello_url(&format!("/1/boards/{}/", board_id), &[])?;
+
+        let params = [("subscribed", subscribed.to_string())];
+
+        Ok(client.send(client.put(url).form(&params))?.json()?)
+    }
+
+    /// Retrieves the boards starred by the authenticated member
+    #[cfg(feature = "http")]
+    pub fn get_starred(client: &TrelloClient) -> Result<Vec<Board>> {
+        let url = client.config.get_trello_url(
+            "/1/members/me/boards/",
+            &[
+                ("filter", "starred"),
+                ("fields", &Board::get_fields().join(",")),
+            ],
+        )?;
+
+        Ok(client.send(client.get(url))?.json()?)
+    }
+
+    /// Stars or unstars the board with the specified id for the authenticated
+    /// member. Starring an already starred board (or unstarring a board which
+    /// is not starred) does nothing.
+    #[cfg(feature = "http")]
+    pub fn set_starred(client: &TrelloClient, board_id: &str, starred: bool) -> Result<()> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct BoardStar {
+            id: String,
+            id_board: String,
+        }
+
+        let url = client
+            .config
+            .get_trello_url("/1/members/me/boardStars", &[])?;
+        let stars: Vec<BoardStar> = client.send(client.get(url.clone()))?.json()?;
+        let star = stars.into_iter().find(|s| s.id_board == board_id);
+
+        match (starred, star) {
+            (true, None) => {
+                let params = [("idBoard", board_id), ("pos", "top")];
+                client.send(client.post(url).form(&params))?;
+            }
+            (false, Some(star)) => {
+                let url = client
+                    .config
+                    .get_trello_url(&format!("/1/members/me/boardStars/{}", star.id), &[])?;
+                client.send(client.delete(url))?;
+            }
+            _ => debug!("Board {} already has starred={}", board_id, starred),
+        }
+
+        Ok(())
+    }
+
+    /// Uploads the name and closed state of the board, updating the board with
+    /// the response. Lists retrieved with the board are kept.
+    #[cfg(feature = "http")]
+    pub fn save(&mut self, client: &TrelloClient) -> Result<()> {
+        let lists = self.lists.take();
+        *self = Board::update(client, self)?;
+        self.lists = lists;
+        Ok(())
+    }
+
+    /// Archives (closes) the board, updating the board with the response
+    #[cfg(feature = "http")]
+    pub fn archive(&mut self, client: &TrelloClient) -> Result<()> {
+        let lists = self.lists.take();
+        *self = Board::patch(client, &self.id, &BoardPatch::new().closed(true))?;
+        self.lists = lists;
+        Ok(())
+    }
+
+    /// Retrieves the latest version of the board along with its open lists
+    /// and cards
+    #[cfg(feature = "http")]
+    pub fn refresh(&mut self, client: &TrelloClient) -> Result<()> {
+        let mut board = Board::get(client, &self.id)?;
+        board.retrieve_nested(client)?;
+        *self = board;
+        Ok(())
+    }
+
+    /// Creates a list with the given name on the board. The list is also
+    /// added to the lists of the board, if they have been retrieved.
+    #[cfg(feature = "http")]
+    pub fn add_list(&mut self, client: &TrelloClient, name: &str) -> Result<List> {
+        let list = List::create(client, &self.id, name, None)?;
+        if let Loaded::Fetched(lists) = &mut self.lists {
+            lists.push(list.clone());
+        }
+        Ok(list)
+    }
+
+    /// Applies the given partial update to the board with the specified id
+    #[cfg(feature = "http")]
+    pub fn patch(client: &TrelloClient, board_id: &str, patch: &BoardPatch) -> Result<Board> {
+        let url = client
+            .config
+            .get_trello_url(&format!("/1/boards/{}/", board_id), &[])?;
+
+        Ok(client.send(client.put(url).form(&patch.params()))?.json()?)
     }
 
+    #[cfg(feature = "http")]
     pub fn update(client: &TrelloClient, board: &Board) -> Result<Board> {
         let url = client
             .config
@@ -124,33 +836,263 @@ impl Board {
 
         let params = [("name", &board.name), ("closed", &board.closed.to_string())];
 
-        Ok(client
-            .client
-            .put(url)
-            .form(&params)
-            .send()?
-            .error_for_status()?
-            .json()?)
+        Ok(client.send(client.put(url).form(&params))?.json()?)
     }
 
+    /// Permanently deletes the board with the specified id, subject to the
+    /// safety policy of the client. When the policy is `ArchiveOnly`, the
+    /// board is closed instead.
+    #[cfg(feature = "http")]
+    pub fn delete(
+        client: &TrelloClient,
+        board_id: &str,
+        confirmation: Option<&ConfirmationToken>,
+    ) -> Result<()> {
+        let url = client
+            .config
+            .get_trello_url(&format!("/1/boards/{}", board_id), &[])?;
+
+        match client.safety_policy().check(board_id, confirmation)? {
+            Deletion::Delete => client.send(client.delete(url))?,
+            Deletion::Archive => {
+                debug!("Closing board {} instead of deleting it", board_id);
+                client.send(client.put(url).form(&[("closed", "true")]))?
+            }
+        };
+
+        Ok(())
+    }
+
+    #[cfg(feature = "http")]
     pub fn get_all(client: &TrelloClient) -> Result<Vec<Board>> {
+        Board::get_all_with_fields(client, &[])
+    }
+
+    /// Retrieves the open boards of the authenticated member with the given
+    /// fields on top of the fields every board is retrieved with
+    #[cfg(feature = "http")]
+    pub fn get_all_with_fields(client: &TrelloClient, fields: &[BoardField]) -> Result<Vec<Board>> {
         let url = client.config.get_trello_url(
             "/1/members/me/boards/",
             &[
                 ("filter", "open"),
-                ("fields", &Board::get_fields().join(",")),
+                ("fields", &BoardField::with_defaults(fields)),
             ],
         )?;
 
-        Ok(client.client.get(url).send()?.error_for_status()?.json()?)
+        Ok(client.send(client.get(url))?.json()?)
+    }
+
+    /// Retrieves the archived cards of the board, including cards in archived lists
+    #[cfg(feature = "http")]
+    pub fn get_closed_cards(client: &TrelloClient, board_id: &str) -> Result<Vec<Card>> {
+        let url = client.config.get_trello_url(
+            &format!("/1/boards/{}/cards/closed", board_id),
+            &[("fields", &Card::get_fields().join(","))],
+        )?;
+
+        Ok(client.send(client.get(url))?.json()?)
+    }
+
+    /// Finds the one archived card on the board whose name matches the pattern
+    /// (a case insensitive regular expression).
+    #[cfg(feature = "http")]
+    pub fn find_archived_card(
+        client: &TrelloClient,
+        board_id: &str,
+        name_pattern: &str,
+    ) -> Result<Card> {
+        let re = RegexBuilder::new(name_pattern)
+            .case_insensitive(true)
+            .build()?;
+
+        let mut cards = Board::get_closed_cards(client, board_id)?
+            .into_iter()
+            .filter(|c| re.is_match(&c.name))
+            .collect::<Vec<Card>>();
+
+        match cards.len() {
+            1 => Ok(cards.remove(0)),
+            0 => Err(TrelloError::NotFound(format!(
+                "Archived card '{}'",
+                name_pattern
+            ))),
+            _ => Err(TrelloError::Ambiguous(format!(
+                "Archived card '{}' matches {}",
+                name_pattern,
+                cards
+                    .iter()
+                    .map(|c| format!("'{}'", c.name))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ))),
+        }
+    }
+
+    /// Restores the one archived card on the board whose name matches the pattern.
+    /// Returns the restored card along with the list it was restored to. Note
+    /// that the list itself may be archived.
+    #[cfg(feature = "http")]
+    pub fn restore_card(
+        client: &TrelloClient,
+        board_id: &str,
+        name_pattern: &str,
+    ) -> Result<(Card, List)> {
+        let card = Board::find_archived_card(client, board_id, name_pattern)?;
+        let card = Card::open(client, &card.id)?;
+        let list = Card::get_list(client, &card.id)?;
+
+        if list.closed {
+            warn!(
+                "Card '{}' was restored to archived list '{}'",
+                card.name, list.name
+            );
+        }
+
+        Ok((card, list))
+    }
+
+    /// Retrieves the archived lists of the board, without their cards
+    #[cfg(feature = "http")]
+    pub fn get_closed_lists(client: &TrelloClient, board_id: &str) -> Result<Vec<List>> {
+        let url = client.config.get_trello_url(
+            &format!("/1/boards/{}/lists/closed", board_id),
+            &[("fields", &List::get_fields().join(","))],
+        )?;
+
+        Ok(client.send(client.get(url))?.json()?)
     }
 
+    #[cfg(feature = "http")]
     pub fn get(client: &TrelloClient, board_id: &str) -> Result<Board> {
+        Board::get_expanded(client, board_id, &Expand::new())
+    }
+
+    /// Like `get`, keeping the status, duration and raw body of the response
+    /// alongside the board
+    #[cfg(feature = "http")]
+    pub fn get_with_response(client: &TrelloClient, board_id: &str) -> Result<Response<Board>> {
+        Board::get_expanded_with_response(client, board_id, &Expand::new())
+    }
+
+    /// Retrieves the board with the given fields on top of the fields every
+    /// board is retrieved with
+    #[cfg(feature = "http")]
+    pub fn get_with_fields(
+        client: &TrelloClient,
+        board_id: &str,
+        fields: &[BoardField],
+    ) -> Result<Board> {
         let url = client.config.get_trello_url(
             &format!("/1/boards/{}", board_id),
-            &[("fields", &Board::get_fields().join(","))],
+            &[("fields", &BoardField::with_defaults(fields))],
         )?;
 
-        Ok(client.client.get(url).send()?.error_for_status()?.json()?)
+        Ok(client.send(client.get(url))?.json()?)
+    }
+
+    /// Retrieves the board along with the given nested resources, in a
+    /// single request
+    #[cfg(feature = "http")]
+    pub fn get_expanded(client: &TrelloClient, board_id: &str, expand: &Expand) -> Result<Board> {
+        Ok(Board::get_expanded_with_response(client, board_id, expand)?.value)
+    }
+
+    /// Like `get_expanded`, keeping the status, duration and raw body of the
+    /// response alongside the board
+    #[cfg(feature = "http")]
+    pub fn get_expanded_with_response(
+        client: &TrelloClient,
+        board_id: &str,
+        expand: &Expand,
+    ) -> Result<Response<Board>> {
+        let fields = Board::get_fields().join(",");
+        let mut params = vec![("fields", fields.as_str())];
+        params.extend(expand.board_params());
+
+        let url = client
+            .config
+            .get_trello_url(&format!("/1/boards/{}", board_id), &params)?;
+
+        client.send_with_response(client.get(url))
+    }
+
+    /// Retrieves several boards along with their lists and cards in parallel,
+    /// a few boards at a time. The boards are returned in the order of the
+    /// given ids. If any board cannot be retrieved, the first error is returned.
+    #[cfg(feature = "http")]
+    pub fn get_many_nested(client: &TrelloClient, board_ids: &[&str]) -> Result<Vec<Board>> {
+        Board::get_many_nested_with(client, board_ids, RunOptions::new())
+    }
+
+    /// Like `get_many_nested`, reporting each board retrieved to the progress
+    /// of the options. Once cancelled, the boards which weren't retrieved yet
+    /// are skipped and `TrelloError::Cancelled` is returned with the boards
+    /// which were, see `TrelloError::partial`.
+    #[cfg(feature = "http")]
+    pub fn get_many_nested_with(
+        client: &TrelloClient,
+        board_ids: &[&str],
+        mut options: RunOptions,
+    ) -> Result<Vec<Board>> {
+        let total = board_ids.len();
+        options.update(GET_MANY_PHASE, 0, total);
+
+        let cancel = options.cancel.clone();
+        let next = AtomicUsize::new(0);
+        let results = thread::scope(|scope| {
+            let (sender, receiver) = mpsc::channel();
+            let cancel = &cancel;
+            let next = &next;
+            for _ in 0..GET_MANY_WORKERS.min(total) {
+                let sender = sender.clone();
+                scope.spawn(move || loop {
+                    let index = next.fetch_add(1, AtomicOrdering::Relaxed);
+                    let board_id = match board_ids.get(index) {
+                        Some(board_id) => board_id,
+                        None => break,
+                    };
+                    let retrieve = || {
+                        let options = match cancel {
+                            Some(cancel) => RunOptions::new().cancel(cancel),
+                            None => RunOptions::new(),
+                        };
+                        options.check(0)?;
+                        let mut board = Board::get(client, board_id)?;
+                        board.retrieve_nested_with(client, options)?;
+                        Ok(board)
+                    };
+                    // the receiver outlives the threads of the scope
+                    sender.send((index, retrieve())).unwrap();
+                });
+            }
+            drop(sender);
+
+            let mut results: Vec<Option<Result<Board>>> = board_ids.iter().map(|_| None).collect();
+            for (done, (index, result)) in receiver.iter().enumerate() {
+                results[index] = Some(result);
+                options.update(GET_MANY_PHASE, done + 1, total);
+            }
+            results
+        });
+        options.finish();
+
+        let results: Vec<Result<Board>> = results
+            .into_iter()
+            .map(|r| r.expect("Board retrieval thread panicked"))
+            .collect();
+        let cancelled = results
+            .iter()
+            .any(|r| matches!(r, Err(TrelloError::Cancelled { .. })));
+        if !cancelled {
+            return results.into_iter().collect();
+        }
+
+        let boards: Vec<Board> = results.into_iter().filter_map(|r| r.ok()).collect();
+        let cancelled = TrelloError::Cancelled {
+            completed: boards.len(),
+            partial: None,
+        };
+        Err(cancelled.with_partial(boards))
     }
 }
@@ -0,0 +1,77 @@
+//! The report shared by the tools which bring several boards in line with
+//! each other, e.g. `labels::sync` and `lists::normalize`.
+use crate::ids::BoardId;
+use crate::trello_object::{RenderOptions, Renderable};
+
+use colored::*;
+
+/// A change planned or made on a single board
+pub trait BoardChange: Renderable {
+    /// What the changes are made to, e.g. "label"
+    const NOUN: &'static str;
+    /// Shown for a board which needs no changes, e.g. "Labels in sync"
+    const UNCHANGED: &'static str;
+}
+
+/// The changes for a single board
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoardChanges<C> {
+    pub board_id: BoardId,
+    pub board_name: String,
+    pub changes: Vec<C>,
+}
+
+/// The changes for each board, in the order of the boards
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeReport<C> {
+    pub boards: Vec<BoardChanges<C>>,
+    /// Set when the changes were only planned, not made
+    pub dry_run: bool,
+}
+
+impl<C> Default for ChangeReport<C> {
+    fn default() -> ChangeReport<C> {
+        ChangeReport {
+            boards: vec![],
+            dry_run: false,
+        }
+    }
+}
+
+impl<C> ChangeReport<C> {
+    pub fn is_empty(&self) -> bool {
+        self.boards.iter().all(|b| b.changes.is_empty())
+    }
+
+    pub fn changes(&self) -> impl Iterator<Item = &C> {
+        self.boards.iter().flat_map(|b| &b.changes)
+    }
+}
+
+impl<C: BoardChange> Renderable for ChangeReport<C> {
+    fn render_with(&self, options: &RenderOptions) -> String {
+        let mut result = vec![];
+        if self.dry_run {
+            result.push("Dry run, no changes were made".dimmed().to_string());
+        }
+
+        for board in &self.boards {
+            result.push(board.board_name.bold().to_string());
+            if board.changes.is_empty() {
+                result.push(String::from(C::UNCHANGED));
+            }
+            result.extend(board.changes.iter().map(|c| c.render_with(options)));
+        }
+
+        result.join("\n")
+    }
+
+    fn simple_render(&self) -> String {
+        format!(
+            "{} {} changes on {} boards",
+            self.changes().count(),
+            C::NOUN,
+            self.boards.len()
+        )
+    }
+}
@@ -0,0 +1,126 @@
+//! Git branch names suggested from cards, e.g. `feat/123-fix-login-timeout`.
+use crate::card::Card;
+
+/// How branch names are built from cards. The template may contain the
+/// placeholders `{type}`, `{number}` and `{slug}`.
+/// ```
+/// let mut card = trello::Card::new("", "Fix: login times out!", "", None, "", None);
+/// card.id_short = Some(123);
+///
+/// assert_eq!(card.suggest_branch_name(), "feat/123-fix-login-times-out");
+///
+/// let scheme = trello::BranchScheme {
+///     template: String::from("{number}/{slug}"),
+///     ..trello::BranchScheme::default()
+/// };
+/// assert_eq!(card.suggest_branch_name_with(&scheme), "123/fix-login-times-out");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BranchScheme {
+    pub template: String,
+    /// Longer slugs are cut at the last word which fits
+    pub max_slug_len: usize,
+    /// Branch types keyed by label name keywords (ignoring case). The type of
+    /// the first keyword found in the labels of the card is used.
+    pub types: Vec<(String, String)>,
+    /// The type used when no keyword matches
+    pub default_type: String,
+}
+
+impl Default for BranchScheme {
+    fn default() -> BranchScheme {
+        let types = [
+            ("bug", "fix"),
+            ("fix", "fix"),
+            ("chore", "chore"),
+            ("doc", "docs"),
+        ];
+
+        BranchScheme {
+            template: String::from("{type}/{number}-{slug}"),
+            max_slug_len: 40,
+            types: types
+                .iter()
+                .map(|(k, t)| (String::from(*k), String::from(*t)))
+                .collect(),
+            default_type: String::from("feat"),
+        }
+    }
+}
+
+impl BranchScheme {
+    fn branch_type(&self, card: &Card) -> &str {
+        let labels = card
+            .labels
+            .iter()
+            .flatten()
+            .map(|l| l.name.to_lowercase())
+            .collect::<Vec<String>>();
+
+        self.types
+            .iter()
+            .find(|(keyword, _)| {
+                let keyword = keyword.to_lowercase();
+                labels.iter().any(|l| l.contains(&keyword))
+            })
+            .map_or(&self.default_type, |(_, branch_type)| branch_type)
+    }
+}
+
+/// Lowercase ascii words of the text joined by dashes
+fn slug(text: &str, max_len: usize) -> String {
+    let words = text
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_ascii_lowercase());
+
+    let mut result = String::new();
+    for word in words {
+        let separator = if result.is_empty() { 0 } else { 1 };
+        if result.len() + separator + word.len() > max_len {
+            if result.is_empty() {
+                result = word[..max_len.min(word.len())].to_string();
+            }
+            break;
+        }
+        if separator == 1 {
+            result.push('-');
+        }
+        result.push_str(&word);
+    }
+
+    if result.is_empty() {
+        String::from("card")
+    } else {
+        result
+    }
+}
+
+impl Card {
+    /// Suggests a git branch name for the card using the default scheme, see
+    /// `BranchScheme`
+    pub fn suggest_branch_name(&self) -> String {
+        self.suggest_branch_name_with(&BranchScheme::default())
+    }
+
+    /// Suggests a git branch name for the card. The number is the short id of
+    /// the card, falling back to its short link when the short id is unknown.
+    pub fn suggest_branch_name_with(&self, scheme: &BranchScheme) -> String {
+        let number = match (self.id_short, self.short_link()) {
+            (Some(id_short), _) => id_short.to_string(),
+            (None, Some(short_link)) => short_link.to_string(),
+            (None, None) => self.id.chars().take(8).collect(),
+        };
+
+        let name = scheme
+            .template
+            .replace("{type}", scheme.branch_type(self))
+            .replace("{number}", &number)
+            .replace("{slug}", &slug(&self.name, scheme.max_slug_len));
+
+        // an empty number would leave stray separators behind
+        name.trim_matches(|c| c == '-' || c == '/')
+            .replace("/-", "/")
+            .replace("--", "-")
+    }
+}
@@ -0,0 +1,118 @@
+//! Links git commits to the Trello cards they mention, e.g. from a
+//! `post-commit` or `post-receive` hook. Cards are mentioned in a commit
+//! message by their url (`https://trello.com/c/a1B2c3D4`) or with a
+//! `Trello: a1B2c3D4` trailer, and each mentioned card gets a comment with the
+//! commit hash, message and repository.
+#[cfg(feature = "http")]
+use crate::card::Card;
+#[cfg(feature = "http")]
+use crate::client::TrelloClient;
+#[cfg(feature = "http")]
+use crate::trello_error::TrelloError;
+
+use regex::Regex;
+
+#[cfg(feature = "http")]
+type Result<T> = std::result::Result<T, TrelloError>;
+
+/// Number of characters of the hash shown in comments
+const SHORT_HASH_LEN: usize = 7;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Commit {
+    pub hash: String,
+    pub message: String,
+    /// The web page of the repository, e.g. `https://github.com/owner/repo`
+    pub repo_url: Option<String>,
+}
+
+impl Commit {
+    pub fn new(hash: &str, message: &str) -> Commit {
+        Commit {
+            hash: String::from(hash),
+            message: String::from(message),
+            repo_url: None,
+        }
+    }
+
+    pub fn repo_url(mut self, repo_url: &str) -> Commit {
+        self.repo_url = Some(repo_url.trim_end_matches('/').to_string());
+        self
+    }
+
+    pub fn short_hash(&self) -> &str {
+        self.hash.get(..SHORT_HASH_LEN).unwrap_or(&self.hash)
+    }
+
+    /// The page of the commit, using the `<repo>/commit/<hash>` layout of
+    /// GitHub, GitLab and Gitea
+    pub fn url(&self) -> Option<String> {
+        self.repo_url
+            .as_ref()
+            .map(|repo| format!("{}/commit/{}", repo, self.hash))
+    }
+
+    /// The short links of the cards mentioned in the message, see `card_refs`
+    pub fn card_refs(&self) -> Vec<String> {
+        card_refs(&self.message)
+    }
+
+    /// The Markdown comment added to mentioned cards
+    pub fn comment(&self) -> String {
+        let hash = match self.url() {
+            Some(url) => format!("[{}]({})", self.short_hash(), url),
+            None => format!("`{}`", self.short_hash()),
+        };
+        let mut result = match &self.repo_url {
+            Some(repo) => format!("Commit {} in {}", hash, repo),
+            None => format!("Commit {}", hash),
+        };
+        result.push('\n');
+        for line in self.message.trim().lines() {
+            result.push_str(format!("\n> {}", line).trim_end());
+        }
+        result
+    }
+}
+
+/// Extracts the short links of the cards mentioned in a commit message, in
+/// order of first mention, from card urls and `Trello:` trailers.
+/// ```
+/// let message = "Fix login timeout\n\nSee https://trello.com/c/a1B2c3D4/12-login\nTrello: e5F6g7H8";
+/// assert_eq!(trello::bridges::git::card_refs(message), vec!["a1B2c3D4", "e5F6g7H8"]);
+/// ```
+pub fn card_refs(message: &str) -> Vec<String> {
+    let re = Regex::new(r"(?im)trello\.com/c/([a-z0-9]+)|^\s*trello:\s*([a-z0-9]+)\s*$").unwrap();
+
+    let mut result: Vec<String> = vec![];
+    for caps in re.captures_iter(message) {
+        let short_link = caps.get(1).or_else(|| caps.get(2)).unwrap().as_str();
+        if !result.iter().any(|r| r == short_link) {
+            result.push(String::from(short_link));
+        }
+    }
+    result
+}
+
+/// Adds a comment about the commit to the card, which is referenced by its
+/// id, short link or url
+#[cfg(feature = "http")]
+pub fn link_commit(client: &TrelloClient, card_ref: &str, commit: &Commit) -> Result<()> {
+    let card_id = card_refs(card_ref)
+        .into_iter()
+        .next()
+        .unwrap_or_else(|| card_ref.trim().to_string());
+
+    Card::add_comment(client, &card_id, &commit.comment())
+}
+
+/// Links the commit to every card mentioned in its message, returning the
+/// short links of the cards
+#[cfg(feature = "http")]
+pub fn link_mentioned_cards(client: &TrelloClient, commit: &Commit) -> Result<Vec<String>> {
+    let card_refs = commit.card_refs();
+    for card_ref in &card_refs {
+        link_commit(client, card_ref, commit)?;
+    }
+    Ok(card_refs)
+}
@@ -0,0 +1,183 @@
+//! Imports GitHub issues into a Trello list. Each card created from an issue
+//! has a reference to the issue on the last line of its description, which
+//! is used to update the same card rather than creating a duplicate when
+//! the import is run again.
+use crate::card::{Card, CardPatch};
+use crate::client::TrelloClient;
+use crate::label::Label;
+use crate::trello_error::TrelloError;
+use crate::trello_object::TrelloObject;
+
+use reqwest::header::{ACCEPT, AUTHORIZATION, USER_AGENT};
+use serde::Deserialize;
+
+type Result<T> = std::result::Result<T, TrelloError>;
+
+const PER_PAGE: usize = 100;
+
+// https://docs.github.com/en/rest/issues/issues#list-repository-issues
+#[derive(Deserialize, Debug, Eq, PartialEq, Clone)]
+pub struct Issue {
+    pub number: u64,
+    pub title: String,
+    pub body: Option<String>,
+    pub html_url: String,
+    #[serde(default)]
+    pub labels: Vec<IssueLabel>,
+    // Pull requests are also returned by the issues endpoint
+    #[serde(default)]
+    pull_request: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize, Debug, Eq, PartialEq, Clone)]
+pub struct IssueLabel {
+    pub name: String,
+}
+
+#[derive(Debug, Default, Eq, PartialEq, Clone)]
+pub struct ImportSummary {
+    pub created: usize,
+    pub updated: usize,
+    pub unchanged: usize,
+}
+
+#[derive(Debug)]
+pub struct GitHubClient {
+    api_url: String,
+    token: Option<String>,
+    client: reqwest::blocking::Client,
+}
+
+impl GitHubClient {
+    pub fn new(token: Option<&str>) -> GitHubClient {
+        GitHubClient {
+            api_url: String::from("https://api.github.com"),
+            token: token.map(String::from),
+            client: reqwest::blocking::Client::new(),
+        }
+    }
+
+    /// Sets the url of the GitHub API, e.g. for GitHub Enterprise
+    pub fn api_url(mut self, api_url: &str) -> GitHubClient {
+        self.api_url = api_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Retrieves all open issues (excluding pull requests) of the repository,
+    /// which is given in the `owner/name` format.
+    pub fn get_issues(&self, repo: &str) -> Result<Vec<Issue>> {
+        let mut issues = vec![];
+
+        for page in 1.. {
+            let url = url::Url::parse_with_params(
+                &format!("{}/repos/{}/issues", self.api_url, repo),
+                &[
+                    ("state", "open"),
+                    ("per_page", &PER_PAGE.to_string()),
+                    ("page", &page.to_string()),
+                ],
+            )?;
+
+            let mut request = self
+                .client
+                .get(url)
+                .header(USER_AGENT, "tro")
+                .header(ACCEPT, "application/vnd.github+json");
+            if let Some(token) = &self.token {
+                request = request.header(AUTHORIZATION, format!("token {}", token));
+            }
+
+            let result: Vec<Issue> = request.send()?.error_for_status()?.json()?;
+            let count = result.len();
+            issues.extend(result.into_iter().filter(|i| i.pull_request.is_none()));
+
+            if count < PER_PAGE {
+                break;
+            }
+        }
+
+        Ok(issues)
+    }
+}
+
+/// The line identifying the issue a card was created from
+fn reference(repo: &str, issue: &Issue) -> String {
+    format!(
+        "GitHub issue: {}#{} ({})",
+        repo, issue.number, issue.html_url
+    )
+}
+
+fn description(repo: &str, issue: &Issue) -> String {
+    match issue.body.as_deref().map(str::trim) {
+        Some(body) if !body.is_empty() => format!("{}\n\n{}", body, reference(repo, issue)),
+        _ => reference(repo, issue),
+    }
+}
+
+/// Finds the card which was created from the issue, if any
+fn find_card<'a>(cards: &'a [Card], repo: &str, issue: &Issue) -> Option<&'a Card> {
+    let key = format!("GitHub issue: {}#{} ", repo, issue.number);
+
+    cards
+        .iter()
+        .find(|c| c.desc.lines().last().is_some_and(|l| l.starts_with(&key)))
+}
+
+/// Creates a card in the list for every issue. Cards which were created by a
+/// previous import are updated instead if the issue title or body changed,
+/// including the ones which were since archived or moved to another list of
+/// the board.
+/// Issue labels are applied to new cards when the board has a label of the
+/// same name (ignoring case); other issue labels are skipped.
+pub fn import_issues(
+    client: &TrelloClient,
+    board_id: &str,
+    list_id: &str,
+    repo: &str,
+    issues: &[Issue],
+) -> Result<ImportSummary> {
+    let url = client.config.get_trello_url(
+        &format!("/1/boards/{}/cards", board_id),
+        &[("filter", "all"), ("fields", &Card::get_fields().join(","))],
+    )?;
+    let cards: Vec<Card> = client.send(client.get(url))?.json()?;
+    let labels = Label::get_all(client, board_id)?;
+    let mut summary = ImportSummary::default();
+
+    for issue in issues {
+        let desc = description(repo, issue);
+
+        if let Some(card) = find_card(&cards, repo, issue) {
+            if card.name == issue.title && card.desc == desc {
+                summary.unchanged += 1;
+            } else {
+                debug!("Updating card {} from issue #{}", card.id, issue.number);
+                let patch = CardPatch::new().name(&issue.title).desc(&desc);
+                Card::patch(client, &card.id, &patch)?;
+                summary.updated += 1;
+            }
+            continue;
+        }
+
+        debug!("Creating card from issue #{}", issue.number);
+        let card = Card::create(
+            client,
+            list_id,
+            &Card::new("", &issue.title, &desc, None, "", None),
+        )?;
+
+        for issue_label in &issue.labels {
+            match labels
+                .iter()
+                .find(|l| l.name.to_lowercase() == issue_label.name.to_lowercase())
+            {
+                Some(label) => Label::add_to_card(client, &card.id, &label.id)?,
+                None => debug!("No label on board matching '{}'", issue_label.name),
+            }
+        }
+        summary.created += 1;
+    }
+
+    Ok(summary)
+}
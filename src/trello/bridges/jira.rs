@@ -0,0 +1,149 @@
+//! Imports issues from the CSV export of a Jira search ("Export > CSV").
+use crate::card::CardContents;
+#[cfg(feature = "http")]
+use crate::card::{Card, CardPatch};
+#[cfg(feature = "http")]
+use crate::client::TrelloClient;
+#[cfg(feature = "http")]
+use crate::label::Label;
+use crate::trello_error::TrelloError;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use std::path::Path;
+
+type Result<T> = std::result::Result<T, TrelloError>;
+
+/// Color of labels created for Jira labels and priorities missing from the board
+#[cfg(feature = "http")]
+const LABEL_COLOR: &str = "sky";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JiraIssue {
+    pub contents: CardContents,
+    pub labels: Vec<String>,
+    pub priority: Option<String>,
+    pub due: Option<DateTime<Utc>>,
+}
+
+impl JiraIssue {
+    /// All the labels which should be applied to the card, including the priority
+    pub fn card_labels(&self) -> Vec<String> {
+        let mut labels = self.labels.clone();
+        if let Some(priority) = &self.priority {
+            labels.push(format!("Priority: {}", priority));
+        }
+        labels
+    }
+}
+
+/// Parses the dates formats used by Jira exports, e.g. "12/Jun/20 5:00 PM"
+fn parse_date(value: &str) -> Option<DateTime<Utc>> {
+    const DATETIME_FORMATS: &[&str] = &["%d/%b/%y %I:%M %p", "%Y-%m-%d %H:%M", "%Y-%m-%d %H:%M:%S"];
+    const DATE_FORMATS: &[&str] = &["%d/%b/%y", "%Y-%m-%d"];
+
+    let value = value.trim();
+
+    DATETIME_FORMATS
+        .iter()
+        .find_map(|f| NaiveDateTime::parse_from_str(value, f).ok())
+        .or_else(|| {
+            DATE_FORMATS
+                .iter()
+                .find_map(|f| NaiveDate::parse_from_str(value, f).ok())
+                .map(|d| d.and_hms(0, 0, 0))
+        })
+        .map(|d| Utc.from_utc_datetime(&d))
+}
+
+/// Reads the issues from a Jira CSV export. Jira writes one "Labels" column
+/// per label, all of which are read. Due dates which cannot be parsed are ignored.
+pub fn import_csv<P: AsRef<Path>>(path: P) -> Result<Vec<JiraIssue>> {
+    let mut reader = csv::Reader::from_path(path)?;
+    let headers = reader.headers()?.clone();
+
+    let column = |name: &str| headers.iter().position(|h| h.eq_ignore_ascii_case(name));
+    let summary = column("Summary")
+        .ok_or_else(|| TrelloError::CardParse(String::from("Jira export has no Summary column")))?;
+    let description = column("Description");
+    let priority = column("Priority");
+    let due = column("Due Date");
+    let labels = headers
+        .iter()
+        .enumerate()
+        .filter(|(_, h)| h.eq_ignore_ascii_case("Labels"))
+        .map(|(i, _)| i)
+        .collect::<Vec<usize>>();
+
+    let mut issues = vec![];
+    for record in reader.records() {
+        let record = record?;
+        let get = |index: Option<usize>| {
+            index
+                .and_then(|i| record.get(i))
+                .map(str::trim)
+                .filter(|v| !v.is_empty())
+        };
+
+        issues.push(JiraIssue {
+            contents: CardContents {
+                name: get(Some(summary)).unwrap_or_default().to_string(),
+                desc: get(description).unwrap_or_default().to_string(),
+            },
+            labels: labels
+                .iter()
+                .filter_map(|i| get(Some(*i)))
+                .map(String::from)
+                .collect(),
+            priority: get(priority).map(String::from),
+            due: get(due).and_then(parse_date),
+        });
+    }
+
+    Ok(issues)
+}
+
+/// Creates a card in the list for every issue. Labels (and priorities) are
+/// matched to the board labels by name, creating any which do not exist.
+#[cfg(feature = "http")]
+pub fn create_cards(
+    client: &TrelloClient,
+    board_id: &str,
+    list_id: &str,
+    issues: &[JiraIssue],
+) -> Result<Vec<Card>> {
+    let mut board_labels = Label::get_all(client, board_id)?;
+    let mut cards = vec![];
+
+    for issue in issues {
+        let new_card = Card::new(
+            "",
+            &issue.contents.name,
+            &issue.contents.desc,
+            None,
+            "",
+            None,
+        );
+        let mut card = Card::create(client, list_id, &new_card)?;
+
+        if issue.due.is_some() {
+            card = Card::patch(client, &card.id, &CardPatch::new().due(issue.due))?;
+        }
+
+        for name in issue.card_labels() {
+            let label = match board_labels.iter().find(|l| l.name == name) {
+                Some(label) => label.clone(),
+                None => {
+                    debug!("Creating label '{}'", name);
+                    let label = Label::create(client, board_id, &name, LABEL_COLOR)?;
+                    board_labels.push(label.clone());
+                    label
+                }
+            };
+            Label::add_to_card(client, &card.id, &label.id)?;
+        }
+
+        cards.push(card);
+    }
+
+    Ok(cards)
+}
@@ -0,0 +1,6 @@
+//! Conversions between Trello and other tools
+pub mod git;
+#[cfg(feature = "github")]
+pub mod github;
+pub mod jira;
+pub mod taskwarrior;
@@ -0,0 +1,124 @@
+//! Conversion between cards and taskwarrior tasks, in the JSON format used
+//! by `task export` and `task import`. The id of the card a task was created
+//! from is kept in the `trelloid` user defined attribute.
+use crate::board::Board;
+use crate::card::Card;
+use crate::label::Label;
+use crate::list::List;
+use crate::trello_error::TrelloError;
+
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+type Result<T> = std::result::Result<T, TrelloError>;
+
+const DATE_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone)]
+pub struct Task {
+    pub description: String,
+    pub status: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_date",
+        deserialize_with = "deserialize_date"
+    )]
+    pub due: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trelloid: Option<String>,
+}
+
+fn serialize_date<S: Serializer>(
+    date: &Option<DateTime<Utc>>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    match date {
+        Some(d) => serializer.serialize_str(&d.format(DATE_FORMAT).to_string()),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn deserialize_date<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Option<DateTime<Utc>>, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    NaiveDateTime::parse_from_str(&value, DATE_FORMAT)
+        .map(|d| Some(Utc.from_utc_datetime(&d)))
+        .map_err(serde::de::Error::custom)
+}
+
+/// Taskwarrior tags cannot contain spaces
+fn tag(label: &Label) -> String {
+    label.name.replace(' ', "_")
+}
+
+/// Converts a card to a task. The project is made up of the board and list names
+/// (e.g. "Home.TODO") and the tags are the card label names.
+pub fn to_task(board: &Board, list: &List, card: &Card) -> Task {
+    Task {
+        description: card.name.clone(),
+        status: String::from(if card.closed { "completed" } else { "pending" }),
+        due: card.due,
+        tags: card
+            .labels
+            .iter()
+            .flatten()
+            .filter(|l| !l.name.is_empty())
+            .map(tag)
+            .collect(),
+        project: Some(format!("{}.{}", board.name, list.name)),
+        trelloid: Some(card.id.to_string()),
+    }
+}
+
+/// Converts all the cards of a board (with its nested lists and cards retrieved)
+pub fn export_board(board: &Board) -> Vec<Task> {
+    board
+        .lists
+        .iter()
+        .flat_map(|l| l.cards.iter().map(move |c| to_task(board, l, c)))
+        .collect()
+}
+
+/// Converts a task to a card. Tags become labels with no id, which need to be
+/// matched with the labels of a board before they can be applied.
+pub fn from_task(task: &Task) -> Card {
+    let labels = task
+        .tags
+        .iter()
+        .map(|t| Label::new("", &t.replace('_', " "), ""))
+        .collect::<Vec<Label>>();
+
+    let mut card = Card::new(
+        task.trelloid.as_deref().unwrap_or_default(),
+        &task.description,
+        "",
+        if labels.is_empty() {
+            None
+        } else {
+            Some(labels)
+        },
+        "",
+        task.due,
+    );
+    card.closed = task.status == "completed";
+    card
+}
+
+/// Serializes tasks to the JSON accepted by `task import`
+pub fn to_json(tasks: &[Task]) -> Result<String> {
+    serde_json::to_string(tasks).map_err(|e| TrelloError::Io(e.into()))
+}
+
+/// Parses the output of `task export`
+pub fn from_json(json: &str) -> Result<Vec<Task>> {
+    serde_json::from_str(json).map_err(|source| TrelloError::Deserialize {
+        source,
+        body: json.to_string(),
+    })
+}
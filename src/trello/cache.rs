@@ -0,0 +1,50 @@
+//! In-memory memo of the labels and members of boards, kept by each client
+//! so that repeated lookups (e.g. when applying labels interactively) do not
+//! cost a round trip every time. Entries are invalidated when labels are
+//! created or deleted through the same client.
+use crate::label::Label;
+use crate::member::Member;
+
+use std::collections::HashMap;
+
+#[derive(Debug, Default)]
+pub(crate) struct LookupCache {
+    labels: HashMap<String, Vec<Label>>,
+    members: HashMap<String, Vec<Member>>,
+}
+
+impl LookupCache {
+    pub fn labels(&self, board_id: &str) -> Option<Vec<Label>> {
+        self.labels.get(board_id).cloned()
+    }
+
+    pub fn set_labels(&mut self, board_id: &str, labels: &[Label]) {
+        self.labels.insert(String::from(board_id), labels.to_vec());
+    }
+
+    pub fn members(&self, board_id: &str) -> Option<Vec<Member>> {
+        self.members.get(board_id).cloned()
+    }
+
+    pub fn set_members(&mut self, board_id: &str, members: &[Member]) {
+        self.members
+            .insert(String::from(board_id), members.to_vec());
+    }
+
+    /// Forgets the labels and members of the given board
+    pub fn invalidate_board(&mut self, board_id: &str) {
+        self.labels.remove(board_id);
+        self.members.remove(board_id);
+    }
+
+    /// Forgets the labels of any board the given label belongs to
+    pub fn invalidate_label(&mut self, label_id: &str) {
+        self.labels
+            .retain(|_, labels| !labels.iter().any(|l| l.id == label_id));
+    }
+
+    pub fn clear(&mut self) {
+        self.labels.clear();
+        self.members.clear();
+    }
+}
@@ -0,0 +1,70 @@
+use crate::trello_error::TrelloError;
+
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+type Result<T> = std::result::Result<T, TrelloError>;
+
+/// Asks long operations, such as flushing a `WriteQueue` or running a
+/// `BoardWatcher`, to stop. The operations check the token between requests
+/// and fail with `TrelloError::Cancelled` once it is cancelled. Clones share
+/// the same state, so that e.g. a Ctrl-C handler can cancel an operation
+/// running on another thread.
+/// ```
+/// let token = trello::CancelToken::new();
+/// let handler_token = token.clone();
+/// handler_token.cancel();
+/// assert!(token.is_cancelled());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    state: Arc<(Mutex<bool>, Condvar)>,
+}
+
+impl CancelToken {
+    pub fn new() -> CancelToken {
+        CancelToken::default()
+    }
+
+    /// Cancels the operations using the token, waking those which are
+    /// waiting between requests. There is no way to undo it.
+    pub fn cancel(&self) {
+        let (cancelled, wake) = &*self.state;
+        *cancelled.lock().unwrap() = true;
+        wake.notify_all();
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        *self.state.0.lock().unwrap()
+    }
+
+    /// Fails with `TrelloError::Cancelled` when cancelled, recording the
+    /// number of steps the operation completed
+    pub fn check(&self, completed: usize) -> Result<()> {
+        if self.is_cancelled() {
+            debug!("Cancelled after {} steps", completed);
+            return Err(TrelloError::Cancelled {
+                completed,
+                partial: None,
+            });
+        }
+        Ok(())
+    }
+
+    /// Waits for the given duration, returning early when cancelled.
+    /// Returns whether the token is cancelled.
+    pub fn sleep(&self, duration: Duration) -> bool {
+        let deadline = Instant::now() + duration;
+        let (cancelled, wake) = &*self.state;
+        let mut cancelled = cancelled.lock().unwrap();
+
+        while !*cancelled {
+            let now = Instant::now();
+            if now >= deadline {
+                break;
+            }
+            cancelled = wake.wait_timeout(cancelled, deadline - now).unwrap().0;
+        }
+        *cancelled
+    }
+}
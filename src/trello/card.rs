@@ -1,27 +1,77 @@
+use crate::attachment::Attachment;
+use crate::checklist::Checklist;
+#[cfg(feature = "http")]
 use crate::client::TrelloClient;
-use crate::formatting::header;
+#[cfg(feature = "http")]
+use crate::expand::Expand;
+use crate::formatting::{header, relative_due};
+use crate::ids::CardId;
+#[cfg(feature = "http")]
+use crate::ids::ListId;
 use crate::label::Label;
+#[cfg(feature = "http")]
+use crate::list::List;
+use crate::member::Member;
+#[cfg(feature = "http")]
+use crate::response::Response;
+#[cfg(feature = "http")]
+use crate::safety::{ConfirmationToken, Deletion};
 use crate::trello_error::TrelloError;
-use crate::trello_object::{Renderable, TrelloObject};
+use crate::trello_object::{Detail, RenderOptions, Renderable, TrelloObject};
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeZone, Utc};
 use colored::Colorize;
-use serde::Deserialize;
+use regex::{Captures, Regex};
+use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
 type Result<T> = std::result::Result<T, TrelloError>;
 
+/// The fields which must be present to deserialize a card
+#[cfg(feature = "http")]
+const REQUIRED_FIELDS: &[&str] = &["id", "name", "desc", "closed", "url"];
+
 // https://developer.atlassian.com/cloud/trello/guides/rest-api/object-definitions/#card-object
-#[derive(Deserialize, Debug, Eq, PartialEq, Clone)]
+#[derive(Deserialize, Serialize, Debug, Eq, PartialEq, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Card {
-    pub id: String,
+    pub id: CardId,
     pub name: String,
     pub desc: String,
     pub closed: bool,
     pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub labels: Option<Vec<Label>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub due: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub subscribed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_last_activity: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub badges: Option<Badges>,
+    /// Ids of the members assigned to the card
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub id_members: Vec<String>,
+    /// The number of the card within its board
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id_short: Option<u64>,
+    /// Only present when the checklists are requested along with the card
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checklists: Option<Vec<Checklist>>,
+    /// Only present when requested, see `Expand`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members: Option<Vec<Member>>,
+    /// Only present when requested, see `Expand`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<Attachment>>,
+    /// The values of the custom fields of the card. Only present when
+    /// requested, see `Expand`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_field_items: Option<Vec<serde_json::Value>>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 impl TrelloObject for Card {
@@ -34,40 +84,152 @@ impl TrelloObject for Card {
     }
 
     fn get_fields() -> &'static [&'static str] {
-        &["id", "name", "desc", "labels", "closed", "due", "url"]
+        &[
+            "id",
+            "name",
+            "desc",
+            "labels",
+            "closed",
+            "due",
+            "url",
+            "subscribed",
+            "dateLastActivity",
+            "badges",
+            "idMembers",
+            "idShort",
+        ]
     }
 }
 
-impl Renderable for Card {
-    fn render(&self) -> String {
-        [header(&self.name, "=").as_str(), &self.desc].join("\n")
+/// Summary counts of the activity on a card
+// https://developer.atlassian.com/cloud/trello/guides/rest-api/object-definitions/#badges
+#[derive(Deserialize, Serialize, Debug, Default, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Badges {
+    pub comments: u64,
+    pub attachments: u64,
+    pub check_items: u64,
+    pub check_items_checked: u64,
+    pub votes: u64,
+    pub due: Option<DateTime<Utc>>,
+}
+
+fn plural(count: u64, word: &str) -> String {
+    if count == 1 {
+        format!("{} {}", count, word)
+    } else {
+        format!("{} {}s", count, word)
     }
+}
 
-    fn simple_render(&self) -> String {
-        let mut lformat: Vec<String> = vec![];
+impl Badges {
+    /// Compact single line summary of the non zero badges, e.g. "2 comments, 1 attachment".
+    /// Check items are not included as they are shown by the checklist progress of a card.
+    pub fn summary(&self) -> String {
+        let mut parts = vec![];
 
-        if self.closed {
-            lformat.push("[Closed]".red().to_string());
+        if self.comments > 0 {
+            parts.push(plural(self.comments, "comment"));
+        }
+        if self.attachments > 0 {
+            parts.push(plural(self.attachments, "attachment"));
+        }
+        if self.votes > 0 {
+            parts.push(plural(self.votes, "vote"));
+        }
+        if let Some(due) = &self.due {
+            parts.push(format!("due {}", due.format("%Y-%m-%d")));
         }
 
-        lformat.push(String::from(&self.name));
+        parts.join(", ")
+    }
+}
 
-        if self.desc != "" {
-            lformat.push("[...]".dimmed().to_string());
+impl Renderable for Card {
+    fn render_with(&self, options: &RenderOptions) -> String {
+        if options.detail == Detail::Minimal {
+            return self.render_line(options);
         }
 
-        if let Some(labels) = &self.labels {
-            for l in labels {
-                lformat.push(l.simple_render());
+        let mut result = vec![header(&self.name, "=")];
+
+        let badges = self.badge_line();
+        if !badges.is_empty() {
+            result.push(badges.dimmed().to_string());
+        }
+
+        if options.detail == Detail::Full {
+            if let (true, Some(labels)) = (options.show_labels, &self.labels) {
+                if !labels.is_empty() {
+                    let labels = labels.iter().map(|l| l.simple_render());
+                    result.push(labels.collect::<Vec<String>>().join(" "));
+                }
             }
+            if let Some(due) = &self.due {
+                result.push(format!(
+                    "Due: {} ({})",
+                    options.format_date(due, "%Y-%m-%d %H:%M"),
+                    relative_due(due, &options.now())
+                ));
+            }
+        }
+        if options.show_ids {
+            result.push(self.id.dimmed().to_string());
+        }
+        if options.show_urls && !self.url.is_empty() {
+            result.push(self.url.dimmed().to_string());
         }
 
-        // trim end in case there is no data presented by lformat
-        lformat.join(" ").trim_end().to_string()
+        result.push(self.desc.clone());
+        result.join("\n")
+    }
+
+    fn simple_render(&self) -> String {
+        self.render_line(&RenderOptions::default())
+    }
+}
+
+/// Which cards to retrieve based on whether they are archived
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardFilter {
+    Open,
+    Closed,
+    All,
+}
+
+impl CardFilter {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CardFilter::Open => "open",
+            CardFilter::Closed => "closed",
+            CardFilter::All => "all",
+        }
+    }
+}
+
+/// The labels applied to a card along with every label of its board, e.g.
+/// to present the full choice when toggling labels
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CardLabels {
+    pub applied: Vec<Label>,
+    pub available: Vec<Label>,
+}
+
+impl CardLabels {
+    pub fn is_applied(&self, label_id: &str) -> bool {
+        self.applied.iter().any(|l| l.id == label_id)
+    }
+
+    /// The labels of the board which are not applied to the card
+    pub fn unapplied(&self) -> Vec<&Label> {
+        self.available
+            .iter()
+            .filter(|l| !self.is_applied(&l.id))
+            .collect()
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub struct CardContents {
     pub name: String,
     pub desc: String,
@@ -133,6 +295,140 @@ impl FromStr for CardContents {
     }
 }
 
+impl CardContents {
+    /// Substitutes the `{{var}}` placeholders in the template with the given
+    /// variables before parsing it like a card render (see `from_str`). The
+    /// `date` variable defaults to today's date. Placeholders without a
+    /// matching variable result in an error.
+    /// ```
+    /// # fn main() -> Result<(), trello::TrelloError> {
+    /// let template = "Sprint {{sprint}} review\n===\nHosted by {{ assignee }}";
+    /// let contents =
+    ///     trello::CardContents::from_template(template, &[("sprint", "12"), ("assignee", "Kim")])?;
+    ///
+    /// assert_eq!(contents.name, "Sprint 12 review");
+    /// assert_eq!(contents.desc, "Hosted by Kim");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_template(template: &str, vars: &[(&str, &str)]) -> Result<CardContents> {
+        let re = Regex::new(r"\{\{\s*([\w.-]+)\s*\}\}").unwrap();
+        let today = Utc::now().format("%Y-%m-%d").to_string();
+
+        let mut unknown = vec![];
+        let filled = re.replace_all(template, |caps: &Captures| {
+            let var = &caps[1];
+            match vars.iter().find(|(name, _)| *name == var) {
+                Some((_, value)) => value.to_string(),
+                None if var == "date" => today.clone(),
+                None => {
+                    unknown.push(var.to_string());
+                    caps[0].to_string()
+                }
+            }
+        });
+
+        if !unknown.is_empty() {
+            return Err(TrelloError::CardParse(format!(
+                "Unknown template variables: {}",
+                unknown.join(", ")
+            )));
+        }
+
+        filled.parse()
+    }
+}
+
+/// A partial update of a card. Only the fields which have been explicitly set
+/// are sent to Trello, so concurrent changes to other fields are not overwritten.
+/// ```
+/// let patch = trello::CardPatch::new().name("Walk the dog").closed(true);
+/// assert_eq!(
+///     patch.params(),
+///     vec![("name", String::from("Walk the dog")), ("closed", String::from("true"))],
+/// );
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CardPatch {
+    name: Option<String>,
+    desc: Option<String>,
+    closed: Option<bool>,
+    due: Option<Option<DateTime<Utc>>>,
+    list_id: Option<String>,
+    pub(crate) last_activity: Option<DateTime<Utc>>,
+}
+
+impl CardPatch {
+    pub fn new() -> CardPatch {
+        CardPatch::default()
+    }
+
+    pub fn name(mut self, name: &str) -> CardPatch {
+        self.name = Some(String::from(name));
+        self
+    }
+
+    pub fn desc(mut self, desc: &str) -> CardPatch {
+        self.desc = Some(String::from(desc));
+        self
+    }
+
+    pub fn closed(mut self, closed: bool) -> CardPatch {
+        self.closed = Some(closed);
+        self
+    }
+
+    /// Sets the due date of the card. Passing None removes the due date.
+    pub fn due(mut self, due: Option<DateTime<Utc>>) -> CardPatch {
+        self.due = Some(due);
+        self
+    }
+
+    pub fn list_id(mut self, list_id: &str) -> CardPatch {
+        self.list_id = Some(String::from(list_id));
+        self
+    }
+
+    /// Only apply the patch if the last activity date of the card in Trello
+    /// matches the given value (typically the `date_last_activity` read before
+    /// making changes). A `TrelloError::Conflict` is returned otherwise.
+    pub fn if_last_activity(mut self, last_activity: Option<DateTime<Utc>>) -> CardPatch {
+        self.last_activity = last_activity;
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.params().is_empty()
+    }
+
+    /// The form parameters which will be sent to Trello
+    pub fn params(&self) -> Vec<(&'static str, String)> {
+        let mut params = vec![];
+
+        if let Some(name) = &self.name {
+            params.push(("name", name.clone()));
+        }
+        if let Some(desc) = &self.desc {
+            params.push(("desc", desc.clone()));
+        }
+        if let Some(closed) = self.closed {
+            params.push(("closed", closed.to_string()));
+        }
+        if let Some(due) = &self.due {
+            let value = match due {
+                Some(due) => due.to_rfc3339(),
+                None => String::from("null"),
+            };
+            params.push(("due", value));
+        }
+        if let Some(list_id) = &self.list_id {
+            params.push(("idList", list_id.clone()));
+        }
+
+        params
+    }
+}
+
 impl Card {
     pub fn new(
         id: &str,
@@ -143,24 +439,198 @@ impl Card {
         due: Option<DateTime<Utc>>,
     ) -> Card {
         Card {
-            id: String::from(id),
+            id: CardId::new(id),
             name: String::from(name),
             desc: String::from(desc),
             url: String::from(url),
             labels,
             due,
             closed: false,
+            subscribed: false,
+            date_last_activity: None,
+            badges: None,
+            id_members: vec![],
+            id_short: None,
+            checklists: None,
+            members: None,
+            attachments: None,
+            custom_field_items: None,
+            #[cfg(feature = "extra-fields")]
+            extra: serde_json::Map::new(),
+        }
+    }
+
+    /// The time the card was created, which Trello encodes in the first
+    /// eight hex digits of the id as a unix timestamp
+    pub fn created_at(&self) -> Option<DateTime<Utc>> {
+        let seconds = i64::from_str_radix(self.id.get(..8)?, 16).ok()?;
+        Utc.timestamp_opt(seconds, 0).single()
+    }
+
+    /// Number of complete check items and the total number of check items across
+    /// all the checklists of the card. If the checklists were not retrieved, the
+    /// counts from the card badges are used. None if the card has no check items.
+    pub fn checklist_progress(&self) -> Option<(usize, usize)> {
+        let (complete, total) = match (&self.checklists, &self.badges) {
+            (None, Some(badges)) => (
+                badges.check_items_checked as usize,
+                badges.check_items as usize,
+            ),
+            _ => self
+                .checklists
+                .iter()
+                .flatten()
+                .map(|c| c.progress())
+                .fold((0, 0), |(c1, t1), (c2, t2)| (c1 + c2, t1 + t2)),
+        };
+
+        if total == 0 {
+            None
+        } else {
+            Some((complete, total))
+        }
+    }
+
+    /// Renders the card on a single line, as it appears within a list
+    pub(crate) fn render_line(&self, options: &RenderOptions) -> String {
+        let mut lformat: Vec<String> = vec![];
+
+        if self.closed {
+            lformat.push("[Closed]".red().to_string());
+        }
+
+        lformat.push(String::from(&self.name));
+
+        if options.detail != Detail::Minimal && !self.desc.is_empty() {
+            lformat.push("[...]".dimmed().to_string());
+        }
+
+        if let (true, Some(labels)) = (options.show_labels, &self.labels) {
+            for l in labels {
+                lformat.push(l.simple_render());
+            }
+        }
+
+        if let (Some(due), false) = (&self.due, self.closed || options.detail == Detail::Minimal) {
+            let now = options.now();
+            let relative = format!("({})", relative_due(due, &now));
+            if *due < now {
+                lformat.push(relative.red().to_string());
+            } else {
+                lformat.push(relative.dimmed().to_string());
+            }
         }
+
+        if options.show_ids {
+            lformat.push(format!("({})", self.id).dimmed().to_string());
+        }
+
+        if options.show_urls && !self.url.is_empty() {
+            lformat.push(self.url.dimmed().to_string());
+        }
+
+        // trim end in case there is no data presented by lformat
+        lformat.join(" ").trim_end().to_string()
+    }
+
+    /// The checklist progress and badge summary of the card on a single line,
+    /// e.g. "[3/7] 2 comments". Empty if there is nothing to show.
+    pub fn badge_line(&self) -> String {
+        let mut parts = vec![];
+
+        if let Some((complete, total)) = self.checklist_progress() {
+            parts.push(format!("[{}/{}]", complete, total));
+        }
+        if let Some(badges) = &self.badges {
+            parts.push(badges.summary());
+        }
+
+        parts.join(" ").trim_end().to_string()
+    }
+
+    /// Retrieves the checklists of the card, storing them in the card
+    #[cfg(feature = "http")]
+    pub fn retrieve_checklists(&mut self, client: &TrelloClient) -> Result<()> {
+        self.checklists = Some(Checklist::get_all(client, &self.id)?);
+        Ok(())
+    }
+
+    /// Retrieves the labels of the card along with the labels available on
+    /// its board. The labels of the board are remembered by the client, see
+    /// `Label::get_all`.
+    #[cfg(feature = "http")]
+    pub fn get_labels(client: &TrelloClient, card_id: &str) -> Result<CardLabels> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct CardBoardLabels {
+            id_board: String,
+            labels: Vec<Label>,
+        }
+
+        let url = client.config.get_trello_url(
+            &format!("/1/cards/{}", card_id),
+            &[("fields", "idBoard,labels")],
+        )?;
+        let card: CardBoardLabels = client.send(client.get(url))?.json()?;
+
+        Ok(CardLabels {
+            applied: card.labels,
+            available: Label::get_all(client, &card.id_board)?,
+        })
     }
 
+    /// Retrieves the card with the specified id, with all its fields
+    #[cfg(feature = "http")]
     pub fn get(client: &TrelloClient, card_id: &str) -> Result<Card> {
+        Card::get_expanded(client, card_id, &Expand::new())
+    }
+
+    /// Like `get`, keeping the status, duration and raw body of the response
+    /// alongside the card
+    #[cfg(feature = "http")]
+    pub fn get_with_response(client: &TrelloClient, card_id: &str) -> Result<Response<Card>> {
+        Card::get_expanded_with_response(client, card_id, &Expand::new())
+    }
+
+    /// Retrieves the card with the specified id, with only the given fields
+    /// (e.g. "due" or "idMembers") besides the id, name, description, url
+    /// and closed state, which every card has.
+    #[cfg(feature = "http")]
+    pub fn get_with_fields(client: &TrelloClient, card_id: &str, fields: &[&str]) -> Result<Card> {
+        let mut selected = REQUIRED_FIELDS.to_vec();
+        selected.extend(fields.iter().filter(|f| !REQUIRED_FIELDS.contains(f)));
+
+        let url = client.config.get_trello_url(
+            &format!("/1/cards/{}", card_id),
+            &[("fields", &selected.join(","))],
+        )?;
+
+        Ok(client.send(client.get(url))?.json()?)
+    }
+
+    /// Retrieves the card along with the given nested resources, in a
+    /// single request
+    #[cfg(feature = "http")]
+    pub fn get_expanded(client: &TrelloClient, card_id: &str, expand: &Expand) -> Result<Card> {
+        Ok(Card::get_expanded_with_response(client, card_id, expand)?.value)
+    }
+
+    /// Like `get_expanded`, keeping the status, duration and raw body of the
+    /// response alongside the card
+    #[cfg(feature = "http")]
+    pub fn get_expanded_with_response(
+        client: &TrelloClient,
+        card_id: &str,
+        expand: &Expand,
+    ) -> Result<Response<Card>> {
         let url = client
             .config
-            .get_trello_url(&format!("/1/cards/{}", card_id), &[])?;
+            .get_trello_url(&format!("/1/cards/{}", card_id), &expand.card_params())?;
 
-        Ok(client.client.get(url).send()?.error_for_status()?.json()?)
+        client.send_with_response(client.get(url))
     }
 
+    #[cfg(feature = "http")]
     pub fn create(client: &TrelloClient, list_id: &str, card: &Card) -> Result<Card> {
         let url = client.config.get_trello_url("/1/cards/", &[])?;
 
@@ -170,15 +640,59 @@ impl Card {
             ("idList", list_id),
         ];
 
-        Ok(client
-            .client
-            .post(url)
-            .form(&params)
-            .send()?
-            .error_for_status()?
-            .json()?)
+        Ok(client.send(client.post(url).form(&params))?.json()?)
+    }
+
+    /// Creates a card from a template in the card render format, substituting
+    /// its `{{var}}` placeholders first. See `CardContents::from_template`.
+    #[cfg(feature = "http")]
+    pub fn create_from_template_str(
+        client: &TrelloClient,
+        list_id: &str,
+        template: &str,
+        vars: &[(&str, &str)],
+    ) -> Result<Card> {
+        let contents = CardContents::from_template(template, vars)?;
+        let card = Card::new("", &contents.name, &contents.desc, None, "", None);
+
+        Card::create(client, list_id, &card)
+    }
+
+    /// Like `create`, but retries up to `retries` times when the request fails
+    /// in a way which leaves it unknown whether the card was created (timeouts,
+    /// dropped connections and server errors). Before each retry, the list is
+    /// checked for an open card with the same name created within `window`; if
+    /// one is found, the earlier attempt succeeded and that card is returned
+    /// instead of creating a duplicate.
+    #[cfg(feature = "http")]
+    pub fn create_idempotent(
+        client: &TrelloClient,
+        list_id: &str,
+        card: &Card,
+        retries: usize,
+        window: chrono::Duration,
+    ) -> Result<Card> {
+        let mut attempt = 0;
+        loop {
+            let error = match Card::create(client, list_id, card) {
+                Err(e) if attempt < retries && is_retryable(&e) => e,
+                result => return result,
+            };
+            attempt += 1;
+            debug!("Unable to create card (attempt {}): {}", attempt, error);
+
+            let cutoff = Utc::now() - window;
+            let existing = Card::get_all(client, list_id)?.into_iter().find(|c| {
+                c.name == card.name && c.created_at().is_some_and(|created| created >= cutoff)
+            });
+            if let Some(existing) = existing {
+                debug!("Card was created by a previous attempt: {}", existing.id);
+                return Ok(existing);
+            }
+        }
     }
 
+    #[cfg(feature = "http")]
     pub fn open(client: &TrelloClient, card_id: &str) -> Result<Card> {
         let url = client
             .config
@@ -186,15 +700,40 @@ impl Card {
 
         let params = [("closed", "false")];
 
-        Ok(client
-            .client
-            .put(url)
-            .form(&params)
-            .send()?
-            .error_for_status()?
-            .json()?)
+        Ok(client.send(client.put(url).form(&params))?.json()?)
     }
 
+    /// Verifies that nobody has modified the card with the specified id since
+    /// the given last activity date was read
+    #[cfg(feature = "http")]
+    fn check_last_activity(
+        client: &TrelloClient,
+        card_id: &str,
+        expected: DateTime<Utc>,
+    ) -> Result<()> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct CardActivity {
+            date_last_activity: DateTime<Utc>,
+        }
+
+        let url = client.config.get_trello_url(
+            &format!("/1/cards/{}", card_id),
+            &[("fields", "dateLastActivity")],
+        )?;
+        let activity: CardActivity = client.send(client.get(url))?.json()?;
+
+        if activity.date_last_activity != expected {
+            return Err(TrelloError::Conflict {
+                expected,
+                actual: activity.date_last_activity,
+            });
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "http")]
     pub fn update(client: &TrelloClient, card: &Card) -> Result<Card> {
         let url = client
             .config
@@ -206,38 +745,175 @@ impl Card {
             ("closed", &card.closed.to_string()),
         ];
 
-        Ok(client
-            .client
-            .put(url)
-            .form(&params)
-            .send()?
-            .error_for_status()?
-            .json()?)
+        Ok(client.send(client.put(url).form(&params))?.json()?)
+    }
+
+    /// Uploads the name, description and closed state of the card, updating the
+    /// card with the response. Checklists retrieved with the card are kept.
+    #[cfg(feature = "http")]
+    pub fn save(&mut self, client: &TrelloClient) -> Result<()> {
+        let checklists = self.checklists.take();
+        *self = Card::update(client, self)?;
+        self.checklists = checklists;
+        Ok(())
+    }
+
+    /// Archives the card, updating the card with the response
+    #[cfg(feature = "http")]
+    pub fn archive(&mut self, client: &TrelloClient) -> Result<()> {
+        let checklists = self.checklists.take();
+        *self = Card::patch(client, &self.id, &CardPatch::new().closed(true))?;
+        self.checklists = checklists;
+        Ok(())
+    }
+
+    /// Retrieves the latest version of the card
+    #[cfg(feature = "http")]
+    pub fn refresh(&mut self, client: &TrelloClient) -> Result<()> {
+        *self = Card::get(client, &self.id)?;
+        Ok(())
+    }
+
+    /// Moves the card to the bottom of the specified list
+    #[cfg(feature = "http")]
+    pub fn move_to(&self, client: &TrelloClient, list_id: &ListId) -> Result<()> {
+        Card::move_to_list(client, &self.id, list_id)
+    }
+
+    #[cfg(feature = "http")]
+    pub fn add_label(&self, client: &TrelloClient, label: &Label) -> Result<()> {
+        Label::add_to_card(client, &self.id, &label.id)
+    }
+
+    #[cfg(feature = "http")]
+    pub fn remove_label(&self, client: &TrelloClient, label: &Label) -> Result<()> {
+        Label::remove_from_card(client, &self.id, &label.id)
+    }
+
+    /// Applies the given partial update to the card with the specified id
+    #[cfg(feature = "http")]
+    pub fn patch(client: &TrelloClient, card_id: &str, patch: &CardPatch) -> Result<Card> {
+        if let Some(expected) = patch.last_activity {
+            Card::check_last_activity(client, card_id, expected)?;
+        }
+
+        let url = client
+            .config
+            .get_trello_url(&format!("/1/cards/{}/", card_id), &[])?;
+
+        Ok(client.send(client.put(url).form(&patch.params()))?.json()?)
+    }
+
+    /// Sets or, when `due` is None, removes the due date of the card with the
+    /// specified id. With the `human-dates` feature, dates such as
+    /// "next friday 5pm" can be parsed with `dates::parse_human`.
+    #[cfg(feature = "http")]
+    pub fn set_due(
+        client: &TrelloClient,
+        card_id: &str,
+        due: Option<DateTime<Utc>>,
+    ) -> Result<Card> {
+        Card::patch(client, card_id, &CardPatch::new().due(due))
+    }
+
+    /// Subscribes (watches) or unsubscribes the authenticated member
+    /// to/from the card with the specified id
+    #[cfg(feature = "http")]
+    pub fn set_subscribed(client: &TrelloClient, card_id: &str, subscribed: bool) -> Result<Card> {
+        let url = client
+            .config
+            .get_trello_url(&format!("/1/cards/{}/", card_id), &[])?;
+
+        let params = [("subscribed", subscribed.to_string())];
+
+        Ok(client.send(client.put(url).form(&params))?.json()?)
+    }
+
+    /// Adds a comment to the card with the specified id
+    #[cfg(feature = "http")]
+    pub fn add_comment(client: &TrelloClient, card_id: &str, text: &str) -> Result<()> {
+        let url = client
+            .config
+            .get_trello_url(&format!("/1/cards/{}/actions/comments", card_id), &[])?;
+
+        client.send(client.post(url).form(&[("text", text)]))?;
+        Ok(())
+    }
+
+    /// Permanently deletes the card with the specified id, subject to the
+    /// safety policy of the client. When the policy is `ArchiveOnly`, the
+    /// card is archived instead.
+    #[cfg(feature = "http")]
+    pub fn delete(
+        client: &TrelloClient,
+        card_id: &str,
+        confirmation: Option<&ConfirmationToken>,
+    ) -> Result<()> {
+        let url = client
+            .config
+            .get_trello_url(&format!("/1/cards/{}", card_id), &[])?;
+
+        match client.safety_policy().check(card_id, confirmation)? {
+            Deletion::Delete => client.send(client.delete(url))?,
+            Deletion::Archive => {
+                debug!("Archiving card {} instead of deleting it", card_id);
+                client.send(client.put(url).form(&[("closed", "true")]))?
+            }
+        };
+
+        Ok(())
+    }
+
+    /// Retrieves the list the card with the specified id is in
+    #[cfg(feature = "http")]
+    pub fn get_list(client: &TrelloClient, card_id: &str) -> Result<List> {
+        let url = client.config.get_trello_url(
+            &format!("/1/cards/{}/list", card_id),
+            &[("fields", &List::get_fields().join(","))],
+        )?;
+
+        Ok(client.send(client.get(url))?.json()?)
     }
 
     // Moves a card to the list with the specified id
+    #[deprecated(since = "2.10.0", note = "use `Card::move_to_list` with typed ids")]
+    #[cfg(feature = "http")]
     pub fn change_list(client: &TrelloClient, card_id: &str, list_id: &str) -> Result<()> {
+        Card::move_to_list(client, &CardId::new(card_id), &ListId::new(list_id))
+    }
+
+    /// Moves the card to the bottom of the specified list
+    #[cfg(feature = "http")]
+    pub fn move_to_list(client: &TrelloClient, card_id: &CardId, list_id: &ListId) -> Result<()> {
         let url = client
             .config
             .get_trello_url(&format!("/1/cards/{}/", card_id), &[])?;
 
-        let params = [("idList", list_id)];
+        let params = [("idList", list_id.as_str())];
 
-        client
-            .client
-            .put(url)
-            .form(&params)
-            .send()?
-            .error_for_status()?;
+        client.send(client.put(url).form(&params))?;
 
         Ok(())
     }
 
+    #[cfg(feature = "http")]
     pub fn get_all(client: &TrelloClient, list_id: &str) -> Result<Vec<Card>> {
         let url = client.config.get_trello_url(
             &format!("/1/lists/{}/cards/", list_id),
             &[("fields", &Card::get_fields().join(","))],
         )?;
-        Ok(client.client.get(url).send()?.error_for_status()?.json()?)
+        Ok(client.send(client.get(url))?.json()?)
+    }
+}
+
+/// Whether a failed request may or may not have been carried out by Trello
+#[cfg(feature = "http")]
+fn is_retryable(error: &TrelloError) -> bool {
+    match error {
+        TrelloError::Reqwest(e) => {
+            let e = e.inner();
+            e.is_timeout() || e.is_request() || e.status().is_some_and(|s| s.is_server_error())
+        }
+        _ => false,
     }
 }
@@ -0,0 +1,229 @@
+//! The history of a card's fields, parsed from the update actions Trello
+//! records whenever a card is renamed, edited, moved, labelled or given a
+//! new due date.
+use crate::card::Card;
+#[cfg(feature = "http")]
+use crate::client::TrelloClient;
+use crate::formatting::line_diff;
+use crate::ids::CardId;
+#[cfg(feature = "http")]
+use crate::trello_error::TrelloError;
+use crate::trello_object::{RenderOptions, Renderable};
+use crate::webhook::Action;
+
+use chrono::{DateTime, Utc};
+use colored::*;
+use serde_json::Value;
+
+#[cfg(feature = "http")]
+type Result<T> = std::result::Result<T, TrelloError>;
+
+/// The card actions which change the fields tracked in a `CardHistory`
+#[cfg(feature = "http")]
+const HISTORY_ACTIONS: &str = "updateCard,addLabelToCard,removeLabelFromCard";
+/// Maximum number of actions Trello returns in a single request
+#[cfg(feature = "http")]
+const ACTIONS_LIMIT: &str = "1000";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldChange {
+    Name {
+        from: String,
+        to: String,
+    },
+    Desc {
+        from: String,
+        to: String,
+    },
+    /// The names of the lists the card moved between
+    List {
+        from: String,
+        to: String,
+    },
+    LabelAdded(String),
+    LabelRemoved(String),
+    Due {
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    },
+}
+
+/// A single change along with when and by whom it was made
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistoryEntry {
+    pub date: DateTime<Utc>,
+    /// The full name of the member who made the change, if known
+    pub member: Option<String>,
+    pub change: FieldChange,
+}
+
+/// The changes made to a card, oldest first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardHistory {
+    pub card_id: CardId,
+    pub entries: Vec<HistoryEntry>,
+}
+
+impl CardHistory {
+    /// Builds the history from the actions of a card, in any order. Actions
+    /// which do not change a tracked field are ignored.
+    pub fn from_actions(card_id: &str, actions: &[Action]) -> CardHistory {
+        let mut entries = actions
+            .iter()
+            .flat_map(|action| {
+                let member = action.member_creator.as_ref().map(|m| m.full_name.clone());
+                changes(action).into_iter().map(move |change| HistoryEntry {
+                    date: action.date,
+                    member: member.clone(),
+                    change,
+                })
+            })
+            .collect::<Vec<HistoryEntry>>();
+        entries.sort_by_key(|e| e.date);
+
+        CardHistory {
+            card_id: CardId::new(card_id),
+            entries,
+        }
+    }
+
+    /// The descriptions of the card over time, oldest first, starting with
+    /// the description before the first recorded change
+    pub fn descriptions(&self) -> Vec<(Option<DateTime<Utc>>, &str)> {
+        let mut result = vec![];
+        for entry in &self.entries {
+            if let FieldChange::Desc { from, to } = &entry.change {
+                if result.is_empty() {
+                    result.push((None, from.as_str()));
+                }
+                result.push((Some(entry.date), to.as_str()));
+            }
+        }
+        result
+    }
+}
+
+fn changes(action: &Action) -> Vec<FieldChange> {
+    let data = &action.data;
+    let label_name = || {
+        data.label
+            .as_ref()
+            .and_then(|l| l.name.clone())
+            .unwrap_or_default()
+    };
+
+    match action.action_type.as_str() {
+        "addLabelToCard" => vec![FieldChange::LabelAdded(label_name())],
+        "removeLabelFromCard" => vec![FieldChange::LabelRemoved(label_name())],
+        "updateCard" => {
+            let (card, old) = match (&data.card, &data.old) {
+                (Some(card), Some(old)) => (card, old),
+                _ => return vec![],
+            };
+            let new = |key: &str| card.extra.get(key);
+
+            let mut result = vec![];
+            if let Some(Value::String(from)) = old.get("name") {
+                result.push(FieldChange::Name {
+                    from: from.clone(),
+                    to: card.name.clone().unwrap_or_default(),
+                });
+            }
+            if let Some(Value::String(from)) = old.get("desc") {
+                result.push(FieldChange::Desc {
+                    from: from.clone(),
+                    to: new("desc")
+                        .and_then(|v| v.as_str())
+                        .map(String::from)
+                        .unwrap_or_default(),
+                });
+            }
+            if let (Some(before), Some(after)) = (&data.list_before, &data.list_after) {
+                result.push(FieldChange::List {
+                    from: before.name.clone().unwrap_or_else(|| before.id.clone()),
+                    to: after.name.clone().unwrap_or_else(|| after.id.clone()),
+                });
+            }
+            if old.contains_key("due") {
+                result.push(FieldChange::Due {
+                    from: parse_date(old.get("due")),
+                    to: parse_date(new("due")),
+                });
+            }
+            result
+        }
+        _ => vec![],
+    }
+}
+
+fn parse_date(value: Option<&Value>) -> Option<DateTime<Utc>> {
+    let date = value?.as_str()?;
+    DateTime::parse_from_rfc3339(date)
+        .ok()
+        .map(|d| d.with_timezone(&Utc))
+}
+
+impl Card {
+    /// Retrieves the history of the name, description, list, labels and due
+    /// date of the card. Only the most recent 1000 changes are considered.
+    #[cfg(feature = "http")]
+    pub fn history(client: &TrelloClient, card_id: &str) -> Result<CardHistory> {
+        let url = client.config.get_trello_url(
+            &format!("/1/cards/{}/actions", card_id),
+            &[("filter", HISTORY_ACTIONS), ("limit", ACTIONS_LIMIT)],
+        )?;
+        let actions: Vec<Action> = client.send(client.get(url))?.json()?;
+
+        Ok(CardHistory::from_actions(card_id, &actions))
+    }
+}
+
+impl Renderable for CardHistory {
+    /// Renders one line per change. Description changes are followed by a
+    /// diff of the description lines.
+    fn render_with(&self, options: &RenderOptions) -> String {
+        let mut result = vec![];
+
+        for entry in &self.entries {
+            let summary = match &entry.change {
+                FieldChange::Name { from, to } => format!("Renamed '{}' to '{}'", from, to),
+                FieldChange::Desc { .. } => String::from("Edited the description"),
+                FieldChange::List { from, to } => format!("Moved from {} to {}", from, to),
+                FieldChange::LabelAdded(name) => format!("Added label {}", name),
+                FieldChange::LabelRemoved(name) => format!("Removed label {}", name),
+                FieldChange::Due { to: None, .. } => String::from("Removed the due date"),
+                FieldChange::Due { to: Some(due), .. } => format!(
+                    "Set the due date to {}",
+                    options.format_date(due, "%a %b %e %Y %H:%M")
+                ),
+            };
+
+            let mut line = format!(
+                "{} {}",
+                options.format_date(&entry.date, "%Y-%m-%d %H:%M").dimmed(),
+                summary
+            );
+            if let Some(member) = &entry.member {
+                line.push_str(&format!(" {}", format!("({})", member).dimmed()));
+            }
+            result.push(line);
+
+            if let FieldChange::Desc { from, to } = &entry.change {
+                for (marker, text) in line_diff(from, to) {
+                    let text = format!("    {} {}", marker, text);
+                    result.push(match marker {
+                        '-' => text.red().to_string(),
+                        '+' => text.green().to_string(),
+                        _ => text,
+                    });
+                }
+            }
+        }
+
+        result.join("\n")
+    }
+
+    fn simple_render(&self) -> String {
+        format!("{}: {} changes", self.card_id, self.entries.len())
+    }
+}
@@ -0,0 +1,119 @@
+#[cfg(feature = "http")]
+use crate::client::TrelloClient;
+use crate::formatting::header;
+#[cfg(feature = "http")]
+use crate::trello_error::TrelloError;
+use crate::trello_object::{Detail, RenderOptions, Renderable, TrelloObject};
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "http")]
+type Result<T> = std::result::Result<T, TrelloError>;
+
+// https://developer.atlassian.com/cloud/trello/guides/rest-api/object-definitions/#checklist-object
+#[derive(Deserialize, Serialize, Debug, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Checklist {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub check_items: Vec<CheckItem>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckItem {
+    pub id: String,
+    pub name: String,
+    pub state: CheckItemState,
+}
+
+#[derive(Deserialize, Serialize, Debug, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckItemState {
+    Complete,
+    Incomplete,
+}
+
+impl TrelloObject for Checklist {
+    fn get_type() -> String {
+        String::from("Checklist")
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn get_fields() -> &'static [&'static str] {
+        &["id", "name"]
+    }
+}
+
+impl Renderable for Checklist {
+    fn render_with(&self, options: &RenderOptions) -> String {
+        if options.detail == Detail::Minimal {
+            return self.simple_render();
+        }
+
+        let mut result = vec![header(&self.name, "-")];
+        for item in &self.check_items {
+            let mark = match item.state {
+                CheckItemState::Complete => "x",
+                CheckItemState::Incomplete => " ",
+            };
+            result.push(format!("[{}] {}", mark, item.name));
+        }
+        result.join("\n")
+    }
+
+    fn simple_render(&self) -> String {
+        let (complete, total) = self.progress();
+        format!("{} [{}/{}]", self.name, complete, total)
+    }
+}
+
+impl Checklist {
+    pub fn new(id: &str, name: &str, check_items: Vec<CheckItem>) -> Checklist {
+        Checklist {
+            id: String::from(id),
+            name: String::from(name),
+            check_items,
+            #[cfg(feature = "extra-fields")]
+            extra: serde_json::Map::new(),
+        }
+    }
+
+    /// Number of complete check items and the total number of check items
+    pub fn progress(&self) -> (usize, usize) {
+        let complete = self
+            .check_items
+            .iter()
+            .filter(|i| i.state == CheckItemState::Complete)
+            .count();
+
+        (complete, self.check_items.len())
+    }
+
+    #[cfg(feature = "http")]
+    pub fn get_all(client: &TrelloClient, card_id: &str) -> Result<Vec<Checklist>> {
+        let url = client.config.get_trello_url(
+            &format!("/1/cards/{}/checklists", card_id),
+            &[("fields", &Checklist::get_fields().join(","))],
+        )?;
+
+        Ok(client.send(client.get(url))?.json()?)
+    }
+}
+
+impl CheckItem {
+    pub fn new(id: &str, name: &str, state: CheckItemState) -> CheckItem {
+        CheckItem {
+            id: String::from(id),
+            name: String::from(name),
+            state,
+        }
+    }
+}
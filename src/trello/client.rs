@@ -1,48 +1,577 @@
+use crate::trello_error::TrelloError;
+#[cfg(feature = "http")]
+use crate::{
+    audit_log::AuditLog,
+    board::Board,
+    cache::LookupCache,
+    card::Card,
+    member::Member,
+    rate_limit::RateLimitStatus,
+    redact::redact_url,
+    response::{self, BodySize},
+    safety::{ConfirmationToken, Deletion, SafetyPolicy},
+    single_flight::SingleFlight,
+    transfer::{self, TransferStats},
+};
+
+#[cfg(feature = "http")]
+use reqwest::{
+    blocking::{Request, RequestBuilder, Response},
+    header::{HeaderMap, HeaderName, HeaderValue, ACCEPT_ENCODING},
+    Method, StatusCode,
+};
+#[cfg(feature = "http")]
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use std::env;
 use std::error::Error;
+use std::fmt;
 use std::fs;
 use std::path::PathBuf;
+#[cfg(feature = "http")]
+use std::sync::{Mutex, MutexGuard};
+#[cfg(feature = "http")]
+use std::time::{Duration, Instant};
+
+/// Environment variables read by `ClientConfig::from_env`
+const KEY_VAR: &str = "TRELLO_API_KEY";
+const TOKEN_VAR: &str = "TRELLO_API_TOKEN";
+const HOST_VAR: &str = "TRELLO_API_HOST";
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Deserialize, Serialize)]
 pub struct ClientConfig {
     #[serde(default = "ClientConfig::default_host")]
     pub host: String,
+    /// Prefix added to the path of every request, for use when Trello is
+    /// exposed under a sub path (e.g. by a corporate proxy)
+    #[serde(default)]
+    pub path_prefix: String,
     pub token: String,
     pub key: String,
 }
 
+#[cfg(feature = "http")]
 #[derive(Debug)]
 pub struct TrelloClient {
     pub config: ClientConfig,
     pub client: reqwest::blocking::Client,
+    rate_limit: Mutex<Option<RateLimitStatus>>,
+    lookups: Mutex<LookupCache>,
+    safety_policy: SafetyPolicy,
+    audit_log: Option<AuditLog>,
+    read_only: bool,
+    in_flight: SingleFlight,
+    transfer: Mutex<TransferStats>,
 }
 
+#[cfg(feature = "http")]
 impl TrelloClient {
     pub fn new(config: ClientConfig) -> Self {
         TrelloClient {
             config,
             client: reqwest::blocking::Client::new(),
+            rate_limit: Mutex::new(None),
+            lookups: Mutex::new(LookupCache::default()),
+            safety_policy: SafetyPolicy::default(),
+            audit_log: None,
+            read_only: false,
+            in_flight: SingleFlight::default(),
+            transfer: Mutex::new(TransferStats::default()),
+        }
+    }
+
+    /// Creates a client which only retrieves data. Any request which would
+    /// create, update or delete something fails with `TrelloError::ReadOnly`
+    /// before it is sent, so that e.g. reporting tools are guaranteed to have
+    /// no side effects.
+    pub fn read_only(config: ClientConfig) -> Self {
+        TrelloClient {
+            read_only: true,
+            ..TrelloClient::new(config)
         }
     }
+
+    pub fn builder(config: ClientConfig) -> ClientBuilder {
+        ClientBuilder::new(config)
+    }
+
+    /// Creates a client with the credentials found in the environment,
+    /// see `ClientConfig::from_env`
+    pub fn from_env() -> Result<Self, TrelloError> {
+        Ok(TrelloClient::new(ClientConfig::from_env()?))
+    }
+
+    pub fn get(&self, url: url::Url) -> RequestBuilder {
+        self.client.get(url)
+    }
+
+    pub fn post(&self, url: url::Url) -> RequestBuilder {
+        self.client.post(url)
+    }
+
+    pub fn put(&self, url: url::Url) -> RequestBuilder {
+        self.client.put(url)
+    }
+
+    pub fn delete(&self, url: url::Url) -> RequestBuilder {
+        self.client.delete(url)
+    }
+
+    /// Sends the given request, keeping track of the rate limit information
+    /// returned by Trello and recording changes in the audit log (if any).
+    /// Unlike `send`, error statuses are not treated as errors.
+    fn dispatch(
+        &self,
+        request: RequestBuilder,
+    ) -> Result<(Response, Option<BodySize>), TrelloError> {
+        let request = request.build()?;
+        debug!("{} {}", request.method(), redact_url(request.url()));
+
+        if self.read_only && !request.method().is_safe() {
+            return Err(TrelloError::ReadOnly(format!(
+                "{} {}",
+                request.method(),
+                redact_url(request.url())
+            )));
+        }
+
+        // identical GETs sent concurrently, e.g. by get_many_nested, share
+        // a single request
+        let mut size = None;
+        let response = match &self.audit_log {
+            Some(log) if request.method() != Method::GET => {
+                let (response, received) = log.execute(&self.client, request)?;
+                if let Some(received) = &received {
+                    self.transfer.lock().unwrap().record(received);
+                }
+                size = received;
+                response
+            }
+            _ if request.method() == Method::GET => {
+                let key = request.url().to_string();
+                self.in_flight.run(&key, || {
+                    let (response, received) = self.receive(request)?;
+                    size = received;
+                    Ok::<_, TrelloError>(response)
+                })?
+            }
+            _ => {
+                let (response, received) = self.receive(request)?;
+                size = received;
+                response
+            }
+        };
+
+        if let Some(status) = RateLimitStatus::from_headers(response.headers()) {
+            trace!("Rate limit status: {:?}", status);
+            *self.rate_limit.lock().unwrap() = Some(status);
+        }
+
+        Ok((response, size))
+    }
+
+    /// Executes the request, asking for a compressed response. Successful
+    /// responses are decompressed before they are returned. Changes recorded
+    /// in the audit log are executed by the log instead, see
+    /// `AuditLog::execute`.
+    fn receive(&self, mut request: Request) -> Result<(Response, Option<BodySize>), TrelloError> {
+        request.headers_mut().insert(
+            ACCEPT_ENCODING,
+            HeaderValue::from_static(transfer::ACCEPT_ENCODING),
+        );
+
+        let response = self.client.execute(request)?;
+        if !response.status().is_success() {
+            return Ok((response, None));
+        }
+
+        let (response, size) = transfer::decode(response)?;
+        debug!(
+            "Received {} bytes ({} decompressed)",
+            size.transferred, size.decoded
+        );
+        self.transfer.lock().unwrap().record(&size);
+
+        Ok((response, Some(size)))
+    }
+
+    /// Sends the given request, returning an error if the response has
+    /// an error status.
+    pub fn send(&self, request: RequestBuilder) -> Result<Response, TrelloError> {
+        Ok(self.dispatch(request)?.0.error_for_status()?)
+    }
+
+    /// Sends the given request and deserializes the body of the response, keeping
+    /// the response status, duration of the request, raw body and its size
+    /// alongside the value.
+    /// If deserialization fails, the raw body is included in the returned error.
+    pub fn send_with_response<T: DeserializeOwned>(
+        &self,
+        request: RequestBuilder,
+    ) -> Result<response::Response<T>, TrelloError> {
+        let start = Instant::now();
+        let (response, size) = self.dispatch(request)?;
+        let response = response.error_for_status()?;
+        let status = response.status();
+        let body = response.text()?;
+        let duration = start.elapsed();
+
+        debug!("Received {} response in {:?}", status, duration);
+        trace!("Response body: {}", body);
+
+        let value = serde_json::from_str(&body).map_err(|source| TrelloError::Deserialize {
+            source,
+            body: body.clone(),
+        })?;
+
+        Ok(response::Response {
+            value,
+            status,
+            duration,
+            body,
+            size,
+        })
+    }
+
+    /// Retrieves the given path (e.g. "/1/boards/{id}/checklists") with the
+    /// given query parameters and deserializes the body of the response. This
+    /// allows calling endpoints which are not wrapped by this crate with the
+    /// same credentials, rate limit tracking and error handling.
+    pub fn get_json<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        params: &[(&str, &str)],
+    ) -> Result<T, TrelloError> {
+        let url = self.config.get_trello_url(path, params)?;
+        Ok(self.send_with_response(self.get(url))?.value)
+    }
+
+    /// Posts the given form parameters to the path and deserializes the body
+    /// of the response, see `get_json`
+    pub fn post_json<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        form: &[(&str, &str)],
+    ) -> Result<T, TrelloError> {
+        let url = self.config.get_trello_url(path, &[])?;
+        Ok(self.send_with_response(self.post(url).form(form))?.value)
+    }
+
+    /// Puts the given form parameters to the path and deserializes the body
+    /// of the response, see `get_json`
+    pub fn put_json<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        form: &[(&str, &str)],
+    ) -> Result<T, TrelloError> {
+        let url = self.config.get_trello_url(path, &[])?;
+        Ok(self.send_with_response(self.put(url).form(form))?.value)
+    }
+
+    /// Deletes the given path and deserializes the body of the response, see
+    /// `get_json`. The object being deleted is the last segment of the path,
+    /// which is checked against the safety policy of the client. As objects
+    /// can't be archived through an arbitrary path, the deletion is refused
+    /// under `SafetyPolicy::ArchiveOnly`.
+    pub fn delete_json<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        params: &[(&str, &str)],
+        confirmation: Option<&ConfirmationToken>,
+    ) -> Result<T, TrelloError> {
+        let object_id = path.trim_end_matches('/').rsplit('/').next().unwrap_or("");
+        if self.safety_policy.check(object_id, confirmation)? == Deletion::Archive {
+            return Err(TrelloError::DeletionNotPermitted(format!(
+                "'{}' cannot be archived, and only archiving is permitted",
+                object_id
+            )));
+        }
+
+        let url = self.config.get_trello_url(path, params)?;
+        Ok(self.send_with_response(self.delete(url))?.value)
+    }
+
+    pub fn safety_policy(&self) -> SafetyPolicy {
+        self.safety_policy
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    pub fn audit_log(&self) -> Option<&AuditLog> {
+        self.audit_log.as_ref()
+    }
+
+    /// Confirms that the object with the given id should be permanently deleted.
+    /// The token is required by delete operations when the client has been built
+    /// with `SafetyPolicy::RequireConfirmationToken`.
+    pub fn confirm_deletion(&self, object_id: &str) -> ConfirmationToken {
+        ConfirmationToken::new(object_id)
+    }
+
+    /// The number of responses and bytes received by this client, e.g. to
+    /// keep an eye on data usage over metered connections. Only successful
+    /// responses are counted.
+    pub fn transfer_stats(&self) -> TransferStats {
+        *self.transfer.lock().unwrap()
+    }
+
+    /// Returns the rate limit information reported by the last response
+    /// received from Trello. None is returned if no request has been made yet.
+    pub fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        *self.rate_limit.lock().unwrap()
+    }
+
+    pub(crate) fn lookups(&self) -> MutexGuard<'_, LookupCache> {
+        self.lookups.lock().unwrap()
+    }
+
+    /// Forgets the labels and members of the given board retrieved by this
+    /// client, e.g. after they were changed outside of this client
+    pub fn invalidate_lookups(&self, board_id: &str) {
+        self.lookups().invalidate_board(board_id);
+    }
+
+    /// Forgets the labels and members of all boards retrieved by this client
+    pub fn clear_lookups(&self) {
+        self.lookups().clear();
+    }
+
+    /// Retrieves the member the client is authenticated as
+    pub fn me(&self) -> Result<Member, TrelloError> {
+        Member::me(self)
+    }
+
+    /// Checks that the credentials of the client are valid. Unlike `me`, an
+    /// unauthorized response is mapped to either `TrelloError::InvalidKey` or
+    /// `TrelloError::InvalidToken` (when the token is invalid, expired or revoked)
+    /// so that a precise error can be shown to the user.
+    pub fn validate(&self) -> Result<Member, TrelloError> {
+        let url = self.config.get_trello_url("/1/members/me/", &[])?;
+
+        let (response, _) = self.dispatch(self.get(url))?;
+
+        if response.status() == StatusCode::UNAUTHORIZED {
+            let (response, _) = transfer::decode(response)?;
+            let body = response.text()?;
+            debug!("Unable to validate credentials: {}", body);
+
+            return Err(if body.to_lowercase().contains("key") {
+                TrelloError::InvalidKey
+            } else {
+                TrelloError::InvalidToken(body.trim().to_string())
+            });
+        }
+
+        Ok(response.error_for_status()?.json()?)
+    }
+}
+
+#[cfg(feature = "http")]
+/// Builds a TrelloClient with additional options
+/// ```
+/// # fn main() -> Result<(), trello::TrelloError> {
+/// let config = trello::ClientConfig::new("https://api.trello.com", "some-token", "some-key");
+/// let client = trello::TrelloClient::builder(config)
+///     .header("X-Proxy-Auth", "secret")?
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ClientBuilder {
+    config: ClientConfig,
+    headers: HeaderMap,
+    safety_policy: SafetyPolicy,
+    audit_log: Option<AuditLog>,
+    read_only: bool,
+    http2_prior_knowledge: bool,
+    // None keeps the default of reqwest, Some(None) never closes connections
+    pool_idle_timeout: Option<Option<Duration>>,
+    pool_max_idle_per_host: Option<usize>,
+}
+
+#[cfg(feature = "http")]
+impl ClientBuilder {
+    pub fn new(config: ClientConfig) -> Self {
+        ClientBuilder {
+            config,
+            headers: HeaderMap::new(),
+            safety_policy: SafetyPolicy::default(),
+            audit_log: None,
+            read_only: false,
+            http2_prior_knowledge: false,
+            pool_idle_timeout: None,
+            pool_max_idle_per_host: None,
+        }
+    }
+
+    /// Speaks HTTP/2 from the start, without negotiating it, for servers
+    /// and proxies known to support it. As nothing is negotiated, every
+    /// request fails against a server or proxy which only speaks HTTP/1.1,
+    /// as many corporate proxies do. HTTP/2 is otherwise never used, since
+    /// the native TLS backend of reqwest 0.10 doesn't offer it through ALPN
+    /// during the handshake.
+    pub fn http2_prior_knowledge(mut self, enabled: bool) -> Self {
+        self.http2_prior_knowledge = enabled;
+        self
+    }
+
+    /// Sets how long an idle connection is kept open for reuse, or forever
+    /// with None. Watchers which poll less often than this open a new
+    /// connection for every poll, while some middleboxes silently drop
+    /// connections which stay idle for too long.
+    pub fn pool_idle_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the maximum number of idle connections kept open to each host
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Rejects every request which is not a retrieval, see
+    /// `TrelloClient::read_only`
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Sets how the client handles requests to permanently delete objects
+    pub fn safety_policy(mut self, safety_policy: SafetyPolicy) -> Self {
+        self.safety_policy = safety_policy;
+        self
+    }
+
+    /// Records every create, update and delete request in the given audit log
+    pub fn audit_log(mut self, audit_log: AuditLog) -> Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
+
+    /// Adds a header which will be sent with every request
+    pub fn header(mut self, name: &str, value: &str) -> Result<Self, TrelloError> {
+        let name = HeaderName::from_bytes(name.as_bytes())
+            .map_err(|_| TrelloError::InvalidHeader(name.to_string()))?;
+        let value = HeaderValue::from_str(value)
+            .map_err(|_| TrelloError::InvalidHeader(name.to_string()))?;
+
+        self.headers.insert(name, value);
+        Ok(self)
+    }
+
+    pub fn build(self) -> Result<TrelloClient, TrelloError> {
+        let mut builder = reqwest::blocking::Client::builder().default_headers(self.headers);
+        if self.http2_prior_knowledge {
+            builder = builder.http2_prior_knowledge();
+        }
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        let client = builder.build()?;
+
+        Ok(TrelloClient {
+            config: self.config,
+            client,
+            rate_limit: Mutex::new(None),
+            lookups: Mutex::new(LookupCache::default()),
+            safety_policy: self.safety_policy,
+            audit_log: self.audit_log,
+            read_only: self.read_only,
+            in_flight: SingleFlight::default(),
+            transfer: Mutex::new(TransferStats::default()),
+        })
+    }
+}
+
+// The credentials are never included, so that they cannot end up in logs
+impl fmt::Debug for ClientConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientConfig")
+            .field("host", &self.host)
+            .field("path_prefix", &self.path_prefix)
+            .field("token", &"<redacted>")
+            .field("key", &"<redacted>")
+            .finish()
+    }
 }
 
 impl ClientConfig {
     pub fn new(host: &str, token: &str, key: &str) -> Self {
         ClientConfig {
             host: String::from(host),
+            path_prefix: String::new(),
             token: String::from(token),
             key: String::from(key),
         }
     }
 
-    fn config_dir() -> Result<PathBuf, Box<dyn Error>> {
+    /// Reads the credentials from the `TRELLO_API_KEY` and `TRELLO_API_TOKEN`
+    /// environment variables. The host may be overridden with `TRELLO_API_HOST`.
+    /// The returned error lists every variable which is missing or malformed.
+    pub fn from_env() -> Result<Self, TrelloError> {
+        Self::from_env_with(|name| env::var(name).ok())
+    }
+
+    /// Like `from_env`, but looks up variables with the given function
+    pub fn from_env_with<F: Fn(&str) -> Option<String>>(lookup: F) -> Result<Self, TrelloError> {
+        let mut problems = vec![];
+
+        let mut var = |name: &str, valid: fn(&str) -> bool, expected: &str| {
+            match lookup(name).map(|v| v.trim().to_string()) {
+                None => problems.push(format!("{} is not set", name)),
+                Some(v) if v.is_empty() => problems.push(format!("{} is empty", name)),
+                Some(v) if !valid(&v) => {
+                    problems.push(format!("{} is malformed (expected {})", name, expected))
+                }
+                Some(v) => return v,
+            }
+            String::new()
+        };
+
+        let key = var(
+            KEY_VAR,
+            |v| v.len() == 32 && v.chars().all(|c| c.is_ascii_hexdigit()),
+            "32 hexadecimal characters",
+        );
+        let token = var(
+            TOKEN_VAR,
+            |v| v.chars().all(|c| c.is_ascii_alphanumeric()),
+            "only letters and digits",
+        );
+
+        let host = match lookup(HOST_VAR).map(|v| v.trim().to_string()) {
+            Some(host) if !host.is_empty() => match url::Url::parse(&host) {
+                Ok(url) if url.scheme() == "http" || url.scheme() == "https" => host,
+                _ => {
+                    problems.push(format!("{} is not a valid http(s) url", HOST_VAR));
+                    host
+                }
+            },
+            _ => Self::default_host(),
+        };
+
+        if !problems.is_empty() {
+            return Err(TrelloError::Environment(problems.join(", ")));
+        }
+
+        Ok(ClientConfig::new(&host, &token, &key))
+    }
+
+    pub(crate) fn config_dir() -> Result<PathBuf, Box<dyn Error>> {
         let mut config_path = dirs::config_dir().ok_or("Unable to determine config directory")?;
         config_path.push("tro");
 
         Ok(config_path)
     }
 
-    fn config_path() -> Result<PathBuf, Box<dyn Error>> {
+    pub(crate) fn config_path() -> Result<PathBuf, Box<dyn Error>> {
         let mut config_path = Self::config_dir()?;
         config_path.push("config.toml");
 
@@ -72,13 +601,25 @@ impl ClientConfig {
         String::from("https://api.trello.com")
     }
 
+    /// The base URL all requests are made against (host + path prefix)
+    pub fn base_url(&self) -> String {
+        format!(
+            "{}/{}",
+            self.host.trim_end_matches('/'),
+            self.path_prefix.trim_matches('/')
+        )
+        .trim_end_matches('/')
+        .to_string()
+    }
+
     /// Gets the resultant URL of the Trello Config given some path and additional
     /// parameters. The authentication credentials provided will be included as part
     /// of the generated URL
     /// ```
     /// # fn main() -> Result<(), url::ParseError> {
-    /// let config = trello::ClientConfig {
+    /// let mut config = trello::ClientConfig {
     ///     host: String::from("https://api.trello.com"),
+    ///     path_prefix: String::new(),
     ///     token: String::from("some-token"),
     ///     key: String::from("some-key"),
     /// };
@@ -92,6 +633,13 @@ impl ClientConfig {
     ///     url.to_string(),
     ///     "https://api.trello.com/1/boards/some-id/?key=some-key&token=some-token&lists=open",
     /// );
+    /// config.host = String::from("https://proxy.example.com/");
+    /// config.path_prefix = String::from("/trello");
+    /// let url = config.get_trello_url("/1/me/boards/", &[])?;
+    /// assert_eq!(
+    ///     url.to_string(),
+    ///     "https://proxy.example.com/trello/1/me/boards/?key=some-key&token=some-token"
+    /// );
     /// # Ok(())
     /// # }
     /// ```
@@ -102,9 +650,40 @@ impl ClientConfig {
     ) -> Result<url::Url, url::ParseError> {
         let auth_params: &[(&str, &str)] = &[("key", &self.key), ("token", &self.token)];
 
-        Ok(url::Url::parse_with_params(
-            &format!("{}{}", self.host, path),
+        url::Url::parse_with_params(
+            &format!("{}{}", self.base_url(), path),
             &[auth_params, params].concat(),
-        )?)
+        )
+    }
+}
+
+#[cfg(feature = "http")]
+/// An object fetched from a Trello url
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum TrelloUrlObject {
+    Board(Board),
+    Card(Card),
+}
+
+#[cfg(feature = "http")]
+/// Fetches the board or card a Trello url such as `https://trello.com/c/<short link>/...`
+/// or `https://trello.com/b/<short link>/...` refers to.
+pub fn resolve_url(client: &TrelloClient, url: &str) -> Result<TrelloUrlObject, TrelloError> {
+    let parsed = url::Url::parse(url)?;
+    let segments = parsed
+        .path_segments()
+        .map(|s| s.collect::<Vec<&str>>())
+        .unwrap_or_default();
+
+    match segments.as_slice() {
+        ["b", short_link, ..] if !short_link.is_empty() => {
+            debug!("Resolving board with short link {}", short_link);
+            Ok(TrelloUrlObject::Board(Board::get(client, short_link)?))
+        }
+        ["c", short_link, ..] if !short_link.is_empty() => {
+            debug!("Resolving card with short link {}", short_link);
+            Ok(TrelloUrlObject::Card(Card::get(client, short_link)?))
+        }
+        _ => Err(TrelloError::UnsupportedUrl(url.to_string())),
     }
 }
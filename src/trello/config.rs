@@ -0,0 +1,146 @@
+//! Support for multiple named profiles (e.g. work and personal accounts),
+//! each with their own credentials and an optional default board.
+use crate::client::ClientConfig;
+#[cfg(feature = "http")]
+use crate::client::TrelloClient;
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone)]
+pub struct Profile {
+    #[serde(default = "ClientConfig::default_host")]
+    pub host: String,
+    pub token: String,
+    pub key: String,
+    pub default_board: Option<String>,
+}
+
+impl Profile {
+    pub fn new(host: &str, token: &str, key: &str, default_board: Option<&str>) -> Profile {
+        Profile {
+            host: String::from(host),
+            token: String::from(token),
+            key: String::from(key),
+            default_board: default_board.map(String::from),
+        }
+    }
+
+    pub fn client_config(&self) -> ClientConfig {
+        ClientConfig::new(&self.host, &self.token, &self.key)
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct Profiles {
+    /// Name of the profile used when none is explicitly requested
+    pub current: Option<String>,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+impl Profiles {
+    fn profiles_path() -> Result<PathBuf> {
+        let mut path = ClientConfig::config_dir()?;
+        path.push("profiles.toml");
+
+        Ok(path)
+    }
+
+    /// Loads profiles from the tro configuration directory. An empty set of
+    /// profiles is returned if none have been saved yet.
+    pub fn load() -> Result<Profiles> {
+        let path = Self::profiles_path()?;
+
+        if path.exists() {
+            Self::load_from(&path)
+        } else {
+            debug!("No profiles found at {:?}", path);
+            Ok(Profiles::default())
+        }
+    }
+
+    pub fn load_from(path: &Path) -> Result<Profiles> {
+        debug!("Loading profiles from {:?}", path);
+        let contents = fs::read_to_string(path)?;
+
+        Ok(toml::from_str(&contents)?)
+    }
+
+    pub fn save(&self) -> Result<()> {
+        fs::create_dir_all(ClientConfig::config_dir()?)?;
+
+        self.save_to(&Self::profiles_path()?)
+    }
+
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        debug!("Saving profiles to {:?}", path);
+        fs::write(path, toml::to_string(self)?)?;
+
+        Ok(())
+    }
+
+    /// Returns the names of all available profiles in alphabetical order
+    pub fn list(&self) -> Vec<&str> {
+        self.profiles.keys().map(|k| k.as_str()).collect()
+    }
+
+    pub fn get(&self, name: &str) -> Result<&Profile> {
+        Ok(self
+            .profiles
+            .get(name)
+            .ok_or(format!("Profile '{}' not found", name))?)
+    }
+
+    /// Adds a profile, replacing any existing profile with the same name.
+    /// The first profile to be added becomes the current profile.
+    pub fn add(&mut self, name: &str, profile: Profile) {
+        self.profiles.insert(String::from(name), profile);
+
+        if self.current.is_none() {
+            self.current = Some(String::from(name));
+        }
+    }
+
+    pub fn remove(&mut self, name: &str) -> Result<Profile> {
+        let profile = self
+            .profiles
+            .remove(name)
+            .ok_or(format!("Profile '{}' not found", name))?;
+
+        if self.current.as_deref() == Some(name) {
+            self.current = None;
+        }
+
+        Ok(profile)
+    }
+
+    /// Makes the profile with the given name the current profile
+    pub fn switch(&mut self, name: &str) -> Result<()> {
+        self.get(name)?;
+        self.current = Some(String::from(name));
+
+        Ok(())
+    }
+
+    pub fn current(&self) -> Result<&Profile> {
+        let name = self.current.as_ref().ok_or("No current profile set")?;
+
+        self.get(name)
+    }
+}
+
+#[cfg(feature = "http")]
+impl TrelloClient {
+    /// Creates a client using the credentials of the saved profile with the given name
+    pub fn from_profile(name: &str) -> Result<TrelloClient> {
+        let profiles = Profiles::load()?;
+
+        Ok(TrelloClient::new(profiles.get(name)?.client_config()))
+    }
+}
@@ -0,0 +1,119 @@
+//! The board and list a user is currently working in, persisted between
+//! invocations so that tools can offer "cd into a board" semantics. High
+//! level operations such as those in `ops` take board and list names; use
+//! `Context::board_or` and `Context::list_or` to fall back to the context
+//! when a name is omitted.
+use crate::client::ClientConfig;
+
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+#[derive(Debug, Default, Deserialize, Serialize, Eq, PartialEq, Clone)]
+pub struct Context {
+    pub board: Option<String>,
+    pub list: Option<String>,
+}
+
+impl Context {
+    fn context_path() -> Result<PathBuf> {
+        let mut path = ClientConfig::config_dir()?;
+        path.push("context.toml");
+
+        Ok(path)
+    }
+
+    /// Loads the context from the tro configuration directory. An empty
+    /// context is returned if none has been saved yet.
+    pub fn load() -> Result<Context> {
+        let path = Self::context_path()?;
+
+        if path.exists() {
+            Self::load_from(&path)
+        } else {
+            debug!("No context found at {:?}", path);
+            Ok(Context::default())
+        }
+    }
+
+    pub fn load_from(path: &Path) -> Result<Context> {
+        debug!("Loading context from {:?}", path);
+        let contents = fs::read_to_string(path)?;
+
+        Ok(toml::from_str(&contents)?)
+    }
+
+    pub fn save(&self) -> Result<()> {
+        fs::create_dir_all(ClientConfig::config_dir()?)?;
+
+        self.save_to(&Self::context_path()?)
+    }
+
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        debug!("Saving context to {:?}", path);
+        fs::write(path, toml::to_string(self)?)?;
+
+        Ok(())
+    }
+
+    /// Changes the current board. The current list is cleared as it
+    /// belongs to the previous board.
+    pub fn set_board(&mut self, board: &str) {
+        self.board = Some(String::from(board));
+        self.list = None;
+    }
+
+    pub fn set_list(&mut self, list: &str) -> Result<()> {
+        if self.board.is_none() {
+            return Err("Cannot set a list without a current board".into());
+        }
+        self.list = Some(String::from(list));
+
+        Ok(())
+    }
+
+    /// The given board, or the current board if none is given
+    pub fn board_or<'a>(&'a self, board: Option<&'a str>) -> Result<&'a str> {
+        Ok(board
+            .or(self.board.as_deref())
+            .ok_or("No board given and no current board set")?)
+    }
+
+    /// The given list, or the current list if none is given
+    pub fn list_or<'a>(&'a self, list: Option<&'a str>) -> Result<&'a str> {
+        Ok(list
+            .or(self.list.as_deref())
+            .ok_or("No list given and no current list set")?)
+    }
+}
+
+/// The saved context
+pub fn current() -> Result<Context> {
+    Context::load()
+}
+
+/// Saves the given board as the current board, clearing the current list
+pub fn set_board(board: &str) -> Result<Context> {
+    let mut context = Context::load()?;
+    context.set_board(board);
+    context.save()?;
+
+    Ok(context)
+}
+
+/// Saves the given list of the current board as the current list
+pub fn set_list(list: &str) -> Result<Context> {
+    let mut context = Context::load()?;
+    context.set_list(list)?;
+    context.save()?;
+
+    Ok(context)
+}
+
+/// Removes the saved board and list
+pub fn clear() -> Result<()> {
+    Context::default().save()
+}
@@ -0,0 +1,74 @@
+//! Due dates written the way people say them, such as "tomorrow",
+//! "next friday 5pm" or "in 3 days", for interactive use where ISO
+//! timestamps are awkward to type. Relative dates are resolved in the local
+//! timezone.
+use crate::card::CardContents;
+use crate::trello_error::TrelloError;
+
+use chrono::{DateTime, Local, TimeZone, Utc};
+use chrono_english::{parse_date_string, Dialect};
+
+type Result<T> = std::result::Result<T, TrelloError>;
+
+/// Prefix of the line holding the due date in the editor buffer
+const DUE_PREFIX: &str = "Due:";
+
+/// Parses a date such as "next friday 5pm" relative to now. RFC 3339
+/// timestamps are also accepted.
+pub fn parse_human(text: &str) -> Result<DateTime<Utc>> {
+    parse_human_at(text, Local::now())
+}
+
+/// Parses a date relative to the given time, in its timezone
+pub fn parse_human_at<Tz: TimeZone>(text: &str, now: DateTime<Tz>) -> Result<DateTime<Utc>>
+where
+    Tz::Offset: Copy,
+{
+    let text = text.trim();
+    if let Ok(date) = DateTime::parse_from_rfc3339(text) {
+        return Ok(date.with_timezone(&Utc));
+    }
+
+    parse_date_string(text, now, Dialect::Us)
+        .map(|date| date.with_timezone(&Utc))
+        .map_err(|e| TrelloError::DateParse(format!("'{}': {}", text, e)))
+}
+
+/// The line added to the top of the description in the editor buffer for
+/// cards with a due date, see `take_due`
+pub fn due_line(due: &DateTime<Utc>) -> String {
+    format!(
+        "{} {}",
+        DUE_PREFIX,
+        due.with_timezone(&Local).format("%Y-%m-%d %H:%M")
+    )
+}
+
+/// Removes a `Due: <date>` line from the start of the description in the
+/// editor buffer and parses its date with `parse_human`. Returns
+/// `Ok(None)` when there is no such line.
+pub fn take_due(contents: &mut CardContents) -> Result<Option<DateTime<Utc>>> {
+    take_due_at(contents, Local::now())
+}
+
+pub fn take_due_at<Tz: TimeZone>(
+    contents: &mut CardContents,
+    now: DateTime<Tz>,
+) -> Result<Option<DateTime<Utc>>>
+where
+    Tz::Offset: Copy,
+{
+    let (first, rest) = match contents.desc.split_once('\n') {
+        Some((first, rest)) => (first, rest),
+        None => (contents.desc.as_str(), ""),
+    };
+
+    let text = match first.trim().strip_prefix(DUE_PREFIX) {
+        Some(text) => text,
+        None => return Ok(None),
+    };
+
+    let due = parse_human_at(text, now)?;
+    contents.desc = rest.to_string();
+    Ok(Some(due))
+}
@@ -0,0 +1,147 @@
+//! Dependencies between cards, declared by convention with a line in the card
+//! description such as `depends: a1B2c3D4` (a card short link),
+//! `depends: https://trello.com/c/a1B2c3D4` or `depends: <card id>`. Several
+//! dependencies may be given on one line separated by commas.
+use crate::board::Board;
+use crate::card::Card;
+
+use petgraph::graph::{DiGraph, NodeIndex};
+use std::collections::HashMap;
+
+const MARKER: &str = "depends:";
+
+/// A card in the dependency graph along with the name of its list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardNode {
+    pub card: Card,
+    pub list_name: String,
+}
+
+/// A graph with an edge from each card to each card it depends on
+#[derive(Debug, Clone)]
+pub struct DependencyGraph {
+    pub graph: DiGraph<CardNode, ()>,
+}
+
+impl Card {
+    /// The short link of the card, taken from its url
+    pub fn short_link(&self) -> Option<&str> {
+        parse_short_link(&self.url)
+    }
+
+    /// The references (ids, short links or urls) of the cards this card depends on
+    pub fn dependencies(&self) -> Vec<&str> {
+        self.desc
+            .lines()
+            .filter_map(|line| {
+                let line = line.trim();
+                match line.get(..MARKER.len()) {
+                    Some(prefix) if prefix.eq_ignore_ascii_case(MARKER) => {
+                        Some(&line[MARKER.len()..])
+                    }
+                    _ => None,
+                }
+            })
+            .flat_map(|refs| refs.split(','))
+            .map(|r| r.trim())
+            .filter(|r| !r.is_empty())
+            .collect()
+    }
+}
+
+// e.g. https://trello.com/c/a1B2c3D4/12-card-name
+pub(crate) fn parse_short_link(url: &str) -> Option<&str> {
+    let mut parts = url.split('/').skip_while(|p| *p != "c").skip(1);
+    parts.next().filter(|p| !p.is_empty())
+}
+
+impl Board {
+    /// Builds the dependency graph of the open cards on the board. The board
+    /// must have its lists and cards retrieved beforehand, see
+    /// `Board::retrieve_nested`. References to cards which are not on the
+    /// board are ignored.
+    pub fn dependency_graph(&self) -> DependencyGraph {
+        let mut graph = DiGraph::new();
+        let mut index: HashMap<&str, NodeIndex> = HashMap::new();
+
+        let cards = self
+            .lists
+            .iter()
+            .filter(|l| !l.closed)
+            .flat_map(|l| l.cards.iter().map(move |c| (l, c)))
+            .filter(|(_, c)| !c.closed);
+
+        for (list, card) in cards {
+            let node = graph.add_node(CardNode {
+                card: card.clone(),
+                list_name: list.name.clone(),
+            });
+            index.insert(&card.id, node);
+            if let Some(short_link) = card.short_link() {
+                index.insert(short_link, node);
+            }
+        }
+
+        for node in graph.node_indices().collect::<Vec<NodeIndex>>() {
+            let card = &graph[node].card;
+            let targets = card
+                .dependencies()
+                .into_iter()
+                .filter_map(|r| {
+                    let target = index.get(parse_short_link(r).unwrap_or(r)).copied();
+                    if target.is_none() {
+                        debug!("Ignoring unknown dependency '{}' of card {}", r, card.id);
+                    }
+                    target
+                })
+                .collect::<Vec<NodeIndex>>();
+
+            for target in targets {
+                graph.update_edge(node, target, ());
+            }
+        }
+
+        DependencyGraph { graph }
+    }
+}
+
+impl DependencyGraph {
+    /// The cards the given card depends on
+    pub fn dependencies_of(&self, node: NodeIndex) -> Vec<&CardNode> {
+        self.graph.neighbors(node).map(|n| &self.graph[n]).collect()
+    }
+
+    /// Exports the graph in the Graphviz DOT format. Only cards which take
+    /// part in a dependency are included.
+    pub fn to_dot(&self) -> String {
+        let mut result = vec![String::from("digraph dependencies {")];
+
+        for node in self.graph.node_indices() {
+            if self.graph.neighbors_undirected(node).next().is_none() {
+                continue;
+            }
+            let card_node = &self.graph[node];
+            result.push(format!(
+                "    \"{}\" [label=\"{}\\n({})\"];",
+                escape(&card_node.card.id),
+                escape(&card_node.card.name),
+                escape(&card_node.list_name)
+            ));
+        }
+
+        for edge in self.graph.raw_edges() {
+            result.push(format!(
+                "    \"{}\" -> \"{}\";",
+                escape(&self.graph[edge.source()].card.id),
+                escape(&self.graph[edge.target()].card.id)
+            ));
+        }
+
+        result.push(String::from("}"));
+        result.join("\n")
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
@@ -0,0 +1,164 @@
+//! Differences between two snapshots of the same board, such as a board
+//! saved to disk yesterday and the board as it is today.
+use crate::board::Board;
+use crate::card::Card;
+use crate::formatting::header;
+use crate::list::List;
+use crate::trello_object::{RenderOptions, Renderable};
+
+use colored::*;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum Change {
+    Added {
+        name: String,
+    },
+    Removed {
+        name: String,
+    },
+    Renamed {
+        from: String,
+        to: String,
+    },
+    /// A card moved between lists, identified by the list names
+    Moved {
+        name: String,
+        from: String,
+        to: String,
+    },
+}
+
+impl Change {
+    fn render(&self) -> String {
+        match self {
+            Change::Added { name } => format!("+ {}", name).green().to_string(),
+            Change::Removed { name } => format!("- {}", name).red().to_string(),
+            Change::Renamed { from, to } => format!("~ {} -> {}", from, to).yellow().to_string(),
+            Change::Moved { name, from, to } => format!("> {} ({} -> {})", name, from, to)
+                .blue()
+                .to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct BoardDiff {
+    pub name: String,
+    pub lists: Vec<Change>,
+    pub cards: Vec<Change>,
+}
+
+impl BoardDiff {
+    pub fn is_empty(&self) -> bool {
+        self.lists.is_empty() && self.cards.is_empty()
+    }
+}
+
+impl Renderable for BoardDiff {
+    fn render_with(&self, _options: &RenderOptions) -> String {
+        let mut result = vec![self.name.bold().to_string()];
+
+        if self.is_empty() {
+            result.push(String::from("No changes"));
+        }
+
+        for (title, changes) in &[("Lists", &self.lists), ("Cards", &self.cards)] {
+            if !changes.is_empty() {
+                result.push(String::from(""));
+                result.push(header(title, "-").bold().to_string());
+                result.extend(changes.iter().map(|c| c.render()));
+            }
+        }
+
+        result.join("\n")
+    }
+
+    fn simple_render(&self) -> String {
+        format!(
+            "{}: {} list changes, {} card changes",
+            self.name,
+            self.lists.len(),
+            self.cards.len()
+        )
+    }
+}
+
+fn lists(board: &Board) -> &[List] {
+    board.lists.as_slice()
+}
+
+/// All cards on the board along with the list they are in
+fn cards(board: &Board) -> Vec<(&Card, &List)> {
+    lists(board)
+        .iter()
+        .flat_map(|l| l.cards.iter().map(move |c| (c, l)))
+        .collect()
+}
+
+/// Compares two snapshots of a board. Lists and cards are matched by id, so
+/// renamed objects are reported as such rather than being removed and added.
+/// Both boards are expected to have their nested lists and cards retrieved.
+pub fn board_diff(old: &Board, new: &Board) -> BoardDiff {
+    let mut list_changes = vec![];
+
+    for list in lists(new) {
+        match lists(old).iter().find(|l| l.id == list.id) {
+            None => list_changes.push(Change::Added {
+                name: list.name.clone(),
+            }),
+            Some(previous) if previous.name != list.name => list_changes.push(Change::Renamed {
+                from: previous.name.clone(),
+                to: list.name.clone(),
+            }),
+            Some(_) => {}
+        }
+    }
+    for list in lists(old) {
+        if !lists(new).iter().any(|l| l.id == list.id) {
+            list_changes.push(Change::Removed {
+                name: list.name.clone(),
+            });
+        }
+    }
+
+    let old_cards = cards(old);
+    let new_cards = cards(new);
+    let mut card_changes = vec![];
+
+    for (card, list) in &new_cards {
+        match old_cards.iter().find(|(c, _)| c.id == card.id) {
+            None => card_changes.push(Change::Added {
+                name: card.name.clone(),
+            }),
+            Some((previous, previous_list)) => {
+                if previous.name != card.name {
+                    card_changes.push(Change::Renamed {
+                        from: previous.name.clone(),
+                        to: card.name.clone(),
+                    });
+                }
+                // Lists are compared by id, renaming a list does not move its cards
+                if previous_list.id != list.id {
+                    card_changes.push(Change::Moved {
+                        name: card.name.clone(),
+                        from: previous_list.name.clone(),
+                        to: list.name.clone(),
+                    });
+                }
+            }
+        }
+    }
+    for (card, _) in &old_cards {
+        if !new_cards.iter().any(|(c, _)| c.id == card.id) {
+            card_changes.push(Change::Removed {
+                name: card.name.clone(),
+            });
+        }
+    }
+
+    BoardDiff {
+        name: new.name.clone(),
+        lists: list_changes,
+        cards: card_changes,
+    }
+}
@@ -0,0 +1,102 @@
+//! Nested resources to retrieve along with a board or card, so that a
+//! single request returns exactly what the caller needs.
+//!
+//! https://developer.atlassian.com/cloud/trello/guides/rest-api/nested-resources/
+
+/// The nested resources to include when retrieving a board or card, e.g.
+/// `Expand::new().members().checklists()`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Expand {
+    labels: bool,
+    members: bool,
+    checklists: bool,
+    attachments: bool,
+    custom_fields: bool,
+}
+
+impl Expand {
+    pub fn new() -> Expand {
+        Expand::default()
+    }
+
+    /// Every nested resource
+    pub fn all() -> Expand {
+        Expand {
+            labels: true,
+            members: true,
+            checklists: true,
+            attachments: true,
+            custom_fields: true,
+        }
+    }
+
+    /// The labels of a board. The labels of a card are always included.
+    pub fn labels(mut self) -> Expand {
+        self.labels = true;
+        self
+    }
+
+    pub fn members(mut self) -> Expand {
+        self.members = true;
+        self
+    }
+
+    pub fn checklists(mut self) -> Expand {
+        self.checklists = true;
+        self
+    }
+
+    /// The attachments of a card. Ignored for boards, as attachments belong
+    /// to cards.
+    pub fn attachments(mut self) -> Expand {
+        self.attachments = true;
+        self
+    }
+
+    /// The custom field definitions of a board, or the custom field values
+    /// of a card
+    pub fn custom_fields(mut self) -> Expand {
+        self.custom_fields = true;
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self == &Expand::default()
+    }
+
+    /// The query parameters which include the resources in a board request
+    pub fn board_params(&self) -> Vec<(&'static str, &'static str)> {
+        let mut params = vec![];
+        if self.labels {
+            params.push(("labels", "all"));
+        }
+        if self.members {
+            params.push(("members", "all"));
+        }
+        if self.checklists {
+            params.push(("checklists", "all"));
+        }
+        if self.custom_fields {
+            params.push(("customFields", "true"));
+        }
+        params
+    }
+
+    /// The query parameters which include the resources in a card request
+    pub fn card_params(&self) -> Vec<(&'static str, &'static str)> {
+        let mut params = vec![];
+        if self.members {
+            params.push(("members", "true"));
+        }
+        if self.checklists {
+            params.push(("checklists", "all"));
+        }
+        if self.attachments {
+            params.push(("attachments", "true"));
+        }
+        if self.custom_fields {
+            params.push(("customFieldItems", "true"));
+        }
+        params
+    }
+}
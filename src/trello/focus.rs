@@ -0,0 +1,283 @@
+//! Focus (pomodoro) sessions recorded against cards. A session is started
+//! locally and, when stopped, stored as a structured comment on the card,
+//! e.g.
+//!
+//! ```text
+//! Focus session: 25 minutes
+//! start: 2020-06-28T06:00:00+00:00
+//! end: 2020-06-28T06:25:00+00:00
+//! ```
+//!
+//! so the accumulated focus time of a card or a whole board can be computed
+//! from the comments later, from any device.
+#[cfg(feature = "http")]
+use crate::card::Card;
+#[cfg(feature = "http")]
+use crate::client::TrelloClient;
+use crate::ids::CardId;
+#[cfg(feature = "http")]
+use crate::trello_error::TrelloError;
+use crate::trello_object::{RenderOptions, Renderable};
+#[cfg(feature = "http")]
+use crate::webhook::Action;
+
+use chrono::{DateTime, Duration, Utc};
+use colored::*;
+
+#[cfg(feature = "http")]
+type Result<T> = std::result::Result<T, TrelloError>;
+
+const HEADER: &str = "Focus session:";
+
+/// Maximum number of actions Trello returns in a single request
+#[cfg(feature = "http")]
+const ACTIONS_LIMIT: &str = "1000";
+
+/// A session which has been started but not yet stopped
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FocusTimer {
+    pub card_id: CardId,
+    pub start: DateTime<Utc>,
+}
+
+impl FocusTimer {
+    pub fn start(card_id: &str) -> FocusTimer {
+        FocusTimer::start_at(card_id, Utc::now())
+    }
+
+    pub fn start_at(card_id: &str, start: DateTime<Utc>) -> FocusTimer {
+        FocusTimer {
+            card_id: CardId::new(card_id),
+            start,
+        }
+    }
+
+    /// Time spent in the session so far
+    pub fn elapsed(&self) -> Duration {
+        Utc::now() - self.start
+    }
+
+    /// Ends the session now without recording it
+    pub fn finish(self) -> FocusSession {
+        self.finish_at(Utc::now())
+    }
+
+    pub fn finish_at(self, end: DateTime<Utc>) -> FocusSession {
+        FocusSession {
+            card_id: self.card_id,
+            start: self.start,
+            end,
+        }
+    }
+
+    /// Ends the session now and records it as a comment on the card
+    #[cfg(feature = "http")]
+    pub fn stop(self, client: &TrelloClient) -> Result<FocusSession> {
+        let session = self.finish();
+        session.record(client)?;
+        Ok(session)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FocusSession {
+    pub card_id: CardId,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+impl FocusSession {
+    pub fn duration(&self) -> Duration {
+        self.end - self.start
+    }
+
+    /// The text of the comment which records the session
+    pub fn to_comment(&self) -> String {
+        format!(
+            "{} {}\nstart: {}\nend: {}",
+            HEADER,
+            format_duration(self.duration()),
+            self.start.to_rfc3339(),
+            self.end.to_rfc3339()
+        )
+    }
+
+    /// Parses a comment written by `to_comment`. Returns None for any other
+    /// comment.
+    pub fn from_comment(card_id: &str, text: &str) -> Option<FocusSession> {
+        let mut lines = text.lines().map(str::trim);
+        if !lines.next()?.starts_with(HEADER) {
+            return None;
+        }
+
+        let mut start = None;
+        let mut end = None;
+        for line in lines {
+            if let Some(value) = line.strip_prefix("start:") {
+                start = DateTime::parse_from_rfc3339(value.trim()).ok();
+            } else if let Some(value) = line.strip_prefix("end:") {
+                end = DateTime::parse_from_rfc3339(value.trim()).ok();
+            }
+        }
+
+        Some(FocusSession {
+            card_id: CardId::new(card_id),
+            start: start?.with_timezone(&Utc),
+            end: end?.with_timezone(&Utc),
+        })
+    }
+
+    /// Adds the comment which records the session to the card
+    #[cfg(feature = "http")]
+    pub fn record(&self, client: &TrelloClient) -> Result<()> {
+        Card::add_comment(client, self.card_id.as_str(), &self.to_comment())
+    }
+}
+
+/// e.g. "25 minutes" or "1 hour 5 minutes"
+pub fn format_duration(duration: Duration) -> String {
+    let minutes = duration.num_minutes();
+    let (hours, minutes) = (minutes / 60, minutes % 60);
+
+    let plural = |count: i64, unit: &str| {
+        if count == 1 {
+            format!("{} {}", count, unit)
+        } else {
+            format!("{} {}s", count, unit)
+        }
+    };
+
+    match (hours, minutes) {
+        (0, m) => plural(m, "minute"),
+        (h, 0) => plural(h, "hour"),
+        (h, m) => format!("{} {}", plural(h, "hour"), plural(m, "minute")),
+    }
+}
+
+/// Total focus time of a single card
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardFocus {
+    pub card_id: CardId,
+    pub card_name: String,
+    pub sessions: usize,
+    pub total: Duration,
+}
+
+/// Total focus time per card, the card with the most focus time first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FocusReport {
+    pub name: String,
+    pub cards: Vec<CardFocus>,
+}
+
+impl FocusReport {
+    /// Collects the sessions recorded in the given comments, which are the
+    /// card id, card name and text of each comment
+    pub fn from_comments<'a, I>(name: &str, comments: I) -> FocusReport
+    where
+        I: IntoIterator<Item = (&'a str, &'a str, &'a str)>,
+    {
+        let mut cards: Vec<CardFocus> = vec![];
+
+        for (card_id, card_name, text) in comments {
+            let session = match FocusSession::from_comment(card_id, text) {
+                Some(session) => session,
+                None => continue,
+            };
+            match cards.iter_mut().find(|c| c.card_id == card_id) {
+                Some(card) => {
+                    card.sessions += 1;
+                    card.total = card.total + session.duration();
+                }
+                None => cards.push(CardFocus {
+                    card_id: CardId::new(card_id),
+                    card_name: String::from(card_name),
+                    sessions: 1,
+                    total: session.duration(),
+                }),
+            }
+        }
+
+        cards.sort_by_key(|c| std::cmp::Reverse(c.total));
+        FocusReport {
+            name: String::from(name),
+            cards,
+        }
+    }
+
+    pub fn total(&self) -> Duration {
+        self.cards
+            .iter()
+            .fold(Duration::zero(), |total, c| total + c.total)
+    }
+
+    /// The focus time recorded on a card
+    #[cfg(feature = "http")]
+    pub fn for_card(client: &TrelloClient, card_id: &str) -> Result<FocusReport> {
+        let actions = comment_actions(client, &format!("/1/cards/{}/actions", card_id))?;
+        Ok(FocusReport::from_actions(card_id, &actions))
+    }
+
+    /// The focus time recorded on every card of a board. Only the most
+    /// recent 1000 comments on the board are considered.
+    #[cfg(feature = "http")]
+    pub fn for_board(client: &TrelloClient, board_id: &str) -> Result<FocusReport> {
+        let actions = comment_actions(client, &format!("/1/boards/{}/actions", board_id))?;
+        Ok(FocusReport::from_actions(board_id, &actions))
+    }
+
+    #[cfg(feature = "http")]
+    fn from_actions(name: &str, actions: &[Action]) -> FocusReport {
+        let comments = actions.iter().filter_map(|a| {
+            let card = a.data.card.as_ref()?;
+            Some((
+                card.id.as_str(),
+                card.name.as_deref().unwrap_or(""),
+                a.data.text.as_deref()?,
+            ))
+        });
+
+        let mut report = FocusReport::from_comments(name, comments);
+        // comments on a single card have the name of the card
+        if let [card] = report.cards.as_slice() {
+            if card.card_id == name && !card.card_name.is_empty() {
+                report.name = card.card_name.clone();
+            }
+        }
+        report
+    }
+}
+
+#[cfg(feature = "http")]
+fn comment_actions(client: &TrelloClient, path: &str) -> Result<Vec<Action>> {
+    let url = client
+        .config
+        .get_trello_url(path, &[("filter", "commentCard"), ("limit", ACTIONS_LIMIT)])?;
+
+    Ok(client.send(client.get(url))?.json()?)
+}
+
+impl Renderable for FocusReport {
+    fn render_with(&self, _options: &RenderOptions) -> String {
+        let mut result = vec![format!(
+            "{} {}",
+            self.name.bold(),
+            format!("({})", format_duration(self.total())).dimmed()
+        )];
+
+        for card in &self.cards {
+            result.push(format!(
+                "{}: {} {}",
+                card.card_name,
+                format_duration(card.total),
+                format!("({} sessions)", card.sessions).dimmed()
+            ));
+        }
+
+        result.join("\n")
+    }
+
+    fn simple_render(&self) -> String {
+        format!("{}: {}", self.name, format_duration(self.total()))
+    }
+}
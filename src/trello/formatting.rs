@@ -1,3 +1,4 @@
+use chrono::{DateTime, Duration, Utc};
 use unicode_width::UnicodeWidthStr;
 
 pub fn title(text: &str) -> String {
@@ -6,6 +7,164 @@ pub fn title(text: &str) -> String {
     [format!(" {} ", text), format!("={}=", border)].join("\n")
 }
 
+/// The shortest prefix length (but at least `min_len`) which makes each of the ids
+/// unique, in the same way that `git log --abbrev` shortens commit hashes.
+pub(crate) fn unique_prefix_len<'a, I: IntoIterator<Item = &'a str>>(
+    ids: I,
+    min_len: usize,
+) -> usize {
+    let mut ids = ids.into_iter().collect::<Vec<&str>>();
+    ids.sort_unstable();
+    ids.dedup();
+
+    ids.windows(2)
+        .map(|pair| {
+            let common = pair[0]
+                .chars()
+                .zip(pair[1].chars())
+                .take_while(|(a, b)| a == b)
+                .count();
+            (common + 1).min(pair[0].len().max(pair[1].len()))
+        })
+        .fold(min_len, usize::max)
+}
+
 pub fn header(text: &str, header_char: &str) -> String {
     [text, &header_char.repeat(UnicodeWidthStr::width(text))].join("\n")
 }
+
+/// Pads the text with spaces up to the given display width
+pub(crate) fn pad(text: &str, width: usize) -> String {
+    let padding = width.saturating_sub(UnicodeWidthStr::width(text));
+    format!("{}{}", text, " ".repeat(padding))
+}
+
+/// Wraps the text into lines of at most the given display width, breaking
+/// between words where possible and within words which are too long
+pub(crate) fn wrap(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = vec![];
+    let mut line = String::new();
+
+    for word in text.split_whitespace() {
+        let mut word = word;
+        loop {
+            let separator = if line.is_empty() { 0 } else { 1 };
+            if line.width() + separator + word.width() <= width {
+                if separator == 1 {
+                    line.push(' ');
+                }
+                line.push_str(word);
+                break;
+            }
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+                continue;
+            }
+            // the word does not fit on a line of its own
+            let mut split = 0;
+            for (index, c) in word.char_indices() {
+                if word[..index].width() + c.to_string().width() > width {
+                    break;
+                }
+                split = index + c.len_utf8();
+            }
+            let split = split.max(word.chars().next().map_or(0, char::len_utf8));
+            lines.push(word[..split].to_string());
+            word = &word[split..];
+            if word.is_empty() {
+                break;
+            }
+        }
+    }
+
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Escapes the characters which have a special meaning in HTML
+pub(crate) fn escape_html(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&#39;"),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// Describes a due date relative to now, e.g. "due in 2 days" or
+/// "overdue by 3h"
+pub(crate) fn relative_due(due: &DateTime<Utc>, now: &DateTime<Utc>) -> String {
+    let difference = *due - *now;
+    let magnitude = if difference < Duration::zero() {
+        -difference
+    } else {
+        difference
+    };
+
+    let amount = if magnitude < Duration::minutes(1) {
+        return String::from("due now");
+    } else if magnitude < Duration::hours(1) {
+        format!("{}m", magnitude.num_minutes())
+    } else if magnitude < Duration::days(1) {
+        format!("{}h", magnitude.num_hours())
+    } else if magnitude.num_days() == 1 {
+        String::from("1 day")
+    } else {
+        format!("{} days", magnitude.num_days())
+    };
+
+    if difference < Duration::zero() {
+        format!("overdue by {}", amount)
+    } else {
+        format!("due in {}", amount)
+    }
+}
+
+/// A line based diff of two texts, using the longest common subsequence of
+/// their lines. Each line is marked with ' ' (unchanged), '-' (removed) or
+/// '+' (added).
+pub(crate) fn line_diff<'a>(old: &'a str, new: &'a str) -> Vec<(char, &'a str)> {
+    let old = old.lines().collect::<Vec<&str>>();
+    let new = new.lines().collect::<Vec<&str>>();
+
+    // common[i][j] is the length of the longest common subsequence of
+    // old[i..] and new[j..]
+    let mut common = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = if old[i] == new[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let mut result = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            result.push((' ', old[i]));
+            i += 1;
+            j += 1;
+        } else if common[i + 1][j] >= common[i][j + 1] {
+            result.push(('-', old[i]));
+            i += 1;
+        } else {
+            result.push(('+', new[j]));
+            j += 1;
+        }
+    }
+    result.extend(old[i..].iter().map(|l| ('-', *l)));
+    result.extend(new[j..].iter().map(|l| ('+', *l)));
+    result
+}
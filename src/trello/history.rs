@@ -0,0 +1,398 @@
+//! A local history of board snapshots, a poor man's version control for
+//! boards. Each snapshot is the board with its lists and cards saved as JSON
+//! under `<dir>/<board id>/<timestamp>.json`, by default within the user's
+//! data directory. Snapshots can be listed, pruned by age and compared with
+//! `diff::board_diff`.
+//!
+//! When no snapshot was taken, `reconstruct` approximates the state of a board
+//! at a past instant by undoing the actions recorded since then.
+use crate::board::Board;
+use crate::card::Card;
+#[cfg(feature = "http")]
+use crate::client::TrelloClient;
+use crate::diff::{board_diff, BoardDiff};
+use crate::ids::BoardId;
+use crate::list::List;
+use crate::trello_error::TrelloError;
+use crate::webhook::{Action, Entity};
+
+use chrono::{DateTime, Duration, NaiveDateTime, TimeZone, Utc};
+use serde_json::Value;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+type Result<T> = std::result::Result<T, TrelloError>;
+
+const TIMESTAMP_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+
+/// The board actions undone by `reconstruct`
+#[cfg(feature = "http")]
+const REPLAYED_ACTIONS: &str =
+    "createCard,updateCard,deleteCard,moveCardToBoard,moveCardFromBoard,createList,updateList";
+/// Trello returns at most 1000 actions per request
+#[cfg(feature = "http")]
+const ACTIONS_LIMIT: usize = 1000;
+
+/// How often snapshots are kept when saving
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    /// Every saved snapshot is kept
+    PerRun,
+    /// Saving replaces any earlier snapshot from the same (UTC) day
+    Daily,
+}
+
+/// A snapshot in the store
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotInfo {
+    pub board_id: BoardId,
+    pub taken_at: DateTime<Utc>,
+    pub path: PathBuf,
+}
+
+#[derive(Debug, Clone)]
+pub struct HistoryStore {
+    dir: PathBuf,
+    frequency: Frequency,
+}
+
+impl HistoryStore {
+    pub fn new(dir: &Path) -> HistoryStore {
+        HistoryStore {
+            dir: dir.to_path_buf(),
+            frequency: Frequency::PerRun,
+        }
+    }
+
+    /// The store in the tro directory of the user's data directory, e.g.
+    /// `~/.local/share/tro/history` on Linux
+    pub fn open_default() -> Result<HistoryStore> {
+        let mut dir = dirs::data_dir().ok_or_else(|| {
+            TrelloError::Io(io::Error::new(
+                io::ErrorKind::NotFound,
+                "Unable to determine data directory",
+            ))
+        })?;
+        dir.push("tro");
+        dir.push("history");
+
+        Ok(HistoryStore::new(&dir))
+    }
+
+    pub fn frequency(mut self, frequency: Frequency) -> HistoryStore {
+        self.frequency = frequency;
+        self
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn board_dir(&self, board_id: &str) -> PathBuf {
+        self.dir.join(board_id)
+    }
+
+    /// Saves a snapshot of the board, which should have its lists and cards
+    /// retrieved (see `Board::retrieve_nested`)
+    pub fn save(&self, board: &Board) -> Result<SnapshotInfo> {
+        self.save_at(board, Utc::now())
+    }
+
+    pub fn save_at(&self, board: &Board, taken_at: DateTime<Utc>) -> Result<SnapshotInfo> {
+        let dir = self.board_dir(&board.id);
+        fs::create_dir_all(&dir)?;
+
+        if self.frequency == Frequency::Daily {
+            let day = taken_at.naive_utc().date();
+            for snapshot in self.list(&board.id)? {
+                if snapshot.taken_at.naive_utc().date() == day {
+                    debug!("Replacing snapshot {:?}", snapshot.path);
+                    fs::remove_file(&snapshot.path)?;
+                }
+            }
+        }
+
+        let path = dir.join(format!("{}.json", taken_at.format(TIMESTAMP_FORMAT)));
+        debug!("Saving snapshot of board {} to {:?}", board.id, path);
+        let file = fs::File::create(&path)?;
+        serde_json::to_writer(file, board).map_err(io::Error::from)?;
+
+        Ok(SnapshotInfo {
+            board_id: board.id.clone(),
+            taken_at,
+            path,
+        })
+    }
+
+    /// The ids of the boards with snapshots in the store
+    pub fn board_ids(&self) -> Result<Vec<String>> {
+        if !self.dir.exists() {
+            return Ok(vec![]);
+        }
+
+        let mut board_ids = vec![];
+        for entry in fs::read_dir(&self.dir)? {
+            let entry = entry?;
+            if entry.file_type()?.is_dir() {
+                board_ids.extend(entry.file_name().to_str().map(String::from));
+            }
+        }
+        board_ids.sort();
+        Ok(board_ids)
+    }
+
+    /// The snapshots of the board, oldest first
+    pub fn list(&self, board_id: &str) -> Result<Vec<SnapshotInfo>> {
+        let dir = self.board_dir(board_id);
+        if !dir.exists() {
+            return Ok(vec![]);
+        }
+
+        let mut snapshots = vec![];
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            let taken_at = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .and_then(|n| n.strip_suffix(".json"))
+                .and_then(|n| NaiveDateTime::parse_from_str(n, TIMESTAMP_FORMAT).ok());
+
+            match taken_at {
+                Some(taken_at) => snapshots.push(SnapshotInfo {
+                    board_id: BoardId::new(board_id),
+                    taken_at: Utc.from_utc_datetime(&taken_at),
+                    path,
+                }),
+                None => debug!("Ignoring {:?} in snapshot directory", path),
+            }
+        }
+
+        snapshots.sort_by_key(|s| s.taken_at);
+        Ok(snapshots)
+    }
+
+    pub fn load(&self, snapshot: &SnapshotInfo) -> Result<Board> {
+        let file = fs::File::open(&snapshot.path)?;
+        Ok(serde_json::from_reader(file).map_err(io::Error::from)?)
+    }
+
+    /// The most recent snapshot of the board, if any
+    pub fn latest(&self, board_id: &str) -> Result<Option<Board>> {
+        match self.list(board_id)?.last() {
+            Some(snapshot) => Ok(Some(self.load(snapshot)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Removes the snapshots of the board older than `max_age`, returning
+    /// the number of snapshots removed. The most recent snapshot is always
+    /// kept.
+    pub fn prune(&self, board_id: &str, max_age: Duration) -> Result<usize> {
+        self.prune_at(board_id, max_age, Utc::now())
+    }
+
+    pub fn prune_at(&self, board_id: &str, max_age: Duration, now: DateTime<Utc>) -> Result<usize> {
+        let mut snapshots = self.list(board_id)?;
+        snapshots.pop();
+
+        let mut removed = 0;
+        for snapshot in snapshots {
+            if now - snapshot.taken_at > max_age {
+                debug!("Pruning snapshot {:?}", snapshot.path);
+                fs::remove_file(&snapshot.path)?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+
+    /// The changes between two snapshots, see `diff::board_diff`
+    pub fn diff(&self, old: &SnapshotInfo, new: &SnapshotInfo) -> Result<BoardDiff> {
+        Ok(board_diff(&self.load(old)?, &self.load(new)?))
+    }
+}
+
+/// Approximates the open lists and cards of the board at a past instant by
+/// undoing the actions recorded since then, newest first. Only the most
+/// recent 1000 actions are considered. Card names, descriptions, due dates,
+/// lists and archival are restored; cards deleted since then are restored
+/// with the name recorded in the action only, and cards in lists archived
+/// since then are not restored.
+#[cfg(feature = "http")]
+pub fn reconstruct(client: &TrelloClient, board_id: &str, at: DateTime<Utc>) -> Result<Board> {
+    let mut board = Board::get(client, board_id)?;
+    board.retrieve_nested(client)?;
+
+    let closed_lists = Board::get_closed_lists(client, board_id)?;
+    board.lists.get_or_insert_empty().extend(closed_lists);
+    let archived = Board::get_closed_cards(client, board_id)?;
+
+    let since = at.to_rfc3339();
+    let limit = ACTIONS_LIMIT.to_string();
+    let url = client.config.get_trello_url(
+        &format!("/1/boards/{}/actions", board_id),
+        &[
+            ("filter", REPLAYED_ACTIONS),
+            ("since", &since),
+            ("limit", &limit),
+        ],
+    )?;
+    let actions: Vec<Action> = client.send(client.get(url))?.json()?;
+    if actions.len() == ACTIONS_LIMIT {
+        warn!(
+            "Only the latest {} actions of board {} were replayed",
+            ACTIONS_LIMIT, board_id
+        );
+    }
+
+    rewind(&mut board, &actions, &archived, at);
+    Ok(board)
+}
+
+/// Undoes the given actions (as returned by Trello, newest first) which
+/// happened after `at` on a board with its lists and cards retrieved, then
+/// drops the lists and cards which were archived at that time. `archived` is
+/// used to restore cards which were archived since then, see
+/// `Board::get_closed_cards`.
+pub fn rewind(board: &mut Board, actions: &[Action], archived: &[Card], at: DateTime<Utc>) {
+    let lists = board.lists.get_or_insert_empty();
+
+    for action in actions.iter().filter(|a| a.date > at) {
+        debug!("Undoing {} {}", action.action_type, action.id);
+        let data = &action.data;
+        let old = data.old.as_ref();
+
+        match action.action_type.as_str() {
+            "createCard" | "moveCardToBoard" => {
+                if let Some(card) = &data.card {
+                    take_card(lists, &card.id);
+                }
+            }
+            "deleteCard" | "moveCardFromBoard" => {
+                if let (Some(card), Some(list)) = (&data.card, &data.list) {
+                    let card = restored_card(card, archived);
+                    put_card(lists, &list.id, card);
+                }
+            }
+            "updateCard" => {
+                let (entity, old) = match (&data.card, old) {
+                    (Some(entity), Some(old)) => (entity, old),
+                    _ => continue,
+                };
+
+                match old.get("closed") {
+                    // archived since then
+                    Some(Value::Bool(false)) => {
+                        if let Some(list) = &data.list {
+                            let mut card = restored_card(entity, archived);
+                            card.closed = false;
+                            put_card(lists, &list.id, card);
+                        }
+                    }
+                    // restored since then
+                    Some(Value::Bool(true)) => {
+                        take_card(lists, &entity.id);
+                    }
+                    _ => {}
+                }
+
+                if let (Some(before), Some(_)) = (&data.list_before, old.get("idList")) {
+                    if let Some(card) = take_card(lists, &entity.id) {
+                        put_card(lists, &before.id, card);
+                    }
+                }
+
+                if let Some(card) = find_card(lists, &entity.id) {
+                    revert_card(card, old);
+                }
+            }
+            "createList" => {
+                if let Some(list) = &data.list {
+                    lists.retain(|l| l.id != list.id.as_str());
+                }
+            }
+            "updateList" => {
+                let (entity, old) = match (&data.list, old) {
+                    (Some(entity), Some(old)) => (entity, old),
+                    _ => continue,
+                };
+
+                if let Some(list) = lists.iter_mut().find(|l| l.id == entity.id.as_str()) {
+                    if let Some(Value::String(name)) = old.get("name") {
+                        list.name = name.clone();
+                    }
+                    if let Some(Value::Bool(closed)) = old.get("closed") {
+                        list.closed = *closed;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    lists.retain(|l| !l.closed);
+    for list in lists.iter_mut() {
+        if let Some(cards) = list.cards.get_mut() {
+            cards.retain(|c| !c.closed);
+        }
+    }
+}
+
+fn restored_card(entity: &Entity, archived: &[Card]) -> Card {
+    archived
+        .iter()
+        .find(|c| c.id == entity.id.as_str())
+        .cloned()
+        .unwrap_or_else(|| {
+            let name = entity.name.as_deref().unwrap_or("");
+            Card::new(&entity.id, name, "", None, "", None)
+        })
+}
+
+fn revert_card(card: &mut Card, old: &serde_json::Map<String, Value>) {
+    if let Some(Value::String(name)) = old.get("name") {
+        card.name = name.clone();
+    }
+    if let Some(Value::String(desc)) = old.get("desc") {
+        card.desc = desc.clone();
+    }
+    match old.get("due") {
+        Some(Value::Null) => card.due = None,
+        Some(Value::String(due)) => {
+            card.due = DateTime::parse_from_rfc3339(due)
+                .ok()
+                .map(|d| d.with_timezone(&Utc))
+        }
+        _ => {}
+    }
+}
+
+fn find_card<'a>(lists: &'a mut [List], card_id: &str) -> Option<&'a mut Card> {
+    lists
+        .iter_mut()
+        .flat_map(|l| l.cards.iter_mut())
+        .find(|c| c.id == card_id)
+}
+
+fn take_card(lists: &mut [List], card_id: &str) -> Option<Card> {
+    for list in lists.iter_mut() {
+        if let Some(cards) = list.cards.get_mut() {
+            if let Some(index) = cards.iter().position(|c| c.id == card_id) {
+                return Some(cards.remove(index));
+            }
+        }
+    }
+    None
+}
+
+// cards are restored at the top of their list, as their position is unknown
+fn put_card(lists: &mut [List], list_id: &str, card: Card) {
+    match lists.iter_mut().find(|l| l.id == list_id) {
+        Some(list) => list.cards.get_or_insert_empty().insert(0, card),
+        None => debug!(
+            "Unable to restore card {}, list {} is unknown",
+            card.id, list_id
+        ),
+    }
+}
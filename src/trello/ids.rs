@@ -0,0 +1,102 @@
+//! Typed ids for Trello objects. The ids held by the objects and reports of
+//! this crate are typed, so that e.g. the id of a label found on a card
+//! cannot be mistaken for the id of the card. Most functions still take
+//! string ids; each id dereferences to `str`, so it can be passed to them,
+//! and can be created from a `&str` or `String`.
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::Deref;
+
+macro_rules! id_type {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(
+            Deserialize, Serialize, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Clone,
+        )]
+        #[serde(transparent)]
+        pub struct $name(String);
+
+        impl $name {
+            pub fn new(id: &str) -> $name {
+                $name(String::from(id))
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl Deref for $name {
+            type Target = str;
+
+            fn deref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(id: &str) -> $name {
+                $name::new(id)
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(id: String) -> $name {
+                $name(id)
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> String {
+                id.0
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl PartialEq<String> for $name {
+            fn eq(&self, other: &String) -> bool {
+                &self.0 == other
+            }
+        }
+    };
+}
+
+id_type!(
+    /// The id of a `Board`
+    BoardId
+);
+id_type!(
+    /// The id of a `List`
+    ListId
+);
+id_type!(
+    /// The id of a `Card`
+    CardId
+);
+id_type!(
+    /// The id of a `Label`
+    LabelId
+);
@@ -0,0 +1,82 @@
+//! Conversion of documents into boards which can be uploaded to Trello
+//! with `Board::apply_import`.
+use crate::board::Board;
+use crate::card::Card;
+use crate::list::List;
+
+/// Name of the list bullet items are placed in if they appear before any heading
+const DEFAULT_LIST: &str = "Backlog";
+
+fn bullet(line: &str) -> Option<&str> {
+    let item = line
+        .strip_prefix("- ")
+        .or_else(|| line.strip_prefix("* "))
+        .or_else(|| line.strip_prefix("+ "))?;
+
+    // task list items, e.g. "- [ ] Buy milk"
+    let item = item
+        .strip_prefix("[ ] ")
+        .or_else(|| item.strip_prefix("[x] "))
+        .or_else(|| item.strip_prefix("[X] "))
+        .unwrap_or(item);
+
+    Some(item.trim())
+}
+
+/// Converts a Markdown outline into a board. The first H1 heading becomes the
+/// name of the board, H2 headings become lists and top level bullet items become
+/// cards. Indented lines below a card (such as sub-bullets) become the lines of
+/// its description. The returned objects do not have ids as they do not exist
+/// in Trello yet.
+/// ```
+/// let board = trello::import::from_markdown("# Home\n## TODO\n- Laundry\n  - Whites\n");
+///
+/// assert_eq!(board.name, "Home");
+/// let list = &board.lists.get().unwrap()[0];
+/// assert_eq!(list.name, "TODO");
+/// assert_eq!(list.cards.get().unwrap()[0].name, "Laundry");
+/// assert_eq!(list.cards.get().unwrap()[0].desc, "Whites");
+/// ```
+pub fn from_markdown(text: &str) -> Board {
+    let mut board = Board::new("", "", Some(vec![]), "");
+    let lists = board.lists.get_mut().unwrap();
+
+    for line in text.lines() {
+        let trimmed = line.trim();
+        let indented = line.starts_with(' ') || line.starts_with('\t');
+
+        if trimmed.is_empty() {
+            continue;
+        } else if let Some(name) = line.strip_prefix("# ") {
+            if board.name.is_empty() {
+                board.name = name.trim().to_string();
+            }
+        } else if let Some(name) = line.strip_prefix("## ") {
+            lists.push(List::new("", name.trim(), Some(vec![])));
+        } else if let (false, Some(name)) = (indented, bullet(line)) {
+            if lists.is_empty() {
+                lists.push(List::new("", DEFAULT_LIST, Some(vec![])));
+            }
+            let list = lists.last_mut().unwrap();
+            list.cards
+                .get_or_insert_empty()
+                .push(Card::new("", name, "", None, "", None));
+        } else if indented {
+            let card = lists
+                .last_mut()
+                .and_then(|l| l.cards.get_mut())
+                .and_then(|c| c.last_mut());
+
+            if let Some(card) = card {
+                if !card.desc.is_empty() {
+                    card.desc.push('\n');
+                }
+                card.desc.push_str(bullet(trimmed).unwrap_or(trimmed));
+            }
+        } else {
+            debug!("Ignoring line: {}", line);
+        }
+    }
+
+    board
+}
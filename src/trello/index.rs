@@ -0,0 +1,227 @@
+//! A local full-text index over the cards of boards which were retrieved
+//! earlier, e.g. the snapshots of a `history::HistoryStore`, so cards can be
+//! found instantly and without network calls by the words in their names,
+//! descriptions and comments.
+//!
+//! The index is a plain inverted index from lower cased words to the cards
+//! containing them, and can be saved to and loaded from a JSON file.
+use crate::board::Board;
+use crate::history::HistoryStore;
+use crate::ids::{BoardId, CardId};
+use crate::trello_error::TrelloError;
+use crate::webhook::Action;
+
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+type Result<T> = std::result::Result<T, TrelloError>;
+
+/// Words in card names count this many times as much as words in
+/// descriptions and comments when ranking matches
+const NAME_WEIGHT: u32 = 3;
+
+/// An indexed card along with where it can be found
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Document {
+    pub card_id: CardId,
+    pub card_name: String,
+    pub url: String,
+    pub board_id: BoardId,
+    pub board_name: String,
+    pub list_name: String,
+}
+
+/// A card matching a query, with a score which is higher the more often
+/// the query words appear in it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexMatch<'a> {
+    pub document: &'a Document,
+    pub score: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchIndex {
+    documents: HashMap<CardId, Document>,
+    /// The weighted number of occurrences of each word in each card. Sorted
+    /// so that words starting with a prefix can be found as a range.
+    postings: BTreeMap<String, HashMap<CardId, u32>>,
+}
+
+/// Splits text into lower cased words
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_lowercase())
+        .collect()
+}
+
+impl SearchIndex {
+    pub fn new() -> SearchIndex {
+        SearchIndex::default()
+    }
+
+    /// Builds an index of the latest snapshot of every board in the store
+    pub fn from_history(store: &HistoryStore) -> Result<SearchIndex> {
+        let mut index = SearchIndex::new();
+        for board_id in store.board_ids()? {
+            if let Some(board) = store.latest(&board_id)? {
+                index.add_board(&board);
+            }
+        }
+        Ok(index)
+    }
+
+    pub fn load(path: &Path) -> Result<SearchIndex> {
+        let file = fs::File::open(path)?;
+        Ok(serde_json::from_reader(io::BufReader::new(file)).map_err(io::Error::from)?)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let file = fs::File::create(path)?;
+        serde_json::to_writer(io::BufWriter::new(file), self).map_err(io::Error::from)?;
+        Ok(())
+    }
+
+    /// The number of indexed cards
+    pub fn len(&self) -> usize {
+        self.documents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.documents.is_empty()
+    }
+
+    /// Indexes the open cards of the board, which must have its lists and
+    /// cards retrieved beforehand (see `Board::retrieve_nested`). Cards
+    /// indexed earlier for the same board are replaced, along with their
+    /// comments.
+    pub fn add_board(&mut self, board: &Board) {
+        self.remove_board(&board.id);
+
+        let cards = board
+            .lists
+            .iter()
+            .filter(|l| !l.closed)
+            .flat_map(|l| l.cards.iter().map(move |c| (l, c)))
+            .filter(|(_, c)| !c.closed);
+
+        for (list, card) in cards {
+            self.documents.insert(
+                card.id.clone(),
+                Document {
+                    card_id: card.id.clone(),
+                    card_name: card.name.clone(),
+                    url: card.url.clone(),
+                    board_id: board.id.clone(),
+                    board_name: board.name.clone(),
+                    list_name: list.name.clone(),
+                },
+            );
+            self.add_words(&card.id, &card.name, NAME_WEIGHT);
+            self.add_words(&card.id, &card.desc, 1);
+        }
+    }
+
+    /// Removes the cards of the board from the index
+    pub fn remove_board(&mut self, board_id: &str) {
+        self.documents.retain(|_, d| d.board_id != board_id);
+
+        let documents = &self.documents;
+        for cards in self.postings.values_mut() {
+            cards.retain(|card_id, _| documents.contains_key(card_id));
+        }
+        self.postings.retain(|_, cards| !cards.is_empty());
+    }
+
+    /// Indexes the text of a comment on a card which is already indexed.
+    /// Returns false if the card is not in the index.
+    pub fn add_comment(&mut self, card_id: &str, text: &str) -> bool {
+        let card_id = CardId::new(card_id);
+        if !self.documents.contains_key(&card_id) {
+            return false;
+        }
+        self.add_words(&card_id, text, 1);
+        true
+    }
+
+    /// Indexes the comments among the given actions, e.g. the `commentCard`
+    /// actions of a board. Returns the number of comments indexed.
+    pub fn add_comments(&mut self, actions: &[Action]) -> usize {
+        actions
+            .iter()
+            .filter(|a| a.action_type == "commentCard")
+            .filter_map(|a| Some((a.data.card.as_ref()?, a.data.text.as_deref()?)))
+            .filter(|(card, text)| self.add_comment(&card.id, text))
+            .count()
+    }
+
+    fn add_words(&mut self, card_id: &CardId, text: &str, weight: u32) {
+        for word in tokenize(text) {
+            *self
+                .postings
+                .entry(word)
+                .or_default()
+                .entry(card_id.clone())
+                .or_default() += weight;
+        }
+    }
+
+    /// Finds the cards containing every word of the query, best matches
+    /// first. The last word of the query also matches words it is a prefix
+    /// of, so results can be shown while the query is being typed.
+    pub fn search(&self, query: &str) -> Vec<IndexMatch<'_>> {
+        let words = tokenize(query);
+        let (last, rest) = match words.split_last() {
+            Some(split) => split,
+            None => return vec![],
+        };
+
+        let mut scores: Option<HashMap<&CardId, u32>> = None;
+        for word in rest {
+            let matches = self.postings.get(word).into_iter().flatten();
+            scores = Some(intersect(scores, matches));
+        }
+        let prefixed = self
+            .postings
+            .range(last.clone()..)
+            .take_while(|(word, _)| word.starts_with(last.as_str()))
+            .flat_map(|(_, cards)| cards);
+        let scores = intersect(scores, prefixed);
+
+        let mut result = scores
+            .into_iter()
+            .filter_map(|(card_id, score)| {
+                Some(IndexMatch {
+                    document: self.documents.get(card_id)?,
+                    score,
+                })
+            })
+            .collect::<Vec<IndexMatch>>();
+        result.sort_by_key(|m| (Reverse(m.score), m.document.card_name.clone()));
+        result
+    }
+}
+
+// Sums the scores of the cards in both `scores` and `matches`, treating no
+// scores yet as matching every card
+fn intersect<'a, I: Iterator<Item = (&'a CardId, &'a u32)>>(
+    scores: Option<HashMap<&'a CardId, u32>>,
+    matches: I,
+) -> HashMap<&'a CardId, u32> {
+    let mut summed: HashMap<&CardId, u32> = HashMap::new();
+    for (card_id, count) in matches {
+        *summed.entry(card_id).or_default() += count;
+    }
+
+    match scores {
+        None => summed,
+        Some(scores) => scores
+            .into_iter()
+            .filter_map(|(card_id, score)| Some((card_id, score + summed.get(card_id)?)))
+            .collect(),
+    }
+}
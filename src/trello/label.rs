@@ -1,19 +1,32 @@
+#[cfg(feature = "http")]
+use crate::card::Card;
+#[cfg(feature = "http")]
 use crate::client::TrelloClient;
+#[cfg(feature = "http")]
+use crate::ids::CardId;
+use crate::ids::LabelId;
+#[cfg(feature = "http")]
+use crate::safety::{ConfirmationToken, Deletion};
+#[cfg(feature = "http")]
 use crate::trello_error::TrelloError;
-use crate::trello_object::{Renderable, TrelloObject};
+use crate::trello_object::{RenderOptions, Renderable, TrelloObject};
 
 use colored::*;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "http")]
 type Result<T> = std::result::Result<T, TrelloError>;
 
 // https://developers.trello.com/reference/#label-object
-#[derive(Deserialize, Debug, Eq, PartialEq, Clone)]
+#[derive(Deserialize, Serialize, Debug, Eq, PartialEq, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Label {
-    pub id: String,
+    pub id: LabelId,
     pub name: String,
     pub color: String,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 impl TrelloObject for Label {
@@ -31,7 +44,7 @@ impl TrelloObject for Label {
 }
 
 impl Renderable for Label {
-    fn render(&self) -> String {
+    fn render_with(&self, _options: &RenderOptions) -> String {
         self.simple_render()
     }
 
@@ -46,13 +59,24 @@ impl Renderable for Label {
 impl Label {
     pub fn new(id: &str, name: &str, color: &str) -> Label {
         Label {
-            id: String::from(id),
+            id: LabelId::new(id),
             name: String::from(name),
             color: String::from(color),
+            #[cfg(feature = "extra-fields")]
+            extra: serde_json::Map::new(),
         }
     }
 
+    /// Retrieves the labels of the board with the specified id. The labels
+    /// are remembered by the client until a label is created or deleted
+    /// through it, see `TrelloClient::invalidate_lookups`.
+    #[cfg(feature = "http")]
     pub fn get_all(client: &TrelloClient, board_id: &str) -> Result<Vec<Label>> {
+        if let Some(labels) = client.lookups().labels(board_id) {
+            debug!("Using cached labels for board {}", board_id);
+            return Ok(labels);
+        }
+
         let fields = Label::get_fields().join(",");
 
         let url = client.config.get_trello_url(
@@ -60,32 +84,145 @@ impl Label {
             &[("fields", &fields)],
         )?;
 
-        Ok(client.client.get(url).send()?.error_for_status()?.json()?)
+        let labels: Vec<Label> = client.send(client.get(url))?.json()?;
+        client.lookups().set_labels(board_id, &labels);
+
+        Ok(labels)
+    }
+
+    #[cfg(feature = "http")]
+    pub fn create(client: &TrelloClient, board_id: &str, name: &str, color: &str) -> Result<Label> {
+        let url = client.config.get_trello_url("/1/labels/", &[])?;
+
+        let params = [("name", name), ("color", color), ("idBoard", board_id)];
+
+        let label = client.send(client.post(url).form(&params))?.json()?;
+        client.lookups().invalidate_board(board_id);
+
+        Ok(label)
+    }
+
+    /// Changes the name and color of the label with the specified id
+    #[cfg(feature = "http")]
+    pub fn update(client: &TrelloClient, label_id: &str, name: &str, color: &str) -> Result<Label> {
+        let url = client
+            .config
+            .get_trello_url(&format!("/1/labels/{}", label_id), &[])?;
+
+        let params = [("name", name), ("color", color)];
+
+        let label = client.send(client.put(url).form(&params))?.json()?;
+        client.lookups().invalidate_label(label_id);
+
+        Ok(label)
     }
 
+    #[deprecated(
+        since = "2.10.0",
+        note = "use `Label::remove_from_card` with typed ids"
+    )]
+    #[cfg(feature = "http")]
     pub fn remove(client: &TrelloClient, card_id: &str, label_id: &str) -> Result<()> {
+        Label::remove_from_card(client, &CardId::new(card_id), &LabelId::new(label_id))
+    }
+
+    #[cfg(feature = "http")]
+    pub fn remove_from_card(
+        client: &TrelloClient,
+        card_id: &CardId,
+        label_id: &LabelId,
+    ) -> Result<()> {
         let url = client
             .config
             .get_trello_url(&format!("/1/cards/{}/idLabels/{}", card_id, label_id), &[])?;
 
-        client.client.delete(url).send()?.error_for_status()?;
+        client.send(client.delete(url))?;
 
         Ok(())
     }
 
+    /// Permanently deletes the label with the specified id from its board,
+    /// subject to the safety policy of the client. Labels cannot be archived,
+    /// so deletion is refused when the policy is `ArchiveOnly`.
+    #[cfg(feature = "http")]
+    pub fn delete(
+        client: &TrelloClient,
+        label_id: &str,
+        confirmation: Option<&ConfirmationToken>,
+    ) -> Result<()> {
+        if client.safety_policy().check(label_id, confirmation)? == Deletion::Archive {
+            return Err(TrelloError::DeletionNotPermitted(String::from(
+                "labels cannot be archived",
+            )));
+        }
+
+        let url = client
+            .config
+            .get_trello_url(&format!("/1/labels/{}", label_id), &[])?;
+
+        client.send(client.delete(url))?;
+        client.lookups().invalidate_label(label_id);
+
+        Ok(())
+    }
+
+    /// Merges the label `from_label_id` into `into_label_id` on the given board:
+    /// every card carrying the source label, archived ones included, gets the
+    /// target label, then the source label is deleted (subject to the safety
+    /// policy of the client, which is checked before any card is changed).
+    /// Returns the number of cards which were given the target label.
+    #[cfg(feature = "http")]
+    pub fn merge(
+        client: &TrelloClient,
+        board_id: &str,
+        from_label_id: &str,
+        into_label_id: &str,
+        confirmation: Option<&ConfirmationToken>,
+    ) -> Result<usize> {
+        if client.safety_policy().check(from_label_id, confirmation)? == Deletion::Archive {
+            return Err(TrelloError::DeletionNotPermitted(String::from(
+                "labels cannot be archived",
+            )));
+        }
+
+        let url = client.config.get_trello_url(
+            &format!("/1/boards/{}/cards/all", board_id),
+            &[("fields", &Card::get_fields().join(","))],
+        )?;
+        let cards: Vec<Card> = client.send(client.get(url))?.json()?;
+
+        let mut count = 0;
+        for card in cards {
+            let labels = card.labels.unwrap_or_default();
+            let has_label = |id: &str| labels.iter().any(|l| l.id == id);
+
+            if has_label(from_label_id) && !has_label(into_label_id) {
+                debug!("Applying label {} to card {}", into_label_id, card.id);
+                Label::add_to_card(client, &card.id, &LabelId::new(into_label_id))?;
+                count += 1;
+            }
+        }
+
+        Label::delete(client, from_label_id, confirmation)?;
+
+        Ok(count)
+    }
+
+    #[deprecated(since = "2.10.0", note = "use `Label::add_to_card` with typed ids")]
+    #[cfg(feature = "http")]
     pub fn apply(client: &TrelloClient, card_id: &str, label_id: &str) -> Result<()> {
+        Label::add_to_card(client, &CardId::new(card_id), &LabelId::new(label_id))
+    }
+
+    #[cfg(feature = "http")]
+    pub fn add_to_card(client: &TrelloClient, card_id: &CardId, label_id: &LabelId) -> Result<()> {
         let url = client
             .config
             .get_trello_url(&format!("/1/cards/{}/idLabels", card_id), &[])?;
 
-        let params = [("value", label_id)];
+        let params = [("value", label_id.as_str())];
 
-        client
-            .client
-            .post(url)
-            .form(&params)
-            .send()?
-            .error_for_status()?;
+        client.send(client.post(url).form(&params))?;
 
         Ok(())
     }
@@ -0,0 +1,214 @@
+//! Keeps the labels of several boards in line with a canonical set of
+//! labels, as teams with many boards tend to end up with slightly different
+//! names and colors for the same label on each board.
+#[cfg(feature = "http")]
+use crate::board::Board;
+#[cfg(feature = "http")]
+use crate::board_changes::BoardChanges;
+use crate::board_changes::{BoardChange, ChangeReport};
+#[cfg(feature = "http")]
+use crate::client::TrelloClient;
+#[cfg(feature = "http")]
+use crate::ids::BoardId;
+use crate::ids::LabelId;
+use crate::label::Label;
+#[cfg(feature = "http")]
+use crate::progress::RunOptions;
+#[cfg(feature = "http")]
+use crate::trello_error::TrelloError;
+use crate::trello_object::{RenderOptions, Renderable};
+
+use colored::*;
+
+#[cfg(feature = "http")]
+type Result<T> = std::result::Result<T, TrelloError>;
+
+#[cfg(feature = "http")]
+const SYNC_PHASE: &str = "Syncing labels";
+
+/// A change needed to bring the labels of a board in line with the
+/// canonical labels
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LabelChange {
+    Created {
+        name: String,
+        color: String,
+    },
+    /// A label whose name only differed in case or surrounding whitespace,
+    /// and possibly color
+    Renamed {
+        label_id: LabelId,
+        from: String,
+        to: String,
+        color: String,
+    },
+    Recolored {
+        label_id: LabelId,
+        name: String,
+        from: String,
+        to: String,
+    },
+}
+
+impl BoardChange for LabelChange {
+    const NOUN: &'static str = "label";
+    const UNCHANGED: &'static str = "Labels in sync";
+}
+
+impl Renderable for LabelChange {
+    fn render_with(&self, _options: &RenderOptions) -> String {
+        match self {
+            LabelChange::Created { name, color, .. } => {
+                format!("{} {} ({})", "+".green(), name, color)
+            }
+            LabelChange::Renamed { from, to, .. } => {
+                format!("{} {} -> {}", "~".yellow(), from, to)
+            }
+            LabelChange::Recolored { name, from, to, .. } => {
+                format!("{} {} ({} -> {})", "~".yellow(), name, from, to)
+            }
+        }
+    }
+
+    fn simple_render(&self) -> String {
+        match self {
+            LabelChange::Created { name, .. } => format!("Created {}", name),
+            LabelChange::Renamed { to, .. } => format!("Renamed {}", to),
+            LabelChange::Recolored { name, .. } => format!("Recolored {}", name),
+        }
+    }
+}
+
+/// The changes made to each target board, in the order of the boards
+pub type SyncReport = ChangeReport<LabelChange>;
+
+fn normalize(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+/// The changes needed for the labels of a board to include every named
+/// canonical label with the same name and color. Labels which are not
+/// canonical are left alone, as are canonical labels without a name, which
+/// cannot be told apart.
+pub fn plan(canonical: &[Label], labels: &[Label]) -> Vec<LabelChange> {
+    let mut changes = vec![];
+
+    for label in canonical.iter().filter(|l| !l.name.trim().is_empty()) {
+        let exact = labels.iter().find(|l| l.name == label.name);
+        let similar = labels
+            .iter()
+            .find(|l| normalize(&l.name) == normalize(&label.name));
+
+        match (exact, similar) {
+            (Some(existing), _) if existing.color != label.color => {
+                changes.push(LabelChange::Recolored {
+                    label_id: existing.id.clone(),
+                    name: label.name.clone(),
+                    from: existing.color.clone(),
+                    to: label.color.clone(),
+                })
+            }
+            (Some(_), _) => {}
+            (None, Some(existing)) => changes.push(LabelChange::Renamed {
+                label_id: existing.id.clone(),
+                from: existing.name.clone(),
+                to: label.name.clone(),
+                color: label.color.clone(),
+            }),
+            (None, None) => changes.push(LabelChange::Created {
+                name: label.name.clone(),
+                color: label.color.clone(),
+            }),
+        }
+    }
+
+    changes
+}
+
+/// Makes sure every named label of the source board exists with the same
+/// name and color on each target board, renaming labels whose name only
+/// differs in case and fixing their colors. See `plan` to preview the
+/// changes without making them.
+#[cfg(feature = "http")]
+pub fn sync(
+    client: &TrelloClient,
+    source_board: &str,
+    target_boards: &[&str],
+) -> Result<SyncReport> {
+    sync_with(client, source_board, target_boards, RunOptions::new())
+}
+
+/// Like `sync`, reporting each target board synced to the progress of the
+/// options, and stopping with `TrelloError::Cancelled` before the next
+/// target board once cancelled. The boards synced until then keep their
+/// changes, which are given by the `SyncReport` of the error, see
+/// `TrelloError::partial`.
+#[cfg(feature = "http")]
+pub fn sync_with(
+    client: &TrelloClient,
+    source_board: &str,
+    target_boards: &[&str],
+    mut options: RunOptions,
+) -> Result<SyncReport> {
+    let result = sync_boards(client, source_board, target_boards, &mut options);
+    options.finish();
+    result
+}
+
+#[cfg(feature = "http")]
+fn sync_boards(
+    client: &TrelloClient,
+    source_board: &str,
+    target_boards: &[&str],
+    options: &mut RunOptions,
+) -> Result<SyncReport> {
+    let target_boards: Vec<&str> = target_boards
+        .iter()
+        .copied()
+        .filter(|b| *b != source_board)
+        .collect();
+    options.update(SYNC_PHASE, 0, target_boards.len());
+
+    let canonical = Label::get_all(client, source_board)?;
+    let mut report = SyncReport::default();
+
+    for (done, board_id) in target_boards.iter().enumerate() {
+        if let Err(e) = options.check(done) {
+            return Err(e.with_partial(report));
+        }
+        let board = Board::get(client, board_id)?;
+        let changes = plan(&canonical, &Label::get_all(client, board_id)?);
+
+        for change in &changes {
+            debug!("Applying {:?}", change);
+            match change {
+                LabelChange::Created { name, color, .. } => {
+                    Label::create(client, board_id, name, color)?;
+                }
+                LabelChange::Renamed {
+                    label_id,
+                    to: name,
+                    color,
+                    ..
+                }
+                | LabelChange::Recolored {
+                    label_id,
+                    name,
+                    to: color,
+                    ..
+                } => {
+                    Label::update(client, label_id, name, color)?;
+                }
+            }
+        }
+
+        report.boards.push(BoardChanges {
+            board_id: BoardId::new(board_id),
+            board_name: board.name,
+            changes,
+        });
+        options.update(SYNC_PHASE, done + 1, target_boards.len());
+    }
+
+    Ok(report)
+}
@@ -0,0 +1,162 @@
+//! Configurable checks which keep a board tidy, such as flagging cards
+//! without labels or cards which share the same name.
+use crate::board::Board;
+use crate::card::Card;
+use crate::ids::CardId;
+use crate::list::List;
+use crate::trello_error::TrelloError;
+use crate::trello_object::{RenderOptions, Renderable};
+
+use colored::*;
+use regex::{Regex, RegexBuilder};
+use std::collections::HashMap;
+
+type Result<T> = std::result::Result<T, TrelloError>;
+
+#[derive(Debug, Clone)]
+pub enum Rule {
+    /// Cards which have no labels
+    MissingLabels,
+    /// Cards without a due date in lists whose name matches the pattern
+    MissingDueDate(Regex),
+    /// Cards whose description is empty
+    EmptyDescription,
+    /// Cards with the same name (ignoring case) anywhere on the board
+    DuplicateNames,
+}
+
+impl Rule {
+    /// The name used to identify the rule in findings
+    pub fn name(&self) -> &'static str {
+        match self {
+            Rule::MissingLabels => "missing-labels",
+            Rule::MissingDueDate(_) => "missing-due-date",
+            Rule::EmptyDescription => "empty-description",
+            Rule::DuplicateNames => "duplicate-names",
+        }
+    }
+
+    /// Builds a `MissingDueDate` rule from a case insensitive list name pattern
+    pub fn missing_due_date(list_pattern: &str) -> Result<Rule> {
+        let re = RegexBuilder::new(list_pattern)
+            .case_insensitive(true)
+            .build()?;
+        Ok(Rule::MissingDueDate(re))
+    }
+
+    fn check(&self, board: &Board, findings: &mut Vec<Finding>) {
+        if let Rule::DuplicateNames = self {
+            let mut seen: HashMap<String, &Card> = HashMap::new();
+            for (list, card) in open_cards(board) {
+                let key = card.name.trim().to_lowercase();
+                match seen.get(&key) {
+                    Some(first) => findings.push(Finding::new(
+                        self,
+                        list,
+                        card,
+                        &format!("Same name as card {}", first.id),
+                    )),
+                    None => {
+                        seen.insert(key, card);
+                    }
+                }
+            }
+            return;
+        }
+
+        for (list, card) in open_cards(board) {
+            let message = match self {
+                Rule::MissingLabels if card.labels.as_ref().map_or(true, |l| l.is_empty()) => {
+                    "Card has no labels"
+                }
+                Rule::MissingDueDate(re) if card.due.is_none() && re.is_match(&list.name) => {
+                    "Card has no due date"
+                }
+                Rule::EmptyDescription if card.desc.trim().is_empty() => {
+                    "Card has an empty description"
+                }
+                _ => continue,
+            };
+            findings.push(Finding::new(self, list, card, message));
+        }
+    }
+}
+
+fn open_cards(board: &Board) -> impl Iterator<Item = (&List, &Card)> {
+    board
+        .lists
+        .iter()
+        .filter(|l| !l.closed)
+        .flat_map(|l| l.cards.iter().map(move |c| (l, c)))
+        .filter(|(_, c)| !c.closed)
+}
+
+/// A single problem found on a board
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    pub rule: &'static str,
+    pub list_name: String,
+    pub card_id: CardId,
+    pub card_name: String,
+    pub message: String,
+}
+
+impl Finding {
+    fn new(rule: &Rule, list: &List, card: &Card, message: &str) -> Finding {
+        Finding {
+            rule: rule.name(),
+            list_name: list.name.clone(),
+            card_id: card.id.clone(),
+            card_name: card.name.clone(),
+            message: String::from(message),
+        }
+    }
+}
+
+impl Renderable for Finding {
+    fn render_with(&self, _options: &RenderOptions) -> String {
+        format!(
+            "{} {} / {}: {}",
+            format!("[{}]", self.rule).yellow(),
+            self.list_name,
+            self.card_name,
+            self.message
+        )
+    }
+
+    fn simple_render(&self) -> String {
+        format!("[{}] {}", self.rule, self.card_name)
+    }
+}
+
+/// A set of rules to check a board against. The board must have its lists
+/// and cards retrieved beforehand, see `Board::retrieve_nested`.
+#[derive(Debug, Clone, Default)]
+pub struct Linter {
+    rules: Vec<Rule>,
+}
+
+impl Linter {
+    pub fn new() -> Linter {
+        Linter::default()
+    }
+
+    pub fn rule(mut self, rule: Rule) -> Linter {
+        self.rules.push(rule);
+        self
+    }
+
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    /// Runs every rule in order, returning the findings of each rule in
+    /// board order
+    pub fn check(&self, board: &Board) -> Vec<Finding> {
+        let mut findings = vec![];
+        for rule in &self.rules {
+            rule.check(board, &mut findings);
+        }
+        findings
+    }
+}
@@ -1,23 +1,118 @@
 use crate::card::Card;
+#[cfg(feature = "http")]
+use crate::card::CardFilter;
+#[cfg(feature = "http")]
 use crate::client::TrelloClient;
-use crate::formatting::header;
+use crate::formatting::{header, unique_prefix_len};
+use crate::ids::ListId;
+use crate::loaded::Loaded;
+#[cfg(feature = "http")]
+use crate::response::Response;
+#[cfg(feature = "http")]
 use crate::trello_error::TrelloError;
-use crate::trello_object::{Renderable, TrelloObject};
+use crate::trello_object::{Detail, RenderOptions, Renderable, TrelloObject};
 
 use colored::*;
 use regex::RegexBuilder;
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer, Serialize};
 
+#[cfg(feature = "http")]
 type Result<T> = std::result::Result<T, TrelloError>;
 
+/// Iterates over the open cards of a list a page at a time, newest pages
+/// first, so only a single page of cards is held in memory. Returned by
+/// `List::cards_paged`.
+#[cfg(feature = "http")]
+pub struct PagedCards<'a> {
+    client: &'a TrelloClient,
+    list_id: String,
+    page_size: usize,
+    /// The oldest (smallest) id in the last page, the next page starts
+    /// before it
+    before: Option<String>,
+    page: std::vec::IntoIter<Card>,
+    done: bool,
+}
+
+#[cfg(feature = "http")]
+impl PagedCards<'_> {
+    fn next_page(&mut self) -> Result<Vec<Card>> {
+        let fields = Card::get_fields().join(",");
+        let limit = self.page_size.to_string();
+        let mut params = vec![("fields", fields.as_str()), ("limit", limit.as_str())];
+        if let Some(before) = &self.before {
+            params.push(("before", before));
+        }
+
+        let url = self
+            .client
+            .config
+            .get_trello_url(&format!("/1/lists/{}/cards", self.list_id), &params)?;
+
+        Ok(self.client.send(self.client.get(url))?.json()?)
+    }
+}
+
+#[cfg(feature = "http")]
+impl Iterator for PagedCards<'_> {
+    type Item = Result<Card>;
+
+    fn next(&mut self) -> Option<Result<Card>> {
+        if let Some(card) = self.page.next() {
+            return Some(Ok(card));
+        }
+        if self.done {
+            return None;
+        }
+
+        let cards = match self.next_page() {
+            Ok(cards) => cards,
+            Err(err) => {
+                self.done = true;
+                return Some(Err(err));
+            }
+        };
+        // A short page is the last one, saving a request for an empty page
+        self.done = cards.len() < self.page_size;
+        // Cards are returned in the order of the list rather than by id, so
+        // the last card isn't necessarily the oldest
+        self.before = cards.iter().map(|c| c.id.as_str()).min().map(String::from);
+        self.page = cards.into_iter();
+
+        self.page.next().map(Ok)
+    }
+}
+
 // https://developers.trello.com/reference/#list-object
-#[derive(Deserialize, Debug, Eq, PartialEq, Clone)]
+#[derive(Deserialize, Serialize, Debug, Eq, PartialEq, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct List {
-    pub id: String,
+    pub id: ListId,
     pub name: String,
     pub closed: bool,
-    pub cards: Option<Vec<Card>>,
+    #[serde(default)]
+    pub cards: Loaded<Card>,
+    /// The number of cards the list should not exceed, if set in Trello
+    #[serde(
+        default,
+        deserialize_with = "deserialize_soft_limit",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub soft_limit: Option<u64>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+// Trello may return the soft limit as either a number or a string
+fn deserialize_soft_limit<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Option<u64>, D::Error> {
+    match Option::<serde_json::Value>::deserialize(deserializer)? {
+        Some(serde_json::Value::Number(n)) => Ok(n.as_u64()),
+        Some(serde_json::Value::String(s)) => Ok(s.parse().ok()),
+        _ => Ok(None),
+    }
 }
 
 impl TrelloObject for List {
@@ -30,20 +125,14 @@ impl TrelloObject for List {
     }
 
     fn get_fields() -> &'static [&'static str] {
-        &["id", "name", "closed"]
+        &["id", "name", "closed", "softLimit"]
     }
 }
 
 impl Renderable for List {
-    fn render(&self) -> String {
-        let title = header(&self.name, "-").bold().to_string();
-        let mut result: Vec<String> = vec![title];
-        if let Some(cards) = &self.cards {
-            for c in cards {
-                result.push(format!("* {}", c.simple_render()));
-            }
-        }
-        result.join("\n")
+    /// The badge summary of each card is shown when its badges have been retrieved
+    fn render_with(&self, options: &RenderOptions) -> String {
+        self.render_cards(options, true)
     }
 
     fn simple_render(&self) -> String {
@@ -51,14 +140,117 @@ impl Renderable for List {
     }
 }
 
+/// A partial update of a list. Only the fields which have been explicitly
+/// set are sent to Trello.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ListPatch {
+    name: Option<String>,
+    closed: Option<bool>,
+    pos: Option<String>,
+}
+
+impl ListPatch {
+    pub fn new() -> ListPatch {
+        ListPatch::default()
+    }
+
+    pub fn name(mut self, name: &str) -> ListPatch {
+        self.name = Some(String::from(name));
+        self
+    }
+
+    pub fn closed(mut self, closed: bool) -> ListPatch {
+        self.closed = Some(closed);
+        self
+    }
+
+    /// Sets the position of the list as "top", "bottom" or a positive number
+    pub fn pos(mut self, pos: &str) -> ListPatch {
+        self.pos = Some(String::from(pos));
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self == &ListPatch::default()
+    }
+
+    pub fn params(&self) -> Vec<(&'static str, String)> {
+        let mut params = vec![];
+
+        if let Some(name) = &self.name {
+            params.push(("name", name.clone()));
+        }
+        if let Some(closed) = self.closed {
+            params.push(("closed", closed.to_string()));
+        }
+        if let Some(pos) = &self.pos {
+            params.push(("pos", pos.clone()));
+        }
+
+        params
+    }
+}
+
 impl List {
     pub fn new(id: &str, name: &str, cards: Option<Vec<Card>>) -> List {
         List {
-            id: String::from(id),
+            id: ListId::new(id),
             name: String::from(name),
-            cards,
+            cards: cards.into(),
             closed: false,
+            soft_limit: None,
+            #[cfg(feature = "extra-fields")]
+            extra: serde_json::Map::new(),
+        }
+    }
+
+    /// Renders the list, optionally showing the checklist progress of each
+    /// card (e.g. `[3/7]`) when it has check items.
+    pub fn render_with_progress(&self, show_progress: bool) -> String {
+        self.render_cards(&RenderOptions::default(), show_progress)
+    }
+
+    fn render_cards(&self, options: &RenderOptions, show_progress: bool) -> String {
+        let mut result = vec![header(&self.name, "-").bold().to_string()];
+        if options.show_ids {
+            result.push(self.id.dimmed().to_string());
+        }
+        let prefix_len = options
+            .id_prefix
+            .map(|min_len| unique_prefix_len(self.cards.iter().map(|c| c.id.as_str()), min_len));
+
+        if let Loaded::Fetched(cards) = &self.cards {
+            for c in cards {
+                let mut line = match prefix_len {
+                    Some(len) => {
+                        let prefix = c.id.get(..len).unwrap_or(&c.id);
+                        format!("* {} {}", prefix.yellow(), c.render_line(options))
+                    }
+                    None => format!("* {}", c.render_line(options)),
+                };
+
+                if options.detail != Detail::Minimal {
+                    if let (true, Some((complete, total))) = (show_progress, c.checklist_progress())
+                    {
+                        line.push_str(&format!(" [{}/{}]", complete, total));
+                    }
+                    if let Some(badges) = &c.badges {
+                        let summary = badges.summary();
+                        if !summary.is_empty() {
+                            line.push_str(&format!(" {}", summary.dimmed()));
+                        }
+                    }
+                }
+                result.push(line);
+
+                if options.detail == Detail::Full {
+                    for desc_line in c.desc.lines() {
+                        result.push(format!("  {}", desc_line).trim_end().to_string());
+                    }
+                }
+            }
         }
+        result.join("\n")
     }
 
     /// Filters cards that match the given label_filter (As a regular expression).
@@ -114,28 +306,33 @@ impl List {
         };
 
         let mut result = self.clone();
-        result.cards = if let Some(cards) = result.cards {
-            Some(cards.into_iter().filter(closure).collect())
-        } else {
-            None
-        };
+        result.cards = result
+            .cards
+            .map(|cards| cards.into_iter().filter(closure).collect());
         result
     }
 
-    pub fn create(client: &TrelloClient, board_id: &str, name: &str) -> Result<List> {
+    /// Creates a list with the given name on the board with the specified
+    /// id. The position of the list within the board can optionally be
+    /// given as "top", "bottom" or a positive number.
+    #[cfg(feature = "http")]
+    pub fn create(
+        client: &TrelloClient,
+        board_id: &str,
+        name: &str,
+        pos: Option<&str>,
+    ) -> Result<List> {
         let url = client.config.get_trello_url("/1/lists/", &[])?;
 
-        let params = [("name", name), ("idBoard", board_id)];
+        let mut params = vec![("name", name), ("idBoard", board_id)];
+        if let Some(pos) = pos {
+            params.push(("pos", pos));
+        }
 
-        Ok(client
-            .client
-            .post(url)
-            .form(&params)
-            .send()?
-            .error_for_status()?
-            .json()?)
+        Ok(client.send(client.post(url).form(&params))?.json()?)
     }
 
+    #[cfg(feature = "http")]
     pub fn open(client: &TrelloClient, list_id: &str) -> Result<List> {
         let url = client
             .config
@@ -143,15 +340,10 @@ impl List {
 
         let params = [("closed", "false")];
 
-        Ok(client
-            .client
-            .put(url)
-            .form(&params)
-            .send()?
-            .error_for_status()?
-            .json()?)
+        Ok(client.send(client.put(url).form(&params))?.json()?)
     }
 
+    #[cfg(feature = "http")]
     pub fn update(client: &TrelloClient, list: &List) -> Result<List> {
         let url = client
             .config
@@ -159,15 +351,194 @@ impl List {
 
         let params = [("name", &list.name), ("closed", &list.closed.to_string())];
 
-        Ok(client
-            .client
-            .put(url)
-            .form(&params)
-            .send()?
-            .error_for_status()?
-            .json()?)
+        Ok(client.send(client.put(url).form(&params))?.json()?)
+    }
+
+    /// Uploads the name and closed state of the list, updating the list with
+    /// the response. Cards retrieved with the list are kept.
+    #[cfg(feature = "http")]
+    pub fn save(&mut self, client: &TrelloClient) -> Result<()> {
+        let cards = self.cards.take();
+        *self = List::update(client, self)?;
+        self.cards = cards;
+        Ok(())
+    }
+
+    /// Archives the list, updating the list with the response
+    #[cfg(feature = "http")]
+    pub fn archive(&mut self, client: &TrelloClient) -> Result<()> {
+        let cards = self.cards.take();
+        *self = List::patch(client, &self.id, &ListPatch::new().closed(true))?;
+        self.cards = cards;
+        Ok(())
+    }
+
+    /// The open cards of the list, retrieving them on first access
+    #[cfg(feature = "http")]
+    pub fn cards(&mut self, client: &TrelloClient) -> Result<&[Card]> {
+        if !self.cards.is_fetched() {
+            debug!("Retrieving cards for list: {}", self.id);
+            self.cards = Loaded::Fetched(Card::get_all(client, &self.id)?);
+        }
+        Ok(self.cards.as_slice())
+    }
+
+    /// Retrieves the latest open cards of the list
+    #[cfg(feature = "http")]
+    pub fn refresh(&mut self, client: &TrelloClient) -> Result<()> {
+        self.cards = Loaded::Fetched(Card::get_all(client, &self.id)?);
+        Ok(())
+    }
+
+    /// Creates a card with the given name at the bottom of the list. The card
+    /// is also added to the cards of the list, if they have been retrieved.
+    #[cfg(feature = "http")]
+    pub fn add_card(&mut self, client: &TrelloClient, name: &str) -> Result<Card> {
+        let card = Card::create(client, &self.id, &Card::new("", name, "", None, "", None))?;
+        if let Loaded::Fetched(cards) = &mut self.cards {
+            cards.push(card.clone());
+        }
+        Ok(card)
+    }
+
+    /// Applies the given partial update to the list with the specified id
+    #[cfg(feature = "http")]
+    pub fn patch(client: &TrelloClient, list_id: &str, patch: &ListPatch) -> Result<List> {
+        let url = client
+            .config
+            .get_trello_url(&format!("/1/lists/{}/", list_id), &[])?;
+
+        Ok(client.send(client.put(url).form(&patch.params()))?.json()?)
+    }
+
+    /// Moves the list with the specified id to another board. The position of
+    /// the list within the board can optionally be given as "top", "bottom"
+    /// or a positive number.
+    #[cfg(feature = "http")]
+    pub fn move_to_board(
+        client: &TrelloClient,
+        list_id: &str,
+        board_id: &str,
+        pos: Option<&str>,
+    ) -> Result<List> {
+        let url = client
+            .config
+            .get_trello_url(&format!("/1/lists/{}/idBoard", list_id), &[])?;
+
+        let mut params = vec![("value", board_id)];
+        if let Some(pos) = pos {
+            params.push(("pos", pos));
+        }
+
+        Ok(client.send(client.put(url).form(&params))?.json()?)
+    }
+
+    /// Creates a copy of the list with the specified id (including its cards)
+    /// on the same board, using the given name.
+    #[cfg(feature = "http")]
+    pub fn copy(client: &TrelloClient, list_id: &str, name: &str) -> Result<List> {
+        #[derive(Deserialize)]
+        struct ListBoard {
+            id: String,
+        }
+
+        let url = client
+            .config
+            .get_trello_url(&format!("/1/lists/{}/board", list_id), &[("fields", "id")])?;
+        let board: ListBoard = client.send(client.get(url))?.json()?;
+
+        let url = client.config.get_trello_url("/1/lists/", &[])?;
+
+        let params = [
+            ("name", name),
+            ("idBoard", &board.id),
+            ("idListSource", list_id),
+        ];
+
+        Ok(client.send(client.post(url).form(&params))?.json()?)
+    }
+
+    /// Counts the open cards in the list without retrieving their contents
+    #[cfg(feature = "http")]
+    pub fn get_card_count(client: &TrelloClient, list_id: &str) -> Result<usize> {
+        #[derive(Deserialize)]
+        struct CardId {
+            #[allow(dead_code)]
+            id: String,
+        }
+
+        let url = client
+            .config
+            .get_trello_url(&format!("/1/lists/{}/cards", list_id), &[("fields", "id")])?;
+
+        let ids: Vec<CardId> = client.send(client.get(url))?.json()?;
+        Ok(ids.len())
+    }
+
+    /// Retrieves the cards of the list, including archived cards depending on the filter
+    #[cfg(feature = "http")]
+    pub fn get_all_cards_with_filter(
+        client: &TrelloClient,
+        list_id: &str,
+        filter: CardFilter,
+    ) -> Result<Vec<Card>> {
+        let url = client.config.get_trello_url(
+            &format!("/1/lists/{}/cards/{}", list_id, filter.as_str()),
+            &[("fields", &Card::get_fields().join(","))],
+        )?;
+
+        Ok(client.send(client.get(url))?.json()?)
+    }
+
+    /// Iterates over the open cards of the list, retrieving `page_size`
+    /// cards at a time as the iterator advances. Meant for lists with
+    /// thousands of cards. The iterator stops after the first error.
+    #[cfg(feature = "http")]
+    pub fn cards_paged<'a>(
+        client: &'a TrelloClient,
+        list_id: &str,
+        page_size: usize,
+    ) -> PagedCards<'a> {
+        PagedCards {
+            client,
+            list_id: String::from(list_id),
+            page_size: page_size.max(1),
+            before: None,
+            page: vec![].into_iter(),
+            done: false,
+        }
+    }
+
+    /// Retrieves the list with the specified id, along with its open cards
+    /// when `with_cards` is set
+    #[cfg(feature = "http")]
+    pub fn get(client: &TrelloClient, list_id: &str, with_cards: bool) -> Result<List> {
+        Ok(List::get_with_response(client, list_id, with_cards)?.value)
+    }
+
+    /// Like `get`, keeping the status, duration and raw body of the response
+    /// alongside the list
+    #[cfg(feature = "http")]
+    pub fn get_with_response(
+        client: &TrelloClient,
+        list_id: &str,
+        with_cards: bool,
+    ) -> Result<Response<List>> {
+        let fields = List::get_fields().join(",");
+        let mut params = vec![("fields", fields.as_str())];
+
+        if with_cards {
+            params.push(("cards", "open"));
+        }
+
+        let url = client
+            .config
+            .get_trello_url(&format!("/1/lists/{}", list_id), &params)?;
+
+        client.send_with_response(client.get(url))
     }
 
+    #[cfg(feature = "http")]
     pub fn get_all(client: &TrelloClient, board_id: &str, cards: bool) -> Result<Vec<List>> {
         let fields = List::get_fields().join(",");
         let mut params = vec![("fields", fields.as_str())];
@@ -180,6 +551,6 @@ impl List {
             .config
             .get_trello_url(&format!("/1/boards/{}/lists", board_id), &params)?;
 
-        Ok(client.client.get(url).send()?.error_for_status()?.json()?)
+        Ok(client.send(client.get(url))?.json()?)
     }
 }
@@ -0,0 +1,147 @@
+//! Brings the lists of several boards in line with a canonical workflow,
+//! e.g. Backlog, Doing, Review and Done, so boards of different teams can
+//! be read (and reported on) the same way.
+#[cfg(feature = "http")]
+use crate::board::Board;
+#[cfg(feature = "http")]
+use crate::board_changes::BoardChanges;
+use crate::board_changes::{BoardChange, ChangeReport};
+#[cfg(feature = "http")]
+use crate::client::TrelloClient;
+#[cfg(feature = "http")]
+use crate::ids::BoardId;
+use crate::ids::ListId;
+use crate::list::List;
+#[cfg(feature = "http")]
+use crate::list::ListPatch;
+#[cfg(feature = "http")]
+use crate::trello_error::TrelloError;
+use crate::trello_object::{RenderOptions, Renderable};
+
+use colored::*;
+
+#[cfg(feature = "http")]
+type Result<T> = std::result::Result<T, TrelloError>;
+
+/// A change needed for the lists of a board to match the canonical names
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ListChange {
+    Created {
+        name: String,
+    },
+    /// A list whose name only differed in case, whitespace or punctuation,
+    /// e.g. "to-do" for "To Do"
+    Renamed {
+        list_id: ListId,
+        from: String,
+        to: String,
+    },
+}
+
+impl BoardChange for ListChange {
+    const NOUN: &'static str = "list";
+    const UNCHANGED: &'static str = "Lists match";
+}
+
+impl Renderable for ListChange {
+    fn render_with(&self, _options: &RenderOptions) -> String {
+        match self {
+            ListChange::Created { name } => format!("{} {}", "+".green(), name),
+            ListChange::Renamed { from, to, .. } => {
+                format!("{} {} -> {}", "~".yellow(), from, to)
+            }
+        }
+    }
+
+    fn simple_render(&self) -> String {
+        match self {
+            ListChange::Created { name } => format!("Created {}", name),
+            ListChange::Renamed { to, .. } => format!("Renamed {}", to),
+        }
+    }
+}
+
+/// The changes for each board, in the order of the boards
+pub type NormalizeReport = ChangeReport<ListChange>;
+
+fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// The changes needed for the open lists of a board to include a list with
+/// each of the canonical names. Lists whose name only differs in case,
+/// whitespace or punctuation are renamed, missing lists are created. Other
+/// lists are left alone.
+pub fn plan(canonical_names: &[&str], lists: &[List]) -> Vec<ListChange> {
+    let open = lists.iter().filter(|l| !l.closed).collect::<Vec<&List>>();
+    let mut changes = vec![];
+
+    for name in canonical_names {
+        if open.iter().any(|l| l.name == *name) {
+            continue;
+        }
+
+        let similar = open
+            .iter()
+            .find(|l| normalize_name(&l.name) == normalize_name(name));
+
+        changes.push(match similar {
+            Some(list) => ListChange::Renamed {
+                list_id: list.id.clone(),
+                from: list.name.clone(),
+                to: String::from(*name),
+            },
+            None => ListChange::Created {
+                name: String::from(*name),
+            },
+        });
+    }
+
+    changes
+}
+
+/// Renames or creates lists on each board so that it has a list with each
+/// of the canonical names, see `plan`. Created lists are added to the right
+/// of the board. When `dry_run` is set, the changes are only reported.
+#[cfg(feature = "http")]
+pub fn normalize(
+    client: &TrelloClient,
+    boards: &[&str],
+    canonical_names: &[&str],
+    dry_run: bool,
+) -> Result<NormalizeReport> {
+    let mut report = NormalizeReport {
+        boards: vec![],
+        dry_run,
+    };
+
+    for board_id in boards {
+        let board = Board::get(client, board_id)?;
+        let changes = plan(canonical_names, &List::get_all(client, board_id, false)?);
+
+        if !dry_run {
+            for change in &changes {
+                debug!("Applying {:?}", change);
+                match change {
+                    ListChange::Created { name } => {
+                        List::create(client, board_id, name, Some("bottom"))?;
+                    }
+                    ListChange::Renamed { list_id, to, .. } => {
+                        List::patch(client, list_id, &ListPatch::new().name(to))?;
+                    }
+                }
+            }
+        }
+
+        report.boards.push(BoardChanges {
+            board_id: BoardId::new(board_id),
+            board_name: board.name,
+            changes,
+        });
+    }
+
+    Ok(report)
+}
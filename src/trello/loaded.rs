@@ -0,0 +1,128 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::slice;
+
+/// Nested objects (such as the cards of a list) which may or may not have
+/// been retrieved. Unlike `Option<Vec<T>>`, this makes it explicit that
+/// objects which were not fetched are different from an empty collection.
+///
+/// Serialized as `null` when not fetched, and as a sequence otherwise.
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub enum Loaded<T> {
+    #[default]
+    NotFetched,
+    Fetched(Vec<T>),
+}
+
+impl<T> Loaded<T> {
+    pub fn is_fetched(&self) -> bool {
+        matches!(self, Loaded::Fetched(_))
+    }
+
+    /// The fetched objects, if any
+    pub fn get(&self) -> Option<&[T]> {
+        match self {
+            Loaded::Fetched(values) => Some(values),
+            Loaded::NotFetched => None,
+        }
+    }
+
+    pub fn get_mut(&mut self) -> Option<&mut Vec<T>> {
+        match self {
+            Loaded::Fetched(values) => Some(values),
+            Loaded::NotFetched => None,
+        }
+    }
+
+    /// The fetched objects, treating objects which were not fetched as empty
+    pub fn get_or_insert_empty(&mut self) -> &mut Vec<T> {
+        if let Loaded::NotFetched = self {
+            *self = Loaded::Fetched(vec![]);
+        }
+        match self {
+            Loaded::Fetched(values) => values,
+            Loaded::NotFetched => unreachable!(),
+        }
+    }
+
+    /// The fetched objects, or an empty slice if they were not fetched
+    pub fn as_slice(&self) -> &[T] {
+        self.get().unwrap_or(&[])
+    }
+
+    /// Iterates over the fetched objects, if any
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        match self {
+            Loaded::Fetched(values) => values.iter_mut(),
+            Loaded::NotFetched => [].iter_mut(),
+        }
+    }
+
+    /// The fetched objects, or an empty vector if they were not fetched
+    pub fn into_vec(self) -> Vec<T> {
+        match self {
+            Loaded::Fetched(values) => values,
+            Loaded::NotFetched => vec![],
+        }
+    }
+
+    /// Takes the fetched objects, leaving `NotFetched` in their place
+    pub fn take(&mut self) -> Loaded<T> {
+        std::mem::take(self)
+    }
+
+    pub fn map<U, F: FnOnce(Vec<T>) -> Vec<U>>(self, f: F) -> Loaded<U> {
+        match self {
+            Loaded::Fetched(values) => Loaded::Fetched(f(values)),
+            Loaded::NotFetched => Loaded::NotFetched,
+        }
+    }
+}
+
+impl<T> From<Option<Vec<T>>> for Loaded<T> {
+    fn from(values: Option<Vec<T>>) -> Self {
+        match values {
+            Some(values) => Loaded::Fetched(values),
+            None => Loaded::NotFetched,
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for Loaded<T> {
+    fn from(values: Vec<T>) -> Self {
+        Loaded::Fetched(values)
+    }
+}
+
+impl<T> From<Loaded<T>> for Option<Vec<T>> {
+    fn from(values: Loaded<T>) -> Self {
+        match values {
+            Loaded::Fetched(values) => Some(values),
+            Loaded::NotFetched => None,
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Loaded<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Serialize> Serialize for Loaded<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.get().serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Loaded<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Option::<Vec<T>>::deserialize(deserializer)?.into())
+    }
+}
@@ -1,22 +1,146 @@
-use serde::Deserialize;
+use colored::*;
+use serde::{Deserialize, Serialize};
 
+use crate::board::Board;
+use crate::card::Card;
+#[cfg(feature = "http")]
 use crate::client::TrelloClient;
+use crate::formatting::title;
+use crate::list::List;
+#[cfg(feature = "http")]
+use crate::response::Response;
+#[cfg(feature = "http")]
 use crate::trello_error::TrelloError;
+#[cfg(feature = "http")]
+use crate::trello_object::TrelloObject;
+use crate::trello_object::{RenderOptions, Renderable};
 
+#[cfg(feature = "http")]
 type Result<T> = std::result::Result<T, TrelloError>;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize, Debug, Eq, PartialEq, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Member {
     pub id: String,
     pub full_name: String,
     pub username: String,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// A card along with the board and list it belongs to. The board and list
+/// are retrieved without their nested contents.
+#[derive(Deserialize, Serialize, Debug, Eq, PartialEq, Clone)]
+pub struct AssignedCard {
+    #[serde(flatten)]
+    pub card: Card,
+    pub board: Board,
+    pub list: List,
+}
+
+/// The cards assigned to a member across all of their boards
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct AssignedCards {
+    pub cards: Vec<AssignedCard>,
+}
+
+impl AssignedCards {
+    /// The boards of the assigned cards in the order they first appear
+    pub fn boards(&self) -> Vec<&Board> {
+        let mut boards: Vec<&Board> = vec![];
+        for assigned in &self.cards {
+            if !boards.iter().any(|b| b.id == assigned.board.id) {
+                boards.push(&assigned.board);
+            }
+        }
+        boards
+    }
+}
+
+impl Renderable for AssignedCards {
+    fn render_with(&self, options: &RenderOptions) -> String {
+        let mut result = vec![];
+
+        for board in self.boards() {
+            if !result.is_empty() {
+                result.push(String::from(""));
+            }
+            result.push(title(&board.name).bold().to_string());
+
+            for assigned in self.cards.iter().filter(|a| a.board.id == board.id) {
+                result.push(format!(
+                    "* {} {}",
+                    assigned.card.render_line(options),
+                    format!("({})", assigned.list.name).dimmed()
+                ));
+            }
+        }
+
+        result.join("\n")
+    }
+
+    fn simple_render(&self) -> String {
+        format!(
+            "{} cards on {} boards",
+            self.cards.len(),
+            self.boards().len()
+        )
+    }
 }
 
 impl Member {
+    #[cfg(feature = "http")]
     pub fn me(client: &TrelloClient) -> Result<Member> {
+        Ok(Member::me_with_response(client)?.value)
+    }
+
+    /// Like `me`, keeping the status, duration and raw body of the response
+    /// alongside the member
+    #[cfg(feature = "http")]
+    pub fn me_with_response(client: &TrelloClient) -> Result<Response<Member>> {
         let url = client.config.get_trello_url("/1/members/me/", &[])?;
 
-        Ok(client.client.get(url).send()?.error_for_status()?.json()?)
+        client.send_with_response(client.get(url))
+    }
+
+    /// Retrieves the members of the board with the specified id. Like labels,
+    /// the members are remembered by the client, see `Label::get_all`.
+    #[cfg(feature = "http")]
+    pub fn get_all(client: &TrelloClient, board_id: &str) -> Result<Vec<Member>> {
+        if let Some(members) = client.lookups().members(board_id) {
+            debug!("Using cached members for board {}", board_id);
+            return Ok(members);
+        }
+
+        let url = client.config.get_trello_url(
+            &format!("/1/boards/{}/members", board_id),
+            &[("fields", "id,fullName,username")],
+        )?;
+
+        let members: Vec<Member> = client.send(client.get(url))?.json()?;
+        client.lookups().set_members(board_id, &members);
+
+        Ok(members)
+    }
+
+    /// Retrieves the open cards the authenticated member is assigned to
+    /// across all boards and workspaces, along with their boards and lists.
+    #[cfg(feature = "http")]
+    pub fn get_assigned_cards(client: &TrelloClient) -> Result<AssignedCards> {
+        let url = client.config.get_trello_url(
+            "/1/members/me/cards",
+            &[
+                ("fields", &Card::get_fields().join(",")),
+                ("board", "true"),
+                ("board_fields", &Board::get_fields().join(",")),
+                ("list", "true"),
+                ("list_fields", &List::get_fields().join(",")),
+            ],
+        )?;
+
+        let cards = client.send(client.get(url))?.json()?;
+
+        Ok(AssignedCards { cards })
     }
 }
@@ -0,0 +1,137 @@
+//! Board health metrics in the Prometheus text exposition format, e.g. for
+//! serving from an endpoint which Prometheus scrapes.
+//!
+//! Only available with the `metrics` feature.
+use crate::board::Board;
+use crate::card::Card;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoardMetrics {
+    pub board_name: String,
+    /// Open cards in each open list, in board order
+    pub cards_per_list: Vec<(String, usize)>,
+    pub overdue: usize,
+    pub created_per_day: BTreeMap<NaiveDate, usize>,
+    pub closed_per_day: BTreeMap<NaiveDate, usize>,
+}
+
+impl BoardMetrics {
+    /// Collects metrics from a board with its lists and cards retrieved (see
+    /// `Board::retrieve_nested`) and its archived cards (see
+    /// `Board::get_closed_cards`). The day an archived card was closed is
+    /// taken from its last activity.
+    pub fn new(board: &Board, closed_cards: &[Card], now: DateTime<Utc>) -> BoardMetrics {
+        let lists = board.lists.iter().filter(|l| !l.closed);
+        let open_cards = lists
+            .clone()
+            .flat_map(|l| l.cards.iter())
+            .filter(|c| !c.closed);
+
+        let mut created_per_day = BTreeMap::new();
+        for card in open_cards.clone().chain(closed_cards) {
+            if let Some(created) = card.created_at() {
+                *created_per_day
+                    .entry(created.naive_utc().date())
+                    .or_insert(0) += 1;
+            }
+        }
+
+        let mut closed_per_day = BTreeMap::new();
+        for card in closed_cards {
+            if let Some(closed) = card.date_last_activity {
+                *closed_per_day.entry(closed.naive_utc().date()).or_insert(0) += 1;
+            }
+        }
+
+        BoardMetrics {
+            board_name: board.name.clone(),
+            cards_per_list: lists
+                .map(|l| {
+                    let count = l.cards.iter().filter(|c| !c.closed).count();
+                    (l.name.clone(), count)
+                })
+                .collect(),
+            overdue: open_cards
+                .filter(|c| c.due.is_some_and(|due| due < now))
+                .count(),
+            created_per_day,
+            closed_per_day,
+        }
+    }
+
+    pub fn render_prometheus(&self) -> String {
+        let board = escape(&self.board_name);
+        let mut result = vec![];
+
+        metric(
+            &mut result,
+            "trello_cards",
+            "Number of open cards in each list",
+            self.cards_per_list.iter().map(|(list, count)| {
+                (
+                    format!("board=\"{}\",list=\"{}\"", board, escape(list)),
+                    *count,
+                )
+            }),
+        );
+        metric(
+            &mut result,
+            "trello_cards_overdue",
+            "Number of open cards past their due date",
+            vec![(format!("board=\"{}\"", board), self.overdue)],
+        );
+        for (name, help, per_day) in &[
+            (
+                "trello_cards_created",
+                "Number of cards created on each day",
+                &self.created_per_day,
+            ),
+            (
+                "trello_cards_closed",
+                "Number of cards archived on each day",
+                &self.closed_per_day,
+            ),
+        ] {
+            metric(
+                &mut result,
+                name,
+                help,
+                per_day
+                    .iter()
+                    .map(|(day, count)| (format!("board=\"{}\",day=\"{}\"", board, day), *count)),
+            );
+        }
+
+        result.push(String::new());
+        result.join("\n")
+    }
+}
+
+fn metric<I: IntoIterator<Item = (String, usize)>>(
+    result: &mut Vec<String>,
+    name: &str,
+    help: &str,
+    samples: I,
+) {
+    result.push(format!("# HELP {} {}", name, help));
+    result.push(format!("# TYPE {} gauge", name));
+    for (labels, value) in samples {
+        result.push(format!("{}{{{}}} {}", name, labels, value));
+    }
+}
+
+// https://prometheus.io/docs/instrumenting/exposition_formats/#text-format-details
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Collects and renders the metrics of a board, see `BoardMetrics::new`
+pub fn render_prometheus(board: &Board, closed_cards: &[Card], now: DateTime<Utc>) -> String {
+    BoardMetrics::new(board, closed_cards, now).render_prometheus()
+}
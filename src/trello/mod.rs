@@ -1,29 +1,116 @@
 #[macro_use]
 extern crate log;
 
+#[cfg(feature = "auth")]
+pub mod auth;
+
+pub mod analytics;
+#[cfg(feature = "wasm")]
+mod async_client;
 mod attachment;
+pub mod audit;
+mod audit_log;
 mod board;
+mod board_changes;
+mod branch;
+pub mod bridges;
+#[cfg(feature = "http")]
+mod cache;
+mod cancel;
 mod card;
+mod card_history;
+mod checklist;
 mod client;
+pub mod config;
+pub mod context;
+#[cfg(feature = "human-dates")]
+pub mod dates;
+pub mod dependencies;
+pub mod diff;
+mod expand;
+pub mod focus;
 mod formatting;
+pub mod history;
+mod ids;
+pub mod import;
+pub mod index;
 mod label;
+pub mod labels;
+pub mod lint;
 mod list;
+pub mod lists;
+mod loaded;
 mod member;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+#[cfg(feature = "notify")]
+pub mod notify;
+#[cfg(feature = "http")]
+pub mod ops;
+pub mod parse;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod pick;
+pub mod policy;
+pub mod progress;
+mod rate_limit;
+pub mod redact;
+pub mod references;
+#[cfg(feature = "report")]
+pub mod report;
+pub mod reports;
+mod response;
+mod safety;
 mod search;
+#[cfg(feature = "http")]
+mod single_flight;
+pub mod templates;
+#[cfg(feature = "http")]
+mod transfer;
 mod trello_error;
 mod trello_object;
+#[cfg(feature = "tui")]
+pub mod tui;
+pub mod views;
+pub mod watch;
+pub mod webhook;
+mod write_queue;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "http"))]
 mod tests;
 
-pub use attachment::Attachment;
-pub use board::Board;
-pub use card::{Card, CardContents};
-pub use client::{ClientConfig, TrelloClient};
+#[cfg(feature = "wasm")]
+pub use async_client::AsyncTrelloClient;
+pub use attachment::{Attachment, Preview, ProgressCallback, UploadOptions, MAX_TEXT_BYTES};
+pub use audit_log::{AuditEntry, AuditLog};
+pub use board::{Board, BoardField, BoardPatch};
+pub use board_changes::{BoardChange, BoardChanges, ChangeReport};
+pub use branch::BranchScheme;
+pub use cancel::CancelToken;
+pub use card::{Badges, Card, CardContents, CardFilter, CardLabels, CardPatch};
+pub use card_history::{CardHistory, FieldChange, HistoryEntry};
+pub use checklist::{CheckItem, CheckItemState, Checklist};
+pub use client::ClientConfig;
+#[cfg(feature = "http")]
+pub use client::{resolve_url, ClientBuilder, TrelloClient, TrelloUrlObject};
+pub use expand::Expand;
 pub use formatting::{header, title};
+pub use ids::{BoardId, CardId, LabelId, ListId};
 pub use label::Label;
-pub use list::List;
-pub use member::Member;
-pub use search::{search, SearchOptions, SearchResult};
+#[cfg(feature = "http")]
+pub use list::PagedCards;
+pub use list::{List, ListPatch};
+pub use loaded::Loaded;
+pub use member::{AssignedCard, AssignedCards, Member};
+pub use rate_limit::{RateLimit, RateLimitStatus};
+pub use response::{BodySize, Response};
+pub use safety::{ConfirmationToken, SafetyPolicy};
+#[cfg(feature = "http")]
+pub use search::{search, search_attachments};
+pub use search::{AttachmentMatch, SearchOptions, SearchResult};
+#[cfg(feature = "http")]
+pub use transfer::TransferStats;
+#[cfg(any(feature = "http", feature = "wasm"))]
+pub use trello_error::RequestError;
 pub use trello_error::TrelloError;
-pub use trello_object::{Renderable, TrelloObject};
+pub use trello_object::{Detail, RenderOptions, Renderable, TrelloObject};
+pub use write_queue::{Mutation, WriteQueue};
@@ -0,0 +1,153 @@
+//! Notifications shown on the local desktop for cards which are coming due.
+//! Meant to be called periodically, from a `BoardWatcher` loop or a timer,
+//! with the cards of a board or the result of a search. Each card is only
+//! notified once per due date.
+//!
+//! Notifications are raised through the notifier of the platform:
+//! `notify-send` on Linux and BSD, `osascript` on macOS and PowerShell on
+//! Windows.
+use crate::card::Card;
+use crate::formatting::relative_due;
+use crate::ids::CardId;
+use crate::trello_error::TrelloError;
+
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashSet;
+use std::process::Command;
+
+type Result<T> = std::result::Result<T, TrelloError>;
+
+/// A card due within the notification window
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DueNotification {
+    pub card_id: CardId,
+    pub card_name: String,
+    pub url: String,
+    pub due: DateTime<Utc>,
+}
+
+impl DueNotification {
+    pub fn summary(&self) -> String {
+        self.card_name.clone()
+    }
+
+    /// E.g. "due in 2h"
+    pub fn body(&self, now: DateTime<Utc>) -> String {
+        relative_due(&self.due, &now)
+    }
+}
+
+/// The open cards due between now and the end of the window, soonest first.
+/// Cards which are already overdue are not included.
+pub fn due_within(cards: &[Card], now: DateTime<Utc>, window: Duration) -> Vec<DueNotification> {
+    let mut result = cards
+        .iter()
+        .filter(|c| !c.closed)
+        .filter_map(|c| {
+            let due = c.due?;
+            if due < now || due > now + window {
+                return None;
+            }
+            Some(DueNotification {
+                card_id: c.id.clone(),
+                card_name: c.name.clone(),
+                url: c.url.clone(),
+                due,
+            })
+        })
+        .collect::<Vec<DueNotification>>();
+    result.sort_by_key(|n| n.due);
+    result
+}
+
+/// Raises desktop notifications for cards coming due, remembering which
+/// cards were notified so repeated calls do not notify them again. A card
+/// whose due date changes is notified again.
+#[derive(Debug, Clone)]
+pub struct DesktopNotifier {
+    window: Duration,
+    notified: HashSet<(CardId, DateTime<Utc>)>,
+}
+
+impl DesktopNotifier {
+    /// Notifies cards due within `window` from the time of each call
+    pub fn new(window: Duration) -> DesktopNotifier {
+        DesktopNotifier {
+            window,
+            notified: HashSet::new(),
+        }
+    }
+
+    /// The cards due within the window which have not been notified yet.
+    /// They are marked as notified.
+    pub fn pending(&mut self, cards: &[Card], now: DateTime<Utc>) -> Vec<DueNotification> {
+        due_within(cards, now, self.window)
+            .into_iter()
+            .filter(|n| self.notified.insert((n.card_id.clone(), n.due)))
+            .collect()
+    }
+
+    /// Raises a notification for each card due within the window which has
+    /// not been notified yet, returning the number of notifications raised
+    pub fn notify_due(&mut self, cards: &[Card]) -> Result<usize> {
+        let now = Utc::now();
+        let pending = self.pending(cards, now);
+        for notification in &pending {
+            show(&notification.summary(), &notification.body(now))?;
+        }
+        Ok(pending.len())
+    }
+}
+
+/// Shows a single notification through the notifier of the platform
+pub fn show(summary: &str, body: &str) -> Result<()> {
+    let status = if cfg!(target_os = "macos") {
+        Command::new("osascript")
+            .arg("-e")
+            .arg(format!(
+                "display notification {} with title {}",
+                applescript_string(body),
+                applescript_string(summary)
+            ))
+            .status()?
+    } else if cfg!(target_os = "windows") {
+        // The script keeps running until the balloon tip is gone, so it is
+        // left to finish on its own
+        Command::new("powershell")
+            .args(["-NoProfile", "-Command", &powershell_script(summary, body)])
+            .spawn()?;
+        return Ok(());
+    } else {
+        // "--" keeps a summary starting with "-" from being read as an option
+        Command::new("notify-send")
+            .args(["--app-name", "tro", "--", summary, body])
+            .status()?
+    };
+
+    if !status.success() {
+        return Err(std::io::Error::other(format!("Notifier exited with {}", status)).into());
+    }
+    Ok(())
+}
+
+pub(crate) fn applescript_string(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn powershell_string(text: &str) -> String {
+    format!("'{}'", text.replace('\'', "''"))
+}
+
+// Uses a balloon tip, which is available without any additional modules
+fn powershell_script(summary: &str, body: &str) -> String {
+    format!(
+        "Add-Type -AssemblyName System.Windows.Forms; \
+         $n = New-Object System.Windows.Forms.NotifyIcon; \
+         $n.Icon = [System.Drawing.SystemIcons]::Information; \
+         $n.Visible = $true; \
+         $n.ShowBalloonTip(10000, {}, {}, 'Info'); \
+         Start-Sleep -Seconds 10; $n.Dispose()",
+        powershell_string(summary),
+        powershell_string(body)
+    )
+}
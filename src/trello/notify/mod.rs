@@ -0,0 +1,142 @@
+//! Notifications about boards sent to chat tools. Events found by a
+//! `BoardWatcher` or the summary of a report are formatted as Slack message
+//! payloads, with links to the cards, and posted to an incoming webhook.
+//! Any service accepting Slack compatible webhooks can be used.
+//!
+//! With the `desktop-notify` feature, cards coming due can also be shown as
+//! notifications on the local desktop, see `desktop`.
+#[cfg(feature = "desktop-notify")]
+pub mod desktop;
+
+use crate::client::TrelloClient;
+use crate::trello_error::TrelloError;
+use crate::trello_object::Renderable;
+use crate::watch::{Event, EventType};
+
+use serde::Serialize;
+use serde_json::{json, Value};
+use url::Url;
+
+type Result<T> = std::result::Result<T, TrelloError>;
+
+/// Slack rejects messages with more blocks than this
+const MAX_BLOCKS: usize = 50;
+
+const CARD_URL: &str = "https://trello.com/c/";
+
+/// The payload of a Slack incoming webhook. The text is shown in
+/// notifications and by clients which do not support blocks.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct SlackMessage {
+    pub text: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub blocks: Vec<Value>,
+}
+
+impl SlackMessage {
+    pub fn new(text: &str) -> SlackMessage {
+        SlackMessage {
+            text: String::from(text),
+            blocks: vec![],
+        }
+    }
+
+    /// A message with a section for each event on the board, linking to the
+    /// cards. Events which do not fit within Slack's block limit are counted
+    /// at the end of the message.
+    pub fn from_events(board_name: &str, events: &[Event]) -> SlackMessage {
+        let mut blocks = vec![json!({
+            "type": "header",
+            "text": {"type": "plain_text", "text": board_name},
+        })];
+
+        // leaves room for the header and the count of remaining events
+        let shown = events.len().min(MAX_BLOCKS - 2);
+        for event in &events[..shown] {
+            blocks.push(json!({
+                "type": "section",
+                "text": {"type": "mrkdwn", "text": event_text(event)},
+            }));
+        }
+        if events.len() > shown {
+            blocks.push(json!({
+                "type": "context",
+                "elements": [{
+                    "type": "mrkdwn",
+                    "text": format!("and {} more changes", events.len() - shown),
+                }],
+            }));
+        }
+
+        SlackMessage {
+            text: format!("{} changes on {}", events.len(), board_name),
+            blocks,
+        }
+    }
+
+    /// A message with the one line summary of the report, see
+    /// `Renderable::simple_render`
+    pub fn from_report<R: Renderable>(report: &R) -> SlackMessage {
+        SlackMessage::new(&report.simple_render())
+    }
+}
+
+fn event_text(event: &Event) -> String {
+    let card = format!(
+        "*<{}{}|{}>*",
+        CARD_URL,
+        event.card_id,
+        escape(&event.card_name)
+    );
+    let list = escape(&event.list);
+
+    match event.event {
+        EventType::CardCreated => format!("{} was added to _{}_", card, list),
+        EventType::CardRemoved => format!("{} was removed from _{}_", card, list),
+        EventType::CardMoved => match &event.from_list {
+            Some(from) => format!("{} moved from _{}_ to _{}_", card, escape(from), list),
+            None => format!("{} moved to _{}_", card, list),
+        },
+        EventType::CardRenamed => format!("{} was renamed in _{}_", card, list),
+    }
+}
+
+// https://api.slack.com/reference/surfaces/formatting#escaping
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Posts messages to a Slack incoming webhook
+#[derive(Debug, Clone)]
+pub struct SlackNotifier {
+    webhook_url: Url,
+}
+
+impl SlackNotifier {
+    pub fn new(webhook_url: &str) -> Result<SlackNotifier> {
+        Ok(SlackNotifier {
+            webhook_url: Url::parse(webhook_url)?,
+        })
+    }
+
+    pub fn send(&self, client: &TrelloClient, message: &SlackMessage) -> Result<()> {
+        client.send(client.post(self.webhook_url.clone()).json(message))?;
+        Ok(())
+    }
+
+    /// Posts the events found by a `BoardWatcher` poll. Nothing is sent when
+    /// there are no events.
+    pub fn notify_events(
+        &self,
+        client: &TrelloClient,
+        board_name: &str,
+        events: &[Event],
+    ) -> Result<()> {
+        if events.is_empty() {
+            return Ok(());
+        }
+        self.send(client, &SlackMessage::from_events(board_name, events))
+    }
+}
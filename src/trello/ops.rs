@@ -0,0 +1,239 @@
+//! High level operations which resolve boards, lists, cards and labels by
+//! name, perform an action and return the result. Each function maps to a
+//! single command a user might type, e.g. "add card X to list Y on board Z".
+//!
+//! Names are matched case insensitively. An exact match is preferred,
+//! otherwise the name must be contained in exactly one object's name.
+use crate::board::Board;
+use crate::card::Card;
+use crate::client::{ClientConfig, TrelloClient};
+use crate::label::Label;
+use crate::parse::card_spec_at;
+use crate::trello_error::TrelloError;
+use crate::trello_object::{Renderable, TrelloObject};
+
+use chrono::{DateTime, Duration, Local, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fs;
+
+type Result<T> = std::result::Result<T, TrelloError>;
+
+/// A capture identical to a card created in the inbox list less than this
+/// many minutes earlier is not added again
+const CAPTURE_DEDUP_MINUTES: i64 = 10;
+
+fn find_by_name<'a, T: TrelloObject>(objects: &'a [T], name: &str) -> Result<&'a T> {
+    let lowered = name.to_lowercase();
+
+    if let Some(exact) = objects
+        .iter()
+        .find(|o| o.get_name().to_lowercase() == lowered)
+    {
+        return Ok(exact);
+    }
+
+    let matches = objects
+        .iter()
+        .filter(|o| o.get_name().to_lowercase().contains(&lowered))
+        .collect::<Vec<&T>>();
+
+    match matches.as_slice() {
+        [single] => Ok(single),
+        [] => Err(TrelloError::NotFound(format!(
+            "{} '{}'",
+            T::get_type(),
+            name
+        ))),
+        _ => Err(TrelloError::Ambiguous(format!(
+            "{} '{}' matches {}",
+            T::get_type(),
+            name,
+            matches
+                .iter()
+                .map(|o| format!("'{}'", o.get_name()))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ))),
+    }
+}
+
+/// Retrieves the named board along with its open lists and cards
+fn get_board(client: &TrelloClient, board_name: &str) -> Result<Board> {
+    let boards = Board::get_all(client)?;
+    let mut board = find_by_name(&boards, board_name)?.clone();
+    board.retrieve_nested(client)?;
+    Ok(board)
+}
+
+fn find_card(board: &Board, card_name: &str) -> Result<Card> {
+    let cards = board
+        .lists
+        .iter()
+        .flat_map(|l| l.cards.iter())
+        .cloned()
+        .collect::<Vec<Card>>();
+
+    Ok(find_by_name(&cards, card_name)?.clone())
+}
+
+/// Renders the named board with all its open lists and cards
+pub fn show_board(client: &TrelloClient, board_name: &str) -> Result<String> {
+    Ok(get_board(client, board_name)?.render())
+}
+
+/// Creates a card with the given name at the bottom of the named list
+pub fn add_card(
+    client: &TrelloClient,
+    board_name: &str,
+    list_name: &str,
+    card_name: &str,
+) -> Result<Card> {
+    let board = get_board(client, board_name)?;
+    let list = find_by_name(board.lists.as_slice(), list_name)?;
+
+    Card::create(
+        client,
+        &list.id,
+        &Card::new("", card_name, "", None, "", None),
+    )
+}
+
+/// Moves the named card, which may be in any list on the board, to the named list
+pub fn move_card(
+    client: &TrelloClient,
+    board_name: &str,
+    card_name: &str,
+    list_name: &str,
+) -> Result<()> {
+    let board = get_board(client, board_name)?;
+    let card = find_card(&board, card_name)?;
+    let list = find_by_name(board.lists.as_slice(), list_name)?;
+
+    Card::move_to_list(client, &card.id, &list.id)
+}
+
+/// Applies the named board label to the named card
+pub fn label_card(
+    client: &TrelloClient,
+    board_name: &str,
+    card_name: &str,
+    label_name: &str,
+) -> Result<()> {
+    let board = get_board(client, board_name)?;
+    let card = find_card(&board, card_name)?;
+    let labels = Label::get_all(client, &board.id)?;
+    let label = find_by_name(&labels, label_name)?;
+
+    Label::add_to_card(client, &card.id, &label.id)
+}
+
+/// The board and list, by name, new cards are captured into by
+/// `quick_capture`
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct Inbox {
+    pub board: String,
+    pub list: String,
+}
+
+impl Inbox {
+    /// Reads the `[inbox]` table of the configuration file, if it has one
+    pub fn load_config() -> std::result::Result<Option<Inbox>, Box<dyn Error>> {
+        let contents = fs::read_to_string(ClientConfig::config_path()?)?;
+        Ok(Inbox::from_toml(&contents)?)
+    }
+
+    /// Reads the `[inbox]` table of a configuration, if it has one
+    pub fn from_toml(contents: &str) -> std::result::Result<Option<Inbox>, toml::de::Error> {
+        #[derive(Deserialize)]
+        struct Config {
+            inbox: Option<Inbox>,
+        }
+
+        Ok(toml::from_str::<Config>(contents)?.inbox)
+    }
+}
+
+/// The card added by `quick_capture`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Captured {
+    pub card: Card,
+    /// Set when an identical card had just been captured, which is returned
+    /// instead of adding another one
+    pub duplicate: bool,
+}
+
+/// Adds a card written in the single line syntax of `parse::card_spec` to
+/// the bottom of the list of the inbox, see `Inbox::load_config`, e.g.
+/// `Buy milk #errand !due:tomorrow`. The named labels are applied and the
+/// due date is set. An `@name` target is the name of another list on the
+/// inbox board, or of another board to capture into its inbox list.
+///
+/// Capturing the same text twice within a few minutes only adds one card.
+pub fn quick_capture(client: &TrelloClient, inbox: &Inbox, text: &str) -> Result<Captured> {
+    quick_capture_at(client, inbox, text, Local::now())
+}
+
+/// Like `quick_capture`, resolving relative due dates from the given time
+pub fn quick_capture_at<Tz: TimeZone>(
+    client: &TrelloClient,
+    inbox: &Inbox,
+    text: &str,
+    now: DateTime<Tz>,
+) -> Result<Captured>
+where
+    Tz::Offset: Copy,
+{
+    let spec = card_spec_at(text, now)?;
+    if spec.name.is_empty() {
+        return Err(TrelloError::CardParse(String::from("Nothing to capture")));
+    }
+
+    let mut board = get_board(client, &inbox.board)?;
+    let mut list_name = inbox.list.as_str();
+    if let Some(target) = &spec.target {
+        if find_by_name(board.lists.as_slice(), target).is_ok() {
+            list_name = target;
+        } else {
+            board = get_board(client, target)?;
+        }
+    }
+    let list = find_by_name(board.lists.as_slice(), list_name)?;
+
+    let cutoff = now.with_timezone(&Utc) - Duration::minutes(CAPTURE_DEDUP_MINUTES);
+    let recent = list
+        .cards
+        .iter()
+        .find(|c| c.name == spec.name && c.created_at().is_some_and(|d| d >= cutoff));
+    if let Some(card) = recent {
+        debug!("Already captured as {}", card.id);
+        return Ok(Captured {
+            card: card.clone(),
+            duplicate: true,
+        });
+    }
+
+    let mut params = vec![
+        ("name", spec.name.clone()),
+        ("idList", list.id.to_string()),
+        ("pos", String::from("bottom")),
+    ];
+    if !spec.labels.is_empty() {
+        let labels = Label::get_all(client, &board.id)?;
+        let label_ids = spec
+            .labels
+            .iter()
+            .map(|name| Ok(find_by_name(&labels, name)?.id.to_string()))
+            .collect::<Result<Vec<String>>>()?;
+        params.push(("idLabels", label_ids.join(",")));
+    }
+    if let Some(due) = spec.due {
+        params.push(("due", due.to_rfc3339()));
+    }
+
+    let url = client.config.get_trello_url("/1/cards/", &[])?;
+    Ok(Captured {
+        card: client.send(client.post(url).form(&params))?.json()?,
+        duplicate: false,
+    })
+}
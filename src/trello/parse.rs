@@ -0,0 +1,124 @@
+//! The single line syntax for entering cards quickly, e.g.
+//!
+//! ```text
+//! Fix login #bug !fri @Backlog
+//! ```
+//!
+//! which is a card named "Fix login", labelled "bug", due on Friday and
+//! meant for the "Backlog" list. Frontends parse the line with `card_spec`
+//! and resolve the label and list names against the board themselves.
+//!
+//! * `#label` applies a label, and can be repeated
+//! * `!date` or `!due:date` sets the due date, see `parse_due`. A `!word`
+//!   which isn't a date is part of the name, e.g. "Deploy !now".
+//! * `@name` hints at where the card should go, usually a list
+//!
+//! Everything else, in order, is the name of the card.
+use crate::trello_error::TrelloError;
+
+use chrono::{DateTime, Local, TimeZone, Utc};
+#[cfg(not(feature = "human-dates"))]
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+type Result<T> = std::result::Result<T, TrelloError>;
+
+/// A card written in the single line syntax
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CardSpec {
+    pub name: String,
+    /// The label names, without the leading `#`
+    pub labels: Vec<String>,
+    pub due: Option<DateTime<Utc>>,
+    /// The name of the list (or board) the card should be added to, without
+    /// the leading `@`
+    pub target: Option<String>,
+}
+
+/// Parses a card written in the single line syntax, resolving relative due
+/// dates from now in the local timezone. Only the last due date and target
+/// are kept when given more than once. Fails when the date of a `!due:date`
+/// token can't be parsed.
+/// ```
+/// let spec = trello::parse::card_spec("Fix login #bug @Backlog").unwrap();
+/// assert_eq!(spec.name, "Fix login");
+/// assert_eq!(spec.labels, vec!["bug"]);
+/// assert_eq!(spec.target.as_deref(), Some("Backlog"));
+/// ```
+pub fn card_spec(text: &str) -> Result<CardSpec> {
+    card_spec_at(text, Local::now())
+}
+
+/// Like `card_spec`, resolving relative due dates from the given time
+pub fn card_spec_at<Tz: TimeZone>(text: &str, now: DateTime<Tz>) -> Result<CardSpec>
+where
+    Tz::Offset: Copy,
+{
+    let mut spec = CardSpec::default();
+    let mut words = vec![];
+
+    for word in text.split_whitespace() {
+        let token = |prefix: char| word.strip_prefix(prefix).filter(|t| !t.is_empty());
+
+        if let Some(label) = token('#') {
+            spec.labels.push(String::from(label));
+        } else if let Some(due) = token('!') {
+            match due.strip_prefix("due:") {
+                Some(due) => spec.due = Some(parse_due(due, now)?),
+                None => match parse_due(due, now) {
+                    Ok(due) => spec.due = Some(due),
+                    Err(_) => words.push(word),
+                },
+            }
+        } else if let Some(target) = token('@') {
+            spec.target = Some(String::from(target));
+        } else {
+            words.push(word);
+        }
+    }
+
+    spec.name = words.join(" ");
+    Ok(spec)
+}
+
+/// Parses the date of a `!date` token with `dates::parse_human_at`
+#[cfg(feature = "human-dates")]
+pub fn parse_due<Tz: TimeZone>(text: &str, now: DateTime<Tz>) -> Result<DateTime<Utc>>
+where
+    Tz::Offset: Copy,
+{
+    crate::dates::parse_human_at(text, now)
+}
+
+/// Parses the date of a `!date` token. Understands "today", "tomorrow",
+/// weekdays such as "fri" (the next one, today included) and dates such as
+/// "2020-07-01", all at noon, as well as RFC 3339 timestamps. The
+/// `human-dates` feature allows many more forms, such as "next friday 5pm".
+#[cfg(not(feature = "human-dates"))]
+pub fn parse_due<Tz: TimeZone>(text: &str, now: DateTime<Tz>) -> Result<DateTime<Utc>>
+where
+    Tz::Offset: Copy,
+{
+    if let Ok(date) = DateTime::parse_from_rfc3339(text) {
+        return Ok(date.with_timezone(&Utc));
+    }
+
+    let today = now.date().naive_local();
+    let date = match text.to_lowercase().as_str() {
+        "today" => Some(today),
+        "tomorrow" => today.succ_opt(),
+        lowered => match lowered.parse::<Weekday>() {
+            Ok(weekday) => {
+                let days = (7 + weekday.num_days_from_monday()
+                    - today.weekday().num_days_from_monday())
+                    % 7;
+                Some(today + Duration::days(days.into()))
+            }
+            Err(_) => NaiveDate::parse_from_str(text, "%Y-%m-%d").ok(),
+        },
+    };
+
+    date.and_then(|d| d.and_hms_opt(12, 0, 0))
+        .and_then(|d| now.timezone().from_local_datetime(&d).single())
+        .map(|d| d.with_timezone(&Utc))
+        .ok_or_else(|| TrelloError::DateParse(format!("'{}'", text)))
+}
@@ -0,0 +1,135 @@
+//! Inline fuzzy selection prompt for Trello objects. Typing narrows down
+//! the candidates by fuzzy matching against their names, the arrow keys
+//! change the highlighted candidate and enter selects it.
+#[cfg(feature = "http")]
+use crate::board::Board;
+#[cfg(feature = "http")]
+use crate::card::Card;
+#[cfg(feature = "http")]
+use crate::client::TrelloClient;
+#[cfg(feature = "http")]
+use crate::list::List;
+#[cfg(feature = "http")]
+use crate::trello_error::TrelloError;
+use crate::trello_object::{Renderable, TrelloObject};
+
+use console::{Key, Term};
+use std::io;
+
+#[cfg(feature = "http")]
+type Result<T> = std::result::Result<T, TrelloError>;
+
+/// Maximum number of candidates displayed below the prompt
+const MAX_ROWS: usize = 10;
+
+/// Scores how well the pattern fuzzy matches the text. Every character of
+/// the pattern must appear in the text in the same order (ignoring case).
+/// Consecutive matches and matches at the start of words score higher.
+/// Returns None if the pattern does not match.
+pub fn score(pattern: &str, text: &str) -> Option<i64> {
+    let text = text.to_lowercase().chars().collect::<Vec<char>>();
+    let mut score = 0;
+    let mut position = 0;
+    let mut previous: Option<usize> = None;
+
+    for p in pattern.to_lowercase().chars() {
+        let index = position + text[position..].iter().position(|&c| c == p)?;
+
+        score += 1;
+        if index == 0 || !text[index - 1].is_alphanumeric() {
+            score += 8;
+        }
+        match previous {
+            Some(prev) if prev + 1 == index => score += 5,
+            Some(prev) => score -= (index - prev - 1).min(5) as i64,
+            None => score -= index.min(5) as i64,
+        }
+
+        previous = Some(index);
+        position = index + 1;
+    }
+
+    Some(score)
+}
+
+/// Returns the indices of the objects matching the pattern, best match
+/// first. Objects with equal scores retain their original order.
+pub fn rank<T: TrelloObject>(pattern: &str, objects: &[T]) -> Vec<usize> {
+    let mut scores = objects
+        .iter()
+        .enumerate()
+        .filter_map(|(i, o)| score(pattern, o.get_name()).map(|s| (i, s)))
+        .collect::<Vec<(usize, i64)>>();
+
+    scores.sort_by_key(|&(_, s)| std::cmp::Reverse(s));
+    scores.into_iter().map(|(i, _)| i).collect()
+}
+
+/// Displays an inline fuzzy prompt over the objects on stderr.
+/// Returns None if the user cancelled with escape.
+pub fn pick<T: TrelloObject + Renderable>(objects: &[T]) -> io::Result<Option<&T>> {
+    let term = Term::stderr();
+    let mut query = String::new();
+    let mut selected = 0;
+    let mut drawn = 0;
+
+    loop {
+        let matches = rank(&query, objects);
+        selected = selected.min(matches.len().saturating_sub(1));
+
+        term.clear_last_lines(drawn)?;
+        term.write_line(&format!("{} > {}", T::get_type(), query))?;
+        for (row, index) in matches.iter().take(MAX_ROWS).enumerate() {
+            let marker = if row == selected { ">" } else { " " };
+            term.write_line(&format!("{} {}", marker, objects[*index].simple_render()))?;
+        }
+        drawn = 1 + matches.len().min(MAX_ROWS);
+
+        match term.read_key()? {
+            Key::Escape => {
+                term.clear_last_lines(drawn)?;
+                return Ok(None);
+            }
+            Key::Enter => {
+                if let Some(index) = matches.get(selected) {
+                    term.clear_last_lines(drawn)?;
+                    return Ok(Some(&objects[*index]));
+                }
+            }
+            Key::ArrowUp => selected = selected.saturating_sub(1),
+            Key::ArrowDown | Key::Tab if selected + 1 < matches.len().min(MAX_ROWS) => {
+                selected += 1
+            }
+            Key::Backspace => {
+                query.pop();
+                selected = 0;
+            }
+            Key::Char(c) if !c.is_control() => {
+                query.push(c);
+                selected = 0;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Prompts the user to pick one of their open boards
+#[cfg(feature = "http")]
+pub fn pick_board(client: &TrelloClient) -> Result<Option<Board>> {
+    let boards = Board::get_all(client)?;
+    Ok(pick(&boards)?.cloned())
+}
+
+/// Prompts the user to pick one of the open lists on a board
+#[cfg(feature = "http")]
+pub fn pick_list(client: &TrelloClient, board_id: &str) -> Result<Option<List>> {
+    let lists = List::get_all(client, board_id, false)?;
+    Ok(pick(&lists)?.cloned())
+}
+
+/// Prompts the user to pick one of the open cards in a list
+#[cfg(feature = "http")]
+pub fn pick_card(client: &TrelloClient, list_id: &str) -> Result<Option<Card>> {
+    let cards = Card::get_all(client, list_id)?;
+    Ok(pick(&cards)?.cloned())
+}
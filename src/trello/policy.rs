@@ -0,0 +1,121 @@
+//! Policies which can be checked against a board, such as the work in
+//! progress limits used by kanban teams.
+use crate::board::Board;
+use crate::list::List;
+use crate::trello_error::TrelloError;
+use crate::trello_object::{RenderOptions, Renderable};
+
+use colored::*;
+use regex::{Regex, RegexBuilder};
+
+type Result<T> = std::result::Result<T, TrelloError>;
+
+/// Maximum card counts for lists, keyed by case insensitive patterns which
+/// are matched against list names. The first matching pattern wins. Lists
+/// which match no pattern fall back to the soft limit set in Trello, if any.
+#[derive(Debug, Clone, Default)]
+pub struct WipLimits {
+    limits: Vec<(Regex, usize)>,
+}
+
+impl WipLimits {
+    pub fn new() -> WipLimits {
+        WipLimits::default()
+    }
+
+    pub fn limit(mut self, pattern: &str, max_cards: usize) -> Result<WipLimits> {
+        let re = RegexBuilder::new(pattern).case_insensitive(true).build()?;
+        self.limits.push((re, max_cards));
+        Ok(self)
+    }
+
+    /// The limit which applies to the given list, if any
+    pub fn limit_for(&self, list: &List) -> Option<usize> {
+        self.limits
+            .iter()
+            .find(|(re, _)| re.is_match(&list.name))
+            .map(|(_, max_cards)| *max_cards)
+            .or_else(|| list.soft_limit.map(|l| l as usize))
+    }
+}
+
+/// The number of open cards in a list along with its limit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListWip {
+    pub name: String,
+    pub count: usize,
+    pub limit: Option<usize>,
+}
+
+impl ListWip {
+    pub fn is_over_limit(&self) -> bool {
+        self.limit.is_some_and(|limit| self.count > limit)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WipReport {
+    pub name: String,
+    pub lists: Vec<ListWip>,
+}
+
+impl WipReport {
+    pub fn violations(&self) -> Vec<&ListWip> {
+        self.lists.iter().filter(|l| l.is_over_limit()).collect()
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.violations().is_empty()
+    }
+}
+
+impl Renderable for WipReport {
+    fn render_with(&self, _options: &RenderOptions) -> String {
+        let mut result = vec![self.name.bold().to_string()];
+
+        for list in &self.lists {
+            let line = match list.limit {
+                Some(limit) => format!("{} {}/{}", list.name, list.count, limit),
+                None => format!("{} {}", list.name, list.count),
+            };
+            if list.is_over_limit() {
+                result.push(line.red().to_string());
+            } else {
+                result.push(line);
+            }
+        }
+
+        result.join("\n")
+    }
+
+    fn simple_render(&self) -> String {
+        format!(
+            "{}: {} lists over their WIP limit",
+            self.name,
+            self.violations().len()
+        )
+    }
+}
+
+impl Board {
+    /// Checks the open lists of the board against the given limits. The
+    /// board must have its lists and cards retrieved beforehand, see
+    /// `Board::retrieve_nested`.
+    pub fn check_wip(&self, limits: &WipLimits) -> WipReport {
+        let lists = self
+            .lists
+            .iter()
+            .filter(|l| !l.closed)
+            .map(|l| ListWip {
+                name: l.name.clone(),
+                count: l.cards.iter().filter(|c| !c.closed).count(),
+                limit: limits.limit_for(l),
+            })
+            .collect();
+
+        WipReport {
+            name: self.name.clone(),
+            lists,
+        }
+    }
+}
@@ -0,0 +1,178 @@
+//! Progress reporting for operations which make many requests, such as
+//! flushing a `WriteQueue`, retrieving the nested content of boards or
+//! syncing labels across boards. Frontends pass a `Progress` in the
+//! `RunOptions` of the `*_with` variant of the operation to show how far
+//! along it is.
+//!
+//! Closures taking the phase, the number of steps done and the total number
+//! of steps can be used directly:
+//! ```
+//! use trello::progress::Progress;
+//!
+//! let mut log = vec![];
+//! let mut progress = |phase: &str, done: usize, total: usize| {
+//!     log.push(format!("{}: {}/{}", phase, done, total));
+//! };
+//! progress.update("Sending changes", 1, 2);
+//! assert_eq!(log, vec!["Sending changes: 1/2"]);
+//! ```
+use crate::cancel::CancelToken;
+#[cfg(feature = "http")]
+use crate::trello_error::TrelloError;
+
+#[cfg(feature = "progress-bar")]
+use indicatif::{ProgressBar, ProgressStyle};
+
+/// Receives updates from a long operation. An operation goes through one or
+/// more phases, each made of a known number of steps, and calls `update`
+/// when a phase starts (with `done` at 0) and after each of its steps.
+pub trait Progress {
+    fn update(&mut self, phase: &str, done: usize, total: usize);
+
+    /// Called once the operation is over, whether it succeeded or not
+    fn finish(&mut self) {}
+}
+
+impl<F: FnMut(&str, usize, usize)> Progress for F {
+    fn update(&mut self, phase: &str, done: usize, total: usize) {
+        self(phase, done, total)
+    }
+}
+
+/// Ignores every update, for the operations called without progress
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoProgress;
+
+impl Progress for NoProgress {
+    fn update(&mut self, _phase: &str, _done: usize, _total: usize) {}
+}
+
+/// How a long operation reports its progress and learns that it should
+/// stop. The default reports nothing and runs until done.
+/// ```
+/// use trello::progress::RunOptions;
+///
+/// let token = trello::CancelToken::new();
+/// let mut progress = |_: &str, _: usize, _: usize| {};
+/// let options = RunOptions::new().progress(&mut progress).cancel(&token);
+/// ```
+#[derive(Default)]
+pub struct RunOptions<'a> {
+    pub progress: Option<&'a mut dyn Progress>,
+    /// Stops the operation with `TrelloError::Cancelled` between requests
+    pub cancel: Option<CancelToken>,
+}
+
+impl<'a> RunOptions<'a> {
+    pub fn new() -> RunOptions<'a> {
+        RunOptions::default()
+    }
+
+    pub fn progress(mut self, progress: &'a mut dyn Progress) -> RunOptions<'a> {
+        self.progress = Some(progress);
+        self
+    }
+
+    pub fn cancel(mut self, cancel: &CancelToken) -> RunOptions<'a> {
+        self.cancel = Some(cancel.clone());
+        self
+    }
+}
+
+#[cfg(feature = "http")]
+impl RunOptions<'_> {
+    pub(crate) fn update(&mut self, phase: &str, done: usize, total: usize) {
+        if let Some(progress) = &mut self.progress {
+            progress.update(phase, done, total);
+        }
+    }
+
+    pub(crate) fn finish(&mut self) {
+        if let Some(progress) = &mut self.progress {
+            progress.finish();
+        }
+    }
+
+    /// Fails with `TrelloError::Cancelled` when cancelled, see
+    /// `CancelToken::check`
+    pub(crate) fn check(&self, completed: usize) -> Result<(), TrelloError> {
+        match &self.cancel {
+            Some(cancel) => cancel.check(completed),
+            None => Ok(()),
+        }
+    }
+
+    /// Waits for the given duration, returning early when cancelled
+    pub(crate) fn sleep(&self, duration: std::time::Duration) {
+        match &self.cancel {
+            Some(cancel) => {
+                cancel.sleep(duration);
+            }
+            None => std::thread::sleep(duration),
+        }
+    }
+}
+
+/// The percentage of the steps done, rounded down. A phase without any
+/// steps is complete.
+/// ```
+/// assert_eq!(trello::progress::percent(1, 3), 33);
+/// assert_eq!(trello::progress::percent(0, 0), 100);
+/// ```
+pub fn percent(done: usize, total: usize) -> u8 {
+    if total == 0 {
+        return 100;
+    }
+    (done.min(total) * 100 / total) as u8
+}
+
+/// Shows the progress as a bar on stderr, starting over for each phase
+#[cfg(feature = "progress-bar")]
+#[derive(Debug)]
+pub struct Bar {
+    bar: ProgressBar,
+    phase: String,
+}
+
+#[cfg(feature = "progress-bar")]
+impl Bar {
+    pub fn new() -> Bar {
+        Bar::with_bar(ProgressBar::new(0))
+    }
+
+    /// Reports through the given bar, e.g. one from a `MultiProgress` or a
+    /// hidden one
+    pub fn with_bar(bar: ProgressBar) -> Bar {
+        if let Ok(style) = ProgressStyle::with_template("{msg} [{bar:40}] {pos}/{len}") {
+            bar.set_style(style.progress_chars("=> "));
+        }
+        Bar {
+            bar,
+            phase: String::new(),
+        }
+    }
+}
+
+#[cfg(feature = "progress-bar")]
+impl Default for Bar {
+    fn default() -> Self {
+        Bar::new()
+    }
+}
+
+#[cfg(feature = "progress-bar")]
+impl Progress for Bar {
+    fn update(&mut self, phase: &str, done: usize, total: usize) {
+        if phase != self.phase {
+            self.phase = String::from(phase);
+            self.bar.reset();
+            self.bar.set_message(self.phase.clone());
+        }
+        self.bar.set_length(total as u64);
+        self.bar.set_position(done as u64);
+    }
+
+    fn finish(&mut self) {
+        self.bar.finish_and_clear();
+    }
+}
@@ -0,0 +1,65 @@
+use http::HeaderMap;
+
+/// The limit imposed by Trello on a single scope (API key, token or member)
+/// as reported by the most recent response
+/// https://developer.atlassian.com/cloud/trello/guides/rest-api/rate-limits/
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct RateLimit {
+    pub max: u64,
+    pub remaining: u64,
+    pub interval_ms: u64,
+}
+
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub struct RateLimitStatus {
+    pub api_key: Option<RateLimit>,
+    pub api_token: Option<RateLimit>,
+    pub member: Option<RateLimit>,
+}
+
+impl RateLimit {
+    fn from_headers(headers: &HeaderMap, scope: &str) -> Option<RateLimit> {
+        let value = |name: &str| -> Option<u64> {
+            headers
+                .get(format!("x-rate-limit-{}-{}", scope, name).as_str())?
+                .to_str()
+                .ok()?
+                .parse()
+                .ok()
+        };
+
+        Some(RateLimit {
+            max: value("max")?,
+            remaining: value("remaining")?,
+            interval_ms: value("interval-ms")?,
+        })
+    }
+}
+
+impl RateLimitStatus {
+    /// Parses the rate limit headers returned by Trello. None is returned
+    /// if the headers do not contain any rate limit information.
+    pub fn from_headers(headers: &HeaderMap) -> Option<RateLimitStatus> {
+        let status = RateLimitStatus {
+            api_key: RateLimit::from_headers(headers, "api-key"),
+            api_token: RateLimit::from_headers(headers, "api-token"),
+            member: RateLimit::from_headers(headers, "member"),
+        };
+
+        if status == RateLimitStatus::default() {
+            None
+        } else {
+            Some(status)
+        }
+    }
+
+    /// The number of requests which can be made before any of the
+    /// reported limits are hit
+    pub fn remaining(&self) -> Option<u64> {
+        [self.api_key, self.api_token, self.member]
+            .iter()
+            .flatten()
+            .map(|l| l.remaining)
+            .min()
+    }
+}
@@ -0,0 +1,43 @@
+//! Removal of credentials from urls and messages. The API key and token are
+//! sent as query parameters, so any url (or error describing a request) would
+//! otherwise leak them into logs.
+use regex::Regex;
+use std::borrow::Cow;
+
+const REDACTED: &str = "REDACTED";
+const SECRET_PARAMS: &[&str] = &["key", "token"];
+
+/// Returns the url with the values of its credential parameters replaced
+pub fn redact_url(url: &url::Url) -> url::Url {
+    let mut redacted = url.clone();
+    if url.query().is_none() {
+        return redacted;
+    }
+
+    let pairs = url
+        .query_pairs()
+        .map(|(name, value)| {
+            if SECRET_PARAMS.contains(&name.as_ref()) {
+                (name, Cow::Borrowed(REDACTED))
+            } else {
+                (name, value)
+            }
+        })
+        .collect::<Vec<(Cow<str>, Cow<str>)>>();
+
+    redacted.query_pairs_mut().clear().extend_pairs(pairs);
+    redacted
+}
+
+/// Replaces the values of credential parameters in any url found in the text
+/// ```
+/// assert_eq!(
+///     trello::redact::redact("error sending request for url (https://api.trello.com/1/boards/?key=abc&token=def&fields=id)"),
+///     "error sending request for url (https://api.trello.com/1/boards/?key=REDACTED&token=REDACTED&fields=id)",
+/// );
+/// ```
+pub fn redact(text: &str) -> String {
+    let re = Regex::new(r#"([?&"](?:key|token)=)[^&#\s)"]*"#).unwrap();
+    re.replace_all(text, format!("${{1}}{}", REDACTED).as_str())
+        .to_string()
+}
@@ -0,0 +1,194 @@
+//! Wiki style links between the cards of a board. A description may refer
+//! to another card by its number, e.g. `#12`, or by its name, e.g.
+//! `[[Design the logo]]`. `Resolver::link` turns these references into
+//! markdown links to the cards, which Trello renders as links, and
+//! `Resolver::unlink` turns the links back into references, e.g. to show or
+//! edit a description the way it was written.
+//!
+//! ```text
+//! See #12 and [[Design the logo]]
+//! See [#12 Pick colors](https://trello.com/c/a1B2c3D4) and [Design the logo](https://trello.com/c/e5F6g7H8)
+//! ```
+use crate::board::Board;
+use crate::card::Card;
+#[cfg(feature = "http")]
+use crate::card::CardPatch;
+#[cfg(feature = "http")]
+use crate::client::TrelloClient;
+use crate::dependencies::parse_short_link;
+#[cfg(feature = "http")]
+use crate::trello_error::TrelloError;
+
+use regex::{Captures, Regex};
+
+#[cfg(feature = "http")]
+type Result<T> = std::result::Result<T, TrelloError>;
+
+/// Resolves references to the open cards of a board
+#[derive(Debug, Clone)]
+pub struct Resolver<'a> {
+    cards: Vec<&'a Card>,
+}
+
+impl<'a> Resolver<'a> {
+    /// The board must have its lists and cards retrieved beforehand, see
+    /// `Board::retrieve_nested`
+    pub fn new(board: &'a Board) -> Resolver<'a> {
+        Resolver::from_cards(
+            board
+                .lists
+                .iter()
+                .filter(|l| !l.closed)
+                .flat_map(|l| l.cards.iter()),
+        )
+    }
+
+    pub fn from_cards<I: IntoIterator<Item = &'a Card>>(cards: I) -> Resolver<'a> {
+        Resolver {
+            cards: cards.into_iter().filter(|c| !c.closed).collect(),
+        }
+    }
+
+    /// The card with the given number on the board
+    pub fn by_number(&self, number: u64) -> Option<&'a Card> {
+        self.cards
+            .iter()
+            .find(|c| c.id_short == Some(number))
+            .copied()
+    }
+
+    /// The card with the given name, ignoring case when no card has the
+    /// exact name. None when several cards match.
+    pub fn by_name(&self, name: &str) -> Option<&'a Card> {
+        let name = name.trim();
+        let unique = |matches: Vec<&'a Card>| match matches.as_slice() {
+            [card] => Some(*card),
+            _ => None,
+        };
+
+        let exact = self
+            .cards
+            .iter()
+            .filter(|c| c.name == name)
+            .copied()
+            .collect();
+        unique(exact).or_else(|| {
+            unique(
+                self.cards
+                    .iter()
+                    .filter(|c| c.name.to_lowercase() == name.to_lowercase())
+                    .copied()
+                    .collect(),
+            )
+        })
+    }
+
+    /// The card a Trello card url points to
+    pub fn by_url(&self, url: &str) -> Option<&'a Card> {
+        let short_link = parse_short_link(url)?;
+        self.cards
+            .iter()
+            .find(|c| c.short_link() == Some(short_link))
+            .copied()
+    }
+
+    /// Replaces `#12` and `[[Card Name]]` references with markdown links to
+    /// the cards. References to unknown cards, and references within the
+    /// text of existing links, are left as they are, so that linking text
+    /// again does not change it.
+    pub fn link(&self, text: &str) -> String {
+        // Existing links are matched first in order to skip them, and `#12`
+        // must not be part of a word, an url or an html entity
+        let re =
+            Regex::new(r"\[(?:\\.|[^\]\\\n])*\]\(\S+?\)|\[\[([^\[\]\n]+)\]\]|(^|[^\w&/#])#(\d+)\b")
+                .unwrap();
+
+        re.replace_all(text, |caps: &Captures| {
+            if let Some(name) = caps.get(1) {
+                return match self.by_name(name.as_str()) {
+                    Some(card) => format!("[{}]({})", escape(&card.name), card.url),
+                    None => caps[0].to_string(),
+                };
+            }
+
+            let card = caps
+                .get(3)
+                .and_then(|n| n.as_str().parse().ok())
+                .and_then(|n| self.by_number(n));
+            match card {
+                Some(card) => format!(
+                    "{}[#{} {}]({})",
+                    &caps[2],
+                    &caps[3],
+                    escape(&card.name),
+                    card.url
+                ),
+                None => caps[0].to_string(),
+            }
+        })
+        .to_string()
+    }
+
+    /// Replaces the links created by `link` with the references they were
+    /// created from. Links to other pages, or whose text was changed, are
+    /// left as they are.
+    pub fn unlink(&self, text: &str) -> String {
+        let re = Regex::new(r"\[((?:\\.|[^\]\\\n])*)\]\((\S+?)\)").unwrap();
+
+        re.replace_all(text, |caps: &Captures| {
+            let card = match self.by_url(&caps[2]) {
+                Some(card) => card,
+                None => return caps[0].to_string(),
+            };
+            let link_text = unescape(&caps[1]);
+
+            match card.id_short {
+                Some(number) if link_text == format!("#{} {}", number, card.name) => {
+                    format!("#{}", number)
+                }
+                _ if link_text == card.name => format!("[[{}]]", card.name),
+                _ => caps[0].to_string(),
+            }
+        })
+        .to_string()
+    }
+}
+
+// Card names may contain brackets, which would end the link text
+fn escape(name: &str) -> String {
+    name.replace('\\', "\\\\")
+        .replace('[', "\\[")
+        .replace(']', "\\]")
+}
+
+fn unescape(text: &str) -> String {
+    let mut result = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => result.extend(chars.next()),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// Links the references in the descriptions of every open card on the
+/// board, see `Resolver::link`, returning the number of cards updated. The
+/// board must have its lists and cards retrieved beforehand.
+#[cfg(feature = "http")]
+pub fn link_descriptions(client: &TrelloClient, board: &Board) -> Result<usize> {
+    let resolver = Resolver::new(board);
+    let mut updated = 0;
+
+    for card in &resolver.cards {
+        let desc = resolver.link(&card.desc);
+        if desc != card.desc {
+            debug!("Linking references in the description of {}", card.id);
+            Card::patch(client, &card.id, &CardPatch::new().desc(&desc))?;
+            updated += 1;
+        }
+    }
+
+    Ok(updated)
+}
@@ -0,0 +1,279 @@
+//! Printable reports summarizing a board for sprint reviews and audits: the
+//! contents of each list, the overdue cards and a table of statistics. Reports
+//! are rendered as HTML styled for printing, which can be printed from a
+//! browser or converted to PDF with an external tool such as `wkhtmltopdf`.
+use crate::board::Board;
+use crate::dependencies::CardNode;
+use crate::formatting::escape_html;
+use crate::trello_error::TrelloError;
+use crate::trello_object::{RenderOptions, Renderable};
+
+use chrono::{DateTime, Utc};
+use colored::*;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+type Result<T> = std::result::Result<T, TrelloError>;
+
+/// The converter used by `Report::write_pdf`, which reads HTML from stdin
+/// when given `-` as its input
+pub const DEFAULT_PDF_CONVERTER: &str = "wkhtmltopdf";
+
+const STYLE: &str = "\
+body { font-family: serif; font-size: 11pt; margin: 2em; }
+h1 { margin-bottom: 0; }
+.generated { color: #666; margin-top: 0.25em; }
+table { border-collapse: collapse; width: 100%; margin-bottom: 1.5em; }
+th, td { border: 1px solid #999; padding: 0.25em 0.5em; text-align: left; vertical-align: top; }
+th { background: #eee; }
+td.number, th.number { text-align: right; }
+tr.overdue td { color: #b00; }
+section { page-break-inside: avoid; }
+@media print { body { margin: 0; } a { color: inherit; text-decoration: none; } }";
+
+/// Counts of the cards in a single list
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ListStats {
+    pub name: String,
+    pub open: usize,
+    pub with_due: usize,
+    pub overdue: usize,
+    pub without_labels: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub name: String,
+    pub url: String,
+    pub generated_at: DateTime<Utc>,
+    /// The open cards of each open list
+    pub cards: Vec<(String, Vec<CardNode>)>,
+    /// Open cards whose due date has passed, the most overdue first
+    pub overdue: Vec<CardNode>,
+    pub stats: Vec<ListStats>,
+}
+
+impl Report {
+    /// Summarizes the open lists and cards of the board at the given time.
+    /// The board must have its lists and cards retrieved beforehand, see
+    /// `Board::retrieve_nested`.
+    pub fn new(board: &Board, now: DateTime<Utc>) -> Report {
+        let mut cards = vec![];
+        let mut overdue = vec![];
+        let mut stats = vec![];
+
+        for list in board.lists.iter().filter(|l| !l.closed) {
+            let mut list_stats = ListStats {
+                name: list.name.clone(),
+                ..ListStats::default()
+            };
+            let mut list_cards = vec![];
+
+            for card in list.cards.iter().filter(|c| !c.closed) {
+                let node = CardNode {
+                    card: card.clone(),
+                    list_name: list.name.clone(),
+                };
+
+                list_stats.open += 1;
+                if card.labels.as_ref().map_or(true, |l| l.is_empty()) {
+                    list_stats.without_labels += 1;
+                }
+                if let Some(due) = card.due {
+                    list_stats.with_due += 1;
+                    if due < now {
+                        list_stats.overdue += 1;
+                        overdue.push(node.clone());
+                    }
+                }
+                list_cards.push(node);
+            }
+
+            cards.push((list.name.clone(), list_cards));
+            stats.push(list_stats);
+        }
+
+        overdue.sort_by_key(|n| n.card.due);
+
+        Report {
+            name: board.name.clone(),
+            url: board.url.clone(),
+            generated_at: now,
+            cards,
+            overdue,
+            stats,
+        }
+    }
+
+    /// The sum of the statistics of every list
+    pub fn totals(&self) -> ListStats {
+        self.stats.iter().fold(
+            ListStats {
+                name: String::from("Total"),
+                ..ListStats::default()
+            },
+            |mut total, s| {
+                total.open += s.open;
+                total.with_due += s.with_due;
+                total.overdue += s.overdue;
+                total.without_labels += s.without_labels;
+                total
+            },
+        )
+    }
+
+    /// Renders the report as a standalone HTML page styled for printing
+    pub fn to_html(&self) -> String {
+        let mut result = vec![
+            String::from("<!DOCTYPE html>"),
+            String::from("<html>"),
+            String::from("<head>"),
+            String::from("<meta charset=\"utf-8\">"),
+            format!("<title>{} report</title>", escape_html(&self.name)),
+            format!("<style>\n{}\n</style>", STYLE),
+            String::from("</head>"),
+            String::from("<body>"),
+            format!("<h1>{}</h1>", escape_html(&self.name)),
+            format!(
+                "<p class=\"generated\">Generated {}</p>",
+                self.generated_at.format("%Y-%m-%d %H:%M UTC")
+            ),
+        ];
+
+        result.push(String::from("<section>"));
+        result.push(String::from("<h2>Summary</h2>"));
+        result.push(String::from("<table>"));
+        result.push(String::from(
+            "<tr><th>List</th><th class=\"number\">Open</th><th class=\"number\">With due date</th>\
+             <th class=\"number\">Overdue</th><th class=\"number\">Without labels</th></tr>",
+        ));
+        for stats in self.stats.iter().chain(std::iter::once(&self.totals())) {
+            result.push(format!(
+                "<tr><td>{}</td><td class=\"number\">{}</td><td class=\"number\">{}</td>\
+                 <td class=\"number\">{}</td><td class=\"number\">{}</td></tr>",
+                escape_html(&stats.name),
+                stats.open,
+                stats.with_due,
+                stats.overdue,
+                stats.without_labels
+            ));
+        }
+        result.push(String::from("</table>"));
+        result.push(String::from("</section>"));
+
+        result.push(String::from("<section>"));
+        result.push(String::from("<h2>Overdue</h2>"));
+        if self.overdue.is_empty() {
+            result.push(String::from("<p>No overdue cards.</p>"));
+        } else {
+            result.push(String::from("<table>"));
+            result.push(String::from(
+                "<tr><th>Card</th><th>List</th><th>Due</th></tr>",
+            ));
+            for node in &self.overdue {
+                result.push(format!(
+                    "<tr class=\"overdue\"><td>{}</td><td>{}</td><td>{}</td></tr>",
+                    escape_html(&node.card.name),
+                    escape_html(&node.list_name),
+                    due_cell(&node.card.due)
+                ));
+            }
+            result.push(String::from("</table>"));
+        }
+        result.push(String::from("</section>"));
+
+        for (list_name, cards) in &self.cards {
+            result.push(String::from("<section>"));
+            result.push(format!("<h2>{}</h2>", escape_html(list_name)));
+            if cards.is_empty() {
+                result.push(String::from("<p>No cards.</p>"));
+            } else {
+                result.push(String::from("<table>"));
+                result.push(String::from(
+                    "<tr><th>Card</th><th>Labels</th><th>Due</th></tr>",
+                ));
+                for node in cards {
+                    let labels = node
+                        .card
+                        .labels
+                        .iter()
+                        .flatten()
+                        .map(|l| escape_html(&l.name))
+                        .collect::<Vec<String>>();
+                    let class = match node.card.due {
+                        Some(due) if due < self.generated_at => " class=\"overdue\"",
+                        _ => "",
+                    };
+                    result.push(format!(
+                        "<tr{}><td>{}</td><td>{}</td><td>{}</td></tr>",
+                        class,
+                        escape_html(&node.card.name),
+                        labels.join(", "),
+                        due_cell(&node.card.due)
+                    ));
+                }
+                result.push(String::from("</table>"));
+            }
+            result.push(String::from("</section>"));
+        }
+
+        result.push(String::from("</body>"));
+        result.push(String::from("</html>"));
+        result.join("\n")
+    }
+
+    /// Converts the report to a PDF at the given path by piping its HTML to
+    /// the given converter, e.g. `DEFAULT_PDF_CONVERTER`. The converter is
+    /// run as `<converter> - <path>`.
+    pub fn write_pdf(&self, converter: &str, path: &Path) -> Result<()> {
+        let mut child = Command::new(converter)
+            .arg("-")
+            .arg(path)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .spawn()?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(self.to_html().as_bytes())?;
+        }
+
+        let status = child.wait()?;
+        if !status.success() {
+            return Err(TrelloError::Io(io::Error::other(format!(
+                "{} failed with {}",
+                converter, status
+            ))));
+        }
+        Ok(())
+    }
+}
+
+fn due_cell(due: &Option<DateTime<Utc>>) -> String {
+    due.map(|d| d.format("%Y-%m-%d").to_string())
+        .unwrap_or_default()
+}
+
+impl Renderable for Report {
+    fn render_with(&self, _options: &RenderOptions) -> String {
+        let mut result = vec![self.name.bold().to_string()];
+
+        for stats in self.stats.iter().chain(std::iter::once(&self.totals())) {
+            let line = format!(
+                "{}: {} open, {} overdue",
+                stats.name, stats.open, stats.overdue
+            );
+            if stats.overdue > 0 {
+                result.push(line.red().to_string());
+            } else {
+                result.push(line);
+            }
+        }
+
+        result.join("\n")
+    }
+
+    fn simple_render(&self) -> String {
+        format!("{}: {} overdue cards", self.name, self.overdue.len())
+    }
+}
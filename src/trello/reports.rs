@@ -0,0 +1,401 @@
+//! Documents generated from the contents of a board, such as release notes
+//! built from the cards which have been moved to a "Done" list and periodic
+//! email digests. With the `smtp` feature, digests can be sent by email.
+use crate::board::Board;
+use crate::card::Card;
+use crate::formatting::escape_html;
+use crate::list::List;
+use crate::trello_error::TrelloError;
+
+use chrono::{DateTime, Duration, Utc};
+#[cfg(feature = "smtp")]
+use lettre::address::{Address, Envelope};
+#[cfg(feature = "smtp")]
+use lettre::transport::smtp::authentication::Credentials;
+#[cfg(feature = "smtp")]
+use lettre::{SmtpTransport, Transport};
+use regex::RegexBuilder;
+
+type Result<T> = std::result::Result<T, TrelloError>;
+
+/// Sections of the release notes in the order they are written. Cards are
+/// placed in the first section with a label containing one of its keywords
+/// (ignoring case), or in the last section otherwise.
+const SECTIONS: [(&str, &[&str]); 3] = [
+    ("Features", &["feature", "enhancement"]),
+    ("Fixes", &["bug", "fix"]),
+    ("Chores", &[]),
+];
+
+fn section_of(card: &Card) -> usize {
+    let labels = card
+        .labels
+        .iter()
+        .flatten()
+        .map(|l| l.name.to_lowercase())
+        .collect::<Vec<String>>();
+
+    SECTIONS
+        .iter()
+        .position(|(_, keywords)| {
+            keywords
+                .iter()
+                .any(|k| labels.iter().any(|l| l.contains(k)))
+        })
+        .unwrap_or(SECTIONS.len() - 1)
+}
+
+/// Generates Markdown release notes from the cards in the lists matching
+/// `done_list_pattern` (a case insensitive regular expression) whose last
+/// activity was at or after `since`, grouped into Features, Fixes and Chores
+/// by their labels. The board must have its lists and cards retrieved
+/// beforehand, see `Board::retrieve_nested`.
+pub fn release_notes(
+    board: &Board,
+    done_list_pattern: &str,
+    since: DateTime<Utc>,
+) -> Result<String> {
+    let re = RegexBuilder::new(done_list_pattern)
+        .case_insensitive(true)
+        .build()?;
+
+    let mut sections: Vec<Vec<&Card>> = vec![vec![]; SECTIONS.len()];
+    let cards = board
+        .lists
+        .iter()
+        .filter(|l| !l.closed && re.is_match(&l.name))
+        .flat_map(|l| l.cards.iter())
+        .filter(|c| c.date_last_activity.is_some_and(|d| d >= since));
+
+    for card in cards {
+        sections[section_of(card)].push(card);
+    }
+
+    let mut result = vec![format!(
+        "# {} release notes since {}",
+        board.name,
+        since.format("%Y-%m-%d")
+    )];
+
+    if sections.iter().all(|s| s.is_empty()) {
+        result.push(String::new());
+        result.push(String::from("No changes."));
+    }
+
+    for ((title, _), cards) in SECTIONS.iter().zip(sections) {
+        if cards.is_empty() {
+            continue;
+        }
+        result.push(String::new());
+        result.push(format!("## {}", title));
+        result.push(String::new());
+        for card in cards {
+            let name = card.name.trim();
+            if card.url.is_empty() {
+                result.push(format!("- {}", name));
+            } else {
+                result.push(format!("- {} ([card]({}))", name, card.url));
+            }
+        }
+    }
+
+    Ok(result.join("\n"))
+}
+
+/// Cards in lists with this name (ignoring case) are considered completed
+const DONE_LIST: &str = "done";
+
+/// Separates the parts of the digest. Never occurs in the base64 encoded parts.
+const BOUNDARY: &str = "=_tro_digest";
+
+/// A span of time covered by a digest, including its start and end
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Period {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+impl Period {
+    pub fn new(start: DateTime<Utc>, end: DateTime<Utc>) -> Period {
+        Period { start, end }
+    }
+
+    /// The period of the given length ending now, e.g. `Period::last(Duration::weeks(1))`
+    pub fn last(length: Duration) -> Period {
+        let end = Utc::now();
+        Period::new(end - length, end)
+    }
+
+    pub fn contains(&self, date: DateTime<Utc>) -> bool {
+        self.start <= date && date <= self.end
+    }
+}
+
+/// The sections of a digest, each with the open cards and the names of
+/// their lists
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailDigest {
+    pub board_name: String,
+    pub period: Period,
+    /// Cards created during the period
+    pub new: Vec<(Card, String)>,
+    /// Older cards with activity during the period outside of a "Done" list.
+    /// A board snapshot cannot tell moves from other changes, so this
+    /// includes any update to the card.
+    pub moved: Vec<(Card, String)>,
+    /// Cards in a "Done" list with activity during the period
+    pub completed: Vec<(Card, String)>,
+}
+
+/// Collects the new, moved and completed cards of the board during the
+/// period into a digest which can be sent by email (see `EmailDigest::to_mime`).
+/// The board must have its lists and cards retrieved beforehand, see
+/// `Board::retrieve_nested`.
+pub fn email_digest(board: &Board, period: Period) -> EmailDigest {
+    let mut digest = EmailDigest {
+        board_name: board.name.clone(),
+        period,
+        new: vec![],
+        moved: vec![],
+        completed: vec![],
+    };
+
+    let cards = board
+        .lists
+        .iter()
+        .filter(|l| !l.closed)
+        .flat_map(|l| l.cards.iter().map(move |c| (l, c)))
+        .filter(|(_, c)| !c.closed);
+
+    for (list, card) in cards {
+        let active = card.date_last_activity.is_some_and(|d| period.contains(d));
+        let section = if is_done(list) {
+            if !active {
+                continue;
+            }
+            &mut digest.completed
+        } else if card.created_at().is_some_and(|d| period.contains(d)) {
+            &mut digest.new
+        } else if active {
+            &mut digest.moved
+        } else {
+            continue;
+        };
+        section.push((card.clone(), list.name.clone()));
+    }
+
+    digest
+}
+
+fn is_done(list: &List) -> bool {
+    list.name.eq_ignore_ascii_case(DONE_LIST)
+}
+
+impl EmailDigest {
+    fn sections(&self) -> [(&str, &[(Card, String)]); 3] {
+        [
+            ("New cards", &self.new),
+            ("Moved cards", &self.moved),
+            ("Completed cards", &self.completed),
+        ]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sections().iter().all(|(_, cards)| cards.is_empty())
+    }
+
+    pub fn subject(&self) -> String {
+        format!(
+            "{} digest {} to {}",
+            self.board_name,
+            self.period.start.format("%Y-%m-%d"),
+            self.period.end.format("%Y-%m-%d")
+        )
+    }
+
+    /// The plain text version of the digest
+    pub fn to_text(&self) -> String {
+        let mut result = vec![self.subject()];
+
+        if self.is_empty() {
+            result.push(String::new());
+            result.push(String::from("No changes."));
+        }
+
+        for (title, cards) in self.sections().iter() {
+            if cards.is_empty() {
+                continue;
+            }
+            result.push(String::new());
+            result.push(format!("{} ({})", title, cards.len()));
+            for (card, list_name) in cards.iter() {
+                let mut line = format!("* {} [{}]", card.name, list_name);
+                if !card.url.is_empty() {
+                    line.push_str(&format!(" {}", card.url));
+                }
+                result.push(line);
+            }
+        }
+
+        result.join("\n")
+    }
+
+    /// The HTML version of the digest
+    pub fn to_html(&self) -> String {
+        let mut result = vec![
+            String::from("<!DOCTYPE html>"),
+            String::from("<html>"),
+            String::from("<body>"),
+            format!("<h1>{}</h1>", escape_html(&self.subject())),
+        ];
+
+        if self.is_empty() {
+            result.push(String::from("<p>No changes.</p>"));
+        }
+
+        for (title, cards) in self.sections().iter() {
+            if cards.is_empty() {
+                continue;
+            }
+            result.push(format!("<h2>{} ({})</h2>", title, cards.len()));
+            result.push(String::from("<ul>"));
+            for (card, list_name) in cards.iter() {
+                let name = if card.url.is_empty() {
+                    escape_html(&card.name)
+                } else {
+                    format!(
+                        "<a href=\"{}\">{}</a>",
+                        escape_html(&card.url),
+                        escape_html(&card.name)
+                    )
+                };
+                result.push(format!(
+                    "<li>{} <small>{}</small></li>",
+                    name,
+                    escape_html(list_name)
+                ));
+            }
+            result.push(String::from("</ul>"));
+        }
+
+        result.push(String::from("</body>"));
+        result.push(String::from("</html>"));
+        result.join("\n")
+    }
+
+    /// The digest as a complete email message, with plain text and HTML
+    /// alternatives. Line breaks in the addresses are dropped so that they
+    /// can't add headers to the message.
+    pub fn to_mime(&self, from: &str, to: &[&str]) -> String {
+        let to: Vec<String> = to.iter().map(|address| strip_newlines(address)).collect();
+        let mut lines = vec![
+            format!("From: {}", strip_newlines(from)),
+            format!("To: {}", to.join(", ")),
+            format!("Subject: {}", encode_header(&self.subject())),
+            format!("Date: {}", self.period.end.to_rfc2822()),
+            String::from("MIME-Version: 1.0"),
+            format!(
+                "Content-Type: multipart/alternative; boundary=\"{}\"",
+                BOUNDARY
+            ),
+            String::new(),
+        ];
+
+        for (content_type, body) in &[
+            ("text/plain", self.to_text()),
+            ("text/html", self.to_html()),
+        ] {
+            lines.push(format!("--{}", BOUNDARY));
+            lines.push(format!("Content-Type: {}; charset=utf-8", content_type));
+            lines.push(String::from("Content-Transfer-Encoding: base64"));
+            lines.push(String::new());
+            let encoded = base64::encode(body);
+            lines.extend(
+                encoded
+                    .as_bytes()
+                    .chunks(76)
+                    .map(|chunk| String::from_utf8_lossy(chunk).into_owned()),
+            );
+        }
+        lines.push(format!("--{}--", BOUNDARY));
+        lines.push(String::new());
+
+        lines.join("\r\n")
+    }
+}
+
+// https://tools.ietf.org/html/rfc2047
+// Control characters are encoded too, a line break in a board name must not
+// end the header
+fn encode_header(text: &str) -> String {
+    if text.is_ascii() && !text.chars().any(|c| c.is_ascii_control()) {
+        String::from(text)
+    } else {
+        format!("=?UTF-8?B?{}?=", base64::encode(text))
+    }
+}
+
+fn strip_newlines(text: &str) -> String {
+    text.chars().filter(|c| *c != '\r' && *c != '\n').collect()
+}
+
+/// The SMTP server used by `send_digest`
+#[cfg(feature = "smtp")]
+#[derive(Debug, Clone)]
+pub struct SmtpSettings {
+    pub host: String,
+    /// Defaults to 587 with `starttls`, or 25 otherwise
+    pub port: Option<u16>,
+    pub credentials: Option<(String, String)>,
+    /// Upgrade the connection with STARTTLS. Only disable for trusted
+    /// servers such as a local relay.
+    pub starttls: bool,
+}
+
+#[cfg(feature = "smtp")]
+impl SmtpSettings {
+    pub fn new(host: &str) -> SmtpSettings {
+        SmtpSettings {
+            host: String::from(host),
+            port: None,
+            credentials: None,
+            starttls: true,
+        }
+    }
+}
+
+/// Sends the digest by email through the given SMTP server
+#[cfg(feature = "smtp")]
+pub fn send_digest(
+    settings: &SmtpSettings,
+    from: &str,
+    to: &[&str],
+    digest: &EmailDigest,
+) -> Result<()> {
+    let address = |a: &str| {
+        a.parse::<Address>()
+            .map_err(|e| TrelloError::Email(format!("{}: {}", a, e)))
+    };
+    let recipients = to.iter().map(|a| address(a)).collect::<Result<Vec<_>>>()?;
+    let envelope = Envelope::new(Some(address(from)?), recipients)
+        .map_err(|e| TrelloError::Email(e.to_string()))?;
+
+    let mut builder = if settings.starttls {
+        SmtpTransport::starttls_relay(&settings.host)
+            .map_err(|e| TrelloError::Email(e.to_string()))?
+    } else {
+        SmtpTransport::builder_dangerous(settings.host.as_str())
+    };
+    if let Some(port) = settings.port {
+        builder = builder.port(port);
+    }
+    if let Some((username, password)) = &settings.credentials {
+        builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+    }
+
+    builder
+        .build()
+        .send_raw(&envelope, digest.to_mime(from, to).as_bytes())
+        .map_err(|e| TrelloError::Email(e.to_string()))?;
+    Ok(())
+}
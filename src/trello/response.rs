@@ -0,0 +1,33 @@
+use http::StatusCode;
+use std::time::Duration;
+
+/// A deserialized response value along with metadata about the request
+/// which produced it. Useful for debugging mismatches between the data
+/// returned by Trello and the structures in this crate.
+#[derive(Debug, Clone)]
+pub struct Response<T> {
+    pub value: T,
+    pub status: StatusCode,
+    pub duration: Duration,
+    /// The raw body of the response, before deserialization
+    pub body: String,
+    /// None when the body wasn't received for this request, e.g. when it
+    /// was shared with an identical request in flight or read by the audit
+    /// log
+    pub size: Option<BodySize>,
+}
+
+/// The size of a single response body
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BodySize {
+    /// Bytes received over the network, i.e. compressed
+    pub transferred: u64,
+    /// Bytes after decompression
+    pub decoded: u64,
+}
+
+impl<T> Response<T> {
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
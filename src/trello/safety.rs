@@ -0,0 +1,72 @@
+#[cfg(feature = "http")]
+use crate::trello_error::TrelloError;
+
+#[cfg(feature = "http")]
+type Result<T> = std::result::Result<T, TrelloError>;
+
+/// Determines how a TrelloClient handles requests to permanently delete objects
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub enum SafetyPolicy {
+    /// Deletions are turned into archive operations. Objects which cannot
+    /// be archived cannot be deleted.
+    ArchiveOnly,
+    /// Deletions are only performed when accompanied by a ConfirmationToken
+    /// for the object being deleted.
+    RequireConfirmationToken,
+    /// Deletions are always performed
+    #[default]
+    Unrestricted,
+}
+
+/// Explicit confirmation that the object with the given id should be
+/// permanently deleted. Obtained from `TrelloClient::confirm_deletion`.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ConfirmationToken {
+    object_id: String,
+}
+
+impl ConfirmationToken {
+    #[cfg(feature = "http")]
+    pub(crate) fn new(object_id: &str) -> ConfirmationToken {
+        ConfirmationToken {
+            object_id: String::from(object_id),
+        }
+    }
+
+    pub fn object_id(&self) -> &str {
+        &self.object_id
+    }
+}
+
+#[cfg(feature = "http")]
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) enum Deletion {
+    Delete,
+    Archive,
+}
+
+impl SafetyPolicy {
+    /// Determines what should happen when deleting the object with the given id
+    #[cfg(feature = "http")]
+    pub(crate) fn check(
+        self,
+        object_id: &str,
+        confirmation: Option<&ConfirmationToken>,
+    ) -> Result<Deletion> {
+        match self {
+            SafetyPolicy::Unrestricted => Ok(Deletion::Delete),
+            SafetyPolicy::ArchiveOnly => Ok(Deletion::Archive),
+            SafetyPolicy::RequireConfirmationToken => match confirmation {
+                Some(token) if token.object_id == object_id => Ok(Deletion::Delete),
+                Some(token) => Err(TrelloError::DeletionNotPermitted(format!(
+                    "confirmation token is for '{}', not '{}'",
+                    token.object_id, object_id
+                ))),
+                None => Err(TrelloError::DeletionNotPermitted(format!(
+                    "a confirmation token is required to delete '{}'",
+                    object_id
+                ))),
+            },
+        }
+    }
+}
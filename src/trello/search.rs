@@ -1,29 +1,25 @@
+use super::attachment::Attachment;
 use super::board::Board;
 use super::card::Card;
+#[cfg(feature = "http")]
 use super::client::TrelloClient;
+#[cfg(feature = "http")]
 use super::trello_error::TrelloError;
+#[cfg(feature = "http")]
 use super::trello_object::TrelloObject;
 
 use serde::Deserialize;
 
+#[cfg(feature = "http")]
 type Result<T> = std::result::Result<T, TrelloError>;
 
+#[derive(Default)]
 pub struct SearchOptions {
     pub partial: bool,
     pub cards_limit: Option<i32>,
     pub boards_limit: Option<i32>,
 }
 
-impl Default for SearchOptions {
-    fn default() -> Self {
-        SearchOptions {
-            partial: false,
-            cards_limit: None,
-            boards_limit: None,
-        }
-    }
-}
-
 #[derive(Deserialize, Debug, Eq, PartialEq, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct SearchResult {
@@ -35,6 +31,7 @@ pub struct SearchResult {
 
 /// Implements the Trello Search API
 /// https://developer.atlassian.com/cloud/trello/rest/api-group-search/#api-search-get
+#[cfg(feature = "http")]
 pub fn search(
     client: &TrelloClient,
     search_term: &str,
@@ -66,5 +63,67 @@ pub fn search(
 
     let url = client.config.get_trello_url("/1/search/", &params)?;
 
-    Ok(client.client.get(url).send()?.error_for_status()?.json()?)
+    Ok(client.send(client.get(url))?.json()?)
+}
+
+/// A card with an attachment whose name or contents match a search term
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct AttachmentMatch {
+    pub card: Card,
+    pub list_name: String,
+    pub attachment: Attachment,
+    /// Whether the term was found in the contents of the attachment rather
+    /// than its name
+    pub in_contents: bool,
+}
+
+/// Searches the names of the attachments of the open cards on the board for
+/// the given term, ignoring case, along with the contents of any plain text
+/// or Markdown attachments (see `Attachment::fetch_text`). The board must have
+/// its lists and cards retrieved beforehand, see `Board::retrieve_nested`.
+/// Cards whose badges show no attachments are skipped, as are the contents of
+/// uploads which are not served by Trello.
+#[cfg(feature = "http")]
+pub fn search_attachments(
+    client: &TrelloClient,
+    board: &Board,
+    search_term: &str,
+) -> Result<Vec<AttachmentMatch>> {
+    let term = search_term.to_lowercase();
+    let mut result = vec![];
+
+    let cards = board
+        .lists
+        .iter()
+        .filter(|l| !l.closed)
+        .flat_map(|l| l.cards.iter().map(move |c| (l, c)))
+        .filter(|(_, c)| !c.closed)
+        .filter(|(_, c)| c.badges.as_ref().map_or(true, |b| b.attachments > 0));
+
+    for (list, card) in cards {
+        for attachment in Attachment::get_all(client, &card.id)? {
+            let in_contents = if attachment.name.to_lowercase().contains(&term) {
+                false
+            } else {
+                match attachment.fetch_text(client) {
+                    Ok(Some(text)) if text.to_lowercase().contains(&term) => true,
+                    Ok(_) => continue,
+                    Err(TrelloError::UnsupportedUrl(url)) => {
+                        debug!("Skipping the contents of attachment at {}", url);
+                        continue;
+                    }
+                    Err(e) => return Err(e),
+                }
+            };
+
+            result.push(AttachmentMatch {
+                card: card.clone(),
+                list_name: list.name.clone(),
+                attachment,
+                in_contents,
+            });
+        }
+    }
+
+    Ok(result)
 }
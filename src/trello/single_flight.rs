@@ -0,0 +1,161 @@
+//! Deduplication of identical GET requests made concurrently through the
+//! same client, e.g. when several threads load the same board. The first
+//! request is sent, and the requests which arrive while it is in flight wait
+//! for its response instead of sending their own. Responses are only read
+//! into memory when they are actually shared.
+use crate::transfer::rebuild;
+
+use reqwest::blocking::Response;
+use reqwest::header::HeaderMap;
+use reqwest::{StatusCode, Version};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+
+/// A response read into memory, from which any number of identical
+/// responses can be created
+#[derive(Debug, Clone)]
+struct SharedResponse {
+    status: StatusCode,
+    version: Version,
+    headers: HeaderMap,
+    body: Vec<u8>,
+}
+
+impl SharedResponse {
+    fn read(response: Response) -> reqwest::Result<SharedResponse> {
+        Ok(SharedResponse {
+            status: response.status(),
+            version: response.version(),
+            headers: response.headers().clone(),
+            body: response.bytes()?.to_vec(),
+        })
+    }
+
+    fn to_response(&self) -> Response {
+        rebuild(
+            self.status,
+            self.version,
+            self.headers.clone(),
+            self.body.clone(),
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+struct Flight {
+    /// Only incremented while the flight is registered
+    waiting: AtomicUsize,
+    /// Set once the request completes. None when it failed, in which case
+    /// the waiting requests are sent on their own.
+    outcome: Mutex<Option<Option<SharedResponse>>>,
+    landed: Condvar,
+}
+
+impl Flight {
+    fn land(&self, outcome: Option<SharedResponse>) {
+        *self.outcome.lock().unwrap() = Some(outcome);
+        self.landed.notify_all();
+    }
+}
+
+/// The requests in flight by their url
+#[derive(Debug, Default)]
+pub(crate) struct SingleFlight {
+    flights: Mutex<HashMap<String, Arc<Flight>>>,
+}
+
+// Makes sure waiting requests are released even if sending panics
+struct Leader<'a> {
+    single_flight: &'a SingleFlight,
+    key: &'a str,
+    flight: Arc<Flight>,
+    landed: bool,
+}
+
+impl Leader<'_> {
+    /// Unregisters the flight, returning the number of requests waiting for
+    /// it. No request can start waiting afterwards.
+    fn unregister(&self) -> usize {
+        self.single_flight.flights.lock().unwrap().remove(self.key);
+        self.flight.waiting.load(Ordering::SeqCst)
+    }
+
+    fn land(mut self, outcome: Option<SharedResponse>) {
+        self.flight.land(outcome);
+        self.landed = true;
+    }
+}
+
+impl Drop for Leader<'_> {
+    fn drop(&mut self) {
+        if !self.landed {
+            self.unregister();
+            self.flight.land(None);
+        }
+    }
+}
+
+impl SingleFlight {
+    /// Sends the request identified by `key` with `send`, unless an
+    /// identical request is already in flight, in which case its response
+    /// is shared
+    pub fn run<E, F>(&self, key: &str, send: F) -> Result<Response, E>
+    where
+        E: From<reqwest::Error>,
+        F: FnOnce() -> Result<Response, E>,
+    {
+        let (flight, leading) = {
+            let mut flights = self.flights.lock().unwrap();
+            match flights.get(key) {
+                Some(flight) => {
+                    flight.waiting.fetch_add(1, Ordering::SeqCst);
+                    (flight.clone(), false)
+                }
+                None => {
+                    let flight = Arc::<Flight>::default();
+                    flights.insert(String::from(key), flight.clone());
+                    (flight, true)
+                }
+            }
+        };
+
+        if !leading {
+            let mut outcome = flight.outcome.lock().unwrap();
+            while outcome.is_none() {
+                outcome = flight.landed.wait(outcome).unwrap();
+            }
+            if let Some(Some(shared)) = &*outcome {
+                trace!("Sharing the response of an identical request");
+                return Ok(shared.to_response());
+            }
+            drop(outcome);
+            return send();
+        }
+
+        let leader = Leader {
+            single_flight: self,
+            key,
+            flight,
+            landed: false,
+        };
+
+        let result = send();
+        if leader.unregister() == 0 {
+            leader.land(None);
+            return result;
+        }
+
+        match result.and_then(|r| Ok(SharedResponse::read(r)?)) {
+            Ok(shared) => {
+                let response = shared.to_response();
+                leader.land(Some(shared));
+                Ok(response)
+            }
+            Err(e) => {
+                leader.land(None);
+                Err(e)
+            }
+        }
+    }
+}
@@ -0,0 +1,175 @@
+//! Built-in board layouts which can be created in one call with
+//! `Board::create_from_template`.
+#[cfg(feature = "http")]
+use crate::board::Board;
+#[cfg(feature = "http")]
+use crate::client::TrelloClient;
+#[cfg(feature = "http")]
+use crate::label::Label;
+#[cfg(feature = "http")]
+use crate::list::List;
+#[cfg(feature = "http")]
+use crate::loaded::Loaded;
+use crate::trello_error::TrelloError;
+
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum BuiltinTemplate {
+    Kanban,
+    Sprint,
+    Eisenhower,
+    Gtd,
+    WeeklyPlanner,
+}
+
+impl BuiltinTemplate {
+    pub fn all() -> &'static [BuiltinTemplate] {
+        &[
+            BuiltinTemplate::Kanban,
+            BuiltinTemplate::Sprint,
+            BuiltinTemplate::Eisenhower,
+            BuiltinTemplate::Gtd,
+            BuiltinTemplate::WeeklyPlanner,
+        ]
+    }
+
+    /// The identifier of the template, as accepted by `from_str`
+    pub fn name(self) -> &'static str {
+        match self {
+            BuiltinTemplate::Kanban => "kanban",
+            BuiltinTemplate::Sprint => "sprint",
+            BuiltinTemplate::Eisenhower => "eisenhower",
+            BuiltinTemplate::Gtd => "gtd",
+            BuiltinTemplate::WeeklyPlanner => "weekly-planner",
+        }
+    }
+
+    /// The names of the lists, from left to right
+    pub fn lists(self) -> &'static [&'static str] {
+        match self {
+            BuiltinTemplate::Kanban => &["Backlog", "To Do", "Doing", "Review", "Done"],
+            BuiltinTemplate::Sprint => &[
+                "Product Backlog",
+                "Sprint Backlog",
+                "In Progress",
+                "Testing",
+                "Done",
+            ],
+            BuiltinTemplate::Eisenhower => &[
+                "Urgent & Important",
+                "Important, Not Urgent",
+                "Urgent, Not Important",
+                "Neither",
+            ],
+            BuiltinTemplate::Gtd => &[
+                "Inbox",
+                "Next Actions",
+                "Waiting For",
+                "Projects",
+                "Someday / Maybe",
+                "Done",
+            ],
+            BuiltinTemplate::WeeklyPlanner => &[
+                "This Week",
+                "Monday",
+                "Tuesday",
+                "Wednesday",
+                "Thursday",
+                "Friday",
+                "Weekend",
+                "Done",
+            ],
+        }
+    }
+
+    /// The names and colors of the labels
+    pub fn labels(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            BuiltinTemplate::Kanban => &[
+                ("Bug", "red"),
+                ("Feature", "green"),
+                ("Chore", "sky"),
+                ("Blocked", "black"),
+            ],
+            BuiltinTemplate::Sprint => &[
+                ("Story", "green"),
+                ("Bug", "red"),
+                ("Spike", "purple"),
+                ("Tech Debt", "orange"),
+            ],
+            BuiltinTemplate::Eisenhower => &[("Delegate", "blue"), ("Delete", "black")],
+            BuiltinTemplate::Gtd => &[
+                ("@home", "green"),
+                ("@work", "blue"),
+                ("@errands", "yellow"),
+                ("@calls", "purple"),
+            ],
+            BuiltinTemplate::WeeklyPlanner => &[
+                ("Work", "blue"),
+                ("Personal", "green"),
+                ("Health", "red"),
+                ("Errand", "yellow"),
+            ],
+        }
+    }
+}
+
+impl fmt::Display for BuiltinTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for BuiltinTemplate {
+    type Err = TrelloError;
+
+    fn from_str(value: &str) -> Result<BuiltinTemplate, TrelloError> {
+        BuiltinTemplate::all()
+            .iter()
+            .find(|t| t.name().eq_ignore_ascii_case(value.trim()))
+            .copied()
+            .ok_or_else(|| TrelloError::NotFound(format!("board template '{}'", value)))
+    }
+}
+
+#[cfg(feature = "http")]
+impl Board {
+    /// Creates a board with the lists and labels of the given template. The
+    /// default lists and labels of Trello are not created. The returned board
+    /// includes its (empty) lists.
+    pub fn create_from_template(
+        client: &TrelloClient,
+        name: &str,
+        template: BuiltinTemplate,
+    ) -> Result<Board, TrelloError> {
+        let url = client.config.get_trello_url("/1/boards/", &[])?;
+        let params = [
+            ("name", name),
+            ("defaultLists", "false"),
+            ("defaultLabels", "false"),
+        ];
+        let mut board: Board = client.send(client.post(url).form(&params))?.json()?;
+
+        let mut lists = vec![];
+        for list_name in template.lists() {
+            let url = client.config.get_trello_url("/1/lists/", &[])?;
+            let params = [
+                ("name", *list_name),
+                ("idBoard", &board.id),
+                ("pos", "bottom"),
+            ];
+            let mut list: List = client.send(client.post(url).form(&params))?.json()?;
+            list.cards = Loaded::Fetched(vec![]);
+            lists.push(list);
+        }
+
+        for (label_name, color) in template.labels() {
+            Label::create(client, &board.id, label_name, color)?;
+        }
+
+        board.lists = Loaded::Fetched(lists);
+        Ok(board)
+    }
+}
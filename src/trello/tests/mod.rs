@@ -1,13 +1,64 @@
+mod test_analytics;
+#[cfg(feature = "wasm")]
+mod test_async_client;
 mod test_attachment;
+mod test_audit;
+mod test_audit_log;
 mod test_board;
+mod test_cancel;
 mod test_card;
+mod test_card_history;
+mod test_checklist;
+mod test_client;
+mod test_config;
+mod test_context;
+#[cfg(feature = "human-dates")]
+mod test_dates;
+mod test_dependencies;
+mod test_diff;
+mod test_focus;
 mod test_formatting;
+mod test_git;
+#[cfg(feature = "github")]
+mod test_github;
+mod test_history;
+mod test_ids;
+mod test_import;
+mod test_index;
+mod test_jira;
 mod test_label;
+mod test_labels;
+mod test_lint;
 mod test_list;
+mod test_lists;
+mod test_loaded;
+mod test_member;
+#[cfg(feature = "metrics")]
+mod test_metrics;
+#[cfg(feature = "notify")]
+mod test_notify;
+mod test_ops;
+mod test_parse;
+mod test_pick;
+mod test_policy;
+mod test_progress;
+mod test_redact;
+mod test_references;
+#[cfg(feature = "report")]
+mod test_report;
+mod test_reports;
 mod test_search;
+mod test_single_flight;
+mod test_taskwarrior;
+mod test_templates;
+#[cfg(feature = "tui")]
+mod test_tui;
+mod test_views;
+mod test_watch;
+mod test_webhook;
+mod test_write_queue;
 
 use super::*;
-use mockito;
 use serde_json::json;
 use std::io::Write;
 use tempfile::NamedTempFile;
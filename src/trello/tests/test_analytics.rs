@@ -0,0 +1,85 @@
+use super::*;
+
+use analytics::blocked_cards;
+use colored::*;
+
+fn card(id: &str, name: &str, depends: &str) -> Card {
+    Card::new(id, name, &format!("depends: {}", depends), None, "", None)
+}
+
+#[test]
+fn test_blocked_cards() {
+    let board = Board::new(
+        "B",
+        "Release",
+        Some(vec![
+            List::new(
+                "L1",
+                "Todo",
+                Some(vec![
+                    card("SHIP", "Ship", "BUILD, DESIGN"),
+                    card("BUILD", "Build", "DESIGN"),
+                    card("DOCS", "Docs", "DESIGN"),
+                ]),
+            ),
+            List::new(
+                "L2",
+                "Doing",
+                Some(vec![Card::new("DESIGN", "Design", "", None, "", None)]),
+            ),
+            List::new("L3", "Done", Some(vec![card("OLD", "Old", "SHIP")])),
+        ]),
+        "",
+    );
+
+    let report = blocked_cards(&board);
+    assert!(!report.has_cycles());
+    assert_eq!(
+        report
+            .blocked
+            .iter()
+            .map(|b| b.card.card.id.as_str())
+            .collect::<Vec<&str>>(),
+        vec!["SHIP", "BUILD", "DOCS"]
+    );
+    assert_eq!(report.blocked[0].waiting_on.len(), 2);
+    assert_eq!(
+        report.simple_render(),
+        "Release: 3 blocked cards, 0 circular dependencies"
+    );
+
+    let rendered = report.render();
+    assert!(rendered.starts_with(&"Release".bold().to_string()));
+    assert!(rendered.ends_with(&format!(
+        "* Docs {} waiting on Design (Doing)",
+        "(Todo)".dimmed()
+    )));
+}
+
+#[test]
+fn test_circular_dependencies() {
+    let board = Board::new(
+        "B",
+        "Loop",
+        Some(vec![List::new(
+            "L1",
+            "Todo",
+            Some(vec![
+                card("A", "Egg", "B"),
+                card("B", "Chicken", "A"),
+                card("C", "Self", "C"),
+            ]),
+        )]),
+        "",
+    );
+
+    let report = blocked_cards(&board);
+    assert_eq!(report.cycles.len(), 2);
+
+    let rendered = report.render();
+    assert!(rendered.contains(&"Error: circular dependency Self -> Self".red().to_string()));
+    assert!(
+        rendered.contains("circular dependency Egg -> Chicken -> Egg")
+            || rendered.contains("circular dependency Chicken -> Egg -> Chicken")
+    );
+}
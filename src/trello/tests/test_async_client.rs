@@ -0,0 +1,95 @@
+use super::*;
+
+use chrono::{TimeZone, Utc};
+
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    tokio::runtime::Builder::new()
+        .basic_scheduler()
+        .enable_all()
+        .build()
+        .unwrap()
+        .block_on(future)
+}
+
+#[test]
+fn test_get_boards() -> Result<()> {
+    let _m = mockito::mock(
+        "GET",
+        "/1/members/me/boards/?key=ASYNC-KEY&token=ASYNC-TOKEN&filter=open&fields=id%2Cname%2Cclosed%2Curl%2Csubscribed",
+    )
+    .with_status(200)
+    .with_body(json!([{"name": "TODO", "id": "abc-def", "closed": false, "url": "bit.ly/1"}]).to_string())
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "ASYNC-TOKEN", "ASYNC-KEY");
+    let client = AsyncTrelloClient::new(config);
+
+    let result = block_on(client.get_boards())?;
+    assert_eq!(
+        result,
+        vec![Board::new("abc-def", "TODO", None, "bit.ly/1")]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_retrieve_nested() -> Result<()> {
+    let _m = mockito::mock(
+        "GET",
+        "/1/boards/ASYNC-BOARD/lists?key=ASYNC-KEY&token=ASYNC-TOKEN&fields=id%2Cname%2Cclosed%2CsoftLimit&cards=open",
+    )
+    .with_status(200)
+    .with_body(
+        json!([{
+            "id": "ASYNC-LIST",
+            "name": "Todo",
+            "closed": false,
+            "cards": [{"id": "ASYNC-CARD", "name": "Laundry", "desc": "", "closed": false, "url": ""}],
+        }])
+        .to_string(),
+    )
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "ASYNC-TOKEN", "ASYNC-KEY");
+    let client = AsyncTrelloClient::new(config);
+
+    let mut board = Board::new("ASYNC-BOARD", "Chores", None, "");
+    block_on(client.retrieve_nested(&mut board))?;
+
+    let lists = board.lists.get().unwrap();
+    assert_eq!(lists.len(), 1);
+    assert_eq!(lists[0].cards.as_slice()[0].name, "Laundry");
+    Ok(())
+}
+
+#[test]
+fn test_patch_card_conflict() {
+    let _m1 = mockito::mock(
+        "GET",
+        "/1/cards/ASYNC-CONFLICT?key=ASYNC-KEY&token=ASYNC-TOKEN&fields=dateLastActivity",
+    )
+    .with_status(200)
+    .with_body(json!({"dateLastActivity": "2020-06-29T06:06:27.000Z"}).to_string())
+    .create();
+
+    let _m2 = mockito::mock(
+        "PUT",
+        "/1/cards/ASYNC-CONFLICT/?key=ASYNC-KEY&token=ASYNC-TOKEN",
+    )
+    .expect(0)
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "ASYNC-TOKEN", "ASYNC-KEY");
+    let client = AsyncTrelloClient::new(config);
+
+    let patch = CardPatch::new()
+        .name("Laundry")
+        .if_last_activity(Some(Utc.ymd(2020, 6, 28).and_hms(6, 6, 27)));
+    let result = block_on(client.patch_card("ASYNC-CONFLICT", &patch));
+
+    match result {
+        Err(TrelloError::Conflict { .. }) => (),
+        other => panic!("Expected a conflict, got {:?}", other),
+    }
+    _m2.assert();
+}
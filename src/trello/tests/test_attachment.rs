@@ -1,10 +1,13 @@
 use super::*;
 
+use chrono::{TimeZone, Utc};
+use colored::*;
+
 #[test]
 fn test_get_all() -> Result<()> {
     let _m = mockito::mock(
         "GET",
-        "/1/cards/FOO-CARD/attachments?key=sekret&token=my-token&fields=id%2Cname%2Curl",
+        "/1/cards/FOO-CARD/attachments?key=sekret&token=my-token&fields=id%2Cname%2Curl%2Cbytes%2Cdate%2CmimeType%2CisUpload%2Cpreviews",
     )
     .with_status(200)
     .with_body(
@@ -12,6 +15,18 @@ fn test_get_all() -> Result<()> {
             "name": "IMG_2000.png",
             "id": "0012310",
             "url": "https://example.com/1/12/IMG_2000.png",
+            "bytes": 2048,
+            "date": "2020-06-28T06:06:27.000Z",
+            "mimeType": "image/png",
+            "isUpload": true,
+            "previews": [{
+                "id": "preview-1",
+                "url": "https://example.com/1/12/previews/IMG_2000.png",
+                "width": 70,
+                "height": 50,
+                "bytes": 512,
+                "scaled": true,
+            }],
         }])
         .to_string(),
     )
@@ -26,6 +41,20 @@ fn test_get_all() -> Result<()> {
         id: String::from("0012310"),
         name: String::from("IMG_2000.png"),
         url: String::from("https://example.com/1/12/IMG_2000.png"),
+        bytes: Some(2048),
+        date: Some(Utc.ymd(2020, 6, 28).and_hms(6, 6, 27)),
+        mime_type: Some(String::from("image/png")),
+        is_upload: true,
+        previews: vec![Preview {
+            id: String::from("preview-1"),
+            url: String::from("https://example.com/1/12/previews/IMG_2000.png"),
+            width: 70,
+            height: 50,
+            bytes: 512,
+            scaled: true,
+        }],
+        #[cfg(feature = "extra-fields")]
+        extra: serde_json::Map::new(),
     }];
 
     assert_eq!(result, expected);
@@ -59,12 +88,161 @@ fn test_apply() -> Result<()> {
 
     assert_eq!(
         result,
-        Attachment {
-            id: String::from("my-attachment"),
-            name: String::from("My Attachment"),
-            url: String::from("https://some-example.com/attachment.txt"),
-        }
+        Attachment::new(
+            "my-attachment",
+            "My Attachment",
+            "https://some-example.com/attachment.txt"
+        )
     );
 
     Ok(())
 }
+
+#[test]
+fn test_apply_reader() -> Result<()> {
+    let _m = mockito::mock("POST", "/1/cards/CARD-23/attachments?key=KEY&token=TOKEN")
+        .match_body(mockito::Matcher::Regex(
+            "filename=\"notes.txt\"\r\nContent-Type: text/markdown".to_string(),
+        ))
+        .with_status(200)
+        .with_body(
+            json!({
+                "id": "my-attachment",
+                "name": "notes.txt",
+                "url": "https://some-example.com/notes.txt",
+            })
+            .to_string(),
+        )
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "TOKEN", "KEY");
+    let client = TrelloClient::new(config);
+
+    let progress = std::sync::Arc::new(std::sync::Mutex::new(vec![]));
+    let progress_clone = progress.clone();
+
+    let data = "some streamed data".as_bytes();
+    let options = UploadOptions {
+        length: Some(data.len() as u64),
+        mime_type: Some(String::from("text/markdown")),
+        progress: Some(Box::new(move |sent, total| {
+            progress_clone.lock().unwrap().push((sent, total))
+        })),
+    };
+
+    let result = Attachment::apply_reader(&client, "CARD-23", "notes.txt", data, options)?;
+
+    assert_eq!(result.name, "notes.txt");
+    assert_eq!(progress.lock().unwrap().last(), Some(&(18, Some(18))));
+
+    Ok(())
+}
+
+#[test]
+fn test_is_text() {
+    let mut attachment = Attachment::new("1", "notes.MD", "");
+    assert!(attachment.is_text());
+
+    attachment.mime_type = Some(String::from("text/plain"));
+    attachment.name = String::from("notes");
+    assert!(attachment.is_text());
+
+    attachment.mime_type = Some(String::from("application/pdf"));
+    attachment.name = String::from("contract.txt");
+    assert!(!attachment.is_text());
+}
+
+#[test]
+fn test_fetch_text() -> Result<()> {
+    let _m = mockito::mock("GET", "/1/cards/FOO-CARD/attachments/1/download/notes.md")
+        .match_header(
+            "authorization",
+            "OAuth oauth_consumer_key=\"sekret\", oauth_token=\"my-token\"",
+        )
+        .with_status(200)
+        .with_body("# Notes\nSign the contract")
+        .create();
+
+    let _large = mockito::mock("GET", "/1/cards/FOO-CARD/attachments/2/download/large.md")
+        .with_status(200)
+        .with_body("x".repeat(MAX_TEXT_BYTES as usize + 1))
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "my-token", "sekret");
+    let client = TrelloClient::new(config);
+
+    // the size is not always given by Trello
+    let mut large = Attachment::new(
+        "2",
+        "large.md",
+        &format!(
+            "{}/1/cards/FOO-CARD/attachments/2/download/large.md",
+            mockito::server_url()
+        ),
+    );
+    large.is_upload = true;
+    assert_eq!(large.fetch_text(&client)?, None);
+
+    let mut attachment = Attachment::new(
+        "1",
+        "notes.md",
+        &format!(
+            "{}/1/cards/FOO-CARD/attachments/1/download/notes.md",
+            mockito::server_url()
+        ),
+    );
+    attachment.is_upload = true;
+
+    assert_eq!(
+        attachment.fetch_text(&client)?.as_deref(),
+        Some("# Notes\nSign the contract")
+    );
+
+    // links and large uploads are not downloaded
+    attachment.bytes = Some(MAX_TEXT_BYTES + 1);
+    assert_eq!(attachment.fetch_text(&client)?, None);
+    attachment.bytes = None;
+    attachment.is_upload = false;
+    assert_eq!(attachment.fetch_text(&client)?, None);
+    Ok(())
+}
+
+#[test]
+fn test_fetch_text_elsewhere() {
+    let config = ClientConfig::new(&mockito::server_url(), "my-token", "sekret");
+    let client = TrelloClient::new(config);
+
+    // the credentials must not be sent to other sites, nor in cleartext
+    for url in &[
+        "https://example.com/notes.md",
+        "https://trello-attachments.s3.amazonaws.com/1/notes.md",
+        "https://attachments.trello.com/1/notes.md",
+        "http://trello.com/1/cards/FOO-CARD/attachments/1/download/notes.md",
+    ] {
+        let mut attachment = Attachment::new("1", "notes.md", url);
+        attachment.is_upload = true;
+
+        match attachment.fetch_text(&client) {
+            Err(TrelloError::UnsupportedUrl(u)) => assert_eq!(&u, url),
+            other => panic!("Unexpected result for {}: {:?}", url, other),
+        }
+    }
+}
+
+#[test]
+fn test_render() {
+    let mut attachment = Attachment::new("1", "report.pdf", "https://example.com/report.pdf");
+    attachment.is_upload = true;
+    attachment.bytes = Some(300);
+    attachment.mime_type = Some(String::from("application/pdf"));
+
+    let expected = format!(
+        "report.pdf\n----------\nhttps://example.com/report.pdf\n{}",
+        "application/pdf, 300 B".dimmed()
+    );
+    assert_eq!(attachment.render(), expected);
+    assert_eq!(
+        attachment.simple_render(),
+        format!("report.pdf {}", "(application/pdf, 300 B)".dimmed())
+    );
+}
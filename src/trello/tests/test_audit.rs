@@ -0,0 +1,231 @@
+use super::*;
+
+use audit::{AuditTarget, BoardPermissions, PermissionsReport};
+use chrono::{FixedOffset, TimeZone, Utc};
+use colored::*;
+
+fn memberships() -> String {
+    json!([
+        {
+            "id": "MS1",
+            "idMember": "M1",
+            "memberType": "admin",
+            "member": {"id": "M1", "fullName": "Ratchet", "username": "ratchet"}
+        },
+        {
+            "id": "MS2",
+            "idMember": "M2",
+            "memberType": "normal",
+            "member": {"id": "M2", "fullName": "Clank", "username": "clank"}
+        },
+        {
+            "id": "MS3",
+            "idMember": "M3",
+            "memberType": "observer",
+            "member": {"id": "M3", "fullName": "Qwark", "username": "qwark"}
+        }
+    ])
+    .to_string()
+}
+
+#[test]
+fn test_permissions_for_organization() -> Result<()> {
+    let _boards = mockito::mock(
+        "GET",
+        "/1/organizations/some-org/boards?key=some-key&token=some-token&fields=id%2Cname%2Cprefs",
+    )
+    .with_status(200)
+    .with_body(
+        json!([
+            {"id": "B1", "name": "Roadmap", "prefs": {"permissionLevel": "public", "voting": "disabled"}},
+            {"id": "B2", "name": "Secrets", "prefs": {"permissionLevel": "private"}}
+        ])
+        .to_string(),
+    )
+    .create();
+    let _b1 = mockito::mock(
+        "GET",
+        "/1/boards/B1/memberships?key=some-key&token=some-token&member=true&member_fields=fullName%2Cusername",
+    )
+    .with_status(200)
+    .with_body(memberships())
+    .create();
+    let _b2 = mockito::mock(
+        "GET",
+        "/1/boards/B2/memberships?key=some-key&token=some-token&member=true&member_fields=fullName%2Cusername",
+    )
+    .with_status(200)
+    .with_body("[]")
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let report = audit::permissions(
+        &client,
+        &AuditTarget::Organization(String::from("some-org")),
+    )?;
+
+    assert_eq!(
+        report.boards,
+        vec![
+            BoardPermissions {
+                board_id: BoardId::new("B1"),
+                name: String::from("Roadmap"),
+                visibility: String::from("public"),
+                admins: vec![String::from("ratchet")],
+                observers: vec![String::from("qwark")],
+            },
+            BoardPermissions {
+                board_id: BoardId::new("B2"),
+                name: String::from("Secrets"),
+                visibility: String::from("private"),
+                admins: vec![],
+                observers: vec![],
+            },
+        ]
+    );
+    assert_eq!(report.exposed().len(), 1);
+    assert_eq!(report.simple_render(), "2 boards, 1 public or observed");
+    Ok(())
+}
+
+#[test]
+fn test_permissions_for_boards() -> Result<()> {
+    let _board = mockito::mock(
+        "GET",
+        "/1/boards/AUDIT-BOARD?key=some-key&token=some-token&fields=id%2Cname%2Cprefs",
+    )
+    .with_status(200)
+    .with_body(
+        json!({"id": "AUDIT-BOARD", "name": "Team", "prefs": {"permissionLevel": "org"}})
+            .to_string(),
+    )
+    .create();
+    let _memberships = mockito::mock(
+        "GET",
+        "/1/boards/AUDIT-BOARD/memberships?key=some-key&token=some-token&member=true&member_fields=fullName%2Cusername",
+    )
+    .with_status(200)
+    .with_body(memberships())
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let target = AuditTarget::Boards(vec![String::from("AUDIT-BOARD")]);
+    let report = audit::permissions(&client, &target)?;
+
+    assert_eq!(report.boards.len(), 1);
+    assert_eq!(report.boards[0].visibility, "org");
+    assert!(report.boards[0].is_exposed());
+    assert!(!report.boards[0].is_public());
+    Ok(())
+}
+
+#[test]
+fn test_render_permissions() {
+    let report = PermissionsReport {
+        boards: vec![
+            BoardPermissions {
+                board_id: BoardId::new("B1"),
+                name: String::from("Roadmap"),
+                visibility: String::from("public"),
+                admins: vec![String::from("ratchet"), String::from("clank")],
+                observers: vec![],
+            },
+            BoardPermissions {
+                board_id: BoardId::new("B2"),
+                name: String::from("Secrets"),
+                visibility: String::from("private"),
+                admins: vec![String::from("ratchet")],
+                observers: vec![],
+            },
+        ],
+    };
+
+    let expected = [
+        "Board    Visibility  Admins          Observers"
+            .bold()
+            .to_string(),
+        "Roadmap  public      ratchet, clank".yellow().to_string(),
+        String::from("Secrets  private     ratchet"),
+    ]
+    .join("\n");
+    assert_eq!(report.render(), expected);
+}
+
+fn mock_org_boards() -> mockito::Mock {
+    mockito::mock(
+        "GET",
+        "/1/organizations/stale-org/boards?key=some-key&token=some-token&filter=open&fields=id%2Cname%2Curl%2CdateLastActivity",
+    )
+    .with_status(200)
+    .with_body(
+        json!([
+            {"id": "B1", "name": "Active", "url": "", "dateLastActivity": "2020-06-27T00:00:00.000Z"},
+            {"id": "B2", "name": "Dusty", "url": "", "dateLastActivity": "2020-03-01T00:00:00.000Z"},
+            {"id": "B3", "name": "Empty", "url": "", "dateLastActivity": null},
+            {"id": "B4", "name": "Old", "url": "", "dateLastActivity": "2019-01-01T00:00:00.000Z"}
+        ])
+        .to_string(),
+    )
+    .create()
+}
+
+#[test]
+fn test_stale_boards() -> Result<()> {
+    let _boards = mock_org_boards();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let now = Utc.ymd(2020, 6, 28).and_hms(0, 0, 0);
+    let stale = audit::stale_boards_at(&client, "stale-org", 90, now)?;
+
+    let names = stale.iter().map(|b| b.name.as_str()).collect::<Vec<&str>>();
+    assert_eq!(names, vec!["Empty", "Old", "Dusty"]);
+    assert_eq!(stale[2].idle_days(now), Some(119));
+
+    let options = RenderOptions {
+        timezone: Some(FixedOffset::east(0)),
+        now: Some(now),
+        ..RenderOptions::default()
+    };
+    assert_eq!(
+        stale[2].render_with(&options),
+        format!(
+            "Dusty {}",
+            "(last active 2020-03-01, 119 days ago)".dimmed()
+        )
+    );
+    assert_eq!(
+        stale[0].render_with(&options),
+        format!("Empty {}", "(never active)".dimmed())
+    );
+    Ok(())
+}
+
+#[test]
+fn test_close_boards() -> Result<()> {
+    let _boards = mock_org_boards();
+    let close = mockito::mock("PUT", "/1/boards/B2/?key=some-key&token=some-token")
+        .match_body("closed=true")
+        .with_status(200)
+        .with_body(json!({"id": "B2", "name": "Dusty", "closed": true, "url": ""}).to_string())
+        .expect(1)
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let now = Utc.ymd(2020, 6, 28).and_hms(0, 0, 0);
+    let stale = audit::stale_boards_at(&client, "stale-org", 90, now)?;
+    let dusty = &stale[2..];
+
+    assert_eq!(audit::close_boards(&client, dusty, true)?, 1);
+    assert_eq!(audit::close_boards(&client, dusty, false)?, 1);
+
+    close.assert();
+    Ok(())
+}
@@ -0,0 +1,161 @@
+use super::*;
+
+#[test]
+fn test_update_is_logged() -> Result<()> {
+    let file = NamedTempFile::new()?;
+
+    let _before = mockito::mock(
+        "GET",
+        "/1/cards/AUDIT-CARD/?key=audit-key&token=audit-token&fields=id%2Cname%2Cclosed",
+    )
+    .with_status(200)
+    .with_body(json!({"id": "AUDIT-CARD", "name": "Old name", "closed": false}).to_string())
+    .create();
+
+    let _update = mockito::mock(
+        "PUT",
+        "/1/cards/AUDIT-CARD/?key=audit-key&token=audit-token",
+    )
+    .match_body("name=New+name&closed=true")
+    .with_status(200)
+    .with_body(
+        json!({
+            "id": "AUDIT-CARD",
+            "name": "New name",
+            "desc": "",
+            "closed": true,
+            "url": "",
+        })
+        .to_string(),
+    )
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "audit-token", "audit-key");
+    let client = TrelloClient::builder(config)
+        .audit_log(AuditLog::new(file.path()).actor("ci-bot"))
+        .build()?;
+
+    let card = Card::patch(
+        &client,
+        "AUDIT-CARD",
+        &CardPatch::new().name("New name").closed(true),
+    )?;
+    assert_eq!(card.name, "New name");
+
+    let entries = client.audit_log().unwrap().entries()?;
+    assert_eq!(entries.len(), 1);
+
+    let entry = &entries[0];
+    assert_eq!(entry.actor, "ci-bot");
+    assert_eq!(entry.method, "PUT");
+    assert_eq!(entry.endpoint, "/1/cards/AUDIT-CARD/");
+    assert_eq!(entry.status, 200);
+    assert_eq!(
+        entry.before,
+        Some(json!({"id": "AUDIT-CARD", "name": "Old name", "closed": false}))
+    );
+    assert_eq!(
+        entry.after,
+        Some(json!({"id": "AUDIT-CARD", "name": "New name", "closed": true}))
+    );
+    Ok(())
+}
+
+#[test]
+fn test_reads_are_not_logged() -> Result<()> {
+    let file = NamedTempFile::new()?;
+
+    let _m = mockito::mock("GET", "/1/cards/AUDIT-READ?key=audit-key&token=audit-token")
+        .with_status(200)
+        .with_body(
+            json!({"id": "AUDIT-READ", "name": "", "desc": "", "closed": false, "url": ""})
+                .to_string(),
+        )
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "audit-token", "audit-key");
+    let client = TrelloClient::builder(config)
+        .audit_log(AuditLog::new(file.path()))
+        .build()?;
+
+    Card::get(&client, "AUDIT-READ")?;
+
+    assert_eq!(client.audit_log().unwrap().entries()?, vec![]);
+    Ok(())
+}
+
+#[test]
+fn test_failed_update_is_logged() -> Result<()> {
+    let file = NamedTempFile::new()?;
+
+    let _before = mockito::mock(
+        "GET",
+        "/1/cards/AUDIT-MISSING/?key=audit-fail-key&token=audit-fail-token&fields=id%2Cname",
+    )
+    .with_status(404)
+    .create();
+    let _update = mockito::mock(
+        "PUT",
+        "/1/cards/AUDIT-MISSING/?key=audit-fail-key&token=audit-fail-token",
+    )
+    .with_status(404)
+    .with_body("card not found")
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "audit-fail-token", "audit-fail-key");
+    let client = TrelloClient::builder(config)
+        .audit_log(AuditLog::new(file.path()))
+        .build()?;
+
+    match Card::patch(&client, "AUDIT-MISSING", &CardPatch::new().name("New name")) {
+        Err(TrelloError::Reqwest(e)) => {
+            let url = e.inner().url().expect("Expected the url of the request");
+            assert_eq!(url.path(), "/1/cards/AUDIT-MISSING/");
+        }
+        other => panic!("Unexpected result: {:?}", other),
+    }
+
+    let entries = client.audit_log().unwrap().entries()?;
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].status, 404);
+    assert_eq!(entries[0].after, None);
+    Ok(())
+}
+
+#[test]
+fn test_update_transfer_stats() -> Result<()> {
+    let file = NamedTempFile::new()?;
+
+    let body = json!({"id": "AUDIT-STATS", "name": "", "desc": "", "closed": false, "url": ""})
+        .to_string();
+    let _before = mockito::mock(
+        "GET",
+        "/1/cards/AUDIT-STATS/?key=audit-stats-key&token=audit-stats-token&fields=id%2Cname",
+    )
+    .with_status(200)
+    .with_body(&body)
+    .create();
+    let _update = mockito::mock(
+        "PUT",
+        "/1/cards/AUDIT-STATS/?key=audit-stats-key&token=audit-stats-token",
+    )
+    .with_status(200)
+    .with_body(&body)
+    .create();
+
+    let config = ClientConfig::new(
+        &mockito::server_url(),
+        "audit-stats-token",
+        "audit-stats-key",
+    );
+    let client = TrelloClient::builder(config)
+        .audit_log(AuditLog::new(file.path()))
+        .build()?;
+
+    Card::patch(&client, "AUDIT-STATS", &CardPatch::new().name(""))?;
+
+    let stats = client.transfer_stats();
+    assert_eq!(stats.responses, 1);
+    assert_eq!(stats.decoded, body.len() as u64);
+    Ok(())
+}
@@ -1,16 +1,30 @@
 use super::*;
 
+use chrono::prelude::*;
 use colored::*;
+use diff::{BoardDiff, Change};
+use regex::Regex;
 
 #[test]
 fn test_new() {
     let board = Board::new("888", "some board", Some(vec![]), "https://trello.com/09");
     let expected = Board {
-        id: String::from("888"),
+        id: BoardId::new("888"),
         name: String::from("some board"),
-        lists: Some(vec![]),
+        lists: Loaded::Fetched(vec![]),
         closed: false,
+        subscribed: false,
         url: String::from("https://trello.com/09"),
+        labels: None,
+        members: None,
+        checklists: None,
+        custom_fields: None,
+        desc: None,
+        prefs: None,
+        date_last_activity: None,
+        id_organization: None,
+        #[cfg(feature = "extra-fields")]
+        extra: serde_json::Map::new(),
     };
     assert_eq!(board, expected);
 }
@@ -164,7 +178,7 @@ fn test_update() -> Result<()> {
 fn test_get_all() -> Result<()> {
     let _m = mockito::mock(
         "GET",
-        "/1/members/me/boards/?key=some-key&token=some-secret-token&filter=open&fields=id%2Cname%2Cclosed%2Curl",
+        "/1/members/me/boards/?key=some-key&token=some-secret-token&filter=open&fields=id%2Cname%2Cclosed%2Curl%2Csubscribed",
     )
     .with_status(200)
     .with_body(
@@ -193,7 +207,7 @@ fn test_get_all() -> Result<()> {
 fn test_get() -> Result<()> {
     let _m = mockito::mock(
         "GET",
-        "/1/boards/some-board-id?key=KEY&token=TOKEN&fields=id%2Cname%2Cclosed%2Curl",
+        "/1/boards/some-board-id?key=KEY&token=TOKEN&fields=id%2Cname%2Cclosed%2Curl%2Csubscribed",
     )
     .with_status(200)
     .with_body(
@@ -221,3 +235,876 @@ fn test_get() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_get_with_response() -> Result<()> {
+    let body = json!({
+        "name": "Debugged Board",
+        "id": "debugged-board-id",
+        "closed": false,
+        "url": "",
+        "unexpected": [1, 2, 3],
+    })
+    .to_string();
+    let _m = mockito::mock(
+        "GET",
+        "/1/boards/debugged-board-id?key=KEY&token=TOKEN&fields=id%2Cname%2Cclosed%2Curl%2Csubscribed",
+    )
+    .with_status(200)
+    .with_body(&body)
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "TOKEN", "KEY");
+    let client = TrelloClient::new(config);
+
+    let response = Board::get_with_response(&client, "debugged-board-id")?;
+    assert_eq!(response.status, 200);
+    assert_eq!(response.body, body);
+    assert_eq!(response.value.name, "Debugged Board");
+
+    Ok(())
+}
+
+#[test]
+fn test_get_all_with_fields() -> Result<()> {
+    let _m = mockito::mock(
+        "GET",
+        "/1/members/me/boards/?key=some-key&token=some-token&filter=open&fields=id%2Cname%2Cclosed%2Curl%2Csubscribed%2Cdesc%2CdateLastActivity",
+    )
+    .with_status(200)
+    .with_body(
+        json!([
+            {
+                "name": "TODO",
+                "id": "abc-def",
+                "closed": false,
+                "url": "bit.ly/1",
+                "desc": "Things to do",
+                "dateLastActivity": "2020-10-12T09:30:00.000Z",
+            },
+        ])
+        .to_string(),
+    )
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let result =
+        Board::get_all_with_fields(&client, &[BoardField::Desc, BoardField::DateLastActivity])?;
+    let mut expected = Board::new("abc-def", "TODO", None, "bit.ly/1");
+    expected.desc = Some(String::from("Things to do"));
+    expected.date_last_activity = Some(Utc.ymd(2020, 10, 12).and_hms(9, 30, 0));
+
+    assert_eq!(result, vec![expected]);
+    Ok(())
+}
+
+#[test]
+fn test_get_with_fields() -> Result<()> {
+    let _m = mockito::mock(
+        "GET",
+        "/1/boards/some-board-id?key=KEY&token=TOKEN&fields=id%2Cname%2Cclosed%2Curl%2Csubscribed%2Cprefs%2CidOrganization",
+    )
+    .with_status(200)
+    .with_body(
+        json!({
+            "name": "My Favourite Board",
+            "id": "some-board-id",
+            "closed": false,
+            "url": "https://bit.ly/12",
+            "prefs": {"permissionLevel": "private"},
+            "idOrganization": "some-org-id",
+        })
+        .to_string(),
+    )
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "TOKEN", "KEY");
+    let client = TrelloClient::new(config);
+
+    let result = Board::get_with_fields(
+        &client,
+        "some-board-id",
+        &[BoardField::Prefs, BoardField::IdOrganization],
+    )?;
+
+    assert_eq!(result.desc, None);
+    assert_eq!(result.id_organization, Some(String::from("some-org-id")));
+    assert_eq!(
+        result.prefs.unwrap().get("permissionLevel"),
+        Some(&json!("private"))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_set_subscribed() -> Result<()> {
+    let _m = mockito::mock(
+        "PUT",
+        "/1/boards/MY-BOARD-ID/?key=some-key&token=some-token",
+    )
+    .match_body("subscribed=false")
+    .with_status(200)
+    .with_body(
+        json!({
+            "name": "TODO",
+            "id": "MY-BOARD-ID",
+            "closed": false,
+            "url": "",
+            "subscribed": false,
+        })
+        .to_string(),
+    )
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let result = Board::set_subscribed(&client, "MY-BOARD-ID", false)?;
+    assert_eq!(result, Board::new("MY-BOARD-ID", "TODO", None, ""));
+    Ok(())
+}
+
+#[test]
+fn test_patch() -> Result<()> {
+    let _m = mockito::mock(
+        "PUT",
+        "/1/boards/MY-BOARD-ID/?key=some-key&token=some-token",
+    )
+    .match_body("name=Groceries")
+    .with_status(200)
+    .with_body(
+        json!({
+            "name": "Groceries",
+            "id": "MY-BOARD-ID",
+            "closed": false,
+            "url": "",
+        })
+        .to_string(),
+    )
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let patch = BoardPatch::new().name("Groceries");
+    let result = Board::patch(&client, "MY-BOARD-ID", &patch)?;
+
+    assert_eq!(result, Board::new("MY-BOARD-ID", "Groceries", None, ""));
+    Ok(())
+}
+
+#[test]
+fn test_from_trello_export() -> Result<()> {
+    let mut file = NamedTempFile::new()?;
+    write!(
+        file,
+        "{}",
+        json!({
+            "id": "EXPORTED",
+            "name": "Knights",
+            "desc": "",
+            "closed": false,
+            "url": "https://trello.com/b/abc/knights",
+            "actions": [],
+            "checklists": [],
+            "labels": [{"id": "L1", "name": "", "color": null, "idBoard": "EXPORTED"}],
+            "lists": [
+                {"id": "SHOVEL", "name": "Shovel", "closed": true, "idBoard": "EXPORTED", "pos": 2048},
+                {"id": "KING", "name": "King", "closed": false, "idBoard": "EXPORTED", "pos": 1024},
+            ],
+            "cards": [
+                {
+                    "id": "RAT", "name": "Propeller Rat", "desc": "", "closed": true,
+                    "url": "", "idList": "KING", "labels": [], "due": null,
+                    "dateLastActivity": "2020-06-28T06:06:27.000Z", "pos": 200,
+                },
+                {
+                    "id": "BOOTS", "name": "Gyro Boots", "desc": "Fly", "closed": false,
+                    "url": "", "idList": "KING",
+                    "labels": [{"id": "L1", "name": "", "color": null, "idBoard": "EXPORTED"}],
+                    "due": null, "dateLastActivity": null, "pos": 100,
+                },
+            ],
+        })
+    )?;
+
+    let board = Board::from_trello_export(file.path())?;
+
+    let boots = Card::new(
+        "BOOTS",
+        "Gyro Boots",
+        "Fly",
+        Some(vec![Label::new("L1", "", "")]),
+        "",
+        None,
+    );
+    let mut rat = Card::new("RAT", "Propeller Rat", "", Some(vec![]), "", None);
+    rat.closed = true;
+    rat.date_last_activity = Some("2020-06-28T06:06:27Z".parse().unwrap());
+    let mut shovel = List::new("SHOVEL", "Shovel", Some(vec![]));
+    shovel.closed = true;
+
+    let expected = Board::new(
+        "EXPORTED",
+        "Knights",
+        Some(vec![
+            List::new("KING", "King", Some(vec![boots, rat])),
+            shovel,
+        ]),
+        "https://trello.com/b/abc/knights",
+    );
+    assert_eq!(board, expected);
+    Ok(())
+}
+
+#[test]
+fn test_apply_import() -> Result<()> {
+    let _board = mockito::mock("POST", "/1/boards/?key=import-key&token=import-token")
+        .match_body("name=Groceries&defaultLists=false")
+        .with_status(200)
+        .with_body(
+            json!({"id": "NEW-BOARD", "name": "Groceries", "closed": false, "url": ""}).to_string(),
+        )
+        .create();
+    let _list = mockito::mock("POST", "/1/lists/?key=import-key&token=import-token")
+        .match_body("name=Dairy&idBoard=NEW-BOARD&pos=bottom")
+        .with_status(200)
+        .with_body(json!({"id": "NEW-LIST", "name": "Dairy", "closed": false}).to_string())
+        .create();
+    let _card = mockito::mock("POST", "/1/cards/?key=import-key&token=import-token")
+        .match_body("name=Milk&desc=Semi+skimmed&idList=NEW-LIST")
+        .with_status(200)
+        .with_body(
+            json!({"id": "NEW-CARD", "name": "Milk", "desc": "Semi skimmed", "closed": false, "url": ""})
+                .to_string(),
+        )
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "import-token", "import-key");
+    let client = TrelloClient::new(config);
+
+    let import = import::from_markdown("# Groceries\n## Dairy\n- Milk\n  - Semi skimmed");
+    let result = Board::apply_import(&client, &import)?;
+
+    let expected = Board::new(
+        "NEW-BOARD",
+        "Groceries",
+        Some(vec![List::new(
+            "NEW-LIST",
+            "Dairy",
+            Some(vec![Card::new(
+                "NEW-CARD",
+                "Milk",
+                "Semi skimmed",
+                None,
+                "",
+                None,
+            )]),
+        )]),
+        "",
+    );
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_render_org() {
+    let mut boots = Card::new(
+        "",
+        "Gyro Boots",
+        "Fly high\n* really high",
+        Some(vec![
+            Label::new("", "Relic", ""),
+            Label::new("", "Super rare", ""),
+        ]),
+        "",
+        Some("2020-06-28T00:00:00Z".parse().unwrap()),
+    );
+    boots.closed = true;
+    let rat = Card::new(
+        "",
+        "Propeller Rat",
+        "",
+        None,
+        "",
+        Some("2020-06-29T06:06:27Z".parse().unwrap()),
+    );
+
+    let board = Board::new(
+        "",
+        "Knights",
+        Some(vec![
+            List::new("", "King", Some(vec![boots, rat])),
+            List::new("", "Shovel", Some(vec![])),
+        ]),
+        "",
+    );
+
+    #[rustfmt::skip]
+    let expected = [
+        "#+TITLE: Knights",
+        "* King",
+        "** DONE Gyro Boots :Relic:Super_rare:",
+        "   SCHEDULED: <2020-06-28 Sun>",
+        "   Fly high",
+        "   * really high",
+        "** TODO Propeller Rat",
+        "   SCHEDULED: <2020-06-29 Mon 06:06>",
+        "* Shovel",
+    ].join("\n");
+
+    assert_eq!(board.render_org(), expected);
+}
+
+fn columns_board() -> Board {
+    let mut boots = Card::new("", "Gyro Boots", "", None, "", None);
+    boots.closed = true;
+
+    Board::new(
+        "",
+        "Knights",
+        Some(vec![
+            List::new(
+                "",
+                "King",
+                Some(vec![
+                    boots,
+                    Card::new("", "Propeller Rat collects the gems", "", None, "", None),
+                ]),
+            ),
+            List::new(
+                "",
+                "Shovel",
+                Some(vec![Card::new("", "Flare Wand", "", None, "", None)]),
+            ),
+        ]),
+        "",
+    )
+}
+
+/// The text without colours, so that the output is the same whether or not
+/// the tests run in a terminal
+fn uncoloured(text: &str) -> String {
+    Regex::new("\x1b\\[[0-9;]*m")
+        .unwrap()
+        .replace_all(text, "")
+        .into_owned()
+}
+
+#[test]
+fn test_render_columns() {
+    let board = columns_board();
+
+    let rule = "─".repeat(24);
+    #[rustfmt::skip]
+    let expected = [
+        " Knights ",
+        "=========",
+        "",
+        &format!("{:24}  Shovel", "King"),
+        &format!("{}  {}", rule, rule),
+        "• Propeller Rat collects  • Flare Wand",
+        "  the gems",
+    ].join("\n");
+
+    assert_eq!(uncoloured(&board.render_columns(80)), expected);
+}
+
+#[test]
+fn test_render_columns_wraps_rows() {
+    let board = columns_board();
+
+    let rule = "─".repeat(24);
+    #[rustfmt::skip]
+    let expected = [
+        " Knights ",
+        "=========",
+        "",
+        "King",
+        &rule,
+        "• Propeller Rat collects",
+        "  the gems",
+        "",
+        "Shovel",
+        &rule,
+        "• Flare Wand",
+    ].join("\n");
+
+    assert_eq!(uncoloured(&board.render_columns(40)), expected);
+}
+
+#[test]
+fn test_render_html() {
+    let mut boots = Card::new("", "Gyro Boots", "", None, "", None);
+    boots.closed = true;
+    let rat = Card::new(
+        "",
+        "Propeller <Rat>",
+        "Collects \"gems\"\n& more",
+        Some(vec![
+            Label::new("", "Relic", "sky"),
+            Label::new("", "Trap", "red\"><script>alert(1)</script>"),
+            Label::new("", "Shade", "green_dark"),
+        ]),
+        "https://trello.com/c/a1B2c3D4",
+        Some("2020-06-29T06:06:27Z".parse().unwrap()),
+    );
+    let mut specter = List::new("", "Specter", Some(vec![]));
+    specter.closed = true;
+
+    let board = Board::new(
+        "",
+        "Knights & Co",
+        Some(vec![
+            List::new("", "King", Some(vec![boots, rat])),
+            List::new("", "Shovel", Some(vec![])),
+            specter,
+        ]),
+        "https://trello.com/b/kn1ghts",
+    );
+
+    let html = board.render_html();
+
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.ends_with("</html>"));
+    assert!(html.contains("<title>Knights &amp; Co</title>"));
+    assert!(html.contains("<h1><a href=\"https://trello.com/b/kn1ghts\">Knights &amp; Co</a></h1>"));
+    assert!(html.contains("<h2>King</h2>"));
+    assert!(html.contains("<h2>Shovel</h2>"));
+    assert!(!html.contains("Specter"));
+    assert!(!html.contains("Gyro Boots"));
+    assert!(html.contains("<span class=\"label\" style=\"background: deepskyblue\">Relic</span>"));
+    assert!(html.contains("<span class=\"label\" style=\"background: gray\">Trap</span>"));
+    assert!(html.contains("<span class=\"label\" style=\"background: green\">Shade</span>"));
+    assert!(!html.contains("<script>"));
+    assert!(html
+        .contains("<h3><a href=\"https://trello.com/c/a1B2c3D4\">Propeller &lt;Rat&gt;</a></h3>"));
+    assert!(html.contains("<div class=\"due\">Due 2020-06-29 06:06</div>"));
+    assert!(html.contains("<p class=\"desc\">Collects &quot;gems&quot;\n&amp; more</p>"));
+}
+
+#[test]
+fn test_render_id_prefixes() {
+    let board = Board::new(
+        "",
+        "Knights",
+        Some(vec![
+            List::new(
+                "",
+                "King",
+                Some(vec![Card::new(
+                    "5f0a1b00",
+                    "Gyro Boots",
+                    "",
+                    None,
+                    "",
+                    None,
+                )]),
+            ),
+            List::new(
+                "",
+                "Shovel",
+                Some(vec![Card::new(
+                    "5f0a1c00",
+                    "Propeller Rat",
+                    "",
+                    None,
+                    "",
+                    None,
+                )]),
+            ),
+        ]),
+        "",
+    );
+
+    assert_eq!(board.card_id_prefix_len(4), 6);
+
+    let options = RenderOptions {
+        id_prefix: Some(4),
+        ..RenderOptions::default()
+    };
+    #[rustfmt::skip]
+    let expected = [
+        " Knights \n=========".bold().to_string(),
+        String::new(),
+        "King\n----".bold().to_string(),
+        format!("* {} Gyro Boots", "5f0a1b".yellow()),
+        String::new(),
+        "Shovel\n------".bold().to_string(),
+        format!("* {} Propeller Rat", "5f0a1c".yellow()),
+    ].join("\n");
+    assert_eq!(board.render_with(&options), expected);
+
+    // rendered by itself, a list only needs its own card ids to be unique
+    let list = &board.lists.get().unwrap()[0];
+    assert!(list
+        .render_with(&options)
+        .ends_with(&format!("* {} Gyro Boots", "5f0a".yellow())));
+}
+
+#[test]
+fn test_get_closed_cards() -> Result<()> {
+    let _m = mockito::mock(
+        "GET",
+        "/1/boards/ARCHIVE-BOARD/cards/closed?key=some-key&token=some-token&fields=id%2Cname%2Cdesc%2Clabels%2Cclosed%2Cdue%2Curl%2Csubscribed%2CdateLastActivity%2Cbadges%2CidMembers%2CidShort",
+    )
+    .with_status(200)
+    .with_body(
+        json!([{"id": "OLD", "name": "Old card", "desc": "", "closed": true, "url": ""}]).to_string(),
+    )
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let result = Board::get_closed_cards(&client, "ARCHIVE-BOARD")?;
+
+    let mut expected = Card::new("OLD", "Old card", "", None, "", None);
+    expected.closed = true;
+    assert_eq!(result, vec![expected]);
+    Ok(())
+}
+
+#[test]
+fn test_get_closed_lists() -> Result<()> {
+    let _m = mockito::mock(
+        "GET",
+        "/1/boards/ARCHIVE-BOARD/lists/closed?key=some-key&token=some-token&fields=id%2Cname%2Cclosed%2CsoftLimit",
+    )
+    .with_status(200)
+    .with_body(json!([{"id": "OLD-LIST", "name": "Old list", "closed": true}]).to_string())
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let result = Board::get_closed_lists(&client, "ARCHIVE-BOARD")?;
+
+    let mut expected = List::new("OLD-LIST", "Old list", None);
+    expected.closed = true;
+    assert_eq!(result, vec![expected]);
+    Ok(())
+}
+
+#[test]
+fn test_restore_card() -> Result<()> {
+    let _closed = mockito::mock(
+        "GET",
+        "/1/boards/RESTORE-BOARD/cards/closed?key=some-key&token=some-token&fields=id%2Cname%2Cdesc%2Clabels%2Cclosed%2Cdue%2Curl%2Csubscribed%2CdateLastActivity%2Cbadges%2CidMembers%2CidShort",
+    )
+    .with_status(200)
+    .with_body(
+        json!([
+            {"id": "RAT", "name": "Propeller Rat", "desc": "", "closed": true, "url": ""},
+            {"id": "BOOTS", "name": "Gyro Boots", "desc": "", "closed": true, "url": ""},
+            {"id": "BOOTS2", "name": "Fur Boots", "desc": "", "closed": true, "url": ""},
+        ])
+        .to_string(),
+    )
+    .create();
+    let _open = mockito::mock("PUT", "/1/cards/RAT?key=some-key&token=some-token")
+        .match_body("closed=false")
+        .with_status(200)
+        .with_body(
+            json!({"id": "RAT", "name": "Propeller Rat", "desc": "", "closed": false, "url": ""})
+                .to_string(),
+        )
+        .create();
+    let _list = mockito::mock(
+        "GET",
+        "/1/cards/RAT/list?key=some-key&token=some-token&fields=id%2Cname%2Cclosed%2CsoftLimit",
+    )
+    .with_status(200)
+    .with_body(json!({"id": "KING", "name": "King", "closed": false}).to_string())
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let (card, list) = Board::restore_card(&client, "RESTORE-BOARD", "propeller")?;
+    assert_eq!(card, Card::new("RAT", "Propeller Rat", "", None, "", None));
+    assert_eq!(list, List::new("KING", "King", None));
+
+    match Board::restore_card(&client, "RESTORE-BOARD", "boots") {
+        Err(TrelloError::Ambiguous(msg)) => assert_eq!(
+            msg,
+            "Archived card 'boots' matches 'Gyro Boots', 'Fur Boots'"
+        ),
+        other => panic!("Unexpected result: {:?}", other),
+    }
+    match Board::find_archived_card(&client, "RESTORE-BOARD", "shovel") {
+        Err(TrelloError::NotFound(_)) => {}
+        other => panic!("Unexpected result: {:?}", other),
+    }
+    Ok(())
+}
+
+#[test]
+fn test_rename_cards() -> Result<()> {
+    let _m = mockito::mock("PUT", "/1/cards/RENAME-1/?key=some-key&token=some-token")
+        .match_body("name=Fix+login")
+        .with_status(200)
+        .with_body(
+            json!({"id": "RENAME-1", "name": "Fix login", "desc": "", "closed": false, "url": ""})
+                .to_string(),
+        )
+        .expect(1)
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let mut board = Board::new(
+        "RENAME-BOARD",
+        "Tickets",
+        Some(vec![List::new(
+            "L",
+            "Todo",
+            Some(vec![
+                Card::new("RENAME-1", "PROJ-12: Fix login", "", None, "", None),
+                Card::new("RENAME-2", "Write docs", "", None, "", None),
+            ]),
+        )]),
+        "",
+    );
+
+    let expected = BoardDiff {
+        name: String::from("Tickets"),
+        lists: vec![],
+        cards: vec![Change::Renamed {
+            from: String::from("PROJ-12: Fix login"),
+            to: String::from("Fix login"),
+        }],
+    };
+
+    let dry_run = board.rename_cards(&client, r"^PROJ-\d+: ", "", true)?;
+    assert_eq!(dry_run, expected);
+    assert_eq!(
+        board.lists.get().unwrap()[0].cards.get().unwrap()[0].name,
+        "PROJ-12: Fix login"
+    );
+
+    let result = board.rename_cards(&client, r"^PROJ-\d+: ", "", false)?;
+    assert_eq!(result, expected);
+    assert_eq!(
+        board.lists.get().unwrap()[0].cards.get().unwrap()[0].name,
+        "Fix login"
+    );
+    _m.assert();
+    Ok(())
+}
+
+#[test]
+fn test_get_starred() -> Result<()> {
+    let _m = mockito::mock(
+        "GET",
+        "/1/members/me/boards/?key=some-key&token=some-token&filter=starred&fields=id%2Cname%2Cclosed%2Curl%2Csubscribed",
+    )
+    .with_status(200)
+    .with_body(json!([{"id": "STAR", "name": "Favourite", "closed": false, "url": ""}]).to_string())
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let result = Board::get_starred(&client)?;
+    assert_eq!(result, vec![Board::new("STAR", "Favourite", None, "")]);
+    Ok(())
+}
+
+#[test]
+fn test_set_starred() -> Result<()> {
+    let _stars = mockito::mock(
+        "GET",
+        "/1/members/me/boardStars?key=some-key&token=some-token",
+    )
+    .with_status(200)
+    .with_body(json!([{"id": "STAR-1", "idBoard": "STARRED-BOARD", "pos": 1}]).to_string())
+    .create();
+    let _add = mockito::mock(
+        "POST",
+        "/1/members/me/boardStars?key=some-key&token=some-token",
+    )
+    .match_body("idBoard=OTHER-BOARD&pos=top")
+    .with_status(200)
+    .with_body(json!({"id": "STAR-2", "idBoard": "OTHER-BOARD", "pos": 0}).to_string())
+    .expect(1)
+    .create();
+    let _remove = mockito::mock(
+        "DELETE",
+        "/1/members/me/boardStars/STAR-1?key=some-key&token=some-token",
+    )
+    .with_status(200)
+    .expect(1)
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    Board::set_starred(&client, "OTHER-BOARD", true)?;
+    Board::set_starred(&client, "STARRED-BOARD", true)?;
+    Board::set_starred(&client, "STARRED-BOARD", false)?;
+    Board::set_starred(&client, "OTHER-BOARD", false)?;
+    _add.assert();
+    _remove.assert();
+    Ok(())
+}
+
+#[test]
+fn test_instance_refresh() -> Result<()> {
+    let _board = mockito::mock(
+        "GET",
+        "/1/boards/INSTANCE-BOARD?key=some-key&token=some-token&fields=id%2Cname%2Cclosed%2Curl%2Csubscribed",
+    )
+    .with_status(200)
+    .with_body(json!({"id": "INSTANCE-BOARD", "name": "Renamed", "closed": false, "url": ""}).to_string())
+    .create();
+    let _lists = mockito::mock(
+        "GET",
+        "/1/boards/INSTANCE-BOARD/lists?key=some-key&token=some-token&fields=id%2Cname%2Cclosed%2CsoftLimit&cards=open",
+    )
+    .with_status(200)
+    .with_body(json!([{"id": "L1", "name": "Todo", "closed": false, "cards": []}]).to_string())
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let mut board = Board::new("INSTANCE-BOARD", "Original", None, "");
+    board.refresh(&client)?;
+
+    assert_eq!(board.name, "Renamed");
+    assert_eq!(
+        board.lists,
+        Loaded::Fetched(vec![List::new("L1", "Todo", Some(vec![]))])
+    );
+    Ok(())
+}
+
+#[test]
+fn test_lazy_lists() -> Result<()> {
+    let _m = mockito::mock(
+        "GET",
+        "/1/boards/LAZY-BOARD/lists?key=some-key&token=some-token&fields=id%2Cname%2Cclosed%2CsoftLimit&cards=open",
+    )
+    .with_status(200)
+    .with_body(json!([{"id": "L1", "name": "Todo", "closed": false, "cards": []}]).to_string())
+    .expect(1)
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let mut board = Board::new("LAZY-BOARD", "Lazy", None, "");
+    assert_eq!(board.lists(&client)?.len(), 1);
+    assert_eq!(board.lists(&client)?[0].name, "Todo");
+    _m.assert();
+    Ok(())
+}
+
+#[test]
+fn test_get_many_nested() -> Result<()> {
+    let mut mocks = vec![];
+    let many = [
+        ("MANY-1", "First"),
+        ("MANY-2", "Second"),
+        ("MANY-4", "Fourth"),
+        ("MANY-5", "Fifth"),
+        ("MANY-6", "Sixth"),
+        ("MANY-7", "Seventh"),
+    ];
+    for (id, name) in &many {
+        mocks.push(
+            mockito::mock(
+                "GET",
+                format!(
+                    "/1/boards/{}?key=some-key&token=some-token&fields=id%2Cname%2Cclosed%2Curl%2Csubscribed",
+                    id
+                )
+                .as_str(),
+            )
+            .with_status(200)
+            .with_body(json!({"id": id, "name": name, "closed": false, "url": ""}).to_string())
+            .create(),
+        );
+        mocks.push(
+            mockito::mock(
+                "GET",
+                format!(
+                    "/1/boards/{}/lists?key=some-key&token=some-token&fields=id%2Cname%2Cclosed%2CsoftLimit&cards=open",
+                    id
+                )
+                .as_str(),
+            )
+            .with_status(200)
+            .with_body(
+                json!([{"id": format!("{}-L", id), "name": "Todo", "closed": false, "cards": []}])
+                    .to_string(),
+            )
+            .create(),
+        );
+    }
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let boards = Board::get_many_nested(&client, &["MANY-2", "MANY-1"])?;
+    assert_eq!(
+        boards.iter().map(|b| b.name.as_str()).collect::<Vec<_>>(),
+        vec!["Second", "First"]
+    );
+    assert_eq!(boards[0].lists.as_slice()[0].id, "MANY-2-L");
+
+    // more boards than are retrieved at the same time
+    let ids = ["MANY-7", "MANY-2", "MANY-6", "MANY-1", "MANY-5", "MANY-4"];
+    let boards = Board::get_many_nested(&client, &ids)?;
+    assert_eq!(
+        boards.iter().map(|b| b.id.as_str()).collect::<Vec<_>>(),
+        ids
+    );
+
+    let missing = mockito::mock(
+        "GET",
+        "/1/boards/MANY-3?key=some-key&token=some-token&fields=id%2Cname%2Cclosed%2Curl%2Csubscribed",
+    )
+    .with_status(404)
+    .create();
+    assert!(Board::get_many_nested(&client, &["MANY-1", "MANY-3"]).is_err());
+    missing.assert();
+    Ok(())
+}
+
+#[test]
+fn test_get_expanded() -> Result<()> {
+    let _m = mockito::mock(
+        "GET",
+        "/1/boards/EXPAND-BOARD?key=some-key&token=some-token&fields=id%2Cname%2Cclosed%2Curl%2Csubscribed&labels=all&members=all",
+    )
+    .with_status(200)
+    .with_body(
+        json!({
+            "id": "EXPAND-BOARD",
+            "name": "Expanded",
+            "closed": false,
+            "url": "",
+            "labels": [{"id": "L1", "name": "Bug", "color": "red"}],
+            "members": [{"id": "M1", "fullName": "Ratchet", "username": "ratchet"}],
+        })
+        .to_string(),
+    )
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let expand = Expand::new().labels().members().attachments();
+    let board = Board::get_expanded(&client, "EXPAND-BOARD", &expand)?;
+
+    assert_eq!(board.labels, Some(vec![Label::new("L1", "Bug", "red")]));
+    assert_eq!(board.members.unwrap()[0].full_name, "Ratchet");
+    assert_eq!(board.checklists, None);
+    Ok(())
+}
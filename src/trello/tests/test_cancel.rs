@@ -0,0 +1,176 @@
+use super::*;
+
+use progress::RunOptions;
+use std::thread;
+use std::time::{Duration, Instant};
+use watch::BoardWatcher;
+
+fn cancel_after(token: &CancelToken, delay: Duration) -> thread::JoinHandle<()> {
+    let token = token.clone();
+    thread::spawn(move || {
+        thread::sleep(delay);
+        token.cancel();
+    })
+}
+
+#[test]
+fn test_sleep() {
+    let token = CancelToken::new();
+    assert!(!token.sleep(Duration::from_millis(10)));
+    assert!(token.check(3).is_ok());
+
+    let canceller = cancel_after(&token, Duration::from_millis(50));
+    let start = Instant::now();
+    assert!(token.sleep(Duration::from_secs(60)));
+    assert!(start.elapsed() < Duration::from_secs(10));
+    canceller.join().unwrap();
+
+    match token.check(3) {
+        Err(TrelloError::Cancelled { completed: 3, .. }) => {}
+        other => panic!("Unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn test_flush_cancelled() {
+    let labels = mockito::mock(
+        "POST",
+        mockito::Matcher::Regex(String::from(
+            "^/1/cards/CANCEL-[12]/idLabels\\?key=cancel-key",
+        )),
+    )
+    .with_status(200)
+    .with_body("{}")
+    .expect(1)
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "cancel-token", "cancel-key");
+    let client = TrelloClient::new(config);
+
+    let mut queue = WriteQueue::new().interval(Duration::from_millis(0));
+    for card_id in &["CANCEL-1", "CANCEL-2"] {
+        queue.push(Mutation::ApplyLabel {
+            card_id: CardId::new(card_id),
+            label_id: LabelId::new("LABEL"),
+        });
+    }
+
+    let token = CancelToken::new();
+    let canceller = token.clone();
+    let mut progress = |_: &str, done: usize, _: usize| {
+        if done == 1 {
+            canceller.cancel();
+        }
+    };
+
+    let options = RunOptions::new().progress(&mut progress).cancel(&token);
+    match queue.flush_with(&client, options) {
+        Err(TrelloError::Cancelled { completed: 1, .. }) => {}
+        other => panic!("Unexpected result: {:?}", other),
+    }
+    labels.assert();
+    assert_eq!(queue.len(), 1);
+}
+
+#[test]
+fn test_watcher_cancelled() {
+    let board = mockito::mock(
+        "GET",
+        "/1/boards/CANCEL-WATCHED?key=cancel-key&token=cancel-token&fields=id%2Cname%2Cclosed%2Curl%2Csubscribed",
+    )
+    .with_status(200)
+    .with_body(json!({"id": "CANCEL-WATCHED", "name": "Watched", "closed": false, "url": ""}).to_string())
+    .expect(1)
+    .create();
+    let _lists = mockito::mock(
+        "GET",
+        "/1/boards/CANCEL-WATCHED/lists?key=cancel-key&token=cancel-token&fields=id%2Cname%2Cclosed%2CsoftLimit&cards=open",
+    )
+    .with_status(200)
+    .with_body(json!([]).to_string())
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "cancel-token", "cancel-key");
+    let client = TrelloClient::new(config);
+
+    let token = CancelToken::new();
+    let mut watcher = BoardWatcher::new("CANCEL-WATCHED").cancel_token(token.clone());
+    let canceller = cancel_after(&token, Duration::from_millis(200));
+
+    let start = Instant::now();
+    match watcher.run(&client, Duration::from_secs(60)) {
+        Err(TrelloError::Cancelled { completed: 1, .. }) => {}
+        other => panic!("Unexpected result: {:?}", other),
+    }
+    assert!(start.elapsed() < Duration::from_secs(10));
+    canceller.join().unwrap();
+    board.assert();
+}
+
+#[test]
+fn test_get_many_nested_cancelled() {
+    let config = ClientConfig::new(&mockito::server_url(), "cancel-token", "cancel-key");
+    let client = TrelloClient::new(config);
+
+    let token = CancelToken::new();
+    token.cancel();
+
+    let options = RunOptions::new().cancel(&token);
+    let error = Board::get_many_nested_with(&client, &["CANCEL-A", "CANCEL-B"], options)
+        .expect_err("Expected the retrieval to be cancelled");
+    match error {
+        TrelloError::Cancelled { completed: 0, .. } => {}
+        ref other => panic!("Unexpected error: {:?}", other),
+    }
+    assert_eq!(error.partial::<Vec<Board>>(), Some(&vec![]));
+}
+
+#[test]
+fn test_sync_cancelled() {
+    let _labels = mockito::mock(
+        "GET",
+        mockito::Matcher::Regex(String::from(
+            "^/1/boards/CANCEL-(SOURCE|A)/labels\\?key=cancel-sync-key",
+        )),
+    )
+    .with_status(200)
+    .with_body(json!([]).to_string())
+    .create();
+    let _board = mockito::mock(
+        "GET",
+        "/1/boards/CANCEL-A?key=cancel-sync-key&token=cancel-sync-token&fields=id%2Cname%2Cclosed%2Curl%2Csubscribed",
+    )
+    .with_status(200)
+    .with_body(json!({"id": "CANCEL-A", "name": "Alpha", "closed": false, "url": ""}).to_string())
+    .create();
+
+    let config = ClientConfig::new(
+        &mockito::server_url(),
+        "cancel-sync-token",
+        "cancel-sync-key",
+    );
+    let client = TrelloClient::new(config);
+
+    let token = CancelToken::new();
+    let canceller = token.clone();
+    let mut progress = |_: &str, done: usize, _: usize| {
+        if done == 1 {
+            canceller.cancel();
+        }
+    };
+
+    let options = RunOptions::new().progress(&mut progress).cancel(&token);
+    let error = labels::sync_with(&client, "CANCEL-SOURCE", &["CANCEL-A", "CANCEL-B"], options)
+        .expect_err("Expected the sync to be cancelled");
+    match error {
+        TrelloError::Cancelled { completed: 1, .. } => {}
+        ref other => panic!("Unexpected error: {:?}", other),
+    }
+
+    let report = error
+        .partial::<labels::SyncReport>()
+        .expect("Expected the report of the synced boards");
+    assert_eq!(report.boards.len(), 1);
+    assert_eq!(report.boards[0].board_id, "CANCEL-A");
+    assert!(report.is_empty());
+}
@@ -1,17 +1,29 @@
 use super::*;
-use chrono::{TimeZone, Utc};
+use chrono::{FixedOffset, TimeZone, Utc};
+use colored::Colorize;
 
 #[test]
 fn test_new() {
     let card = Card::new("A", "B", "C", None, "https://trello.com/my/card", None);
     let expected = Card {
-        id: String::from("A"),
+        id: CardId::new("A"),
         name: String::from("B"),
         desc: String::from("C"),
         labels: None,
         due: None,
         closed: false,
+        subscribed: false,
+        date_last_activity: None,
+        badges: None,
+        id_members: vec![],
+        id_short: None,
+        checklists: None,
+        members: None,
+        attachments: None,
+        custom_field_items: None,
         url: String::from("https://trello.com/my/card"),
+        #[cfg(feature = "extra-fields")]
+        extra: serde_json::Map::new(),
     };
     assert_eq!(card, expected);
 }
@@ -34,13 +46,24 @@ fn test_render() {
 #[test]
 fn test_simple_render() {
     let card = Card {
-        id: String::from("1234"),
+        id: CardId::new("1234"),
         name: String::from("Fire Monkey"),
         desc: String::from(""),
         closed: false,
         url: String::from(""),
         labels: None,
         due: None,
+        subscribed: false,
+        date_last_activity: None,
+        badges: None,
+        id_members: vec![],
+        id_short: None,
+        checklists: None,
+        members: None,
+        attachments: None,
+        custom_field_items: None,
+        #[cfg(feature = "extra-fields")]
+        extra: serde_json::Map::new(),
     };
 
     let expected = "Fire Monkey";
@@ -50,13 +73,24 @@ fn test_simple_render() {
 #[test]
 fn test_simple_render_with_description() {
     let card = Card {
-        id: String::from("1234"),
+        id: CardId::new("1234"),
         name: String::from("Ice Snail"),
         desc: String::from("Some details which should not be shown"),
         closed: false,
         url: String::from(""),
         labels: None,
         due: None,
+        subscribed: false,
+        date_last_activity: None,
+        badges: None,
+        id_members: vec![],
+        id_short: None,
+        checklists: None,
+        members: None,
+        attachments: None,
+        custom_field_items: None,
+        #[cfg(feature = "extra-fields")]
+        extra: serde_json::Map::new(),
     };
 
     let expected = "Ice Snail \u{1b}[2m[...]\u{1b}[0m";
@@ -66,13 +100,24 @@ fn test_simple_render_with_description() {
 #[test]
 fn test_simple_render_with_labels() {
     let card = Card {
-        id: String::from("1234"),
+        id: CardId::new("1234"),
         name: String::from("Lightning Goat"),
         desc: String::from(""),
         closed: false,
         url: String::from(""),
         labels: Some(vec![Label::new("", "Animals", "green")]),
         due: None,
+        subscribed: false,
+        date_last_activity: None,
+        badges: None,
+        id_members: vec![],
+        id_short: None,
+        checklists: None,
+        members: None,
+        attachments: None,
+        custom_field_items: None,
+        #[cfg(feature = "extra-fields")]
+        extra: serde_json::Map::new(),
     };
 
     let expected = "Lightning Goat \u{1b}[48;2;97;189;79;37m Animals \u{1b}[0m";
@@ -82,13 +127,24 @@ fn test_simple_render_with_labels() {
 #[test]
 fn test_simple_render_closed() {
     let card = Card {
-        id: String::from("1234"),
+        id: CardId::new("1234"),
         name: String::from("Earth Seagull"),
         desc: String::from(""),
         closed: true,
         url: String::from(""),
         labels: None,
         due: None,
+        subscribed: false,
+        date_last_activity: None,
+        badges: None,
+        id_members: vec![],
+        id_short: None,
+        checklists: None,
+        members: None,
+        attachments: None,
+        custom_field_items: None,
+        #[cfg(feature = "extra-fields")]
+        extra: serde_json::Map::new(),
     };
 
     let expected = "\u{1b}[31m[Closed]\u{1b}[0m Earth Seagull";
@@ -130,6 +186,121 @@ fn test_get() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_get_labels() -> Result<()> {
+    let _card = mockito::mock(
+        "GET",
+        "/1/cards/LABELLED-CARD?key=some-key&token=some-token&fields=idBoard%2Clabels",
+    )
+    .with_status(200)
+    .with_body(
+        json!({
+            "id": "LABELLED-CARD",
+            "idBoard": "LABELLED-BOARD",
+            "labels": [{"id": "L1", "name": "Bug", "color": "red"}],
+        })
+        .to_string(),
+    )
+    .create();
+    let _labels = mockito::mock(
+        "GET",
+        "/1/boards/LABELLED-BOARD/labels?key=some-key&token=some-token&fields=id%2Cname%2Ccolor",
+    )
+    .with_status(200)
+    .with_body(
+        json!([
+            {"id": "L1", "name": "Bug", "color": "red"},
+            {"id": "L2", "name": "Feature", "color": "green"},
+        ])
+        .to_string(),
+    )
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let result = Card::get_labels(&client, "LABELLED-CARD")?;
+
+    assert_eq!(result.applied, vec![Label::new("L1", "Bug", "red")]);
+    assert_eq!(result.available.len(), 2);
+    assert!(result.is_applied("L1"));
+    assert_eq!(
+        result.unapplied(),
+        vec![&Label::new("L2", "Feature", "green")]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_get_with_fields() -> Result<()> {
+    let _m = mockito::mock(
+        "GET",
+        "/1/cards/CARD-FOO?key=some-key&token=some-token&fields=id%2Cname%2Cdesc%2Cclosed%2Curl%2Cdue",
+    )
+    .with_status(200)
+    .with_body(
+        json!({
+            "id": "CARD-FOO",
+            "name": "Card Foo",
+            "desc": "",
+            "closed": false,
+            "url": "",
+            "due": "2020-06-28T06:00:00.000Z",
+        })
+        .to_string(),
+    )
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let result = Card::get_with_fields(&client, "CARD-FOO", &["name", "due"])?;
+
+    assert_eq!(result.due, Some(Utc.ymd(2020, 6, 28).and_hms(6, 0, 0)));
+    assert_eq!(result.labels, None);
+    Ok(())
+}
+
+#[test]
+fn test_get_expanded() -> Result<()> {
+    let _m = mockito::mock(
+        "GET",
+        "/1/cards/CARD-FOO?key=some-key&token=some-token&members=true&checklists=all&attachments=true&customFieldItems=true",
+    )
+    .with_status(200)
+    .with_body(
+        json!({
+            "name": "Card Foo",
+            "desc": "",
+            "id": "CARD-FOO",
+            "closed": false,
+            "url": "",
+            "members": [{"id": "M1", "fullName": "Ratchet", "username": "ratchet"}],
+            "checklists": [],
+            "attachments": [
+                {"id": "A1", "name": "notes.txt", "url": "https://example.com/notes.txt"}
+            ],
+            "customFieldItems": [{"id": "CF1", "value": {"text": "high"}}],
+        })
+        .to_string(),
+    )
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let result = Card::get_expanded(&client, "CARD-FOO", &Expand::all())?;
+
+    assert_eq!(result.members.unwrap()[0].username, "ratchet");
+    assert_eq!(result.checklists, Some(vec![]));
+    assert_eq!(result.attachments.unwrap()[0].name, "notes.txt");
+    assert_eq!(
+        result.custom_field_items,
+        Some(vec![json!({"id": "CF1", "value": {"text": "high"}})])
+    );
+    Ok(())
+}
+
 #[test]
 fn test_create() -> Result<()> {
     let _m = mockito::mock("POST", "/1/cards/?key=some-key&token=some-token")
@@ -168,6 +339,63 @@ fn test_create() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_from_template() -> Result<()> {
+    let template = "Standup {{date}}\n===\nSprint {{sprint}}, run by {{assignee}}";
+
+    let contents = CardContents::from_template(template, &[("sprint", "7"), ("assignee", "Kim")])?;
+    let today = Utc::now().format("%Y-%m-%d").to_string();
+    assert_eq!(contents.name, format!("Standup {}", today));
+    assert_eq!(contents.desc, "Sprint 7, run by Kim");
+
+    let contents = CardContents::from_template(
+        template,
+        &[("date", "2020-06-28"), ("sprint", "7"), ("assignee", "Kim")],
+    )?;
+    assert_eq!(contents.name, "Standup 2020-06-28");
+
+    let result = CardContents::from_template(template, &[("sprint", "7")]);
+    match result {
+        Err(TrelloError::CardParse(message)) => {
+            assert_eq!(message, "Unknown template variables: assignee")
+        }
+        other => panic!("Unexpected result: {:?}", other),
+    }
+    Ok(())
+}
+
+#[test]
+fn test_create_from_template_str() -> Result<()> {
+    let _m = mockito::mock("POST", "/1/cards/?key=some-key&token=some-token")
+        .match_body("name=Sprint+7+review&desc=Owner%3A+Kim&idList=FOOBAR")
+        .with_status(200)
+        .with_body(
+            json!({
+                "name": "Sprint 7 review",
+                "desc": "Owner: Kim",
+                "id": "88888",
+                "closed": false,
+                "url": "https://example.com/1/12/",
+            })
+            .to_string(),
+        )
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let result = Card::create_from_template_str(
+        &client,
+        "FOOBAR",
+        "Sprint {{sprint}} review\n===\nOwner: {{assignee}}",
+        &[("sprint", "7"), ("assignee", "Kim")],
+    )?;
+
+    assert_eq!(result.id, "88888");
+    assert_eq!(result.name, "Sprint 7 review");
+    Ok(())
+}
+
 #[test]
 fn test_update() -> Result<()> {
     let _m = mockito::mock("PUT", "/1/cards/MY-CARD-ID/?key=some-key&token=some-token")
@@ -207,7 +435,7 @@ fn test_update() -> Result<()> {
 fn test_get_all() -> Result<()> {
     let _m = mockito::mock(
         "GET",
-        "/1/lists/DEADBEEF/cards/?key=some-key&token=some-secret-token&fields=id%2Cname%2Cdesc%2Clabels%2Cclosed%2Cdue%2Curl",
+        "/1/lists/DEADBEEF/cards/?key=some-key&token=some-secret-token&fields=id%2Cname%2Cdesc%2Clabels%2Cclosed%2Cdue%2Curl%2Csubscribed%2CdateLastActivity%2Cbadges%2CidMembers%2CidShort",
     )
     .with_status(200)
     .with_body(
@@ -259,3 +487,502 @@ fn test_get_all() -> Result<()> {
     assert_eq!(result, expected);
     Ok(())
 }
+
+#[test]
+fn test_set_subscribed() -> Result<()> {
+    let _m = mockito::mock("PUT", "/1/cards/MY-CARD-ID/?key=some-key&token=some-token")
+        .match_body("subscribed=true")
+        .with_status(200)
+        .with_body(
+            json!({
+                "name": "Laundry",
+                "desc": "",
+                "id": "MY-CARD-ID",
+                "closed": false,
+                "url": "https://trello.com/abcdef",
+                "subscribed": true,
+            })
+            .to_string(),
+        )
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let result = Card::set_subscribed(&client, "MY-CARD-ID", true)?;
+    assert!(result.subscribed);
+    Ok(())
+}
+
+#[test]
+fn test_add_comment() -> Result<()> {
+    let _m = mockito::mock(
+        "POST",
+        "/1/cards/MY-CARD-ID/actions/comments?key=some-key&token=some-token",
+    )
+    .match_body("text=Done+%26+dusted")
+    .with_status(200)
+    .with_body("{}")
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    Card::add_comment(&client, "MY-CARD-ID", "Done & dusted")?;
+    Ok(())
+}
+
+#[test]
+fn test_set_due() -> Result<()> {
+    let _set = mockito::mock("PUT", "/1/cards/DUE-CARD/?key=some-key&token=some-token")
+        .match_body("due=2020-06-28T17%3A00%3A00%2B00%3A00")
+        .with_status(200)
+        .with_body(
+            json!({
+                "name": "Laundry",
+                "desc": "",
+                "id": "DUE-CARD",
+                "closed": false,
+                "url": "https://trello.com/abcdef",
+                "due": "2020-06-28T17:00:00.000Z",
+            })
+            .to_string(),
+        )
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let due = Utc.ymd(2020, 6, 28).and_hms(17, 0, 0);
+    let result = Card::set_due(&client, "DUE-CARD", Some(due))?;
+    assert_eq!(result.due, Some(due));
+    Ok(())
+}
+
+#[cfg(feature = "extra-fields")]
+#[test]
+fn test_extra_fields() -> std::result::Result<(), serde_json::Error> {
+    let value = json!({
+        "name": "Card Foo",
+        "desc": "",
+        "id": "CARD-FOO",
+        "closed": false,
+        "url": "https://card.foo/123",
+        "subscribed": false,
+        "pos": 16384,
+        "cover": {"color": "green"},
+    });
+
+    let card: Card = serde_json::from_value(value.clone())?;
+
+    assert_eq!(card.extra.get("pos"), Some(&json!(16384)));
+    assert_eq!(card.extra.get("cover"), Some(&json!({"color": "green"})));
+
+    let round_trip = serde_json::to_value(&card)?;
+    assert_eq!(round_trip, value);
+    Ok(())
+}
+
+#[test]
+fn test_patch() -> Result<()> {
+    let _m = mockito::mock("PUT", "/1/cards/MY-CARD-ID/?key=some-key&token=some-token")
+        .match_body("desc=new+desc&due=2020-06-28T06%3A06%3A27%2B00%3A00")
+        .with_status(200)
+        .with_body(
+            json!({
+                "name": "Laundry",
+                "desc": "new desc",
+                "id": "MY-CARD-ID",
+                "closed": false,
+                "url": "https://trello.com/abcdef",
+                "due": "2020-06-28T06:06:27-00:00",
+            })
+            .to_string(),
+        )
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let due = Utc.ymd(2020, 6, 28).and_hms(6, 6, 27);
+    let patch = CardPatch::new().desc("new desc").due(Some(due));
+    let result = Card::patch(&client, "MY-CARD-ID", &patch)?;
+
+    assert_eq!(result.desc, "new desc");
+    assert_eq!(result.due, Some(due));
+    Ok(())
+}
+
+#[test]
+fn test_patch_if_last_activity() -> Result<()> {
+    let _m1 = mockito::mock(
+        "GET",
+        "/1/cards/MY-CARD-ID?key=some-key&token=some-token&fields=dateLastActivity",
+    )
+    .with_status(200)
+    .with_body(json!({"dateLastActivity": "2020-06-28T06:06:27.000Z"}).to_string())
+    .create();
+
+    let _m2 = mockito::mock("PUT", "/1/cards/MY-CARD-ID/?key=some-key&token=some-token")
+        .match_body("name=Laundry")
+        .with_status(200)
+        .with_body(
+            json!({
+                "name": "Laundry",
+                "desc": "",
+                "id": "MY-CARD-ID",
+                "closed": false,
+                "url": "https://trello.com/abcdef",
+            })
+            .to_string(),
+        )
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let last_activity = Utc.ymd(2020, 6, 28).and_hms(6, 6, 27);
+    let patch = CardPatch::new()
+        .name("Laundry")
+        .if_last_activity(Some(last_activity));
+    let result = Card::patch(&client, "MY-CARD-ID", &patch)?;
+
+    assert_eq!(result.name, "Laundry");
+    Ok(())
+}
+
+#[test]
+fn test_patch_conflict() {
+    let _m = mockito::mock(
+        "GET",
+        "/1/cards/OTHER-CARD-ID?key=some-key&token=some-token&fields=dateLastActivity",
+    )
+    .with_status(200)
+    .with_body(json!({"dateLastActivity": "2020-06-29T10:00:00.000Z"}).to_string())
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let last_activity = Utc.ymd(2020, 6, 28).and_hms(6, 6, 27);
+    let patch = CardPatch::new()
+        .name("Laundry")
+        .if_last_activity(Some(last_activity));
+
+    match Card::patch(&client, "OTHER-CARD-ID", &patch) {
+        Err(TrelloError::Conflict { expected, actual }) => {
+            assert_eq!(expected, last_activity);
+            assert_eq!(actual, Utc.ymd(2020, 6, 29).and_hms(10, 0, 0));
+        }
+        other => panic!("Unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn test_delete() -> Result<()> {
+    let _m = mockito::mock("DELETE", "/1/cards/DOOMED?key=some-key&token=some-token")
+        .with_status(200)
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    Card::delete(&client, "DOOMED", None)?;
+    _m.assert();
+    Ok(())
+}
+
+#[test]
+fn test_delete_archive_only() -> Result<()> {
+    let _m = mockito::mock("PUT", "/1/cards/SAVED?key=some-key&token=some-token")
+        .match_body("closed=true")
+        .with_status(200)
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::builder(config)
+        .safety_policy(SafetyPolicy::ArchiveOnly)
+        .build()?;
+
+    Card::delete(&client, "SAVED", None)?;
+    _m.assert();
+    Ok(())
+}
+
+#[test]
+fn test_delete_require_confirmation() -> Result<()> {
+    let _m = mockito::mock("DELETE", "/1/cards/CONFIRMED?key=some-key&token=some-token")
+        .with_status(200)
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::builder(config)
+        .safety_policy(SafetyPolicy::RequireConfirmationToken)
+        .build()?;
+
+    match Card::delete(&client, "CONFIRMED", None) {
+        Err(TrelloError::DeletionNotPermitted(_)) => {}
+        other => panic!("Unexpected result: {:?}", other),
+    }
+
+    let token = client.confirm_deletion("OTHER");
+    match Card::delete(&client, "CONFIRMED", Some(&token)) {
+        Err(TrelloError::DeletionNotPermitted(_)) => {}
+        other => panic!("Unexpected result: {:?}", other),
+    }
+
+    let token = client.confirm_deletion("CONFIRMED");
+    Card::delete(&client, "CONFIRMED", Some(&token))?;
+    _m.assert();
+    Ok(())
+}
+
+#[test]
+fn test_badges() {
+    let badges: Badges = serde_json::from_value(json!({
+        "votes": 0,
+        "viewingMemberVoted": false,
+        "checkItems": 7,
+        "checkItemsChecked": 3,
+        "comments": 2,
+        "attachments": 1,
+        "description": true,
+        "due": "2020-06-28T06:06:27.000Z",
+        "dueComplete": false,
+    }))
+    .unwrap();
+
+    assert_eq!(badges.summary(), "2 comments, 1 attachment, due 2020-06-28");
+    assert_eq!(Badges::default().summary(), "");
+
+    let mut card = Card::new("", "Quest", "Dig", None, "", None);
+    card.badges = Some(badges);
+
+    assert_eq!(card.checklist_progress(), Some((3, 7)));
+    let expected = format!(
+        "Quest\n=====\n{}\nDig",
+        "[3/7] 2 comments, 1 attachment, due 2020-06-28".dimmed()
+    );
+    assert_eq!(card.render(), expected);
+}
+
+#[test]
+fn test_render_with_options() {
+    let card = Card::new(
+        "CARD-ID",
+        "Quest",
+        "Dig",
+        Some(vec![Label::new("", "Relic", "green")]),
+        "https://trello.com/c/quest",
+        Some(Utc.ymd(2020, 6, 28).and_hms(6, 6, 27)),
+    );
+
+    let minimal = RenderOptions {
+        detail: Detail::Minimal,
+        show_labels: false,
+        ..RenderOptions::default()
+    };
+    assert_eq!(card.render_with(&minimal), "Quest");
+
+    let full = RenderOptions {
+        detail: Detail::Full,
+        show_ids: true,
+        show_urls: true,
+        timezone: Some(FixedOffset::east(2 * 3600)),
+        now: Some(Utc.ymd(2020, 6, 26).and_hms(6, 0, 0)),
+        ..RenderOptions::default()
+    };
+    let expected = [
+        String::from("Quest\n====="),
+        Label::new("", "Relic", "green").simple_render(),
+        String::from("Due: 2020-06-28 08:06 (due in 2 days)"),
+        "CARD-ID".dimmed().to_string(),
+        "https://trello.com/c/quest".dimmed().to_string(),
+        String::from("Dig"),
+    ]
+    .join("\n");
+    assert_eq!(card.render_with(&full), expected);
+}
+
+#[test]
+fn test_create_idempotent_finds_previous_attempt() -> Result<()> {
+    let post = mockito::mock("POST", "/1/cards/?key=some-key&token=some-token")
+        .match_body("name=Retry&desc=&idList=RETRY-LIST")
+        .with_status(504)
+        .expect(1)
+        .create();
+
+    let id = format!("{:08x}0000000000000000", Utc::now().timestamp());
+    let _m = mockito::mock(
+        "GET",
+        "/1/lists/RETRY-LIST/cards/?key=some-key&token=some-token&fields=id%2Cname%2Cdesc%2Clabels%2Cclosed%2Cdue%2Curl%2Csubscribed%2CdateLastActivity%2Cbadges%2CidMembers%2CidShort",
+    )
+    .with_status(200)
+    .with_body(
+        json!([
+            {"id": "5eac8f60a3e4b10a3c9c5c2e", "name": "Retry", "desc": "", "closed": false, "url": ""},
+            {"id": id, "name": "Retry", "desc": "", "closed": false, "url": ""},
+        ])
+        .to_string(),
+    )
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let card = Card::new("", "Retry", "", None, "", None);
+    let result = Card::create_idempotent(
+        &client,
+        "RETRY-LIST",
+        &card,
+        3,
+        chrono::Duration::minutes(1),
+    )?;
+
+    assert_eq!(result.id, id);
+    post.assert();
+    Ok(())
+}
+
+#[test]
+fn test_create_idempotent_retries() {
+    let post = mockito::mock("POST", "/1/cards/?key=some-key&token=some-token")
+        .match_body("name=Flaky&desc=&idList=FLAKY-LIST")
+        .with_status(503)
+        .expect(3)
+        .create();
+
+    let _m = mockito::mock(
+        "GET",
+        "/1/lists/FLAKY-LIST/cards/?key=some-key&token=some-token&fields=id%2Cname%2Cdesc%2Clabels%2Cclosed%2Cdue%2Curl%2Csubscribed%2CdateLastActivity%2Cbadges%2CidMembers%2CidShort",
+    )
+    .with_status(200)
+    .with_body(json!([]).to_string())
+    .expect(2)
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let card = Card::new("", "Flaky", "", None, "", None);
+    let result = Card::create_idempotent(
+        &client,
+        "FLAKY-LIST",
+        &card,
+        2,
+        chrono::Duration::minutes(1),
+    );
+
+    assert!(result.is_err());
+    post.assert();
+    _m.assert();
+}
+
+#[test]
+fn test_create_idempotent_client_error() {
+    let post = mockito::mock("POST", "/1/cards/?key=some-key&token=some-token")
+        .match_body("name=Invalid&desc=&idList=INVALID-LIST")
+        .with_status(400)
+        .expect(1)
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let card = Card::new("", "Invalid", "", None, "", None);
+    let result = Card::create_idempotent(
+        &client,
+        "INVALID-LIST",
+        &card,
+        2,
+        chrono::Duration::minutes(1),
+    );
+
+    assert!(result.is_err());
+    post.assert();
+}
+
+#[test]
+fn test_created_at() {
+    let card = Card::new("5eac8f60a3e4b10a3c9c5c2e", "", "", None, "", None);
+    assert_eq!(
+        card.created_at(),
+        Some(Utc.ymd(2020, 5, 1).and_hms(21, 6, 40))
+    );
+    assert_eq!(Card::new("xyz", "", "", None, "", None).created_at(), None);
+}
+
+#[test]
+fn test_instance_save_and_archive() -> Result<()> {
+    let _save = mockito::mock(
+        "PUT",
+        "/1/cards/INSTANCE-CARD/?key=some-key&token=some-token",
+    )
+    .match_body("name=Renamed&desc=&closed=false")
+    .with_status(200)
+    .with_body(
+        json!({"id": "INSTANCE-CARD", "name": "Renamed", "desc": "", "closed": false, "url": ""})
+            .to_string(),
+    )
+    .create();
+    let _archive = mockito::mock(
+        "PUT",
+        "/1/cards/INSTANCE-CARD/?key=some-key&token=some-token",
+    )
+    .match_body("closed=true")
+    .with_status(200)
+    .with_body(
+        json!({"id": "INSTANCE-CARD", "name": "Renamed", "desc": "", "closed": true, "url": ""})
+            .to_string(),
+    )
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let mut card = Card::new("INSTANCE-CARD", "Original", "", None, "", None);
+    card.checklists = Some(vec![]);
+    card.name = String::from("Renamed");
+    card.save(&client)?;
+    assert_eq!(card.name, "Renamed");
+    assert_eq!(card.checklists, Some(vec![]));
+
+    card.archive(&client)?;
+    assert!(card.closed);
+    Ok(())
+}
+
+#[test]
+fn test_suggest_branch_name() {
+    let mut card = Card::new(
+        "5f0a1b00aaaaaaaaaaaaaaaa",
+        "Fix the login timeout on slow networks; retry with exponential backoff",
+        "",
+        Some(vec![Label::new("", "Bug", "red")]),
+        "https://trello.com/c/a1B2c3D4/12-fix",
+        None,
+    );
+    card.id_short = Some(123);
+
+    assert_eq!(
+        card.suggest_branch_name(),
+        "fix/123-fix-the-login-timeout-on-slow-networks"
+    );
+
+    card.id_short = None;
+    card.labels = None;
+    card.name = String::from("Émoji 🎉 support");
+    assert_eq!(card.suggest_branch_name(), "feat/a1B2c3D4-moji-support");
+
+    card.url = String::new();
+    card.name = String::from("🎉");
+    assert_eq!(card.suggest_branch_name(), "feat/5f0a1b00-card");
+
+    let scheme = BranchScheme {
+        template: String::from("{number}-{slug}"),
+        max_slug_len: 5,
+        ..BranchScheme::default()
+    };
+    card.name = String::from("Supercalifragilistic");
+    assert_eq!(card.suggest_branch_name_with(&scheme), "5f0a1b00-super");
+}
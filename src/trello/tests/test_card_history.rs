@@ -0,0 +1,145 @@
+use super::*;
+
+use chrono::{FixedOffset, TimeZone, Utc};
+use colored::*;
+
+fn actions() -> serde_json::Value {
+    // newest first, as returned by Trello
+    json!([
+        {
+            "id": "A4",
+            "idMemberCreator": "M1",
+            "type": "addLabelToCard",
+            "date": "2020-06-28T09:00:00Z",
+            "data": {"card": {"id": "CARD-1"}, "label": {"id": "L1", "name": "urgent"}},
+            "memberCreator": {"id": "M1", "username": "rat", "fullName": "Ratchet"}
+        },
+        {
+            "id": "A3",
+            "idMemberCreator": "M1",
+            "type": "updateCard",
+            "date": "2020-06-28T08:00:00Z",
+            "data": {
+                "card": {"id": "CARD-1", "name": "Gyro Boots", "desc": "Jump\nhigher"},
+                "old": {"desc": "Jump\nhigh"}
+            }
+        },
+        {
+            "id": "A2",
+            "idMemberCreator": "M1",
+            "type": "updateCard",
+            "date": "2020-06-27T08:00:00Z",
+            "data": {
+                "card": {"id": "CARD-1", "name": "Gyro Boots"},
+                "listBefore": {"id": "LIST-1", "name": "Todo"},
+                "listAfter": {"id": "LIST-2", "name": "Doing"},
+                "old": {"idList": "LIST-1"}
+            }
+        },
+        {
+            "id": "A1",
+            "idMemberCreator": "M1",
+            "type": "updateCard",
+            "date": "2020-06-26T08:00:00Z",
+            "data": {
+                "card": {"id": "CARD-1", "name": "Gyro Boots", "due": "2020-07-01T12:00:00.000Z"},
+                "old": {"name": "Boots", "due": null}
+            }
+        },
+        {
+            "id": "A0",
+            "idMemberCreator": "M1",
+            "type": "updateCard",
+            "date": "2020-06-25T08:00:00Z",
+            "data": {"card": {"id": "CARD-1", "name": "Boots", "pos": 1}, "old": {"pos": 2}}
+        }
+    ])
+}
+
+#[test]
+fn test_from_actions() {
+    let actions: Vec<webhook::Action> = serde_json::from_value(actions()).unwrap();
+    let history = CardHistory::from_actions("CARD-1", &actions);
+
+    let changes = history
+        .entries
+        .iter()
+        .map(|e| e.change.clone())
+        .collect::<Vec<FieldChange>>();
+    assert_eq!(
+        changes,
+        vec![
+            FieldChange::Name {
+                from: String::from("Boots"),
+                to: String::from("Gyro Boots"),
+            },
+            FieldChange::Due {
+                from: None,
+                to: Some(Utc.ymd(2020, 7, 1).and_hms(12, 0, 0)),
+            },
+            FieldChange::List {
+                from: String::from("Todo"),
+                to: String::from("Doing"),
+            },
+            FieldChange::Desc {
+                from: String::from("Jump\nhigh"),
+                to: String::from("Jump\nhigher"),
+            },
+            FieldChange::LabelAdded(String::from("urgent")),
+        ]
+    );
+    assert_eq!(history.entries[4].member, Some(String::from("Ratchet")));
+    assert_eq!(
+        history.descriptions(),
+        vec![
+            (None, "Jump\nhigh"),
+            (Some(Utc.ymd(2020, 6, 28).and_hms(8, 0, 0)), "Jump\nhigher")
+        ]
+    );
+}
+
+#[test]
+fn test_history() -> Result<()> {
+    let _m = mockito::mock(
+        "GET",
+        "/1/cards/CARD-1/actions?key=some-key&token=some-token&filter=updateCard%2CaddLabelToCard%2CremoveLabelFromCard&limit=1000",
+    )
+    .with_status(200)
+    .with_body(actions().to_string())
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let history = Card::history(&client, "CARD-1")?;
+    assert_eq!(history.entries.len(), 5);
+
+    let options = RenderOptions {
+        timezone: Some(FixedOffset::east(0)),
+        ..RenderOptions::default()
+    };
+    let expected = [
+        format!(
+            "{} Renamed 'Boots' to 'Gyro Boots'",
+            "2020-06-26 08:00".dimmed()
+        ),
+        format!(
+            "{} Set the due date to Wed Jul  1 2020 12:00",
+            "2020-06-26 08:00".dimmed()
+        ),
+        format!("{} Moved from Todo to Doing", "2020-06-27 08:00".dimmed()),
+        format!("{} Edited the description", "2020-06-28 08:00".dimmed()),
+        String::from("      Jump"),
+        "    - high".red().to_string(),
+        "    + higher".green().to_string(),
+        format!(
+            "{} Added label urgent {}",
+            "2020-06-28 09:00".dimmed(),
+            "(Ratchet)".dimmed()
+        ),
+    ]
+    .join("\n");
+    assert_eq!(history.render_with(&options), expected);
+    assert_eq!(history.simple_render(), "CARD-1: 5 changes");
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use super::*;
+
+#[test]
+fn test_progress() {
+    let checklist = Checklist::new(
+        "1",
+        "Packing",
+        vec![
+            CheckItem::new("a", "Shovel", CheckItemState::Complete),
+            CheckItem::new("b", "Armor", CheckItemState::Incomplete),
+            CheckItem::new("c", "Relics", CheckItemState::Complete),
+        ],
+    );
+
+    assert_eq!(checklist.progress(), (2, 3));
+    assert_eq!(checklist.simple_render(), "Packing [2/3]");
+    assert_eq!(
+        checklist.render(),
+        "Packing\n-------\n[x] Shovel\n[ ] Armor\n[x] Relics"
+    );
+}
+
+#[test]
+fn test_card_checklist_progress() {
+    let mut card = Card::new("", "Quest", "", None, "", None);
+    assert_eq!(card.checklist_progress(), None);
+
+    card.checklists = Some(vec![
+        Checklist::new(
+            "1",
+            "Packing",
+            vec![CheckItem::new("a", "Shovel", CheckItemState::Complete)],
+        ),
+        Checklist::new(
+            "2",
+            "Travel",
+            vec![CheckItem::new("b", "Plains", CheckItemState::Incomplete)],
+        ),
+    ]);
+    assert_eq!(card.checklist_progress(), Some((1, 2)));
+}
+
+#[test]
+fn test_get_all() -> Result<()> {
+    let _m = mockito::mock(
+        "GET",
+        "/1/cards/CHECKED-CARD/checklists?key=some-key&token=some-token&fields=id%2Cname",
+    )
+    .with_status(200)
+    .with_body(
+        json!([{
+            "id": "CL",
+            "name": "Packing",
+            "checkItems": [{"id": "a", "name": "Shovel", "state": "complete", "pos": 1}],
+        }])
+        .to_string(),
+    )
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let result = Checklist::get_all(&client, "CHECKED-CARD")?;
+
+    assert_eq!(
+        result,
+        vec![Checklist::new(
+            "CL",
+            "Packing",
+            vec![CheckItem::new("a", "Shovel", CheckItemState::Complete)],
+        )]
+    );
+    Ok(())
+}
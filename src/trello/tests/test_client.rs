@@ -0,0 +1,555 @@
+use super::*;
+
+#[test]
+fn test_validate() -> Result<()> {
+    let _m = mockito::mock("GET", "/1/members/me/?key=some-key&token=some-token")
+        .with_status(200)
+        .with_body(
+            json!({
+                "id": "MEMBER-ID",
+                "fullName": "Michael",
+                "username": "michael",
+            })
+            .to_string(),
+        )
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let result = client.validate()?;
+    let expected = Member {
+        id: String::from("MEMBER-ID"),
+        full_name: String::from("Michael"),
+        username: String::from("michael"),
+        #[cfg(feature = "extra-fields")]
+        extra: serde_json::Map::new(),
+    };
+
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_validate_invalid_key() {
+    let _m = mockito::mock("GET", "/1/members/me/?key=bad-key&token=some-token")
+        .with_status(401)
+        .with_body("invalid key")
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "bad-key");
+    let client = TrelloClient::new(config);
+
+    match client.validate() {
+        Err(TrelloError::InvalidKey) => {}
+        other => panic!("Unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn test_validate_expired_token() {
+    let _m = mockito::mock("GET", "/1/members/me/?key=some-key&token=old-token")
+        .with_status(401)
+        .with_body("expired token")
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "old-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    match client.validate() {
+        Err(TrelloError::InvalidToken(message)) => assert_eq!(message, "expired token"),
+        other => panic!("Unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn test_rate_limit_status() -> Result<()> {
+    let _m = mockito::mock("GET", "/1/members/me/?key=KEY&token=TOKEN")
+        .with_status(200)
+        .with_header("x-rate-limit-api-token-interval-ms", "10000")
+        .with_header("x-rate-limit-api-token-max", "100")
+        .with_header("x-rate-limit-api-token-remaining", "98")
+        .with_header("x-rate-limit-api-key-interval-ms", "10000")
+        .with_header("x-rate-limit-api-key-max", "300")
+        .with_header("x-rate-limit-api-key-remaining", "290")
+        .with_body(
+            json!({
+                "id": "MEMBER-ID",
+                "fullName": "Michael",
+                "username": "michael",
+            })
+            .to_string(),
+        )
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "TOKEN", "KEY");
+    let client = TrelloClient::new(config);
+
+    assert_eq!(client.rate_limit_status(), None);
+
+    client.me()?;
+
+    let expected = RateLimitStatus {
+        api_key: Some(RateLimit {
+            max: 300,
+            remaining: 290,
+            interval_ms: 10000,
+        }),
+        api_token: Some(RateLimit {
+            max: 100,
+            remaining: 98,
+            interval_ms: 10000,
+        }),
+        member: None,
+    };
+
+    assert_eq!(client.rate_limit_status(), Some(expected));
+    assert_eq!(expected.remaining(), Some(98));
+    Ok(())
+}
+
+#[test]
+fn test_resolve_url_board() -> Result<()> {
+    let _m = mockito::mock(
+        "GET",
+        "/1/boards/AbCd1234?key=KEY&token=TOKEN&fields=id%2Cname%2Cclosed%2Curl%2Csubscribed",
+    )
+    .with_status(200)
+    .with_body(
+        json!({
+            "name": "Groceries",
+            "id": "BOARD-ID",
+            "closed": false,
+            "url": "https://trello.com/b/AbCd1234/groceries",
+        })
+        .to_string(),
+    )
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "TOKEN", "KEY");
+    let client = TrelloClient::new(config);
+
+    let result = resolve_url(&client, "https://trello.com/b/AbCd1234/groceries")?;
+    let expected = TrelloUrlObject::Board(Board::new(
+        "BOARD-ID",
+        "Groceries",
+        None,
+        "https://trello.com/b/AbCd1234/groceries",
+    ));
+
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_resolve_url_card() -> Result<()> {
+    let _m = mockito::mock("GET", "/1/cards/XyZ98765?key=KEY&token=TOKEN")
+        .with_status(200)
+        .with_body(
+            json!({
+                "name": "Milk",
+                "desc": "",
+                "id": "CARD-ID",
+                "closed": false,
+                "url": "https://trello.com/c/XyZ98765/1-milk",
+            })
+            .to_string(),
+        )
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "TOKEN", "KEY");
+    let client = TrelloClient::new(config);
+
+    let result = resolve_url(&client, "https://trello.com/c/XyZ98765/1-milk")?;
+    let expected = TrelloUrlObject::Card(Card::new(
+        "CARD-ID",
+        "Milk",
+        "",
+        None,
+        "https://trello.com/c/XyZ98765/1-milk",
+        None,
+    ));
+
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_resolve_url_unsupported() {
+    let config = ClientConfig::new(&mockito::server_url(), "TOKEN", "KEY");
+    let client = TrelloClient::new(config);
+
+    match resolve_url(&client, "https://trello.com/michael/boards") {
+        Err(TrelloError::UnsupportedUrl(url)) => {
+            assert_eq!(url, "https://trello.com/michael/boards")
+        }
+        other => panic!("Unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn test_builder_headers_and_prefix() -> Result<()> {
+    let _m = mockito::mock("GET", "/trello/1/members/me/?key=KEY&token=TOKEN")
+        .match_header("x-proxy-auth", "secret")
+        .with_status(200)
+        .with_body(
+            json!({
+                "id": "MEMBER-ID",
+                "fullName": "Michael",
+                "username": "michael",
+            })
+            .to_string(),
+        )
+        .create();
+
+    let mut config = ClientConfig::new(&mockito::server_url(), "TOKEN", "KEY");
+    config.path_prefix = String::from("trello");
+    let client = TrelloClient::builder(config)
+        .header("X-Proxy-Auth", "secret")?
+        .build()?;
+
+    assert_eq!(client.me()?.username, "michael");
+    Ok(())
+}
+
+#[test]
+fn test_builder_http2_prior_knowledge() -> Result<()> {
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    // a server which only speaks HTTP/1.1, like many proxies
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let server = std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let _ = stream.read(&mut [0; 1024]);
+        let _ = stream.write_all(b"HTTP/1.1 400 Bad Request\r\nConnection: close\r\n\r\n");
+    });
+
+    let config = ClientConfig::new(&format!("http://{}", address), "h2-token", "h2-key");
+    let client = TrelloClient::builder(config)
+        .http2_prior_knowledge(true)
+        .build()?;
+
+    assert!(client.me().is_err());
+    server.join().unwrap();
+    Ok(())
+}
+
+#[test]
+fn test_builder_connection_pool() -> Result<()> {
+    let _m = mockito::mock("GET", "/1/members/me/?key=pool-key&token=pool-token")
+        .with_status(200)
+        .with_body(json!({"id": "MEMBER-ID", "fullName": "Pool", "username": "pool"}).to_string())
+        .expect(2)
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "pool-token", "pool-key");
+    let client = TrelloClient::builder(config)
+        .pool_idle_timeout(Some(std::time::Duration::from_secs(300)))
+        .pool_max_idle_per_host(1)
+        .build()?;
+
+    assert_eq!(client.me()?.username, "pool");
+    assert_eq!(client.me()?.username, "pool");
+    _m.assert();
+    Ok(())
+}
+
+#[test]
+fn test_read_only() -> Result<()> {
+    let _get = mockito::mock("GET", "/1/cards/RO-CARD?key=ro-key&token=ro-token")
+        .with_status(200)
+        .with_body(
+            json!({"id": "RO-CARD", "name": "Report", "desc": "", "closed": false, "url": ""})
+                .to_string(),
+        )
+        .create();
+    let _put = mockito::mock(
+        "PUT",
+        mockito::Matcher::Regex(String::from("^/1/cards/RO-CARD")),
+    )
+    .expect(0)
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "ro-token", "ro-key");
+    let client = TrelloClient::read_only(config);
+    assert!(client.is_read_only());
+
+    assert_eq!(Card::get(&client, "RO-CARD")?.name, "Report");
+    match Card::patch(&client, "RO-CARD", &CardPatch::new().closed(true)) {
+        Err(TrelloError::ReadOnly(msg)) => {
+            assert!(msg.starts_with("PUT "));
+            assert!(!msg.contains("ro-token"));
+        }
+        other => panic!("Unexpected result: {:?}", other),
+    }
+
+    _put.assert();
+    Ok(())
+}
+
+fn compressed_member(encoding: &str) -> (String, Vec<u8>) {
+    use flate2::write::{GzEncoder, ZlibEncoder};
+    use flate2::Compression;
+    use std::io::Write;
+
+    let body = json!({
+        "id": "MEMBER-ID",
+        "fullName": "Michael",
+        "username": "michael",
+        "bio": "x".repeat(1000),
+    })
+    .to_string();
+
+    let compressed = match encoding {
+        "gzip" => {
+            let mut encoder = GzEncoder::new(vec![], Compression::default());
+            encoder.write_all(body.as_bytes()).unwrap();
+            encoder.finish().unwrap()
+        }
+        _ => {
+            let mut encoder = ZlibEncoder::new(vec![], Compression::default());
+            encoder.write_all(body.as_bytes()).unwrap();
+            encoder.finish().unwrap()
+        }
+    };
+    (body, compressed)
+}
+
+#[test]
+fn test_compressed_responses() -> Result<()> {
+    for encoding in &["gzip", "deflate"] {
+        let (body, compressed) = compressed_member(encoding);
+        let _m = mockito::mock("GET", "/1/members/me/?key=gzip-key&token=gzip-token")
+            .match_header("accept-encoding", "gzip, deflate")
+            .with_status(200)
+            .with_header("content-encoding", encoding)
+            .with_body(&compressed)
+            .create();
+
+        let config = ClientConfig::new(&mockito::server_url(), "gzip-token", "gzip-key");
+        let client = TrelloClient::new(config);
+
+        assert_eq!(client.me()?.username, "michael");
+        assert_eq!(
+            client.transfer_stats(),
+            TransferStats {
+                responses: 1,
+                transferred: compressed.len() as u64,
+                decoded: body.len() as u64,
+            }
+        );
+        assert!(client.transfer_stats().saved() > 900);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_response_body_size() -> Result<()> {
+    let (body, compressed) = compressed_member("gzip");
+    let _m = mockito::mock("GET", "/1/members/me/?key=size-key&token=size-token")
+        .with_status(200)
+        .with_header("content-encoding", "gzip")
+        .with_body(&compressed)
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "size-token", "size-key");
+    let client = TrelloClient::new(config);
+
+    let response = Member::me_with_response(&client)?;
+    assert_eq!(
+        response.size,
+        Some(BodySize {
+            transferred: compressed.len() as u64,
+            decoded: body.len() as u64,
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn test_send_with_response() -> Result<()> {
+    let body = json!({
+        "id": "MEMBER-ID",
+        "fullName": "Michael",
+        "username": "michael",
+    })
+    .to_string();
+
+    let _m = mockito::mock("GET", "/1/members/me/?key=KEY&token=TOKEN")
+        .with_status(200)
+        .with_body(&body)
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "TOKEN", "KEY");
+    let client = TrelloClient::new(config);
+
+    let url = client.config.get_trello_url("/1/members/me/", &[])?;
+    let result: Response<Member> = client.send_with_response(client.get(url))?;
+
+    assert_eq!(result.status, reqwest::StatusCode::OK);
+    assert_eq!(result.body, body);
+    assert_eq!(result.value.username, "michael");
+    Ok(())
+}
+
+#[test]
+fn test_send_with_response_mismatch() -> Result<()> {
+    let _m = mockito::mock("GET", "/1/members/me/?key=KEY&token=TOKEN")
+        .with_status(200)
+        .with_body(json!({"id": "MEMBER-ID"}).to_string())
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "TOKEN", "KEY");
+    let client = TrelloClient::new(config);
+
+    let url = client.config.get_trello_url("/1/members/me/", &[])?;
+    match client.send_with_response::<Member>(client.get(url)) {
+        Err(TrelloError::Deserialize { body, .. }) => assert_eq!(body, r#"{"id":"MEMBER-ID"}"#),
+        other => panic!("Unexpected result: {:?}", other),
+    }
+    Ok(())
+}
+
+#[test]
+fn test_get_json() -> Result<()> {
+    #[derive(serde::Deserialize)]
+    struct Checklist {
+        name: String,
+    }
+
+    let _m = mockito::mock(
+        "GET",
+        "/1/boards/JSON-BOARD/checklists?key=KEY&token=TOKEN&fields=name",
+    )
+    .with_status(200)
+    .with_body(json!([{"id": "CL1", "name": "Release"}]).to_string())
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "TOKEN", "KEY");
+    let client = TrelloClient::new(config);
+
+    let result: Vec<Checklist> =
+        client.get_json("/1/boards/JSON-BOARD/checklists", &[("fields", "name")])?;
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].name, "Release");
+    Ok(())
+}
+
+#[test]
+fn test_post_put_delete_json() -> Result<()> {
+    let _post = mockito::mock("POST", "/1/checklists?key=KEY&token=TOKEN")
+        .match_body("idCard=CARD&name=Release")
+        .with_status(200)
+        .with_body(json!({"id": "CL1", "name": "Release"}).to_string())
+        .create();
+    let _put = mockito::mock("PUT", "/1/checklists/CL1?key=KEY&token=TOKEN")
+        .match_body("name=Launch")
+        .with_status(200)
+        .with_body(json!({"id": "CL1", "name": "Launch"}).to_string())
+        .create();
+    let _delete = mockito::mock("DELETE", "/1/checklists/CL1?key=KEY&token=TOKEN")
+        .with_status(200)
+        .with_body(json!({"limits": {}}).to_string())
+        .create();
+    let _missing = mockito::mock("DELETE", "/1/checklists/CL2?key=KEY&token=TOKEN")
+        .with_status(404)
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "TOKEN", "KEY");
+    let client = TrelloClient::new(config);
+
+    let created: serde_json::Value =
+        client.post_json("/1/checklists", &[("idCard", "CARD"), ("name", "Release")])?;
+    assert_eq!(created["name"], "Release");
+
+    let updated: serde_json::Value = client.put_json("/1/checklists/CL1", &[("name", "Launch")])?;
+    assert_eq!(updated["name"], "Launch");
+
+    let _: serde_json::Value = client.delete_json("/1/checklists/CL1", &[], None)?;
+    assert!(client
+        .delete_json::<serde_json::Value>("/1/checklists/CL2", &[], None)
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_delete_json_safety_policy() -> Result<()> {
+    let delete = mockito::mock("DELETE", "/1/checklists/SAFE-CL?key=KEY&token=TOKEN")
+        .with_status(200)
+        .with_body(json!({"limits": {}}).to_string())
+        .expect(1)
+        .create();
+
+    let config = || ClientConfig::new(&mockito::server_url(), "TOKEN", "KEY");
+    let archive_only = TrelloClient::builder(config())
+        .safety_policy(SafetyPolicy::ArchiveOnly)
+        .build()?;
+    let confirm = TrelloClient::builder(config())
+        .safety_policy(SafetyPolicy::RequireConfirmationToken)
+        .build()?;
+
+    let refused = |client: &TrelloClient, token: Option<&ConfirmationToken>| {
+        matches!(
+            client.delete_json::<serde_json::Value>("/1/checklists/SAFE-CL", &[], token),
+            Err(TrelloError::DeletionNotPermitted(_))
+        )
+    };
+    assert!(refused(&archive_only, None));
+    assert!(refused(&confirm, None));
+    assert!(refused(&confirm, Some(&confirm.confirm_deletion("OTHER"))));
+
+    let token = confirm.confirm_deletion("SAFE-CL");
+    let _: serde_json::Value = confirm.delete_json("/1/checklists/SAFE-CL", &[], Some(&token))?;
+
+    delete.assert();
+    Ok(())
+}
+
+#[test]
+fn test_config_from_env() -> Result<()> {
+    let vars = |name: &str| match name {
+        "TRELLO_API_KEY" => Some(String::from("0123456789abcdef0123456789ABCDEF")),
+        "TRELLO_API_TOKEN" => Some(String::from(" ATTAsecret123 ")),
+        _ => None,
+    };
+
+    let config = ClientConfig::from_env_with(vars)?;
+    assert_eq!(config.host, "https://api.trello.com");
+    assert_eq!(config.key, "0123456789abcdef0123456789ABCDEF");
+    assert_eq!(config.token, "ATTAsecret123");
+    Ok(())
+}
+
+#[test]
+fn test_config_from_env_errors() {
+    let vars = |name: &str| match name {
+        "TRELLO_API_KEY" => Some(String::from("not-a-key")),
+        "TRELLO_API_HOST" => Some(String::from("ftp://example.com")),
+        _ => None,
+    };
+
+    match ClientConfig::from_env_with(vars) {
+        Err(TrelloError::Environment(message)) => assert_eq!(
+            message,
+            "TRELLO_API_KEY is malformed (expected 32 hexadecimal characters), \
+             TRELLO_API_TOKEN is not set, \
+             TRELLO_API_HOST is not a valid http(s) url"
+        ),
+        other => panic!("Expected an environment error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_debug_redacts_credentials() {
+    let config = ClientConfig::new("https://api.trello.com", "very-secret-token", "secret-key");
+    let client = TrelloClient::new(config);
+
+    let output = format!("{:?}", client);
+    assert!(output.contains("https://api.trello.com"));
+    assert!(!output.contains("very-secret-token"));
+    assert!(!output.contains("secret-key"));
+}
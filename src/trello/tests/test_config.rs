@@ -0,0 +1,71 @@
+use super::*;
+
+use config::{Profile, Profiles};
+
+#[test]
+fn test_add_sets_current() {
+    let mut profiles = Profiles::default();
+    profiles.add(
+        "work",
+        Profile::new("https://foo.com", "TOKEN", "KEY", None),
+    );
+    profiles.add("personal", Profile::new("https://bar.com", "T", "K", None));
+
+    assert_eq!(profiles.current, Some(String::from("work")));
+    assert_eq!(profiles.list(), vec!["personal", "work"]);
+}
+
+#[test]
+fn test_switch() -> std::result::Result<(), Box<dyn std::error::Error>> {
+    let mut profiles = Profiles::default();
+    profiles.add(
+        "work",
+        Profile::new("https://foo.com", "TOKEN", "KEY", None),
+    );
+    profiles.add("personal", Profile::new("https://bar.com", "T", "K", None));
+
+    profiles.switch("personal")?;
+    assert_eq!(profiles.current()?.host, "https://bar.com");
+
+    assert!(profiles.switch("idontexist").is_err());
+    assert_eq!(profiles.current, Some(String::from("personal")));
+    Ok(())
+}
+
+#[test]
+fn test_remove_current() -> std::result::Result<(), Box<dyn std::error::Error>> {
+    let mut profiles = Profiles::default();
+    profiles.add(
+        "work",
+        Profile::new("https://foo.com", "TOKEN", "KEY", None),
+    );
+
+    profiles.remove("work")?;
+    assert_eq!(profiles, Profiles::default());
+    assert!(profiles.remove("work").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_save_and_load() -> std::result::Result<(), Box<dyn std::error::Error>> {
+    let file = NamedTempFile::new()?;
+
+    let mut profiles = Profiles::default();
+    profiles.add(
+        "work",
+        Profile::new("https://foo.com", "TOKEN", "KEY", Some("Sprint")),
+    );
+    profiles.save_to(file.path())?;
+
+    let result = Profiles::load_from(file.path())?;
+    assert_eq!(result, profiles);
+    assert_eq!(
+        result
+            .get("work")?
+            .client_config()
+            .get_trello_url("/1/", &[])?
+            .to_string(),
+        "https://foo.com/1/?key=KEY&token=TOKEN"
+    );
+    Ok(())
+}
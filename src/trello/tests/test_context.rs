@@ -0,0 +1,44 @@
+use super::*;
+
+use context::Context;
+
+#[test]
+fn test_set_board_clears_list() -> std::result::Result<(), Box<dyn std::error::Error>> {
+    let mut context = Context::default();
+    assert!(context.set_list("Todo").is_err());
+
+    context.set_board("Sprint");
+    context.set_list("Todo")?;
+    assert_eq!(context.list, Some(String::from("Todo")));
+
+    context.set_board("Backlog");
+    assert_eq!(context.board, Some(String::from("Backlog")));
+    assert_eq!(context.list, None);
+    Ok(())
+}
+
+#[test]
+fn test_board_or() -> std::result::Result<(), Box<dyn std::error::Error>> {
+    let mut context = Context::default();
+    assert!(context.board_or(None).is_err());
+    assert!(context.list_or(None).is_err());
+
+    context.set_board("Sprint");
+    context.set_list("Todo")?;
+    assert_eq!(context.board_or(None)?, "Sprint");
+    assert_eq!(context.board_or(Some("Other"))?, "Other");
+    assert_eq!(context.list_or(None)?, "Todo");
+    Ok(())
+}
+
+#[test]
+fn test_save_and_load() -> std::result::Result<(), Box<dyn std::error::Error>> {
+    let file = NamedTempFile::new()?;
+
+    let mut context = Context::default();
+    context.set_board("Sprint");
+    context.save_to(file.path())?;
+
+    assert_eq!(Context::load_from(file.path())?, context);
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use super::*;
+
+use chrono::{TimeZone, Utc};
+use dates::{parse_human_at, take_due_at};
+
+#[test]
+fn test_parse_human() -> Result<()> {
+    // a sunday
+    let now = Utc.ymd(2020, 6, 28).and_hms(9, 30, 0);
+
+    assert_eq!(
+        parse_human_at("next friday 5pm", now)?,
+        Utc.ymd(2020, 7, 3).and_hms(17, 0, 0)
+    );
+    assert_eq!(
+        parse_human_at("tomorrow 8:30", now)?,
+        Utc.ymd(2020, 6, 29).and_hms(8, 30, 0)
+    );
+    assert_eq!(
+        parse_human_at("2020-07-01T12:00:00+02:00", now)?,
+        Utc.ymd(2020, 7, 1).and_hms(10, 0, 0)
+    );
+    assert!(matches!(
+        parse_human_at("someday maybe", now),
+        Err(TrelloError::DateParse(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_take_due() -> Result<()> {
+    let now = Utc.ymd(2020, 6, 28).and_hms(9, 30, 0);
+
+    let mut contents = CardContents {
+        name: String::from("Laundry"),
+        desc: String::from("Due: tomorrow 5pm\nWhites only"),
+    };
+    assert_eq!(
+        take_due_at(&mut contents, now)?,
+        Some(Utc.ymd(2020, 6, 29).and_hms(17, 0, 0))
+    );
+    assert_eq!(contents.desc, "Whites only");
+
+    let mut contents = CardContents {
+        name: String::from("Laundry"),
+        desc: String::from("Whites only\nDue: tomorrow"),
+    };
+    assert_eq!(take_due_at(&mut contents, now)?, None);
+    assert_eq!(contents.desc, "Whites only\nDue: tomorrow");
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use super::*;
+
+fn board() -> Board {
+    Board::new(
+        "B",
+        "Release",
+        Some(vec![
+            List::new(
+                "L1",
+                "Todo",
+                Some(vec![
+                    Card::new(
+                        "C1",
+                        "Ship \"it\"",
+                        "Final step\ndepends: https://trello.com/c/SHORT2/2-build, C3",
+                        None,
+                        "https://trello.com/c/SHORT1/1-ship",
+                        None,
+                    ),
+                    Card::new(
+                        "C2",
+                        "Build",
+                        "Depends: SHORT3\ndepends: unknown",
+                        None,
+                        "https://trello.com/c/SHORT2/2-build",
+                        None,
+                    ),
+                ]),
+            ),
+            List::new(
+                "L2",
+                "Done",
+                Some(vec![
+                    Card::new(
+                        "C3",
+                        "Design",
+                        "",
+                        None,
+                        "https://trello.com/c/SHORT3/3-design",
+                        None,
+                    ),
+                    Card::new("C4", "Unrelated", "", None, "", None),
+                ]),
+            ),
+        ]),
+        "",
+    )
+}
+
+#[test]
+fn test_card_dependencies() {
+    let card = Card::new(
+        "",
+        "",
+        "text\n  DEPENDS: a, b ,\ndepends on nothing",
+        None,
+        "https://trello.com/c/abc/1-x",
+        None,
+    );
+    assert_eq!(card.dependencies(), vec!["a", "b"]);
+    assert_eq!(card.short_link(), Some("abc"));
+    assert_eq!(Card::new("", "", "", None, "", None).short_link(), None);
+}
+
+#[test]
+fn test_dependency_graph() {
+    let graph = board().dependency_graph();
+
+    assert_eq!(graph.graph.node_count(), 4);
+    assert_eq!(graph.graph.edge_count(), 3);
+
+    let ship = graph
+        .graph
+        .node_indices()
+        .find(|n| graph.graph[*n].card.id == "C1")
+        .unwrap();
+    let mut deps = graph
+        .dependencies_of(ship)
+        .iter()
+        .map(|n| n.card.id.as_str())
+        .collect::<Vec<&str>>();
+    deps.sort_unstable();
+    assert_eq!(deps, vec!["C2", "C3"]);
+}
+
+#[test]
+fn test_to_dot() {
+    let expected = [
+        "digraph dependencies {",
+        r#"    "C1" [label="Ship \"it\"\n(Todo)"];"#,
+        r#"    "C2" [label="Build\n(Todo)"];"#,
+        r#"    "C3" [label="Design\n(Done)"];"#,
+        r#"    "C1" -> "C2";"#,
+        r#"    "C1" -> "C3";"#,
+        r#"    "C2" -> "C3";"#,
+        "}",
+    ]
+    .join("\n");
+    assert_eq!(board().dependency_graph().to_dot(), expected);
+}
@@ -0,0 +1,128 @@
+use super::*;
+
+use colored::*;
+use diff::{board_diff, Change};
+
+fn board(lists: Vec<List>) -> Board {
+    Board::new("board", "Knights", Some(lists), "")
+}
+
+fn card(id: &str, name: &str) -> Card {
+    Card::new(id, name, "", None, "", None)
+}
+
+#[test]
+fn test_no_changes() {
+    let old = board(vec![List::new("1", "King", Some(vec![card("a", "Boots")]))]);
+    let diff = board_diff(&old, &old.clone());
+
+    assert!(diff.is_empty());
+    assert_eq!(
+        diff.render(),
+        ["Knights".bold().to_string(), String::from("No changes")].join("\n")
+    );
+}
+
+#[test]
+fn test_changes() {
+    let old = board(vec![
+        List::new(
+            "1",
+            "King",
+            Some(vec![card("a", "Gyro Boots"), card("b", "Propeller Rat")]),
+        ),
+        List::new("2", "Shovel", Some(vec![card("c", "Fishing Rod")])),
+    ]);
+    let new = board(vec![
+        List::new("1", "King Knight", Some(vec![card("d", "Troupple")])),
+        List::new(
+            "3",
+            "Specter",
+            Some(vec![card("a", "Gyro Boots"), card("b", "Propeller Hat")]),
+        ),
+    ]);
+
+    let diff = board_diff(&old, &new);
+
+    assert_eq!(
+        diff.lists,
+        vec![
+            Change::Renamed {
+                from: String::from("King"),
+                to: String::from("King Knight"),
+            },
+            Change::Added {
+                name: String::from("Specter"),
+            },
+            Change::Removed {
+                name: String::from("Shovel"),
+            },
+        ]
+    );
+    assert_eq!(
+        diff.cards,
+        vec![
+            Change::Added {
+                name: String::from("Troupple"),
+            },
+            Change::Moved {
+                name: String::from("Gyro Boots"),
+                from: String::from("King"),
+                to: String::from("Specter"),
+            },
+            Change::Renamed {
+                from: String::from("Propeller Rat"),
+                to: String::from("Propeller Hat"),
+            },
+            Change::Moved {
+                name: String::from("Propeller Hat"),
+                from: String::from("King"),
+                to: String::from("Specter"),
+            },
+            Change::Removed {
+                name: String::from("Fishing Rod"),
+            },
+        ]
+    );
+    assert_eq!(
+        diff.simple_render(),
+        "Knights: 3 list changes, 5 card changes"
+    );
+}
+
+#[test]
+fn test_list_renamed() {
+    let old = board(vec![List::new("1", "King", Some(vec![card("a", "Boots")]))]);
+    let new = board(vec![List::new(
+        "1",
+        "King Knight",
+        Some(vec![card("a", "Boots")]),
+    )]);
+
+    let diff = board_diff(&old, &new);
+
+    assert_eq!(
+        diff.lists,
+        vec![Change::Renamed {
+            from: String::from("King"),
+            to: String::from("King Knight"),
+        }]
+    );
+    assert_eq!(diff.cards, vec![]);
+}
+
+#[test]
+fn test_render() {
+    let old = board(vec![List::new("1", "King", Some(vec![card("a", "Boots")]))]);
+    let new = board(vec![List::new("1", "King", Some(vec![]))]);
+
+    #[rustfmt::skip]
+    let expected = [
+        "Knights".bold().to_string(),
+        String::from(""),
+        "Cards\n-----".bold().to_string(),
+        "- Boots".red().to_string(),
+    ].join("\n");
+
+    assert_eq!(board_diff(&old, &new).render(), expected);
+}
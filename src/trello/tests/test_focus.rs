@@ -0,0 +1,127 @@
+use super::*;
+
+use chrono::{Duration, TimeZone, Utc};
+use focus::{format_duration, FocusReport, FocusSession, FocusTimer};
+
+fn session(card_id: &str, minutes: i64) -> FocusSession {
+    let start = Utc.ymd(2020, 6, 28).and_hms(6, 0, 0);
+    FocusTimer::start_at(card_id, start).finish_at(start + Duration::minutes(minutes))
+}
+
+#[test]
+fn test_comment_round_trip() {
+    let session = session("CARD-1", 25);
+
+    assert_eq!(
+        session.to_comment(),
+        "Focus session: 25 minutes\nstart: 2020-06-28T06:00:00+00:00\nend: 2020-06-28T06:25:00+00:00"
+    );
+    assert_eq!(
+        FocusSession::from_comment("CARD-1", &session.to_comment()),
+        Some(session)
+    );
+    assert_eq!(FocusSession::from_comment("CARD-1", "Looks good"), None);
+    assert_eq!(
+        FocusSession::from_comment("CARD-1", "Focus session: 5 minutes\nstart: soon"),
+        None
+    );
+}
+
+#[test]
+fn test_format_duration() {
+    assert_eq!(format_duration(Duration::minutes(1)), "1 minute");
+    assert_eq!(format_duration(Duration::minutes(60)), "1 hour");
+    assert_eq!(format_duration(Duration::minutes(125)), "2 hours 5 minutes");
+}
+
+#[test]
+fn test_report_from_comments() {
+    let first = session("CARD-1", 25).to_comment();
+    let second = session("CARD-2", 50).to_comment();
+    let third = session("CARD-1", 50).to_comment();
+
+    let report = FocusReport::from_comments(
+        "Knights",
+        vec![
+            ("CARD-1", "Gyro Boots", first.as_str()),
+            ("CARD-2", "Propeller Rat", second.as_str()),
+            ("CARD-2", "Propeller Rat", "Not a session"),
+            ("CARD-1", "Gyro Boots", third.as_str()),
+        ],
+    );
+
+    let totals = report
+        .cards
+        .iter()
+        .map(|c| (c.card_name.as_str(), c.sessions, c.total.num_minutes()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        totals,
+        vec![("Gyro Boots", 2, 75), ("Propeller Rat", 1, 50)]
+    );
+    assert_eq!(report.simple_render(), "Knights: 2 hours 5 minutes");
+}
+
+#[test]
+fn test_record() -> Result<()> {
+    let _m = mockito::mock(
+        "POST",
+        "/1/cards/CARD-1/actions/comments?key=some-key&token=some-token",
+    )
+    .match_body(mockito::Matcher::Regex(String::from(
+        "^text=Focus\\+session%3A\\+25\\+minutes%0Astart",
+    )))
+    .with_status(200)
+    .with_body(json!({"id": "action-1"}).to_string())
+    .expect(1)
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    session("CARD-1", 25).record(&client)?;
+
+    _m.assert();
+    Ok(())
+}
+
+#[test]
+fn test_for_board() -> Result<()> {
+    let comment = |card_id: &str, name: &str, text: String| {
+        json!({
+            "id": "action",
+            "idMemberCreator": "member",
+            "type": "commentCard",
+            "date": "2020-06-28T07:00:00.000Z",
+            "data": {"card": {"id": card_id, "name": name}, "text": text},
+        })
+    };
+    let _m = mockito::mock(
+        "GET",
+        "/1/boards/BOARD-1/actions?key=some-key&token=some-token&filter=commentCard&limit=1000",
+    )
+    .with_status(200)
+    .with_body(
+        json!([
+            comment("CARD-1", "Gyro Boots", session("CARD-1", 25).to_comment()),
+            comment("CARD-2", "Propeller Rat", String::from("Nice")),
+            comment(
+                "CARD-2",
+                "Propeller Rat",
+                session("CARD-2", 30).to_comment()
+            ),
+        ])
+        .to_string(),
+    )
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let report = FocusReport::for_board(&client, "BOARD-1")?;
+
+    assert_eq!(report.cards.len(), 2);
+    assert_eq!(report.cards[0].card_name, "Propeller Rat");
+    assert_eq!(report.total(), Duration::minutes(55));
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use super::*;
 
-use formatting::header;
+use chrono::{Duration, TimeZone, Utc};
+use formatting::{header, line_diff, relative_due};
 
 #[test]
 fn test_empty() {
@@ -19,3 +20,53 @@ fn test_emoticons() {
     let result = header("foo 🔴", "-");
     assert_eq!(result, String::from("foo 🔴\n------"));
 }
+
+#[test]
+fn test_unique_prefix_len() {
+    use crate::formatting::unique_prefix_len;
+
+    assert_eq!(unique_prefix_len(vec![], 4), 4);
+    assert_eq!(unique_prefix_len(vec!["5f0a1b", "5f0a2c"], 4), 5);
+    assert_eq!(unique_prefix_len(vec!["5f0a1b", "6f0a2c"], 4), 4);
+    assert_eq!(unique_prefix_len(vec!["5f0a1b", "5f0a1b"], 2), 2);
+    assert_eq!(unique_prefix_len(vec!["ab", "abc"], 1), 3);
+}
+
+#[test]
+fn test_relative_due() {
+    let now = Utc.ymd(2020, 6, 28).and_hms(12, 0, 0);
+
+    assert_eq!(relative_due(&now, &now), "due now");
+    assert_eq!(
+        relative_due(&(now + Duration::minutes(45)), &now),
+        "due in 45m"
+    );
+    assert_eq!(
+        relative_due(&(now - Duration::hours(3)), &now),
+        "overdue by 3h"
+    );
+    assert_eq!(
+        relative_due(&(now + Duration::hours(30)), &now),
+        "due in 1 day"
+    );
+    assert_eq!(
+        relative_due(&(now - Duration::days(12)), &now),
+        "overdue by 12 days"
+    );
+}
+
+#[test]
+fn test_line_diff() {
+    let result = line_diff("one\ntwo\nthree", "one\n2\nthree\nfour");
+    assert_eq!(
+        result,
+        vec![
+            (' ', "one"),
+            ('-', "two"),
+            ('+', "2"),
+            (' ', "three"),
+            ('+', "four"),
+        ]
+    );
+    assert_eq!(line_diff("", "new"), vec![('+', "new")]);
+}
@@ -0,0 +1,87 @@
+use super::*;
+
+use bridges::git::{card_refs, link_commit, link_mentioned_cards, Commit};
+
+#[test]
+fn test_card_refs() {
+    let message = [
+        "Fix login timeout (https://trello.com/c/a1B2c3D4)",
+        "",
+        "Also see trello.com/c/a1B2c3D4/12-login and",
+        "https://trello.com/b/Zz9Yy8Xx for the board.",
+        "",
+        "Trello: e5F6g7H8",
+        "trello:   i9J0k1L2  ",
+        "Not a trailer: Trello: m3N4o5P6",
+    ]
+    .join("\n");
+
+    assert_eq!(
+        card_refs(&message),
+        vec!["a1B2c3D4", "e5F6g7H8", "i9J0k1L2"]
+    );
+    assert!(card_refs("Bump version").is_empty());
+}
+
+#[test]
+fn test_comment() {
+    let commit = Commit::new(
+        "0123456789abcdef0123456789abcdef01234567",
+        "Fix login timeout\n\n  Retry the request once\n",
+    );
+    assert_eq!(
+        commit.comment(),
+        "Commit `0123456`\n\n> Fix login timeout\n>\n>   Retry the request once"
+    );
+
+    let commit = commit.repo_url("https://github.com/iesahin/tro/");
+    assert_eq!(
+        commit.comment(),
+        "Commit [0123456](https://github.com/iesahin/tro/commit/0123456789abcdef0123456789abcdef01234567) \
+         in https://github.com/iesahin/tro\n\n> Fix login timeout\n>\n>   Retry the request once"
+    );
+}
+
+#[test]
+fn test_link_commit() -> Result<()> {
+    let _m = mockito::mock(
+        "POST",
+        "/1/cards/a1B2c3D4/actions/comments?key=some-key&token=some-token",
+    )
+    .match_body("text=Commit+%600123456%60%0A%0A%3E+Fix+login")
+    .with_status(200)
+    .with_body(json!({"id": "action-1"}).to_string())
+    .expect(2)
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+    let commit = Commit::new("0123456789", "Fix login");
+
+    link_commit(&client, "https://trello.com/c/a1B2c3D4/12-login", &commit)?;
+    link_commit(&client, "a1B2c3D4", &commit)?;
+
+    _m.assert();
+    Ok(())
+}
+
+#[test]
+fn test_link_mentioned_cards() -> Result<()> {
+    let _m = mockito::mock(
+        "POST",
+        "/1/cards/e5F6g7H8/actions/comments?key=some-key&token=some-token",
+    )
+    .with_status(200)
+    .with_body(json!({"id": "action-1"}).to_string())
+    .expect(1)
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+    let commit = Commit::new("0123456789", "Fix login\n\nTrello: e5F6g7H8");
+
+    assert_eq!(link_mentioned_cards(&client, &commit)?, vec!["e5F6g7H8"]);
+
+    _m.assert();
+    Ok(())
+}
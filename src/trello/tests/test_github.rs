@@ -0,0 +1,134 @@
+use super::*;
+
+use bridges::github::{import_issues, GitHubClient, ImportSummary};
+
+#[test]
+fn test_get_issues() -> Result<()> {
+    let _m = mockito::mock(
+        "GET",
+        "/repos/knights/shovel/issues?state=open&per_page=100&page=1",
+    )
+    .match_header("authorization", "token gh-token")
+    .with_status(200)
+    .with_body(
+        json!([
+            {
+                "number": 1,
+                "title": "Dig",
+                "body": null,
+                "html_url": "https://github.com/knights/shovel/issues/1",
+                "labels": [{"name": "bug"}],
+            },
+            {
+                "number": 2,
+                "title": "Add more digging",
+                "body": "",
+                "html_url": "https://github.com/knights/shovel/pull/2",
+                "labels": [],
+                "pull_request": {},
+            },
+        ])
+        .to_string(),
+    )
+    .create();
+
+    let github = GitHubClient::new(Some("gh-token")).api_url(&mockito::server_url());
+    let issues = github.get_issues("knights/shovel")?;
+
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].number, 1);
+    assert_eq!(issues[0].labels[0].name, "bug");
+    Ok(())
+}
+
+#[test]
+fn test_import_issues() -> Result<()> {
+    let issues: Vec<bridges::github::Issue> = serde_json::from_value(json!([
+        {
+            "number": 1,
+            "title": "Dig",
+            "body": "Dig deeper",
+            "html_url": "https://github.com/knights/shovel/issues/1",
+            "labels": [{"name": "Bug"}, {"name": "unknown"}],
+        },
+        {
+            "number": 2,
+            "title": "Jump",
+            "body": null,
+            "html_url": "https://github.com/knights/shovel/issues/2",
+            "labels": [],
+        },
+        {
+            "number": 3,
+            "title": "Bounce higher",
+            "body": null,
+            "html_url": "https://github.com/knights/shovel/issues/3",
+            "labels": [],
+        },
+    ]))
+    .unwrap();
+
+    let _cards = mockito::mock(
+        "GET",
+        "/1/boards/GH-BOARD/cards?key=gh-key&token=gh-token&filter=all&fields=id%2Cname%2Cdesc%2Clabels%2Cclosed%2Cdue%2Curl%2Csubscribed%2CdateLastActivity%2Cbadges%2CidMembers%2CidShort",
+    )
+    .with_status(200)
+    .with_body(
+        json!([
+            {
+                "id": "JUMP", "name": "Jump", "closed": false, "url": "",
+                "desc": "GitHub issue: knights/shovel#2 (https://github.com/knights/shovel/issues/2)",
+            },
+            {
+                "id": "BOUNCE", "name": "Bounce", "closed": false, "url": "",
+                "desc": "GitHub issue: knights/shovel#3 (https://github.com/knights/shovel/issues/3)",
+            },
+        ])
+        .to_string(),
+    )
+    .create();
+    let _labels = mockito::mock(
+        "GET",
+        "/1/boards/GH-BOARD/labels?key=gh-key&token=gh-token&fields=id%2Cname%2Ccolor",
+    )
+    .with_status(200)
+    .with_body(json!([{"id": "BUG-LABEL", "name": "bug", "color": "red"}]).to_string())
+    .create();
+    let _create = mockito::mock("POST", "/1/cards/?key=gh-key&token=gh-token")
+        .match_body(
+            "name=Dig&desc=Dig+deeper%0A%0AGitHub+issue%3A+knights%2Fshovel%231+%28https%3A%2F%2Fgithub.com%2Fknights%2Fshovel%2Fissues%2F1%29&idList=GH-LIST",
+        )
+        .with_status(200)
+        .with_body(json!({"id": "DIG", "name": "Dig", "desc": "", "closed": false, "url": ""}).to_string())
+        .create();
+    let _apply = mockito::mock("POST", "/1/cards/DIG/idLabels?key=gh-key&token=gh-token")
+        .match_body("value=BUG-LABEL")
+        .with_status(200)
+        .with_body("[]")
+        .create();
+    let _update = mockito::mock("PUT", "/1/cards/BOUNCE/?key=gh-key&token=gh-token")
+        .match_body(mockito::Matcher::Regex(String::from("^name=Bounce\\+higher&desc=")))
+        .with_status(200)
+        .with_body(
+            json!({"id": "BOUNCE", "name": "Bounce higher", "desc": "", "closed": false, "url": ""})
+                .to_string(),
+        )
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "gh-token", "gh-key");
+    let client = TrelloClient::new(config);
+
+    let summary = import_issues(&client, "GH-BOARD", "GH-LIST", "knights/shovel", &issues)?;
+
+    assert_eq!(
+        summary,
+        ImportSummary {
+            created: 1,
+            updated: 1,
+            unchanged: 1,
+        }
+    );
+    _apply.assert();
+    _update.assert();
+    Ok(())
+}
@@ -0,0 +1,304 @@
+use super::*;
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use diff::Change;
+use history::{Frequency, HistoryStore};
+use std::fs;
+use webhook::Action;
+
+fn board(cards: Vec<Card>) -> Board {
+    Board::new(
+        "BOARD-1",
+        "Knights",
+        Some(vec![List::new("LIST-1", "King", Some(cards))]),
+        "",
+    )
+}
+
+#[test]
+fn test_save_and_list() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let store = HistoryStore::new(dir.path());
+
+    assert!(store.list("BOARD-1")?.is_empty());
+    assert_eq!(store.latest("BOARD-1")?, None);
+
+    let first = board(vec![Card::new("1", "Gyro Boots", "", None, "", None)]);
+    let second = board(vec![Card::new("2", "Propeller Rat", "", None, "", None)]);
+    store.save_at(&second, Utc.ymd(2020, 6, 28).and_hms(6, 0, 0))?;
+    store.save_at(&first, Utc.ymd(2020, 6, 27).and_hms(6, 0, 0))?;
+    fs::write(
+        dir.path().join("BOARD-1").join("notes.txt"),
+        "not a snapshot",
+    )?;
+
+    let snapshots = store.list("BOARD-1")?;
+    let times = snapshots.iter().map(|s| s.taken_at).collect::<Vec<_>>();
+    assert_eq!(
+        times,
+        vec![
+            Utc.ymd(2020, 6, 27).and_hms(6, 0, 0),
+            Utc.ymd(2020, 6, 28).and_hms(6, 0, 0)
+        ]
+    );
+    assert_eq!(store.load(&snapshots[0])?, first);
+    assert_eq!(store.latest("BOARD-1")?, Some(second));
+    Ok(())
+}
+
+#[test]
+fn test_daily_frequency() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let store = HistoryStore::new(dir.path()).frequency(Frequency::Daily);
+
+    store.save_at(&board(vec![]), Utc.ymd(2020, 6, 27).and_hms(23, 0, 0))?;
+    store.save_at(&board(vec![]), Utc.ymd(2020, 6, 28).and_hms(6, 0, 0))?;
+    store.save_at(&board(vec![]), Utc.ymd(2020, 6, 28).and_hms(18, 0, 0))?;
+
+    let times = store
+        .list("BOARD-1")?
+        .iter()
+        .map(|s| s.taken_at)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        times,
+        vec![
+            Utc.ymd(2020, 6, 27).and_hms(23, 0, 0),
+            Utc.ymd(2020, 6, 28).and_hms(18, 0, 0)
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_prune() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let store = HistoryStore::new(dir.path());
+
+    for day in 1..=3 {
+        store.save_at(&board(vec![]), Utc.ymd(2020, 6, day).and_hms(0, 0, 0))?;
+    }
+
+    let now = Utc.ymd(2020, 6, 2).and_hms(12, 0, 0);
+    assert_eq!(store.prune_at("BOARD-1", Duration::days(1), now)?, 1);
+    assert_eq!(store.list("BOARD-1")?.len(), 2);
+
+    // the latest snapshot is kept, however old
+    let later = Utc.ymd(2021, 1, 1).and_hms(0, 0, 0);
+    assert_eq!(store.prune_at("BOARD-1", Duration::days(1), later)?, 1);
+    assert_eq!(store.list("BOARD-1")?.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_diff() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let store = HistoryStore::new(dir.path());
+
+    let old = store.save_at(
+        &board(vec![Card::new("1", "Gyro Boots", "", None, "", None)]),
+        Utc.ymd(2020, 6, 27).and_hms(0, 0, 0),
+    )?;
+    let new = store.save_at(
+        &board(vec![Card::new("1", "Gyro Boots+", "", None, "", None)]),
+        Utc.ymd(2020, 6, 28).and_hms(0, 0, 0),
+    )?;
+
+    let diff = store.diff(&old, &new)?;
+    assert_eq!(
+        diff.cards,
+        vec![Change::Renamed {
+            from: String::from("Gyro Boots"),
+            to: String::from("Gyro Boots+"),
+        }]
+    );
+    Ok(())
+}
+
+fn action(action_type: &str, date: DateTime<Utc>, data: serde_json::Value) -> Action {
+    serde_json::from_value(json!({
+        "id": format!("{}-{}", action_type, date.timestamp()),
+        "idMemberCreator": "MEMBER-1",
+        "type": action_type,
+        "date": date.to_rfc3339(),
+        "data": data,
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_rewind() {
+    let at = Utc.ymd(2020, 6, 27).and_hms(0, 0, 0);
+    let mut board = Board::new(
+        "BOARD-1",
+        "Knights",
+        Some(vec![
+            List::new(
+                "LIST-1",
+                "King",
+                Some(vec![
+                    Card::new("1", "Gyro Boots+", "", None, "", None),
+                    Card::new("2", "Propeller Rat", "", None, "", None),
+                ]),
+            ),
+            List::new(
+                "LIST-2",
+                "Queen",
+                Some(vec![Card::new("3", "Black Cat", "", None, "", None)]),
+            ),
+        ]),
+        "",
+    );
+    let mut archived = Card::new("4", "Monkey Bomb", "Bananas", None, "", None);
+    archived.closed = true;
+
+    // newest first, the last one happened before `at`
+    let actions = vec![
+        action(
+            "updateCard",
+            Utc.ymd(2020, 6, 28).and_hms(12, 0, 0),
+            json!({
+                "card": {"id": "1", "name": "Gyro Boots+"},
+                "old": {"name": "Gyro Boots", "desc": "Jump"},
+            }),
+        ),
+        action(
+            "updateCard",
+            Utc.ymd(2020, 6, 28).and_hms(11, 0, 0),
+            json!({
+                "card": {"id": "3", "name": "Black Cat"},
+                "listBefore": {"id": "LIST-1", "name": "King"},
+                "listAfter": {"id": "LIST-2", "name": "Queen"},
+                "old": {"idList": "LIST-1"},
+            }),
+        ),
+        action(
+            "updateCard",
+            Utc.ymd(2020, 6, 28).and_hms(10, 0, 0),
+            json!({
+                "card": {"id": "4", "name": "Monkey Bomb"},
+                "list": {"id": "LIST-2", "name": "Queen"},
+                "old": {"closed": false},
+            }),
+        ),
+        action(
+            "createCard",
+            Utc.ymd(2020, 6, 28).and_hms(9, 0, 0),
+            json!({
+                "card": {"id": "2", "name": "Propeller Rat"},
+                "list": {"id": "LIST-1", "name": "King"},
+            }),
+        ),
+        action(
+            "updateList",
+            Utc.ymd(2020, 6, 28).and_hms(8, 0, 0),
+            json!({
+                "list": {"id": "LIST-2", "name": "Queen"},
+                "old": {"name": "Jack"},
+            }),
+        ),
+        action(
+            "createCard",
+            Utc.ymd(2020, 6, 26).and_hms(0, 0, 0),
+            json!({
+                "card": {"id": "1", "name": "Gyro Boots"},
+                "list": {"id": "LIST-1", "name": "King"},
+            }),
+        ),
+    ];
+
+    history::rewind(&mut board, &actions, &[archived], at);
+
+    let restored = Card::new("4", "Monkey Bomb", "Bananas", None, "", None);
+    assert_eq!(
+        board.lists.into_vec(),
+        vec![
+            List::new(
+                "LIST-1",
+                "King",
+                Some(vec![
+                    Card::new("3", "Black Cat", "", None, "", None),
+                    Card::new("1", "Gyro Boots", "Jump", None, "", None),
+                ]),
+            ),
+            List::new("LIST-2", "Jack", Some(vec![restored])),
+        ]
+    );
+}
+
+#[test]
+fn test_reconstruct() -> Result<()> {
+    let _board = mockito::mock(
+        "GET",
+        "/1/boards/PAST-BOARD?key=some-key&token=some-token&fields=id%2Cname%2Cclosed%2Curl%2Csubscribed",
+    )
+    .with_status(200)
+    .with_body(json!({"id": "PAST-BOARD", "name": "Past", "closed": false, "url": ""}).to_string())
+    .create();
+    let _lists = mockito::mock(
+        "GET",
+        "/1/boards/PAST-BOARD/lists?key=some-key&token=some-token&fields=id%2Cname%2Cclosed%2CsoftLimit&cards=open",
+    )
+    .with_status(200)
+    .with_body(
+        json!([{"id": "L1", "name": "Todo", "closed": false, "cards": [
+            {"id": "C1", "name": "New card", "desc": "", "closed": false, "url": ""}
+        ]}])
+        .to_string(),
+    )
+    .create();
+    let _closed_lists = mockito::mock(
+        "GET",
+        "/1/boards/PAST-BOARD/lists/closed?key=some-key&token=some-token&fields=id%2Cname%2Cclosed%2CsoftLimit",
+    )
+    .with_status(200)
+    .with_body(json!([{"id": "L2", "name": "Old", "closed": true}]).to_string())
+    .create();
+    let _closed_cards = mockito::mock(
+        "GET",
+        "/1/boards/PAST-BOARD/cards/closed?key=some-key&token=some-token&fields=id%2Cname%2Cdesc%2Clabels%2Cclosed%2Cdue%2Curl%2Csubscribed%2CdateLastActivity%2Cbadges%2CidMembers%2CidShort",
+    )
+    .with_status(200)
+    .with_body("[]")
+    .create();
+    let _actions = mockito::mock(
+        "GET",
+        "/1/boards/PAST-BOARD/actions?key=some-key&token=some-token&filter=createCard%2CupdateCard%2CdeleteCard%2CmoveCardToBoard%2CmoveCardFromBoard%2CcreateList%2CupdateList&since=2020-06-27T00%3A00%3A00%2B00%3A00&limit=1000",
+    )
+    .with_status(200)
+    .with_body(
+        json!([
+            {
+                "id": "A2",
+                "idMemberCreator": "M1",
+                "type": "createCard",
+                "date": "2020-06-28T10:00:00Z",
+                "data": {"card": {"id": "C1", "name": "New card"}, "list": {"id": "L1"}}
+            },
+            {
+                "id": "A1",
+                "idMemberCreator": "M1",
+                "type": "updateList",
+                "date": "2020-06-28T09:00:00Z",
+                "data": {"list": {"id": "L2", "name": "Old"}, "old": {"closed": false}}
+            }
+        ])
+        .to_string(),
+    )
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let board = history::reconstruct(&client, "PAST-BOARD", Utc.ymd(2020, 6, 27).and_hms(0, 0, 0))?;
+
+    assert_eq!(board.name, "Past");
+    assert_eq!(
+        board.lists.into_vec(),
+        vec![
+            List::new("L1", "Todo", Some(vec![])),
+            List::new("L2", "Old", None),
+        ]
+    );
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use super::*;
+
+#[test]
+fn test_id_conversions() {
+    let id = CardId::new("5eac8f60");
+
+    assert_eq!(id, "5eac8f60");
+    assert_eq!(id.as_str(), "5eac8f60");
+    assert_eq!(id.to_string(), "5eac8f60");
+    assert_eq!(&id[..4], "5eac");
+    assert_eq!(CardId::from("5eac8f60"), id);
+    assert_eq!(CardId::from(String::from("5eac8f60")), id);
+    assert_eq!(String::from(id), "5eac8f60");
+}
+
+#[test]
+fn test_id_serde() {
+    let label: Label =
+        serde_json::from_value(json!({"id": "L1", "name": "bug", "color": "red"})).unwrap();
+    assert_eq!(label.id, LabelId::new("L1"));
+    assert_eq!(serde_json::to_value(&label.id).unwrap(), json!("L1"));
+}
@@ -0,0 +1,55 @@
+use super::*;
+
+#[test]
+fn test_from_markdown() {
+    let text = [
+        "# Groceries",
+        "Some introduction which is ignored",
+        "",
+        "- Bread",
+        "## Dairy",
+        "- Milk",
+        "  - Semi skimmed",
+        "  * 2 litres",
+        "- [x] Cheese",
+        "",
+        "## Fruit",
+        "* Apples",
+        "    Granny smith",
+    ]
+    .join("\n");
+
+    let mut milk = Card::new("", "Milk", "", None, "", None);
+    milk.desc = String::from("Semi skimmed\n2 litres");
+    let mut apples = Card::new("", "Apples", "", None, "", None);
+    apples.desc = String::from("Granny smith");
+
+    let expected = Board::new(
+        "",
+        "Groceries",
+        Some(vec![
+            List::new(
+                "",
+                "Backlog",
+                Some(vec![Card::new("", "Bread", "", None, "", None)]),
+            ),
+            List::new(
+                "",
+                "Dairy",
+                Some(vec![milk, Card::new("", "Cheese", "", None, "", None)]),
+            ),
+            List::new("", "Fruit", Some(vec![apples])),
+        ]),
+        "",
+    );
+
+    assert_eq!(import::from_markdown(&text), expected);
+}
+
+#[test]
+fn test_from_markdown_empty() {
+    assert_eq!(
+        import::from_markdown(""),
+        Board::new("", "", Some(vec![]), "")
+    );
+}
@@ -0,0 +1,136 @@
+use super::*;
+
+use chrono::{TimeZone, Utc};
+use history::HistoryStore;
+use index::{tokenize, SearchIndex};
+
+fn board(id: &str, name: &str, cards: Vec<Card>) -> Board {
+    Board::new(
+        id,
+        name,
+        Some(vec![List::new(
+            &format!("{}-LIST", id),
+            "Todo",
+            Some(cards),
+        )]),
+        "",
+    )
+}
+
+fn names(index: &SearchIndex, query: &str) -> Vec<String> {
+    index
+        .search(query)
+        .iter()
+        .map(|m| m.document.card_name.clone())
+        .collect()
+}
+
+#[test]
+fn test_tokenize() {
+    assert_eq!(
+        tokenize("Fix the Gyro-Boots, again!"),
+        vec!["fix", "the", "gyro", "boots", "again"]
+    );
+}
+
+#[test]
+fn test_search() {
+    let mut index = SearchIndex::new();
+    let mut archived = Card::new("4", "Gyro Boots v0", "", None, "", None);
+    archived.closed = true;
+    index.add_board(&board(
+        "B1",
+        "Knights",
+        vec![
+            Card::new("1", "Gyro Boots", "Boots which bounce", None, "", None),
+            Card::new("2", "Propeller Rat", "A rat with boots", None, "", None),
+            archived,
+        ],
+    ));
+    index.add_board(&board(
+        "B2",
+        "Pawns",
+        vec![Card::new("3", "Black Cat", "", None, "", None)],
+    ));
+    assert_eq!(index.len(), 3);
+
+    // matches in names rank higher
+    assert_eq!(names(&index, "boots"), vec!["Gyro Boots", "Propeller Rat"]);
+    // every word must match, the last one as a prefix
+    assert_eq!(names(&index, "rat boo"), vec!["Propeller Rat"]);
+    assert_eq!(names(&index, "ca"), vec!["Black Cat"]);
+    assert!(names(&index, "cat boots").is_empty());
+    assert!(index.search("").is_empty());
+
+    let result = index.search("cat");
+    assert_eq!(result[0].document.board_name, "Pawns");
+    assert_eq!(result[0].document.list_name, "Todo");
+}
+
+#[test]
+fn test_comments_and_replacing_boards() {
+    let mut index = SearchIndex::new();
+    index.add_board(&board(
+        "B1",
+        "Knights",
+        vec![Card::new("1", "Gyro Boots", "", None, "", None)],
+    ));
+
+    let actions: Vec<webhook::Action> = serde_json::from_value(json!([
+        {
+            "id": "A1",
+            "idMemberCreator": "M1",
+            "type": "commentCard",
+            "date": "2020-06-28T06:00:00Z",
+            "data": {"card": {"id": "1"}, "text": "Needs more springs"}
+        },
+        {
+            "id": "A2",
+            "idMemberCreator": "M1",
+            "type": "commentCard",
+            "date": "2020-06-28T06:00:00Z",
+            "data": {"card": {"id": "UNKNOWN"}, "text": "Springs everywhere"}
+        }
+    ]))
+    .unwrap();
+    assert_eq!(index.add_comments(&actions), 1);
+    assert_eq!(names(&index, "springs"), vec!["Gyro Boots"]);
+
+    index.add_board(&board(
+        "B1",
+        "Knights",
+        vec![Card::new("2", "Propeller Rat", "", None, "", None)],
+    ));
+    assert!(names(&index, "springs").is_empty());
+    assert!(names(&index, "gyro").is_empty());
+    assert_eq!(names(&index, "rat"), vec!["Propeller Rat"]);
+
+    index.remove_board("B1");
+    assert!(index.is_empty());
+}
+
+#[test]
+fn test_from_history_and_save() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let store = HistoryStore::new(&dir.path().join("history"));
+    let old = board(
+        "B1",
+        "Knights",
+        vec![Card::new("1", "Gyro Boots", "", None, "", None)],
+    );
+    let new = board(
+        "B1",
+        "Knights",
+        vec![Card::new("1", "Gyro Boots+", "", None, "", None)],
+    );
+    store.save_at(&old, Utc.ymd(2020, 6, 27).and_hms(0, 0, 0))?;
+    store.save_at(&new, Utc.ymd(2020, 6, 28).and_hms(0, 0, 0))?;
+
+    let index = SearchIndex::from_history(&store)?;
+    assert_eq!(names(&index, "gyro"), vec!["Gyro Boots+"]);
+
+    let path = dir.path().join("index.json");
+    index.save(&path)?;
+    assert_eq!(SearchIndex::load(&path)?, index);
+    Ok(())
+}
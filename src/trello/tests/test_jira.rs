@@ -0,0 +1,121 @@
+use super::*;
+
+use bridges::jira::{create_cards, import_csv, JiraIssue};
+use chrono::{TimeZone, Utc};
+
+#[test]
+fn test_import_csv() -> Result<()> {
+    let mut file = NamedTempFile::new()?;
+    write!(
+        file,
+        "{}",
+        [
+            "Summary,Issue key,Priority,Labels,Labels,Due Date,Description",
+            "Dig deeper,SK-1,High,shovel,,12/Jun/20 5:00 PM,\"Find the\ntreasure\"",
+            "Jump,SK-2,,,,,",
+        ]
+        .join("\n")
+    )?;
+
+    let issues = import_csv(file.path())?;
+
+    assert_eq!(
+        issues,
+        vec![
+            JiraIssue {
+                contents: CardContents {
+                    name: String::from("Dig deeper"),
+                    desc: String::from("Find the\ntreasure"),
+                },
+                labels: vec![String::from("shovel")],
+                priority: Some(String::from("High")),
+                due: Some(Utc.ymd(2020, 6, 12).and_hms(17, 0, 0)),
+            },
+            JiraIssue {
+                contents: CardContents {
+                    name: String::from("Jump"),
+                    desc: String::new(),
+                },
+                labels: vec![],
+                priority: None,
+                due: None,
+            },
+        ]
+    );
+    assert_eq!(
+        issues[0].card_labels(),
+        vec![String::from("shovel"), String::from("Priority: High")]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_import_csv_without_summary() -> Result<()> {
+    let mut file = NamedTempFile::new()?;
+    write!(file, "Issue key\nSK-1\n")?;
+
+    match import_csv(file.path()) {
+        Err(TrelloError::CardParse(_)) => Ok(()),
+        other => panic!("Unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn test_create_cards() -> Result<()> {
+    let _labels = mockito::mock(
+        "GET",
+        "/1/boards/JIRA-BOARD/labels?key=jira-key&token=jira-token&fields=id%2Cname%2Ccolor",
+    )
+    .with_status(200)
+    .with_body(json!([{"id": "SHOVEL-LABEL", "name": "shovel", "color": "red"}]).to_string())
+    .create();
+    let _create = mockito::mock("POST", "/1/cards/?key=jira-key&token=jira-token")
+        .match_body("name=Dig&desc=&idList=JIRA-LIST")
+        .with_status(200)
+        .with_body(
+            json!({"id": "DIG", "name": "Dig", "desc": "", "closed": false, "url": ""}).to_string(),
+        )
+        .create();
+    let _new_label = mockito::mock("POST", "/1/labels/?key=jira-key&token=jira-token")
+        .match_body("name=Priority%3A+Low&color=sky&idBoard=JIRA-BOARD")
+        .with_status(200)
+        .with_body(json!({"id": "LOW-LABEL", "name": "Priority: Low", "color": "sky"}).to_string())
+        .create();
+    let _apply_shovel = mockito::mock(
+        "POST",
+        "/1/cards/DIG/idLabels?key=jira-key&token=jira-token",
+    )
+    .match_body("value=SHOVEL-LABEL")
+    .with_status(200)
+    .with_body("[]")
+    .create();
+    let _apply_low = mockito::mock(
+        "POST",
+        "/1/cards/DIG/idLabels?key=jira-key&token=jira-token",
+    )
+    .match_body("value=LOW-LABEL")
+    .with_status(200)
+    .with_body("[]")
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "jira-token", "jira-key");
+    let client = TrelloClient::new(config);
+
+    let issues = vec![JiraIssue {
+        contents: CardContents {
+            name: String::from("Dig"),
+            desc: String::new(),
+        },
+        labels: vec![String::from("shovel")],
+        priority: Some(String::from("Low")),
+        due: None,
+    }];
+
+    let cards = create_cards(&client, "JIRA-BOARD", "JIRA-LIST", &issues)?;
+
+    assert_eq!(cards.len(), 1);
+    _new_label.assert();
+    _apply_shovel.assert();
+    _apply_low.assert();
+    Ok(())
+}
@@ -30,6 +30,39 @@ fn test_get_all() -> Result<()> {
 }
 
 #[test]
+fn test_get_all_cached() -> Result<()> {
+    let labels = mockito::mock(
+        "GET",
+        "/1/boards/CACHED-BOARD/labels?key=some-key&token=some-token&fields=id%2Cname%2Ccolor",
+    )
+    .with_status(200)
+    .with_body(json!([{"name": "Tech", "color": "purple", "id": "CACHED-1"}]).to_string())
+    .expect(2)
+    .create();
+
+    let _m = mockito::mock("POST", "/1/labels/?key=some-key&token=some-token")
+        .match_body("name=Bills&color=red&idBoard=CACHED-BOARD")
+        .with_status(200)
+        .with_body(json!({"id": "CACHED-2", "name": "Bills", "color": "red"}).to_string())
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let first = Label::get_all(&client, "CACHED-BOARD")?;
+    let second = Label::get_all(&client, "CACHED-BOARD")?;
+    assert_eq!(first, second);
+
+    // creating a label invalidates the labels of its board
+    Label::create(&client, "CACHED-BOARD", "Bills", "red")?;
+    Label::get_all(&client, "CACHED-BOARD")?;
+
+    labels.assert();
+    Ok(())
+}
+
+#[test]
+#[allow(deprecated)]
 fn test_apply() -> Result<()> {
     let _m = mockito::mock(
         "POST",
@@ -49,6 +82,24 @@ fn test_apply() -> Result<()> {
 }
 
 #[test]
+fn test_create() -> Result<()> {
+    let _m = mockito::mock("POST", "/1/labels/?key=some-key&token=some-token")
+        .match_body("name=Urgent&color=red&idBoard=SOME-BOARD-ID")
+        .with_status(200)
+        .with_body(json!({"id": "NEW-LABEL", "name": "Urgent", "color": "red"}).to_string())
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let result = Label::create(&client, "SOME-BOARD-ID", "Urgent", "red")?;
+
+    assert_eq!(result, Label::new("NEW-LABEL", "Urgent", "red"));
+    Ok(())
+}
+
+#[test]
+#[allow(deprecated)]
 fn test_remove() -> Result<()> {
     let _m = mockito::mock(
         "DELETE",
@@ -64,3 +115,142 @@ fn test_remove() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_delete() -> Result<()> {
+    let _m = mockito::mock(
+        "DELETE",
+        "/1/labels/BAR-LABEL?key=some-key&token=some-token",
+    )
+    .with_status(200)
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    Label::delete(&client, "BAR-LABEL", None)?;
+    _m.assert();
+    Ok(())
+}
+
+#[test]
+fn test_delete_archive_only() -> Result<()> {
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::builder(config)
+        .safety_policy(SafetyPolicy::ArchiveOnly)
+        .build()?;
+
+    match Label::delete(&client, "BAR-LABEL", None) {
+        Err(TrelloError::DeletionNotPermitted(_)) => {}
+        other => panic!("Unexpected result: {:?}", other),
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merge() -> Result<()> {
+    let _cards = mockito::mock(
+        "GET",
+        "/1/boards/MERGE-BOARD/cards/all?key=some-key&token=some-token&fields=id%2Cname%2Cdesc%2Clabels%2Cclosed%2Cdue%2Curl%2Csubscribed%2CdateLastActivity%2Cbadges%2CidMembers%2CidShort",
+    )
+    .with_status(200)
+    .with_body(
+        json!([
+            {"id": "MERGE-1", "name": "a", "desc": "", "closed": false, "url": "",
+             "labels": [{"id": "OLD-LABEL", "name": "bugs", "color": "red"}]},
+            {"id": "MERGE-2", "name": "b", "desc": "", "closed": false, "url": "",
+             "labels": [
+                {"id": "OLD-LABEL", "name": "bugs", "color": "red"},
+                {"id": "NEW-LABEL", "name": "bug", "color": "red"},
+             ]},
+            {"id": "MERGE-3", "name": "c", "desc": "", "closed": false, "url": "", "labels": []},
+        ])
+        .to_string(),
+    )
+    .create();
+    let _apply = mockito::mock(
+        "POST",
+        "/1/cards/MERGE-1/idLabels?key=some-key&token=some-token",
+    )
+    .match_body("value=NEW-LABEL")
+    .with_status(200)
+    .with_body(json!({}).to_string())
+    .expect(1)
+    .create();
+    let _delete = mockito::mock(
+        "DELETE",
+        "/1/labels/OLD-LABEL?key=some-key&token=some-token",
+    )
+    .with_status(200)
+    .expect(1)
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let count = Label::merge(&client, "MERGE-BOARD", "OLD-LABEL", "NEW-LABEL", None)?;
+    assert_eq!(count, 1);
+    _apply.assert();
+    _delete.assert();
+    Ok(())
+}
+
+#[test]
+fn test_merge_requires_confirmation() -> Result<()> {
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::builder(config)
+        .safety_policy(SafetyPolicy::RequireConfirmationToken)
+        .build()?;
+
+    match Label::merge(&client, "MERGE-BOARD", "OLD-LABEL", "NEW-LABEL", None) {
+        Err(TrelloError::DeletionNotPermitted(_)) => {}
+        other => panic!("Unexpected result: {:?}", other),
+    }
+    Ok(())
+}
+
+#[test]
+fn test_add_to_and_remove_from_card() -> Result<()> {
+    let _add = mockito::mock(
+        "POST",
+        "/1/cards/TYPED-CARD/idLabels?key=some-key&token=some-token",
+    )
+    .match_body("value=TYPED-LABEL")
+    .with_status(200)
+    .with_body(json!({}).to_string())
+    .create();
+    let _remove = mockito::mock(
+        "DELETE",
+        "/1/cards/TYPED-CARD/idLabels/TYPED-LABEL?key=some-key&token=some-token",
+    )
+    .with_status(200)
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let card_id = CardId::new("TYPED-CARD");
+    let label_id = LabelId::new("TYPED-LABEL");
+    Label::add_to_card(&client, &card_id, &label_id)?;
+    Label::remove_from_card(&client, &card_id, &label_id)?;
+    _add.assert();
+    _remove.assert();
+    Ok(())
+}
+
+#[test]
+fn test_update() -> Result<()> {
+    let _m = mockito::mock("PUT", "/1/labels/SOME-LABEL?key=some-key&token=some-token")
+        .match_body("name=Urgent&color=red")
+        .with_status(200)
+        .with_body(json!({"id": "SOME-LABEL", "name": "Urgent", "color": "red"}).to_string())
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let result = Label::update(&client, "SOME-LABEL", "Urgent", "red")?;
+
+    assert_eq!(result, Label::new("SOME-LABEL", "Urgent", "red"));
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use super::*;
+
+use colored::*;
+use labels::{plan, LabelChange};
+
+fn canonical() -> Vec<Label> {
+    vec![
+        Label::new("C1", "Bug", "red"),
+        Label::new("C2", "Feature", "green"),
+        Label::new("C3", "Chore", "sky"),
+        Label::new("C4", "", "black"),
+    ]
+}
+
+#[test]
+fn test_plan() {
+    let labels = vec![
+        Label::new("T1", "Bug", "orange"),
+        Label::new("T2", " feature", "green"),
+        Label::new("T3", "Team specific", "pink"),
+    ];
+
+    assert_eq!(
+        plan(&canonical(), &labels),
+        vec![
+            LabelChange::Recolored {
+                label_id: LabelId::new("T1"),
+                name: String::from("Bug"),
+                from: String::from("orange"),
+                to: String::from("red"),
+            },
+            LabelChange::Renamed {
+                label_id: LabelId::new("T2"),
+                from: String::from(" feature"),
+                to: String::from("Feature"),
+                color: String::from("green"),
+            },
+            LabelChange::Created {
+                name: String::from("Chore"),
+                color: String::from("sky"),
+            },
+        ]
+    );
+    assert!(plan(&canonical(), &canonical()).is_empty());
+}
+
+#[test]
+fn test_sync() -> Result<()> {
+    let labels_url = |board_id: &str| {
+        format!(
+            "/1/boards/{}/labels?key=some-key&token=some-token&fields=id%2Cname%2Ccolor",
+            board_id
+        )
+    };
+    let _source = mockito::mock("GET", labels_url("SYNC-SOURCE").as_str())
+        .with_status(200)
+        .with_body(json!([{"id": "C1", "name": "Bug", "color": "red"}]).to_string())
+        .create();
+    let _target = mockito::mock("GET", labels_url("SYNC-TARGET").as_str())
+        .with_status(200)
+        .with_body(json!([{"id": "T1", "name": "bug", "color": "orange"}]).to_string())
+        .create();
+    let _board = mockito::mock(
+        "GET",
+        "/1/boards/SYNC-TARGET?key=some-key&token=some-token&fields=id%2Cname%2Cclosed%2Curl%2Csubscribed",
+    )
+    .with_status(200)
+    .with_body(json!({"id": "SYNC-TARGET", "name": "Target", "closed": false, "url": ""}).to_string())
+    .create();
+    let update = mockito::mock("PUT", "/1/labels/T1?key=some-key&token=some-token")
+        .match_body("name=Bug&color=red")
+        .with_status(200)
+        .with_body(json!({"id": "T1", "name": "Bug", "color": "red"}).to_string())
+        .expect(1)
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let report = labels::sync(&client, "SYNC-SOURCE", &["SYNC-SOURCE", "SYNC-TARGET"])?;
+
+    update.assert();
+    assert_eq!(report.boards.len(), 1);
+    assert_eq!(report.boards[0].board_id, "SYNC-TARGET");
+    assert_eq!(report.boards[0].board_name, "Target");
+    assert_eq!(report.simple_render(), "1 label changes on 1 boards");
+    assert_eq!(
+        report.render(),
+        format!("{}\n{} bug -> Bug", "Target".bold(), "~".yellow())
+    );
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use super::*;
+
+use chrono::{TimeZone, Utc};
+use lint::{Linter, Rule};
+
+fn board() -> Board {
+    let bug = Label::new("L1", "bug", "red");
+    let due = Some(Utc.ymd(2020, 5, 1).and_hms(12, 0, 0));
+
+    let mut archived = Card::new("E", "Fix login", "", None, "", None);
+    archived.closed = true;
+
+    Board::new(
+        "B",
+        "Tidy",
+        Some(vec![
+            List::new(
+                "1",
+                "Backlog",
+                Some(vec![
+                    Card::new(
+                        "A",
+                        "Fix login",
+                        "Broken",
+                        Some(vec![bug.clone()]),
+                        "",
+                        None,
+                    ),
+                    Card::new("B", "Write docs", "", Some(vec![]), "", None),
+                    archived,
+                ]),
+            ),
+            List::new(
+                "2",
+                "Doing",
+                Some(vec![
+                    Card::new(
+                        "C",
+                        "fix login ",
+                        "Again",
+                        Some(vec![bug.clone()]),
+                        "",
+                        None,
+                    ),
+                    Card::new("D", "Release", "Ship it", Some(vec![bug]), "", due),
+                ]),
+            ),
+        ]),
+        "",
+    )
+}
+
+#[test]
+fn test_lint_rules() -> Result<()> {
+    let board = board();
+
+    let findings = Linter::new().rule(Rule::MissingLabels).check(&board);
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].rule, "missing-labels");
+    assert_eq!(findings[0].card_id, "B");
+    assert_eq!(findings[0].list_name, "Backlog");
+
+    let findings = Linter::new()
+        .rule(Rule::missing_due_date("^doing$")?)
+        .check(&board);
+    assert_eq!(
+        findings.iter().map(|f| &f.card_id[..]).collect::<Vec<_>>(),
+        vec!["C"]
+    );
+
+    let findings = Linter::new().rule(Rule::EmptyDescription).check(&board);
+    assert_eq!(
+        findings.iter().map(|f| &f.card_id[..]).collect::<Vec<_>>(),
+        vec!["B"]
+    );
+
+    let findings = Linter::new().rule(Rule::DuplicateNames).check(&board);
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].card_id, "C");
+    assert_eq!(findings[0].message, "Same name as card A");
+    Ok(())
+}
+
+#[test]
+fn test_lint_combined() -> Result<()> {
+    let linter = Linter::new()
+        .rule(Rule::MissingLabels)
+        .rule(Rule::EmptyDescription);
+    let findings = linter.check(&board());
+
+    assert_eq!(linter.rules().len(), 2);
+    assert_eq!(
+        findings.iter().map(|f| f.rule).collect::<Vec<_>>(),
+        vec!["missing-labels", "empty-description"]
+    );
+    assert_eq!(
+        findings[1].simple_render(),
+        "[empty-description] Write docs"
+    );
+    assert!(Linter::new().check(&board()).is_empty());
+    Ok(())
+}
@@ -1,15 +1,19 @@
 use super::*;
 
+use chrono::{Duration, TimeZone, Utc};
 use colored::*;
 
 #[test]
 fn test_new() {
     let list = List::new("123", "my list", Some(vec![]));
     let expected = List {
-        id: String::from("123"),
+        id: ListId::new("123"),
         name: String::from("my list"),
-        cards: Some(vec![]),
+        cards: Loaded::Fetched(vec![]),
         closed: false,
+        soft_limit: None,
+        #[cfg(feature = "extra-fields")]
+        extra: serde_json::Map::new(),
     };
     assert_eq!(list, expected);
 }
@@ -50,6 +54,124 @@ fn test_render_with_cards() {
     assert_eq!(list.render(), expected);
 }
 
+#[test]
+fn test_render_checklist_progress() {
+    let mut card = Card::new("", "hello", "", None, "", None);
+    card.checklists = Some(vec![Checklist::new(
+        "",
+        "",
+        vec![
+            CheckItem::new("", "", CheckItemState::Complete),
+            CheckItem::new("", "", CheckItemState::Incomplete),
+        ],
+    )]);
+    let list = List::new(
+        "aaaaa",
+        "King Knight",
+        Some(vec![card, Card::new("", "world", "", None, "", None)]),
+    );
+
+    let expected = format!(
+        "{}\n{}",
+        "King Knight\n-----------".bold(),
+        "* hello [1/2]\n* world"
+    );
+    assert_eq!(list.render(), expected);
+
+    let expected = format!(
+        "{}\n{}",
+        "King Knight\n-----------".bold(),
+        "* hello\n* world"
+    );
+    assert_eq!(list.render_with_progress(false), expected);
+}
+
+#[test]
+fn test_render_badges() {
+    let mut card = Card::new("", "hello", "", None, "", None);
+    card.badges = Some(Badges {
+        comments: 3,
+        check_items: 2,
+        ..Badges::default()
+    });
+    let list = List::new("aaaaa", "King Knight", Some(vec![card]));
+
+    let expected = format!(
+        "{}\n* hello [0/2] {}",
+        "King Knight\n-----------".bold(),
+        "3 comments".dimmed()
+    );
+    assert_eq!(list.render(), expected);
+}
+
+#[test]
+fn test_render_with_options() {
+    let list = List::new(
+        "aaaaa",
+        "King Knight",
+        Some(vec![
+            Card::new("1", "hello", "first\nsecond", None, "", None),
+            Card::new("2", "world", "", None, "", None),
+        ]),
+    );
+
+    let minimal = RenderOptions {
+        detail: Detail::Minimal,
+        ..RenderOptions::default()
+    };
+    let expected = format!(
+        "{}\n{}",
+        "King Knight\n-----------".bold(),
+        "* hello\n* world"
+    );
+    assert_eq!(list.render_with(&minimal), expected);
+
+    let full = RenderOptions {
+        detail: Detail::Full,
+        show_ids: true,
+        ..RenderOptions::default()
+    };
+    let expected = [
+        "King Knight\n-----------".bold().to_string(),
+        "aaaaa".dimmed().to_string(),
+        format!("* hello {} {}", "[...]".dimmed(), "(1)".dimmed()),
+        String::from("  first"),
+        String::from("  second"),
+        format!("* world {}", "(2)".dimmed()),
+    ]
+    .join("\n");
+    assert_eq!(list.render_with(&full), expected);
+}
+
+#[test]
+fn test_render_due_dates() {
+    let due = Utc.ymd(2020, 6, 28).and_hms(12, 0, 0);
+    let mut done = Card::new("3", "done", "", None, "", Some(due));
+    done.closed = true;
+    let list = List::new(
+        "aaaaa",
+        "King Knight",
+        Some(vec![
+            Card::new("1", "late", "", None, "", Some(due)),
+            Card::new("2", "soon", "", None, "", Some(due + Duration::days(2))),
+            done,
+        ]),
+    );
+
+    let options = RenderOptions {
+        now: Some(due + Duration::hours(3)),
+        ..RenderOptions::default()
+    };
+    let expected = [
+        "King Knight\n-----------".bold().to_string(),
+        format!("* late {}", "(overdue by 3h)".red()),
+        format!("* soon {}", "(due in 1 day)".dimmed()),
+        format!("* {} done", "[Closed]".red()),
+    ]
+    .join("\n");
+    assert_eq!(list.render_with(&options), expected);
+}
+
 #[test]
 fn test_create() -> Result<()> {
     let _m = mockito::mock("POST", "/1/lists/?key=some-key&token=some-token")
@@ -68,7 +190,7 @@ fn test_create() -> Result<()> {
     let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
     let client = TrelloClient::new(config);
 
-    let result = List::create(&client, "LEONSK", "Today")?;
+    let result = List::create(&client, "LEONSK", "Today", None)?;
     let expected = List::new("MTLDA", "Today", None);
     assert_eq!(result, expected);
     Ok(())
@@ -100,11 +222,54 @@ fn test_update() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_get() -> Result<()> {
+    let _list = mockito::mock(
+        "GET",
+        "/1/lists/LIST-ID?key=some-key&token=some-token&fields=id%2Cname%2Cclosed%2CsoftLimit",
+    )
+    .with_status(200)
+    .with_body(json!({"name": "Red", "id": "LIST-ID", "closed": false}).to_string())
+    .create();
+    let _with_cards = mockito::mock(
+        "GET",
+        "/1/lists/LIST-ID?key=some-key&token=some-token&fields=id%2Cname%2Cclosed%2CsoftLimit&cards=open",
+    )
+    .with_status(200)
+    .with_body(
+        json!({
+            "name": "Red",
+            "id": "LIST-ID",
+            "closed": false,
+            "cards": [{"id": "card1", "name": "apple", "desc": "", "closed": false, "url": ""}],
+        })
+        .to_string(),
+    )
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    assert_eq!(
+        List::get(&client, "LIST-ID", false)?,
+        List::new("LIST-ID", "Red", None)
+    );
+    assert_eq!(
+        List::get(&client, "LIST-ID", true)?,
+        List::new(
+            "LIST-ID",
+            "Red",
+            Some(vec![Card::new("card1", "apple", "", None, "", None)])
+        )
+    );
+    Ok(())
+}
+
 #[test]
 fn test_get_all() -> Result<()> {
     let _m = mockito::mock(
         "GET",
-        "/1/boards/some-board-id/lists?key=some-key&token=some-token&fields=id%2Cname%2Cclosed",
+        "/1/boards/some-board-id/lists?key=some-key&token=some-token&fields=id%2Cname%2Cclosed%2CsoftLimit",
     )
     .with_status(200)
     .with_body(
@@ -132,7 +297,7 @@ fn test_get_all() -> Result<()> {
 fn test_get_all_with_cards() -> Result<()> {
     let _m = mockito::mock(
         "GET",
-        "/1/boards/some-board-id/lists?key=some-key&token=some-token&fields=id%2Cname%2Cclosed&cards=open",
+        "/1/boards/some-board-id/lists?key=some-key&token=some-token&fields=id%2Cname%2Cclosed%2CsoftLimit&cards=open",
     )
     .with_status(200)
     .with_body(
@@ -166,3 +331,281 @@ fn test_get_all_with_cards() -> Result<()> {
     assert_eq!(result, expected);
     Ok(())
 }
+
+#[test]
+fn test_move_to_board() -> Result<()> {
+    let _m = mockito::mock(
+        "PUT",
+        "/1/lists/MY-LIST-ID/idBoard?key=some-key&token=some-token",
+    )
+    .match_body("value=OTHER-BOARD&pos=top")
+    .with_status(200)
+    .with_body(
+        json!({
+            "name": "Today",
+            "id": "MY-LIST-ID",
+            "closed": false,
+        })
+        .to_string(),
+    )
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let result = List::move_to_board(&client, "MY-LIST-ID", "OTHER-BOARD", Some("top"))?;
+    assert_eq!(result, List::new("MY-LIST-ID", "Today", None));
+    Ok(())
+}
+
+#[test]
+fn test_copy() -> Result<()> {
+    let _m1 = mockito::mock(
+        "GET",
+        "/1/lists/MY-LIST-ID/board?key=some-key&token=some-token&fields=id",
+    )
+    .with_status(200)
+    .with_body(json!({"id": "MY-BOARD"}).to_string())
+    .create();
+
+    let _m2 = mockito::mock("POST", "/1/lists/?key=some-key&token=some-token")
+        .match_body("name=Tomorrow&idBoard=MY-BOARD&idListSource=MY-LIST-ID")
+        .with_status(200)
+        .with_body(
+            json!({
+                "name": "Tomorrow",
+                "id": "NEW-LIST-ID",
+                "closed": false,
+            })
+            .to_string(),
+        )
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let result = List::copy(&client, "MY-LIST-ID", "Tomorrow")?;
+    assert_eq!(result, List::new("NEW-LIST-ID", "Tomorrow", None));
+    Ok(())
+}
+
+#[test]
+fn test_patch() -> Result<()> {
+    let _m = mockito::mock("PUT", "/1/lists/MY-LIST-ID/?key=some-key&token=some-token")
+        .match_body("pos=top")
+        .with_status(200)
+        .with_body(
+            json!({
+                "name": "Today",
+                "id": "MY-LIST-ID",
+                "closed": false,
+            })
+            .to_string(),
+        )
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let result = List::patch(&client, "MY-LIST-ID", &ListPatch::new().pos("top"))?;
+    assert_eq!(result, List::new("MY-LIST-ID", "Today", None));
+    Ok(())
+}
+
+#[test]
+fn test_get_all_cards_with_filter() -> Result<()> {
+    let _m = mockito::mock(
+        "GET",
+        "/1/lists/FILTERED-LIST/cards/all?key=some-key&token=some-token&fields=id%2Cname%2Cdesc%2Clabels%2Cclosed%2Cdue%2Curl%2Csubscribed%2CdateLastActivity%2Cbadges%2CidMembers%2CidShort",
+    )
+    .with_status(200)
+    .with_body(
+        json!([
+            {"id": "OPEN", "name": "Open card", "desc": "", "closed": false, "url": ""},
+            {"id": "OLD", "name": "Old card", "desc": "", "closed": true, "url": ""},
+        ])
+        .to_string(),
+    )
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let result = List::get_all_cards_with_filter(&client, "FILTERED-LIST", CardFilter::All)?;
+
+    assert_eq!(result.len(), 2);
+    assert!(result[1].closed);
+    Ok(())
+}
+
+#[test]
+fn test_cards_paged() -> Result<()> {
+    let fields = "fields=id%2Cname%2Cdesc%2Clabels%2Cclosed%2Cdue%2Curl%2Csubscribed%2CdateLastActivity%2Cbadges%2CidMembers%2CidShort";
+    let card = |id: &str| json!({"id": id, "name": id, "desc": "", "closed": false, "url": ""});
+
+    let _first = mockito::mock(
+        "GET",
+        format!(
+            "/1/lists/HUGE-LIST/cards?key=some-key&token=some-token&{}&limit=2",
+            fields
+        )
+        .as_str(),
+    )
+    .with_status(200)
+    .with_body(json!([card("5"), card("4")]).to_string())
+    .create();
+    let _second = mockito::mock(
+        "GET",
+        format!(
+            "/1/lists/HUGE-LIST/cards?key=some-key&token=some-token&{}&limit=2&before=4",
+            fields
+        )
+        .as_str(),
+    )
+    .with_status(200)
+    .with_body(json!([card("3"), card("2")]).to_string())
+    .create();
+    let _last = mockito::mock(
+        "GET",
+        format!(
+            "/1/lists/HUGE-LIST/cards?key=some-key&token=some-token&{}&limit=2&before=2",
+            fields
+        )
+        .as_str(),
+    )
+    .with_status(200)
+    .with_body(json!([card("1")]).to_string())
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let ids = List::cards_paged(&client, "HUGE-LIST", 2)
+        .map(|card| Ok(card?.id.to_string()))
+        .collect::<Result<Vec<String>>>()?;
+
+    assert_eq!(ids, vec!["5", "4", "3", "2", "1"]);
+    Ok(())
+}
+
+#[test]
+fn test_cards_paged_out_of_order() -> Result<()> {
+    let fields = "fields=id%2Cname%2Cdesc%2Clabels%2Cclosed%2Cdue%2Curl%2Csubscribed%2CdateLastActivity%2Cbadges%2CidMembers%2CidShort";
+    let card = |id: &str| json!({"id": id, "name": id, "desc": "", "closed": false, "url": ""});
+
+    // cards come back in the order of the list, "2" was moved to the top
+    let _first = mockito::mock(
+        "GET",
+        format!(
+            "/1/lists/SORTED-LIST/cards?key=some-key&token=some-token&{}&limit=3",
+            fields
+        )
+        .as_str(),
+    )
+    .with_status(200)
+    .with_body(json!([card("2"), card("5"), card("4")]).to_string())
+    .create();
+    let _last = mockito::mock(
+        "GET",
+        format!(
+            "/1/lists/SORTED-LIST/cards?key=some-key&token=some-token&{}&limit=3&before=2",
+            fields
+        )
+        .as_str(),
+    )
+    .with_status(200)
+    .with_body(json!([card("1")]).to_string())
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let ids = List::cards_paged(&client, "SORTED-LIST", 3)
+        .map(|card| Ok(card?.id.to_string()))
+        .collect::<Result<Vec<String>>>()?;
+
+    assert_eq!(ids, vec!["2", "5", "4", "1"]);
+    Ok(())
+}
+
+#[test]
+fn test_get_card_count() -> Result<()> {
+    let _m = mockito::mock(
+        "GET",
+        "/1/lists/COUNTED-LIST/cards?key=some-key&token=some-token&fields=id",
+    )
+    .with_status(200)
+    .with_body(json!([{"id": "1"}, {"id": "2"}, {"id": "3"}]).to_string())
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    assert_eq!(List::get_card_count(&client, "COUNTED-LIST")?, 3);
+    Ok(())
+}
+
+#[test]
+fn test_soft_limit() {
+    let list: List = serde_json::from_value(json!({
+        "id": "1", "name": "Doing", "closed": false, "softLimit": 5,
+    }))
+    .unwrap();
+    assert_eq!(list.soft_limit, Some(5));
+
+    let list: List = serde_json::from_value(json!({
+        "id": "1", "name": "Doing", "closed": false, "softLimit": "3",
+    }))
+    .unwrap();
+    assert_eq!(list.soft_limit, Some(3));
+
+    let list: List = serde_json::from_value(json!({
+        "id": "1", "name": "Doing", "closed": false, "softLimit": null,
+    }))
+    .unwrap();
+    assert_eq!(list.soft_limit, None);
+}
+
+#[test]
+fn test_instance_add_card() -> Result<()> {
+    let _m = mockito::mock("POST", "/1/cards/?key=some-key&token=some-token")
+        .match_body("name=Laundry&desc=&idList=INSTANCE-LIST")
+        .with_status(200)
+        .with_body(
+            json!({"id": "NEW-CARD", "name": "Laundry", "desc": "", "closed": false, "url": ""})
+                .to_string(),
+        )
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let mut list = List::new("INSTANCE-LIST", "Chores", Some(vec![]));
+    let card = list.add_card(&client, "Laundry")?;
+
+    assert_eq!(card.id, "NEW-CARD");
+    assert_eq!(list.cards, Loaded::Fetched(vec![card]));
+    Ok(())
+}
+
+#[test]
+fn test_lazy_cards() -> Result<()> {
+    let _m = mockito::mock(
+        "GET",
+        "/1/lists/LAZY-LIST/cards/?key=some-key&token=some-token&fields=id%2Cname%2Cdesc%2Clabels%2Cclosed%2Cdue%2Curl%2Csubscribed%2CdateLastActivity%2Cbadges%2CidMembers%2CidShort",
+    )
+    .with_status(200)
+    .with_body(json!([{"id": "C1", "name": "Dig", "desc": "", "closed": false, "url": ""}]).to_string())
+    .expect(1)
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let mut list = List::new("LAZY-LIST", "Todo", None);
+    assert_eq!(list.cards(&client)?.len(), 1);
+    // the second call uses the cached cards
+    assert_eq!(list.cards(&client)?[0].name, "Dig");
+    _m.assert();
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use super::*;
+
+use colored::*;
+use lists::{plan, ListChange};
+
+const WORKFLOW: &[&str] = &["Backlog", "Doing", "Review", "Done"];
+
+#[test]
+fn test_plan() {
+    let mut archived = List::new("L4", "Review", None);
+    archived.closed = true;
+    let lists = vec![
+        List::new("L1", "backlog", None),
+        List::new("L2", "Doing", None),
+        List::new("L3", "D.O.N.E", None),
+        List::new("L5", "Ideas", None),
+        archived,
+    ];
+
+    assert_eq!(
+        plan(WORKFLOW, &lists),
+        vec![
+            ListChange::Renamed {
+                list_id: ListId::new("L1"),
+                from: String::from("backlog"),
+                to: String::from("Backlog"),
+            },
+            ListChange::Created {
+                name: String::from("Review"),
+            },
+            ListChange::Renamed {
+                list_id: ListId::new("L3"),
+                from: String::from("D.O.N.E"),
+                to: String::from("Done"),
+            },
+        ]
+    );
+}
+
+fn mock_board() -> Vec<mockito::Mock> {
+    vec![
+        mockito::mock(
+            "GET",
+            "/1/boards/NORMALIZE-BOARD?key=some-key&token=some-token&fields=id%2Cname%2Cclosed%2Curl%2Csubscribed",
+        )
+        .with_status(200)
+        .with_body(json!({"id": "NORMALIZE-BOARD", "name": "Team", "closed": false, "url": ""}).to_string())
+        .create(),
+        mockito::mock(
+            "GET",
+            "/1/boards/NORMALIZE-BOARD/lists?key=some-key&token=some-token&fields=id%2Cname%2Cclosed%2CsoftLimit",
+        )
+        .with_status(200)
+        .with_body(json!([{"id": "L1", "name": "doing", "closed": false}]).to_string())
+        .create(),
+    ]
+}
+
+#[test]
+fn test_normalize_dry_run() -> Result<()> {
+    let _board = mock_board();
+    let create = mockito::mock("POST", "/1/lists/?key=some-key&token=some-token")
+        .expect(0)
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let report = lists::normalize(&client, &["NORMALIZE-BOARD"], &["Doing", "Done"], true)?;
+
+    create.assert();
+    let expected = [
+        "Dry run, no changes were made".dimmed().to_string(),
+        "Team".bold().to_string(),
+        format!("{} doing -> Doing", "~".yellow()),
+        format!("{} Done", "+".green()),
+    ]
+    .join("\n");
+    assert_eq!(report.render(), expected);
+    assert_eq!(report.simple_render(), "2 list changes on 1 boards");
+    Ok(())
+}
+
+#[test]
+fn test_normalize() -> Result<()> {
+    let _board = mock_board();
+    let rename = mockito::mock("PUT", "/1/lists/L1/?key=some-key&token=some-token")
+        .match_body("name=Doing")
+        .with_status(200)
+        .with_body(json!({"id": "L1", "name": "Doing", "closed": false}).to_string())
+        .create();
+    let create = mockito::mock("POST", "/1/lists/?key=some-key&token=some-token")
+        .match_body("name=Done&idBoard=NORMALIZE-BOARD&pos=bottom")
+        .with_status(200)
+        .with_body(json!({"id": "L2", "name": "Done", "closed": false}).to_string())
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let report = lists::normalize(&client, &["NORMALIZE-BOARD"], &["Doing", "Done"], false)?;
+
+    rename.assert();
+    create.assert();
+    assert!(!report.dry_run);
+    assert_eq!(report.changes().count(), 2);
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use super::*;
+
+#[test]
+fn test_deserialize_missing_and_null() {
+    let list: List = serde_json::from_value(json!({
+        "id": "L1",
+        "name": "Todo",
+        "closed": false,
+    }))
+    .unwrap();
+    assert_eq!(list.cards, Loaded::NotFetched);
+
+    let loaded: Loaded<Card> = serde_json::from_value(json!(null)).unwrap();
+    assert_eq!(loaded, Loaded::NotFetched);
+}
+
+#[test]
+fn test_deserialize_empty() {
+    let loaded: Loaded<Card> = serde_json::from_value(json!([])).unwrap();
+    assert_eq!(loaded, Loaded::Fetched(vec![]));
+    assert!(loaded.is_fetched());
+}
+
+#[test]
+fn test_serialize() {
+    let loaded: Loaded<u32> = Loaded::NotFetched;
+    assert_eq!(serde_json::to_value(&loaded).unwrap(), json!(null));
+
+    let loaded = Loaded::Fetched(vec![1, 2]);
+    assert_eq!(serde_json::to_value(&loaded).unwrap(), json!([1, 2]));
+}
+
+#[test]
+fn test_accessors() {
+    let mut loaded: Loaded<u32> = Loaded::NotFetched;
+    assert_eq!(loaded.get(), None);
+    assert_eq!(loaded.as_slice(), &[] as &[u32]);
+    assert_eq!(loaded.iter().count(), 0);
+
+    loaded.get_or_insert_empty().push(3);
+    assert_eq!(loaded.get(), Some(&[3][..]));
+
+    let taken = loaded.take();
+    assert_eq!(loaded, Loaded::NotFetched);
+    assert_eq!(
+        taken
+            .map(|v| v.into_iter().map(|x| x * 2).collect())
+            .into_vec(),
+        vec![6]
+    );
+}
+
+#[test]
+fn test_from_option() {
+    let loaded: Loaded<u32> = None.into();
+    assert!(!loaded.is_fetched());
+
+    let loaded: Loaded<u32> = Some(vec![1]).into();
+    let values: Option<Vec<u32>> = loaded.into();
+    assert_eq!(values, Some(vec![1]));
+}
@@ -0,0 +1,82 @@
+use super::*;
+
+use colored::*;
+
+#[test]
+fn test_get_assigned_cards() -> Result<()> {
+    let _m = mockito::mock(
+        "GET",
+        "/1/members/me/cards?key=some-key&token=some-token&fields=id%2Cname%2Cdesc%2Clabels%2Cclosed%2Cdue%2Curl%2Csubscribed%2CdateLastActivity%2Cbadges%2CidMembers%2CidShort&board=true&board_fields=id%2Cname%2Cclosed%2Curl%2Csubscribed&list=true&list_fields=id%2Cname%2Cclosed%2CsoftLimit",
+    )
+    .with_status(200)
+    .with_body(
+        json!([
+            {
+                "id": "C1", "name": "Dig", "desc": "", "closed": false, "url": "",
+                "board": {"id": "B1", "name": "Shovel", "closed": false, "url": ""},
+                "list": {"id": "L1", "name": "Todo", "closed": false},
+            },
+            {
+                "id": "C2", "name": "Bounce", "desc": "", "closed": false, "url": "",
+                "board": {"id": "B2", "name": "Knights", "closed": false, "url": ""},
+                "list": {"id": "L2", "name": "Doing", "closed": false},
+            },
+            {
+                "id": "C3", "name": "Fish", "desc": "", "closed": false, "url": "",
+                "board": {"id": "B1", "name": "Shovel", "closed": false, "url": ""},
+                "list": {"id": "L3", "name": "Done", "closed": false},
+            },
+        ])
+        .to_string(),
+    )
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let result = Member::get_assigned_cards(&client)?;
+    assert_eq!(result.cards.len(), 3);
+    assert_eq!(
+        result.cards[0].card,
+        Card::new("C1", "Dig", "", None, "", None)
+    );
+    assert_eq!(result.cards[1].list, List::new("L2", "Doing", None));
+    assert_eq!(result.simple_render(), "3 cards on 2 boards");
+
+    let expected = [
+        " Shovel \n========".bold().to_string(),
+        format!("* Dig {}", "(Todo)".dimmed()),
+        format!("* Fish {}", "(Done)".dimmed()),
+        String::from(""),
+        " Knights \n=========".bold().to_string(),
+        format!("* Bounce {}", "(Doing)".dimmed()),
+    ]
+    .join("\n");
+    assert_eq!(result.render(), expected);
+    Ok(())
+}
+
+#[test]
+fn test_get_all_cached() -> Result<()> {
+    let members = mockito::mock(
+        "GET",
+        "/1/boards/MEMBERS-BOARD/members?key=some-key&token=some-token&fields=id%2CfullName%2Cusername",
+    )
+    .with_status(200)
+    .with_body(json!([{"id": "M1", "fullName": "Ada Lovelace", "username": "ada"}]).to_string())
+    .expect(2)
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let result = Member::get_all(&client, "MEMBERS-BOARD")?;
+    assert_eq!(result[0].username, "ada");
+    assert_eq!(Member::get_all(&client, "MEMBERS-BOARD")?, result);
+
+    client.invalidate_lookups("MEMBERS-BOARD");
+    Member::get_all(&client, "MEMBERS-BOARD")?;
+
+    members.assert();
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use super::*;
+
+use chrono::{TimeZone, Utc};
+use metrics::render_prometheus;
+
+#[test]
+fn test_render_prometheus() {
+    let mut overdue = Card::new("5eac8f60a3e4b10a3c9c5c2e", "Late", "", None, "", None);
+    overdue.due = Some(Utc.ymd(2020, 5, 2).and_hms(0, 0, 0));
+
+    let board = Board::new(
+        "B",
+        "Team \"A\"",
+        Some(vec![
+            List::new("L1", "Todo", Some(vec![overdue])),
+            List::new(
+                "L2",
+                "Done",
+                Some(vec![Card::new(
+                    "5eac8f61a3e4b10a3c9c5c2f",
+                    "Ok",
+                    "",
+                    None,
+                    "",
+                    None,
+                )]),
+            ),
+        ]),
+        "",
+    );
+
+    let mut closed = Card::new("5eadd4e0a3e4b10a3c9c5c30", "Old", "", None, "", None);
+    closed.closed = true;
+    closed.date_last_activity = Some(Utc.ymd(2020, 5, 3).and_hms(9, 0, 0));
+
+    let now = Utc.ymd(2020, 5, 4).and_hms(0, 0, 0);
+    let expected = [
+        "# HELP trello_cards Number of open cards in each list",
+        "# TYPE trello_cards gauge",
+        r#"trello_cards{board="Team \"A\"",list="Todo"} 1"#,
+        r#"trello_cards{board="Team \"A\"",list="Done"} 1"#,
+        "# HELP trello_cards_overdue Number of open cards past their due date",
+        "# TYPE trello_cards_overdue gauge",
+        r#"trello_cards_overdue{board="Team \"A\""} 1"#,
+        "# HELP trello_cards_created Number of cards created on each day",
+        "# TYPE trello_cards_created gauge",
+        r#"trello_cards_created{board="Team \"A\"",day="2020-05-01"} 2"#,
+        r#"trello_cards_created{board="Team \"A\"",day="2020-05-02"} 1"#,
+        "# HELP trello_cards_closed Number of cards archived on each day",
+        "# TYPE trello_cards_closed gauge",
+        r#"trello_cards_closed{board="Team \"A\"",day="2020-05-03"} 1"#,
+        "",
+    ]
+    .join("\n");
+    assert_eq!(render_prometheus(&board, &[closed], now), expected);
+}
@@ -0,0 +1,156 @@
+use super::*;
+
+#[cfg(feature = "desktop-notify")]
+use chrono::Duration;
+use chrono::{TimeZone, Utc};
+use notify::{SlackMessage, SlackNotifier};
+use watch::{Event, EventType};
+
+fn event(event: EventType, name: &str, list: &str, from_list: Option<&str>) -> Event {
+    Event {
+        event,
+        card_id: CardId::new("5f0a1b"),
+        card_name: String::from(name),
+        list: String::from(list),
+        from_list: from_list.map(String::from),
+        timestamp: Utc.ymd(2020, 6, 28).and_hms(6, 6, 27),
+    }
+}
+
+#[test]
+fn test_from_events() {
+    let events = [
+        event(EventType::CardMoved, "Fix <login>", "Done", Some("Doing")),
+        event(EventType::CardCreated, "Q&A", "Todo", None),
+    ];
+
+    let message = SlackMessage::from_events("Tro", &events);
+
+    assert_eq!(
+        serde_json::to_value(&message).unwrap(),
+        json!({
+            "text": "2 changes on Tro",
+            "blocks": [
+                {"type": "header", "text": {"type": "plain_text", "text": "Tro"}},
+                {
+                    "type": "section",
+                    "text": {
+                        "type": "mrkdwn",
+                        "text": "*<https://trello.com/c/5f0a1b|Fix &lt;login&gt;>* moved from _Doing_ to _Done_",
+                    },
+                },
+                {
+                    "type": "section",
+                    "text": {
+                        "type": "mrkdwn",
+                        "text": "*<https://trello.com/c/5f0a1b|Q&amp;A>* was added to _Todo_",
+                    },
+                },
+            ],
+        })
+    );
+}
+
+#[test]
+fn test_from_events_block_limit() {
+    let events = (0..60)
+        .map(|_| event(EventType::CardRemoved, "Chore", "Todo", None))
+        .collect::<Vec<Event>>();
+
+    let message = SlackMessage::from_events("Tro", &events);
+
+    assert_eq!(message.blocks.len(), 50);
+    assert_eq!(
+        message.blocks[49],
+        json!({
+            "type": "context",
+            "elements": [{"type": "mrkdwn", "text": "and 12 more changes"}],
+        })
+    );
+}
+
+#[test]
+fn test_notify_events() -> Result<()> {
+    let _m = mockito::mock("POST", "/services/T000/B000/XXXX")
+        .match_header("content-type", "application/json")
+        .match_body(mockito::Matcher::PartialJson(
+            json!({"text": "1 changes on Tro"}),
+        ))
+        .with_status(200)
+        .with_body("ok")
+        .expect(1)
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+    let notifier = SlackNotifier::new(&format!(
+        "{}/services/T000/B000/XXXX",
+        mockito::server_url()
+    ))?;
+
+    notifier.notify_events(&client, "Tro", &[])?;
+    notifier.notify_events(
+        &client,
+        "Tro",
+        &[event(EventType::CardRenamed, "Chore", "Todo", None)],
+    )?;
+
+    _m.assert();
+    Ok(())
+}
+
+#[cfg(feature = "desktop-notify")]
+#[test]
+fn test_due_within() {
+    use notify::desktop::due_within;
+
+    let now = Utc.ymd(2020, 6, 28).and_hms(9, 0, 0);
+    let due = |h: u32| Some(Utc.ymd(2020, 6, 28).and_hms(h, 0, 0));
+    let mut archived = Card::new("4", "Archived", "", None, "", due(10));
+    archived.closed = true;
+    let cards = [
+        Card::new("1", "Later", "", None, "", due(12)),
+        Card::new("2", "Soon", "", None, "", due(10)),
+        Card::new("3", "Overdue", "", None, "", due(8)),
+        Card::new("5", "Tomorrow", "", None, "", due(23)),
+        Card::new("6", "Whenever", "", None, "", None),
+        archived,
+    ];
+
+    let result = due_within(&cards, now, Duration::hours(4));
+
+    assert_eq!(
+        result
+            .iter()
+            .map(|n| n.card_name.as_str())
+            .collect::<Vec<&str>>(),
+        vec!["Soon", "Later"]
+    );
+    assert_eq!(result[0].body(now), "due in 1h");
+}
+
+#[cfg(feature = "desktop-notify")]
+#[test]
+fn test_desktop_notifier_pending() {
+    use notify::desktop::DesktopNotifier;
+
+    let now = Utc.ymd(2020, 6, 28).and_hms(9, 0, 0);
+    let mut card = Card::new("1", "Soon", "", None, "", Some(now + Duration::hours(1)));
+    let mut notifier = DesktopNotifier::new(Duration::hours(2));
+
+    assert_eq!(notifier.pending(&[card.clone()], now).len(), 1);
+    assert_eq!(notifier.pending(&[card.clone()], now).len(), 0);
+
+    // a new due date is notified again
+    card.due = Some(now + Duration::minutes(90));
+    assert_eq!(notifier.pending(&[card], now).len(), 1);
+}
+
+#[cfg(feature = "desktop-notify")]
+#[test]
+fn test_applescript_string() {
+    assert_eq!(
+        notify::desktop::applescript_string(r#"Fix "login" \ logout"#),
+        r#""Fix \"login\" \\ logout""#
+    );
+}
@@ -0,0 +1,270 @@
+use super::*;
+
+use chrono::{TimeZone, Utc};
+use ops::Inbox;
+
+fn mock_board() -> (mockito::Mock, mockito::Mock) {
+    let boards = mockito::mock(
+        "GET",
+        "/1/members/me/boards/?key=ops-key&token=ops-token&filter=open&fields=id%2Cname%2Cclosed%2Curl%2Csubscribed",
+    )
+    .with_status(200)
+    .with_body(
+        json!([
+            {"id": "OPS-BOARD", "name": "Groceries", "closed": false, "url": ""},
+            {"id": "OTHER-BOARD", "name": "Chores", "closed": false, "url": ""},
+        ])
+        .to_string(),
+    )
+    .create();
+
+    let lists = mockito::mock(
+        "GET",
+        "/1/boards/OPS-BOARD/lists?key=ops-key&token=ops-token&fields=id%2Cname%2Cclosed%2CsoftLimit&cards=open",
+    )
+    .with_status(200)
+    .with_body(
+        json!([
+            {"id": "TODO-LIST", "name": "TODO", "closed": false, "cards": [
+                {"id": "MILK-CARD", "name": "Milk", "desc": "", "closed": false, "url": ""},
+                {"id": "OAT-MILK-CARD", "name": "Oat Milk", "desc": "", "closed": false, "url": ""},
+            ]},
+            {"id": "DONE-LIST", "name": "Done", "closed": false, "cards": []},
+        ])
+        .to_string(),
+    )
+    .create();
+
+    (boards, lists)
+}
+
+fn client() -> TrelloClient {
+    TrelloClient::new(ClientConfig::new(
+        &mockito::server_url(),
+        "ops-token",
+        "ops-key",
+    ))
+}
+
+#[test]
+fn test_add_card() -> Result<()> {
+    let _m = mock_board();
+    let _create = mockito::mock("POST", "/1/cards/?key=ops-key&token=ops-token")
+        .match_body("name=Eggs&desc=&idList=TODO-LIST")
+        .with_status(200)
+        .with_body(
+            json!({"id": "EGGS-CARD", "name": "Eggs", "desc": "", "closed": false, "url": ""})
+                .to_string(),
+        )
+        .create();
+
+    let card = ops::add_card(&client(), "groceries", "todo", "Eggs")?;
+
+    assert_eq!(card.id, "EGGS-CARD");
+    Ok(())
+}
+
+#[test]
+fn test_move_card_prefers_exact_match() -> Result<()> {
+    let _m = mock_board();
+    let _move = mockito::mock("PUT", "/1/cards/MILK-CARD/?key=ops-key&token=ops-token")
+        .match_body("idList=DONE-LIST")
+        .with_status(200)
+        .with_body("{}")
+        .create();
+
+    ops::move_card(&client(), "groc", "milk", "done")?;
+
+    _move.assert();
+    Ok(())
+}
+
+#[test]
+fn test_label_card() -> Result<()> {
+    let _m = mock_board();
+    let _labels = mockito::mock(
+        "GET",
+        "/1/boards/OPS-BOARD/labels?key=ops-key&token=ops-token&fields=id%2Cname%2Ccolor",
+    )
+    .with_status(200)
+    .with_body(json!([{"id": "DAIRY-LABEL", "name": "Dairy", "color": "blue"}]).to_string())
+    .create();
+    let _apply = mockito::mock(
+        "POST",
+        "/1/cards/OAT-MILK-CARD/idLabels?key=ops-key&token=ops-token",
+    )
+    .match_body("value=DAIRY-LABEL")
+    .with_status(200)
+    .with_body("[]")
+    .create();
+
+    ops::label_card(&client(), "Groceries", "oat", "dairy")?;
+
+    _apply.assert();
+    Ok(())
+}
+
+#[test]
+fn test_unresolved_names() {
+    let _m = mock_board();
+
+    let result = ops::move_card(&client(), "Groceries", "ilk", "done");
+    match result {
+        Err(TrelloError::Ambiguous(msg)) => {
+            assert_eq!(msg, "Card 'ilk' matches 'Milk', 'Oat Milk'")
+        }
+        other => panic!("Unexpected result: {:?}", other),
+    }
+
+    let result = ops::add_card(&client(), "Groceries", "doing", "Eggs");
+    match result {
+        Err(TrelloError::NotFound(msg)) => assert_eq!(msg, "List 'doing'"),
+        other => panic!("Unexpected result: {:?}", other),
+    }
+}
+
+fn capture_client() -> TrelloClient {
+    let config = ClientConfig::new(&mockito::server_url(), "capture-token", "capture-key");
+    TrelloClient::new(config)
+}
+
+fn mock_inbox() -> (mockito::Mock, mockito::Mock) {
+    let boards = mockito::mock(
+        "GET",
+        "/1/members/me/boards/?key=capture-key&token=capture-token&filter=open&fields=id%2Cname%2Cclosed%2Curl%2Csubscribed",
+    )
+    .with_status(200)
+    .with_body(
+        json!([
+            {"id": "HOME-BOARD", "name": "Home", "closed": false, "url": ""},
+            {"id": "WORK-BOARD", "name": "Work", "closed": false, "url": ""},
+        ])
+        .to_string(),
+    )
+    .create();
+
+    // captured just now and yesterday
+    let lists = mockito::mock(
+        "GET",
+        "/1/boards/HOME-BOARD/lists?key=capture-key&token=capture-token&fields=id%2Cname%2Cclosed%2CsoftLimit&cards=open",
+    )
+    .with_status(200)
+    .with_body(
+        json!([
+            {"id": "INBOX-LIST", "name": "Inbox", "closed": false, "cards": [
+                {"id": "5ef830b4aaaa", "name": "Call mom", "desc": "", "closed": false, "url": ""},
+                {"id": "5ef6e060aaaa", "name": "Buy milk", "desc": "", "closed": false, "url": ""},
+            ]},
+            {"id": "SOMEDAY-LIST", "name": "Someday", "closed": false, "cards": []},
+        ])
+        .to_string(),
+    )
+    .create();
+
+    (boards, lists)
+}
+
+fn inbox() -> Inbox {
+    Inbox {
+        board: String::from("Home"),
+        list: String::from("Inbox"),
+    }
+}
+
+#[test]
+fn test_quick_capture() -> Result<()> {
+    let _m = mock_inbox();
+    let _labels = mockito::mock(
+        "GET",
+        "/1/boards/HOME-BOARD/labels?key=capture-key&token=capture-token&fields=id%2Cname%2Ccolor",
+    )
+    .with_status(200)
+    .with_body(json!([{"id": "ERRAND-LABEL", "name": "Errand", "color": "green"}]).to_string())
+    .create();
+    let _create = mockito::mock("POST", "/1/cards/?key=capture-key&token=capture-token")
+        .match_body(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("name".into(), "Buy milk".into()),
+            mockito::Matcher::UrlEncoded("idList".into(), "INBOX-LIST".into()),
+            mockito::Matcher::UrlEncoded("pos".into(), "bottom".into()),
+            mockito::Matcher::UrlEncoded("idLabels".into(), "ERRAND-LABEL".into()),
+            mockito::Matcher::UrlEncoded("due".into(), "2020-07-01T09:00:00+00:00".into()),
+        ]))
+        .with_status(200)
+        .with_body(
+            json!({"id": "5ef831e0bbbb", "name": "Buy milk", "desc": "", "closed": false, "url": ""})
+                .to_string(),
+        )
+        .expect(1)
+        .create();
+
+    let now = Utc.ymd(2020, 6, 28).and_hms(6, 0, 0);
+    let captured = ops::quick_capture_at(
+        &capture_client(),
+        &inbox(),
+        "Buy #errand milk !due:2020-07-01T09:00:00Z",
+        now,
+    )?;
+
+    assert!(!captured.duplicate);
+    assert_eq!(captured.card.id, CardId::new("5ef831e0bbbb"));
+    _create.assert();
+    Ok(())
+}
+
+#[test]
+fn test_quick_capture_duplicate() -> Result<()> {
+    let _m = mock_inbox();
+    let _create = mockito::mock("POST", "/1/cards/?key=capture-key&token=capture-token")
+        .expect(0)
+        .create();
+
+    let now = Utc.ymd(2020, 6, 28).and_hms(6, 0, 0);
+    let captured = ops::quick_capture_at(&capture_client(), &inbox(), "Call   mom", now)?;
+
+    assert!(captured.duplicate);
+    assert_eq!(captured.card.id, CardId::new("5ef830b4aaaa"));
+    _create.assert();
+    Ok(())
+}
+
+#[test]
+fn test_quick_capture_to_list() -> Result<()> {
+    let _m = mock_inbox();
+    let _create = mockito::mock("POST", "/1/cards/?key=capture-key&token=capture-token")
+        .match_body(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("name".into(), "Learn Go".into()),
+            mockito::Matcher::UrlEncoded("idList".into(), "SOMEDAY-LIST".into()),
+        ]))
+        .with_status(200)
+        .with_body(
+            json!({"id": "5ef831e0cccc", "name": "Learn Go", "desc": "", "closed": false, "url": ""})
+                .to_string(),
+        )
+        .expect(1)
+        .create();
+
+    let now = Utc.ymd(2020, 6, 28).and_hms(6, 0, 0);
+    ops::quick_capture_at(&capture_client(), &inbox(), "Learn Go @someday", now)?;
+
+    _create.assert();
+    Ok(())
+}
+
+#[test]
+fn test_quick_capture_nothing() {
+    let now = Utc.ymd(2020, 6, 28).and_hms(6, 0, 0);
+
+    match ops::quick_capture_at(&capture_client(), &inbox(), "#errand @Home", now) {
+        Err(TrelloError::CardParse(_)) => {}
+        other => panic!("Unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn test_inbox_from_toml() {
+    let config = "token = \"capture-token\"\nkey = \"capture-key\"\n";
+    assert_eq!(Inbox::from_toml(config).unwrap(), None);
+
+    let config = format!("{}\n[inbox]\nboard = \"Home\"\nlist = \"Inbox\"\n", config);
+    assert_eq!(Inbox::from_toml(&config).unwrap(), Some(inbox()));
+}
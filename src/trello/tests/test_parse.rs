@@ -0,0 +1,89 @@
+use super::*;
+
+use chrono::{TimeZone, Utc};
+use parse::{card_spec_at, CardSpec};
+
+#[test]
+fn test_card_spec() -> Result<()> {
+    let now = Utc.ymd(2020, 6, 28).and_hms(6, 0, 0);
+
+    assert_eq!(
+        card_spec_at(
+            "Fix #bug login #urgent !due:2020-07-01T09:00:00Z @Backlog",
+            now
+        )?,
+        CardSpec {
+            name: String::from("Fix login"),
+            labels: vec![String::from("bug"), String::from("urgent")],
+            due: Some(Utc.ymd(2020, 7, 1).and_hms(9, 0, 0)),
+            target: Some(String::from("Backlog")),
+        }
+    );
+
+    // lone markers are part of the name
+    assert_eq!(
+        card_spec_at("  Ship it ! # @ ", now)?,
+        CardSpec {
+            name: String::from("Ship it ! # @"),
+            ..CardSpec::default()
+        }
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_card_spec_exclamation() -> Result<()> {
+    let now = Utc.ymd(2020, 6, 28).and_hms(6, 0, 0);
+
+    let mut texts = vec!["Fix !!", "Fix login !someday"];
+    // "now" is a date with the human-dates feature
+    if cfg!(not(feature = "human-dates")) {
+        texts.push("Deploy !now");
+    }
+
+    for text in texts {
+        assert_eq!(
+            card_spec_at(text, now)?,
+            CardSpec {
+                name: String::from(text),
+                ..CardSpec::default()
+            }
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_card_spec_invalid_due() {
+    let now = Utc.ymd(2020, 6, 28).and_hms(6, 0, 0);
+
+    match card_spec_at("Fix login !due:someday", now) {
+        Err(TrelloError::DateParse(_)) => {}
+        other => panic!("Unexpected result: {:?}", other),
+    }
+}
+
+#[cfg(not(feature = "human-dates"))]
+#[test]
+fn test_card_spec_relative_due() -> Result<()> {
+    // a Sunday, 2 in the morning in Istanbul
+    let now = chrono::FixedOffset::east(3 * 3600)
+        .ymd(2020, 6, 28)
+        .and_hms(2, 0, 0);
+    let due = |text: &str| card_spec_at(&format!("Fix login !{}", text), now).map(|s| s.due);
+
+    assert_eq!(due("today")?, Some(Utc.ymd(2020, 6, 28).and_hms(9, 0, 0)));
+    assert_eq!(
+        due("Tomorrow")?,
+        Some(Utc.ymd(2020, 6, 29).and_hms(9, 0, 0))
+    );
+    assert_eq!(due("fri")?, Some(Utc.ymd(2020, 7, 3).and_hms(9, 0, 0)));
+    assert_eq!(due("sunday")?, Some(Utc.ymd(2020, 6, 28).and_hms(9, 0, 0)));
+    assert_eq!(
+        due("due:2020-08-01")?,
+        Some(Utc.ymd(2020, 8, 1).and_hms(9, 0, 0))
+    );
+
+    Ok(())
+}
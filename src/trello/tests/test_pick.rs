@@ -0,0 +1,40 @@
+use super::*;
+
+use pick::{rank, score};
+
+#[test]
+fn test_score_no_match() {
+    assert_eq!(score("xyz", "Backlog"), None);
+    // order matters
+    assert_eq!(score("gb", "Backlog"), None);
+}
+
+#[test]
+fn test_score_empty_pattern() {
+    assert_eq!(score("", "Backlog"), Some(0));
+}
+
+#[test]
+fn test_score_prefers_consecutive() {
+    let consecutive = score("back", "Backlog").unwrap();
+    let scattered = score("back", "Bug Attack").unwrap();
+    assert!(consecutive > scattered);
+}
+
+#[test]
+fn test_score_ignores_case() {
+    assert_eq!(score("BACK", "backlog"), score("back", "Backlog"));
+}
+
+#[test]
+fn test_rank() {
+    let lists = vec![
+        List::new("1", "Done", None),
+        List::new("2", "Bug Attack", None),
+        List::new("3", "Backlog", None),
+    ];
+
+    assert_eq!(rank("back", &lists), vec![2, 1]);
+    assert_eq!(rank("", &lists), vec![0, 1, 2]);
+    assert_eq!(rank("zzz", &lists), Vec::<usize>::new());
+}
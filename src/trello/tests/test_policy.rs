@@ -0,0 +1,72 @@
+use super::*;
+
+use colored::*;
+use policy::WipLimits;
+
+fn board() -> Board {
+    let mut doing = List::new(
+        "2",
+        "Doing",
+        Some(vec![
+            Card::new("a", "one", "", None, "", None),
+            Card::new("b", "two", "", None, "", None),
+            Card::new("c", "three", "", None, "", None),
+        ]),
+    );
+    doing.soft_limit = Some(5);
+    let mut review = List::new(
+        "3",
+        "Code Review",
+        Some(vec![Card::new("d", "four", "", None, "", None)]),
+    );
+    review.soft_limit = Some(0);
+
+    Board::new(
+        "1",
+        "Kanban",
+        Some(vec![List::new("1", "Backlog", Some(vec![])), doing, review]),
+        "",
+    )
+}
+
+#[test]
+fn test_check_wip() -> Result<()> {
+    let limits = WipLimits::new().limit("^doing$", 2)?;
+    let report = board().check_wip(&limits);
+
+    let violations = report.violations();
+    assert_eq!(violations.len(), 2);
+    assert_eq!(violations[0].name, "Doing");
+    assert_eq!(violations[0].limit, Some(2));
+    assert_eq!(violations[1].name, "Code Review");
+    assert_eq!(violations[1].limit, Some(0));
+    assert!(!report.is_ok());
+
+    let expected = [
+        "Kanban".bold().to_string(),
+        String::from("Backlog 0"),
+        "Doing 3/2".red().to_string(),
+        "Code Review 1/0".red().to_string(),
+    ]
+    .join("\n");
+    assert_eq!(report.render(), expected);
+    Ok(())
+}
+
+#[test]
+fn test_check_wip_ok() -> Result<()> {
+    let limits = WipLimits::new().limit("review", 1)?.limit("doing", 3)?;
+    let report = board().check_wip(&limits);
+
+    assert!(report.is_ok());
+    assert_eq!(
+        report.simple_render(),
+        "Kanban: 0 lists over their WIP limit"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_invalid_pattern() {
+    assert!(WipLimits::new().limit("(", 1).is_err());
+}
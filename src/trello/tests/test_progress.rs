@@ -0,0 +1,148 @@
+use super::*;
+
+use progress::{percent, Progress, RunOptions};
+
+#[derive(Default)]
+struct Recorder {
+    updates: Vec<(String, usize, usize)>,
+    finished: bool,
+}
+
+impl Progress for Recorder {
+    fn update(&mut self, phase: &str, done: usize, total: usize) {
+        self.updates.push((String::from(phase), done, total));
+    }
+
+    fn finish(&mut self) {
+        self.finished = true;
+    }
+}
+
+fn updates(phase: &str, steps: &[(usize, usize)]) -> Vec<(String, usize, usize)> {
+    steps
+        .iter()
+        .map(|(done, total)| (String::from(phase), *done, *total))
+        .collect()
+}
+
+#[test]
+fn test_percent() {
+    assert_eq!(percent(0, 4), 0);
+    assert_eq!(percent(3, 4), 75);
+    assert_eq!(percent(4, 4), 100);
+    assert_eq!(percent(5, 4), 100);
+    assert_eq!(percent(0, 0), 100);
+}
+
+#[test]
+fn test_flush_with() -> Result<()> {
+    let _labels = mockito::mock(
+        "POST",
+        mockito::Matcher::Regex(String::from(
+            "^/1/cards/PROGRESS-[12]/idLabels\\?key=progress-key",
+        )),
+    )
+    .with_status(200)
+    .with_body("{}")
+    .expect(2)
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "progress-token", "progress-key");
+    let client = TrelloClient::new(config);
+
+    let mut queue = WriteQueue::new().interval(std::time::Duration::from_millis(0));
+    for card_id in &["PROGRESS-1", "PROGRESS-2"] {
+        queue.push(Mutation::ApplyLabel {
+            card_id: CardId::new(card_id),
+            label_id: LabelId::new("LABEL"),
+        });
+    }
+
+    let mut recorder = Recorder::default();
+    let options = RunOptions::new().progress(&mut recorder);
+    assert_eq!(queue.flush_with(&client, options)?, 2);
+
+    _labels.assert();
+    assert_eq!(
+        recorder.updates,
+        updates("Sending changes", &[(0, 2), (1, 2), (2, 2)])
+    );
+    assert!(recorder.finished);
+    Ok(())
+}
+
+#[test]
+fn test_get_many_nested_with() -> Result<()> {
+    let mut mocks = vec![];
+    for board_id in &["PROGRESS-A", "PROGRESS-B", "PROGRESS-C"] {
+        mocks.push(
+            mockito::mock(
+                "GET",
+                format!(
+                    "/1/boards/{}?key=progress-key&token=progress-token&fields=id%2Cname%2Cclosed%2Curl%2Csubscribed",
+                    board_id
+                )
+                .as_str(),
+            )
+            .with_status(200)
+            .with_body(json!({"id": board_id, "name": board_id, "closed": false, "url": ""}).to_string())
+            .create(),
+        );
+        mocks.push(
+            mockito::mock(
+                "GET",
+                format!(
+                    "/1/boards/{}/lists?key=progress-key&token=progress-token&fields=id%2Cname%2Cclosed%2CsoftLimit&cards=open",
+                    board_id
+                )
+                .as_str(),
+            )
+            .with_status(200)
+            .with_body(json!([]).to_string())
+            .create(),
+        );
+    }
+
+    let config = ClientConfig::new(&mockito::server_url(), "progress-token", "progress-key");
+    let client = TrelloClient::new(config);
+
+    let mut recorder = Recorder::default();
+    let boards = Board::get_many_nested_with(
+        &client,
+        &["PROGRESS-A", "PROGRESS-B", "PROGRESS-C"],
+        RunOptions::new().progress(&mut recorder),
+    )?;
+
+    assert_eq!(boards.len(), 3);
+    assert_eq!(
+        recorder.updates,
+        updates("Retrieving boards", &[(0, 3), (1, 3), (2, 3), (3, 3)])
+    );
+    assert!(recorder.finished);
+    Ok(())
+}
+
+#[test]
+fn test_sync_with_error() {
+    let _source = mockito::mock(
+        "GET",
+        "/1/boards/PROGRESS-SOURCE/labels?key=progress-key&token=progress-token&fields=id%2Cname%2Ccolor",
+    )
+    .with_status(404)
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "progress-token", "progress-key");
+    let client = TrelloClient::new(config);
+
+    let mut recorder = Recorder::default();
+    let result = labels::sync_with(
+        &client,
+        "PROGRESS-SOURCE",
+        &["PROGRESS-SOURCE", "PROGRESS-A", "PROGRESS-B"],
+        RunOptions::new().progress(&mut recorder),
+    );
+
+    assert!(result.is_err());
+    assert_eq!(recorder.updates, updates("Syncing labels", &[(0, 2)]));
+    assert!(recorder.finished);
+}
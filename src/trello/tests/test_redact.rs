@@ -0,0 +1,55 @@
+use super::*;
+
+use redact::{redact, redact_url};
+
+#[test]
+fn test_redact_url() -> Result<()> {
+    let config = ClientConfig::new("https://api.trello.com", "SECRET-TOKEN", "SECRET-KEY");
+    let url = config.get_trello_url("/1/boards/", &[("fields", "id,name")])?;
+
+    assert_eq!(
+        redact_url(&url).as_str(),
+        "https://api.trello.com/1/boards/?key=REDACTED&token=REDACTED&fields=id%2Cname"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_redact_url_without_query() -> Result<()> {
+    let url = url::Url::parse("https://trello.com/b/abc")?;
+    assert_eq!(redact_url(&url), url);
+    Ok(())
+}
+
+#[test]
+fn test_redact() {
+    assert_eq!(
+        redact("GET https://example.com/1/cards?token=abc123&key=def456 failed"),
+        "GET https://example.com/1/cards?token=REDACTED&key=REDACTED failed"
+    );
+    assert_eq!(redact("monkey=1&tokens=2"), "monkey=1&tokens=2");
+}
+
+#[test]
+fn test_request_error_redacted() {
+    // nothing listens on port 1, so the request fails with the url in the error
+    let config = ClientConfig::new("http://127.0.0.1:1", "SECRET-TOKEN", "SECRET-KEY");
+    let client = TrelloClient::new(config);
+
+    let error = Board::get(&client, "some-board").unwrap_err();
+    let source = std::error::Error::source(&error).unwrap().to_string();
+
+    for output in &[error.to_string(), format!("{:?}", error), source] {
+        assert!(output.contains("REDACTED"), "{}", output);
+        assert!(!output.contains("SECRET"), "{}", output);
+    }
+}
+
+#[test]
+fn test_redact_quoted() {
+    // the Debug output of a url shows its query as a quoted string
+    assert_eq!(
+        redact(r#"query: Some("key=abc123&token=def456")"#),
+        r#"query: Some("key=REDACTED&token=REDACTED")"#
+    );
+}
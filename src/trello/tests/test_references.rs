@@ -0,0 +1,136 @@
+use super::*;
+
+use references::Resolver;
+
+fn card(id: &str, number: u64, name: &str, short_link: &str) -> Card {
+    let mut card = Card::new(
+        id,
+        name,
+        "",
+        None,
+        &format!("https://trello.com/c/{}/{}-card", short_link, number),
+        None,
+    );
+    card.id_short = Some(number);
+    card
+}
+
+fn board() -> Board {
+    let mut archived = card("C4", 4, "Old logo", "SHORT4");
+    archived.closed = true;
+
+    Board::new(
+        "B",
+        "Website",
+        Some(vec![
+            List::new(
+                "L1",
+                "Todo",
+                Some(vec![
+                    card("C1", 1, "Design the logo", "SHORT1"),
+                    card("C2", 12, "Pick [brand] colors", "SHORT2"),
+                    archived,
+                ]),
+            ),
+            List::new("L2", "Done", Some(vec![card("C3", 3, "Setup", "SHORT3")])),
+        ]),
+        "",
+    )
+}
+
+#[test]
+fn test_resolve() {
+    let board = board();
+    let resolver = Resolver::new(&board);
+
+    assert_eq!(resolver.by_number(12).unwrap().id, CardId::new("C2"));
+    assert!(resolver.by_number(4).is_none());
+    assert_eq!(
+        resolver.by_name(" design THE logo").unwrap().id,
+        CardId::new("C1")
+    );
+    assert_eq!(
+        resolver.by_url("https://trello.com/c/SHORT3").unwrap().id,
+        CardId::new("C3")
+    );
+    assert!(resolver.by_url("https://example.com/c/UNKNOWN").is_none());
+}
+
+#[test]
+fn test_link() {
+    let board = board();
+    let resolver = Resolver::new(&board);
+
+    let text = "See #12 and [[design the logo]].\n#3 first, not #99, [[Nothing]] or #4.\n\
+                Keep https://example.com/#12, &#12; and issue#12";
+    let linked = resolver.link(text);
+
+    assert_eq!(
+        linked,
+        "See [#12 Pick \\[brand\\] colors](https://trello.com/c/SHORT2/12-card) and \
+         [Design the logo](https://trello.com/c/SHORT1/1-card).\n\
+         [#3 Setup](https://trello.com/c/SHORT3/3-card) first, not #99, [[Nothing]] or #4.\n\
+         Keep https://example.com/#12, &#12; and issue#12"
+    );
+    assert_eq!(
+        resolver.unlink(&linked),
+        "See #12 and [[Design the logo]].\n#3 first, not #99, [[Nothing]] or #4.\n\
+         Keep https://example.com/#12, &#12; and issue#12"
+    );
+}
+
+#[test]
+fn test_link_twice() {
+    let board = board();
+    let resolver = Resolver::new(&board);
+
+    let text = "See #12 and [[Setup]], or [#3 again](https://example.com)";
+    let linked = resolver.link(text);
+
+    assert_eq!(
+        linked,
+        "See [#12 Pick \\[brand\\] colors](https://trello.com/c/SHORT2/12-card) and \
+         [Setup](https://trello.com/c/SHORT3/3-card), or [#3 again](https://example.com)"
+    );
+    assert_eq!(resolver.link(&linked), linked);
+}
+
+#[test]
+fn test_unlink_keeps_other_links() {
+    let board = board();
+    let resolver = Resolver::new(&board);
+
+    let text = "[the logo](https://trello.com/c/SHORT1/1-card) [Setup](https://example.com)";
+    assert_eq!(resolver.unlink(text), text);
+}
+
+#[test]
+fn test_link_descriptions() -> Result<()> {
+    let mut board = board();
+    for card in board.lists.iter_mut().flat_map(|l| l.cards.iter_mut()) {
+        card.desc = match card.id.as_str() {
+            "C1" => String::from("After #3"),
+            _ => String::from("Nothing to link"),
+        };
+    }
+
+    let _patch = mockito::mock("PUT", "/1/cards/C1/?key=ref-key&token=ref-token")
+        .match_body(mockito::Matcher::UrlEncoded(
+            "desc".into(),
+            "After [#3 Setup](https://trello.com/c/SHORT3/3-card)".into(),
+        ))
+        .with_status(200)
+        .with_body(
+            json!({"id": "C1", "name": "Design the logo", "desc": "", "closed": false, "url": ""})
+                .to_string(),
+        )
+        .expect(1)
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "ref-token", "ref-key");
+    let client = TrelloClient::new(config);
+
+    assert_eq!(references::link_descriptions(&client, &board)?, 1);
+    _patch.assert();
+    Ok(())
+}
@@ -0,0 +1,143 @@
+use super::*;
+
+use chrono::{TimeZone, Utc};
+use report::{ListStats, Report};
+use std::path::Path;
+
+fn report_board() -> Board {
+    let mut boots = Card::new("1", "Gyro Boots", "", None, "", None);
+    boots.closed = true;
+    let rat = Card::new(
+        "2",
+        "Propeller <Rat>",
+        "",
+        Some(vec![Label::new("", "Relic", "red")]),
+        "",
+        Some(Utc.ymd(2020, 6, 1).and_hms(0, 0, 0)),
+    );
+    let wand = Card::new(
+        "3",
+        "Flare Wand",
+        "",
+        None,
+        "",
+        Some(Utc.ymd(2020, 7, 1).and_hms(0, 0, 0)),
+    );
+    let dust = Card::new(
+        "4",
+        "Dust Knuckles",
+        "",
+        None,
+        "",
+        Some(Utc.ymd(2020, 5, 1).and_hms(0, 0, 0)),
+    );
+    let mut specter = List::new("", "Specter", Some(vec![]));
+    specter.closed = true;
+
+    Board::new(
+        "",
+        "Knights",
+        Some(vec![
+            List::new("", "King", Some(vec![boots, rat, wand])),
+            List::new("", "Shovel", Some(vec![dust])),
+            specter,
+        ]),
+        "",
+    )
+}
+
+#[test]
+fn test_new() {
+    let report = Report::new(&report_board(), Utc.ymd(2020, 6, 15).and_hms(0, 0, 0));
+
+    assert_eq!(
+        report.stats,
+        vec![
+            ListStats {
+                name: String::from("King"),
+                open: 2,
+                with_due: 2,
+                overdue: 1,
+                without_labels: 1,
+            },
+            ListStats {
+                name: String::from("Shovel"),
+                open: 1,
+                with_due: 1,
+                overdue: 1,
+                without_labels: 1,
+            },
+        ]
+    );
+    assert_eq!(
+        report.totals(),
+        ListStats {
+            name: String::from("Total"),
+            open: 3,
+            with_due: 3,
+            overdue: 2,
+            without_labels: 2,
+        }
+    );
+
+    let overdue = report
+        .overdue
+        .iter()
+        .map(|n| (n.card.name.as_str(), n.list_name.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        overdue,
+        vec![("Dust Knuckles", "Shovel"), ("Propeller <Rat>", "King")]
+    );
+
+    let lists = report
+        .cards
+        .iter()
+        .map(|(name, cards)| (name.as_str(), cards.len()))
+        .collect::<Vec<_>>();
+    assert_eq!(lists, vec![("King", 2), ("Shovel", 1)]);
+}
+
+#[test]
+fn test_to_html() {
+    let report = Report::new(&report_board(), Utc.ymd(2020, 6, 15).and_hms(0, 0, 0));
+    let html = report.to_html();
+
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<title>Knights report</title>"));
+    assert!(html.contains("<p class=\"generated\">Generated 2020-06-15 00:00 UTC</p>"));
+    assert!(html.contains(
+        "<tr><td>Total</td><td class=\"number\">3</td><td class=\"number\">3</td>\
+         <td class=\"number\">2</td><td class=\"number\">2</td></tr>"
+    ));
+    assert!(html.contains(
+        "<tr class=\"overdue\"><td>Propeller &lt;Rat&gt;</td><td>King</td><td>2020-06-01</td></tr>"
+    ));
+    assert!(html.contains("<tr class=\"overdue\"><td>Propeller &lt;Rat&gt;</td><td>Relic</td>"));
+    assert!(html.contains("<tr><td>Flare Wand</td><td></td><td>2020-07-01</td></tr>"));
+    assert!(!html.contains("Gyro Boots"));
+    assert!(!html.contains("Specter"));
+}
+
+#[test]
+fn test_to_html_no_overdue() {
+    let report = Report::new(&report_board(), Utc.ymd(2020, 1, 1).and_hms(0, 0, 0));
+
+    assert!(report.overdue.is_empty());
+    assert!(report.to_html().contains("<p>No overdue cards.</p>"));
+}
+
+#[test]
+fn test_write_pdf_missing_converter() {
+    let report = Report::new(&report_board(), Utc.ymd(2020, 1, 1).and_hms(0, 0, 0));
+
+    let result = report.write_pdf("tro-no-such-converter", Path::new("report.pdf"));
+    assert!(matches!(result, Err(TrelloError::Io(_))));
+}
+
+#[test]
+fn test_render() {
+    let report = Report::new(&report_board(), Utc.ymd(2020, 6, 15).and_hms(0, 0, 0));
+
+    assert_eq!(report.simple_render(), "Knights: 2 overdue cards");
+}
@@ -0,0 +1,310 @@
+use super::*;
+
+use chrono::{TimeZone, Utc};
+use reports::{email_digest, release_notes, Period};
+
+fn done_card(name: &str, labels: &[&str], day: u32) -> Card {
+    let labels = labels.iter().map(|l| Label::new("", l, "green")).collect();
+    let mut card = Card::new("", name, "", Some(labels), "", None);
+    card.date_last_activity = Some(Utc.ymd(2020, 6, day).and_hms(12, 0, 0));
+    card
+}
+
+#[test]
+fn test_release_notes() -> Result<()> {
+    let mut search = done_card("Search boards", &["Feature"], 10);
+    search.url = String::from("https://trello.com/c/a1B2c3D4");
+
+    let board = Board::new(
+        "",
+        "Tro",
+        Some(vec![
+            List::new(
+                "",
+                "Doing",
+                Some(vec![done_card("Upload progress", &["Feature"], 12)]),
+            ),
+            List::new(
+                "",
+                "Done (v2.9)",
+                Some(vec![
+                    search,
+                    done_card("Crash on empty board", &["bug"], 11),
+                    done_card("Bump dependencies", &[], 12),
+                    done_card("Old feature", &["Feature"], 1),
+                    done_card("Dark mode", &["UI", "Enhancement"], 14),
+                ]),
+            ),
+        ]),
+        "",
+    );
+
+    let notes = release_notes(&board, "^done", Utc.ymd(2020, 6, 5).and_hms(0, 0, 0))?;
+
+    #[rustfmt::skip]
+    let expected = [
+        "# Tro release notes since 2020-06-05",
+        "",
+        "## Features",
+        "",
+        "- Search boards ([card](https://trello.com/c/a1B2c3D4))",
+        "- Dark mode",
+        "",
+        "## Fixes",
+        "",
+        "- Crash on empty board",
+        "",
+        "## Chores",
+        "",
+        "- Bump dependencies",
+    ].join("\n");
+
+    assert_eq!(notes, expected);
+    Ok(())
+}
+
+#[test]
+fn test_release_notes_empty() -> Result<()> {
+    let board = Board::new(
+        "",
+        "Tro",
+        Some(vec![List::new("", "Done", Some(vec![]))]),
+        "",
+    );
+
+    let notes = release_notes(&board, "done", Utc.ymd(2020, 6, 5).and_hms(0, 0, 0))?;
+
+    assert_eq!(notes, "# Tro release notes since 2020-06-05\n\nNo changes.");
+    Ok(())
+}
+
+#[test]
+fn test_release_notes_invalid_pattern() {
+    let board = Board::new("", "Tro", Some(vec![]), "");
+
+    let result = release_notes(&board, "(done", Utc.ymd(2020, 6, 5).and_hms(0, 0, 0));
+
+    assert!(matches!(result, Err(TrelloError::Regex(_))));
+}
+
+const NEW_ID: &str = "5ed6e8000000000000000001";
+const OLD_ID: &str = "5e0be1000000000000000002";
+
+fn active_card(id: &str, name: &str, day: u32) -> Card {
+    let mut card = Card::new(id, name, "", None, "", None);
+    card.date_last_activity = Some(Utc.ymd(2020, 6, day).and_hms(12, 0, 0));
+    card
+}
+
+fn digest_board() -> Board {
+    let mut wand = active_card(OLD_ID, "Flare <Wand>", 4);
+    wand.url = String::from("https://trello.com/c/a1B2c3D4");
+
+    Board::new(
+        "",
+        "Knights",
+        Some(vec![
+            List::new(
+                "",
+                "Todo",
+                Some(vec![
+                    active_card(NEW_ID, "Gyro Boots", 3),
+                    active_card(OLD_ID, "Stale", 20),
+                ]),
+            ),
+            List::new("", "Doing", Some(vec![wand])),
+            List::new(
+                "",
+                "Done",
+                Some(vec![
+                    active_card(NEW_ID, "Propeller Rat", 5),
+                    active_card(OLD_ID, "Long done", 20),
+                ]),
+            ),
+        ]),
+        "",
+    )
+}
+
+fn june_week() -> Period {
+    Period::new(
+        Utc.ymd(2020, 6, 1).and_hms(0, 0, 0),
+        Utc.ymd(2020, 6, 8).and_hms(0, 0, 0),
+    )
+}
+
+#[test]
+fn test_email_digest() {
+    let digest = email_digest(&digest_board(), june_week());
+
+    let names = |cards: &[(Card, String)]| {
+        cards
+            .iter()
+            .map(|(c, l)| format!("{} [{}]", c.name, l))
+            .collect::<Vec<String>>()
+    };
+    assert_eq!(names(&digest.new), vec!["Gyro Boots [Todo]"]);
+    assert_eq!(names(&digest.moved), vec!["Flare <Wand> [Doing]"]);
+    assert_eq!(names(&digest.completed), vec!["Propeller Rat [Done]"]);
+
+    #[rustfmt::skip]
+    let expected = [
+        "Knights digest 2020-06-01 to 2020-06-08",
+        "",
+        "New cards (1)",
+        "* Gyro Boots [Todo]",
+        "",
+        "Moved cards (1)",
+        "* Flare <Wand> [Doing] https://trello.com/c/a1B2c3D4",
+        "",
+        "Completed cards (1)",
+        "* Propeller Rat [Done]",
+    ].join("\n");
+    assert_eq!(digest.to_text(), expected);
+
+    let html = digest.to_html();
+    assert!(html.contains("<h2>Moved cards (1)</h2>"));
+    assert!(html.contains(
+        "<li><a href=\"https://trello.com/c/a1B2c3D4\">Flare &lt;Wand&gt;</a> <small>Doing</small></li>"
+    ));
+}
+
+#[test]
+fn test_email_digest_to_mime() {
+    let digest = email_digest(&digest_board(), june_week());
+
+    let mime = digest.to_mime("tro@example.com", &["team@example.com", "lead@example.com"]);
+
+    assert!(mime.starts_with(
+        "From: tro@example.com\r\n\
+         To: team@example.com, lead@example.com\r\n\
+         Subject: Knights digest 2020-06-01 to 2020-06-08\r\n\
+         Date: Mon, 08 Jun 2020 00:00:00 +0000\r\n\
+         MIME-Version: 1.0\r\n\
+         Content-Type: multipart/alternative; boundary=\"=_tro_digest\"\r\n\r\n\
+         --=_tro_digest\r\n\
+         Content-Type: text/plain; charset=utf-8\r\n\
+         Content-Transfer-Encoding: base64\r\n\r\n"
+    ));
+    assert!(mime.contains("--=_tro_digest\r\nContent-Type: text/html; charset=utf-8\r\n"));
+    assert!(mime.ends_with("--=_tro_digest--\r\n"));
+    assert!(mime.lines().all(|l| l.len() <= 78));
+
+    let text = mime
+        .split("\r\n\r\n")
+        .nth(2)
+        .unwrap()
+        .split("\r\n--")
+        .next()
+        .unwrap()
+        .replace("\r\n", "");
+    assert_eq!(
+        String::from_utf8(base64::decode(text).unwrap()).unwrap(),
+        digest.to_text()
+    );
+}
+
+#[test]
+fn test_email_digest_to_mime_header_injection() {
+    let mut board = digest_board();
+    board.name = String::from("Knights\r\nBcc: spy@example.com");
+    let digest = email_digest(&board, june_week());
+
+    let mime = digest.to_mime(
+        "tro@example.com\r\nBcc: spy@example.com",
+        &["team@example.com\nX-Spam: yes"],
+    );
+
+    let headers = mime.split("\r\n\r\n").next().unwrap();
+    assert!(!headers.contains("\r\nBcc:"));
+    assert!(!headers.contains("\nX-Spam:"));
+    assert!(headers.starts_with(
+        "From: tro@example.comBcc: spy@example.com\r\n\
+         To: team@example.comX-Spam: yes\r\n\
+         Subject: =?UTF-8?B?"
+    ));
+}
+
+#[test]
+fn test_email_digest_empty() {
+    let period = Period::new(
+        Utc.ymd(2019, 1, 1).and_hms(0, 0, 0),
+        Utc.ymd(2019, 1, 8).and_hms(0, 0, 0),
+    );
+    let digest = email_digest(&digest_board(), period);
+
+    assert!(digest.is_empty());
+    assert_eq!(
+        digest.to_text(),
+        "Knights digest 2019-01-01 to 2019-01-08\n\nNo changes."
+    );
+}
+
+#[cfg(feature = "smtp")]
+#[test]
+fn test_send_digest() -> Result<()> {
+    use reports::{send_digest, SmtpSettings};
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let port = listener.local_addr()?.port();
+
+    // a minimal SMTP server which records the commands and message it receives
+    let server = thread::spawn(move || -> std::io::Result<Vec<String>> {
+        let (stream, _) = listener.accept()?;
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let mut writer = stream;
+        let mut received = vec![];
+        let mut in_data = false;
+
+        writer.write_all(b"220 localhost ESMTP\r\n")?;
+        loop {
+            let mut line = String::new();
+            if reader.read_line(&mut line)? == 0 {
+                break;
+            }
+            let line = line.trim_end().to_string();
+            received.push(line.clone());
+
+            let reply: &[u8] = if in_data {
+                if line != "." {
+                    continue;
+                }
+                in_data = false;
+                b"250 queued\r\n"
+            } else if line.starts_with("EHLO") {
+                b"250 localhost\r\n"
+            } else if line == "DATA" {
+                in_data = true;
+                b"354 go ahead\r\n"
+            } else if line == "QUIT" {
+                writer.write_all(b"221 bye\r\n")?;
+                break;
+            } else {
+                b"250 ok\r\n"
+            };
+            writer.write_all(reply)?;
+        }
+        Ok(received)
+    });
+
+    let mut settings = SmtpSettings::new("127.0.0.1");
+    settings.port = Some(port);
+    settings.starttls = false;
+
+    let digest = email_digest(&digest_board(), june_week());
+    send_digest(&settings, "tro@example.com", &["team@example.com"], &digest)?;
+
+    let received = server.join().unwrap()?;
+    assert!(received.contains(&String::from("MAIL FROM:<tro@example.com>")));
+    assert!(received.contains(&String::from("RCPT TO:<team@example.com>")));
+    assert!(received.contains(&String::from(
+        "Subject: Knights digest 2020-06-01 to 2020-06-08"
+    )));
+
+    let result = send_digest(&settings, "not an address", &[], &digest);
+    assert!(matches!(result, Err(TrelloError::Email(_))));
+    Ok(())
+}
@@ -41,3 +41,99 @@ fn test_empty() -> Result<()> {
     assert_eq!(result, expected);
     Ok(())
 }
+
+#[test]
+fn test_search_attachments() -> Result<()> {
+    let _contract = mockito::mock(
+        "GET",
+        "/1/cards/CARD-1/attachments?key=some-key&token=some-token&fields=id%2Cname%2Curl%2Cbytes%2Cdate%2CmimeType%2CisUpload%2Cpreviews",
+    )
+    .with_status(200)
+    .with_body(
+        json!([
+            {"id": "a1", "name": "Contract.pdf", "url": "https://example.com/contract.pdf", "isUpload": false},
+            {"id": "a2", "name": "logo.png", "url": "https://example.com/logo.png", "isUpload": false},
+            // uploads stored elsewhere are not downloaded
+            {
+                "id": "a4",
+                "name": "draft.txt",
+                "url": "https://trello-attachments.s3.amazonaws.com/CARD-1/draft.txt",
+                "mimeType": "text/plain",
+                "isUpload": true,
+            },
+        ])
+        .to_string(),
+    )
+    .create();
+    let _notes = mockito::mock(
+        "GET",
+        "/1/cards/CARD-2/attachments?key=some-key&token=some-token&fields=id%2Cname%2Curl%2Cbytes%2Cdate%2CmimeType%2CisUpload%2Cpreviews",
+    )
+    .with_status(200)
+    .with_body(
+        json!([{
+            "id": "a3",
+            "name": "notes.md",
+            "url": format!("{}/1/cards/CARD-2/attachments/a3/download/notes.md", mockito::server_url()),
+            "mimeType": "text/markdown",
+            "isUpload": true,
+        }])
+        .to_string(),
+    )
+    .create();
+    let _download = mockito::mock("GET", "/1/cards/CARD-2/attachments/a3/download/notes.md")
+        .with_status(200)
+        .with_body("The signed CONTRACT is on the shared drive")
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let mut skipped = Card::new("CARD-3", "No attachments", "", None, "", None);
+    skipped.badges = Some(Badges {
+        comments: 0,
+        attachments: 0,
+        check_items: 0,
+        check_items_checked: 0,
+        votes: 0,
+        due: None,
+    });
+    let board = Board::new(
+        "",
+        "Legal",
+        Some(vec![List::new(
+            "",
+            "Todo",
+            Some(vec![
+                Card::new("CARD-1", "Sign", "", None, "", None),
+                Card::new("CARD-2", "Store", "", None, "", None),
+                skipped,
+            ]),
+        )]),
+        "",
+    );
+
+    let result = search_attachments(&client, &board, "contract")?
+        .into_iter()
+        .map(|m| (m.card.name, m.list_name, m.attachment.name, m.in_contents))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        result,
+        vec![
+            (
+                String::from("Sign"),
+                String::from("Todo"),
+                String::from("Contract.pdf"),
+                false
+            ),
+            (
+                String::from("Store"),
+                String::from("Todo"),
+                String::from("notes.md"),
+                true
+            ),
+        ]
+    );
+    Ok(())
+}
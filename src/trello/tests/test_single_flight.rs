@@ -0,0 +1,60 @@
+use super::*;
+
+use single_flight::SingleFlight;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Barrier;
+use std::thread;
+use std::time::Duration;
+
+fn response(body: &str) -> reqwest::blocking::Response {
+    reqwest::blocking::Response::from(http::Response::new(body.to_string()))
+}
+
+#[test]
+fn test_concurrent_requests_are_shared() {
+    let single_flight = SingleFlight::default();
+    let sent = AtomicUsize::new(0);
+    let barrier = Barrier::new(4);
+
+    let bodies = thread::scope(|scope| {
+        let handles = (0..4)
+            .map(|_| {
+                scope.spawn(|| {
+                    barrier.wait();
+                    let response = single_flight
+                        .run("/1/boards/B", || {
+                            sent.fetch_add(1, Ordering::SeqCst);
+                            // long enough for the other threads to join
+                            thread::sleep(Duration::from_millis(200));
+                            Ok::<_, reqwest::Error>(response("board"))
+                        })
+                        .unwrap();
+                    response.text().unwrap()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        handles
+            .into_iter()
+            .map(|h| h.join().unwrap())
+            .collect::<Vec<String>>()
+    });
+
+    assert_eq!(sent.load(Ordering::SeqCst), 1);
+    assert_eq!(bodies, vec!["board"; 4]);
+}
+
+#[test]
+fn test_sequential_requests_are_not_shared() {
+    let single_flight = SingleFlight::default();
+
+    let first = single_flight.run("/1/boards/B", || Ok::<_, reqwest::Error>(response("first")));
+    let second = single_flight.run("/1/boards/B", || {
+        Ok::<_, reqwest::Error>(response("second"))
+    });
+    let other = single_flight.run("/1/boards/C", || Ok::<_, reqwest::Error>(response("other")));
+
+    assert_eq!(first.unwrap().text().unwrap(), "first");
+    assert_eq!(second.unwrap().text().unwrap(), "second");
+    assert_eq!(other.unwrap().text().unwrap(), "other");
+}
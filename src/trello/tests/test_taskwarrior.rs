@@ -0,0 +1,93 @@
+use super::*;
+
+use bridges::taskwarrior::{export_board, from_json, from_task, to_json, Task};
+
+fn board() -> Board {
+    let mut rat = Card::new("RAT", "Propeller Rat", "", None, "", None);
+    rat.closed = true;
+
+    Board::new(
+        "",
+        "Knights",
+        Some(vec![List::new(
+            "",
+            "King",
+            Some(vec![
+                Card::new(
+                    "BOOTS",
+                    "Gyro Boots",
+                    "",
+                    Some(vec![Label::new("", "Super rare", "red")]),
+                    "",
+                    Some("2020-06-28T06:06:27Z".parse().unwrap()),
+                ),
+                rat,
+            ]),
+        )]),
+        "",
+    )
+}
+
+#[test]
+fn test_export_board() -> Result<()> {
+    let tasks = export_board(&board());
+
+    assert_eq!(
+        tasks[0],
+        Task {
+            description: String::from("Gyro Boots"),
+            status: String::from("pending"),
+            due: Some("2020-06-28T06:06:27Z".parse().unwrap()),
+            tags: vec![String::from("Super_rare")],
+            project: Some(String::from("Knights.King")),
+            trelloid: Some(String::from("BOOTS")),
+        }
+    );
+    assert_eq!(tasks[1].status, "completed");
+
+    let json: serde_json::Value = serde_json::from_str(&to_json(&tasks)?).unwrap();
+    assert_eq!(
+        json[0],
+        json!({
+            "description": "Gyro Boots",
+            "status": "pending",
+            "due": "20200628T060627Z",
+            "tags": ["Super_rare"],
+            "project": "Knights.King",
+            "trelloid": "BOOTS",
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn test_import() -> Result<()> {
+    let tasks = from_json(
+        &json!([
+            {
+                "id": 1,
+                "description": "Dig",
+                "status": "pending",
+                "due": "20200628T060627Z",
+                "tags": ["Super_rare"],
+                "uuid": "3ba3ddd3-7bd6-4d6f-b1e0-3e4a3b6a1b1e",
+            },
+            {"description": "Jump", "status": "completed"},
+        ])
+        .to_string(),
+    )?;
+
+    assert_eq!(
+        from_task(&tasks[0]),
+        Card::new(
+            "",
+            "Dig",
+            "",
+            Some(vec![Label::new("", "Super rare", "")]),
+            "",
+            Some("2020-06-28T06:06:27Z".parse().unwrap()),
+        )
+    );
+    assert!(from_task(&tasks[1]).closed);
+    Ok(())
+}
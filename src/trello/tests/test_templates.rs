@@ -0,0 +1,57 @@
+use super::*;
+
+use templates::BuiltinTemplate;
+
+#[test]
+fn test_from_str() -> Result<()> {
+    assert_eq!(
+        "Weekly-Planner".parse::<BuiltinTemplate>()?,
+        BuiltinTemplate::WeeklyPlanner
+    );
+    for template in BuiltinTemplate::all() {
+        assert_eq!(template.to_string().parse::<BuiltinTemplate>()?, *template);
+    }
+    assert!("scrumban".parse::<BuiltinTemplate>().is_err());
+    Ok(())
+}
+
+#[test]
+fn test_create_from_template() -> Result<()> {
+    let _board = mockito::mock("POST", "/1/boards/?key=some-key&token=some-token")
+        .match_body("name=Priorities&defaultLists=false&defaultLabels=false")
+        .with_status(200)
+        .with_body(
+            json!({"id": "TEMPLATE-BOARD", "name": "Priorities", "closed": false, "url": ""})
+                .to_string(),
+        )
+        .create();
+
+    let lists = mockito::mock("POST", "/1/lists/?key=some-key&token=some-token")
+        .match_body(mockito::Matcher::Regex(
+            "idBoard=TEMPLATE-BOARD&pos=bottom$".to_string(),
+        ))
+        .with_status(200)
+        .with_body(json!({"id": "TEMPLATE-LIST", "name": "Neither", "closed": false}).to_string())
+        .expect(4)
+        .create();
+
+    let labels = mockito::mock("POST", "/1/labels/?key=some-key&token=some-token")
+        .match_body(mockito::Matcher::Regex(
+            "idBoard=TEMPLATE-BOARD$".to_string(),
+        ))
+        .with_status(200)
+        .with_body(json!({"id": "TEMPLATE-LABEL", "name": "Delete", "color": "black"}).to_string())
+        .expect(2)
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let board = Board::create_from_template(&client, "Priorities", BuiltinTemplate::Eisenhower)?;
+
+    assert_eq!(board.id, "TEMPLATE-BOARD");
+    assert_eq!(board.lists.as_slice().len(), 4);
+    lists.assert();
+    labels.assert();
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use super::*;
+
+use ratatui::crossterm::event::KeyCode;
+use tui::{Action, BoardView, Mode};
+
+fn board() -> Board {
+    Board::new(
+        "board",
+        "Knights",
+        Some(vec![
+            List::new(
+                "king",
+                "King",
+                Some(vec![
+                    Card::new("1", "Gyro Boots", "", None, "", None),
+                    Card::new("2", "Propeller Rat", "", None, "", None),
+                ]),
+            ),
+            List::new("shovel", "Shovel", Some(vec![])),
+        ]),
+        "",
+    )
+}
+
+#[test]
+fn test_navigation() {
+    let mut view = BoardView::new(board());
+
+    assert_eq!(view.selected_card().unwrap().name, "Gyro Boots");
+
+    view.handle_key(KeyCode::Char('j'));
+    assert_eq!(view.selected_card().unwrap().name, "Propeller Rat");
+
+    // cannot move past the last card
+    view.handle_key(KeyCode::Char('j'));
+    assert_eq!(view.selected_card().unwrap().name, "Propeller Rat");
+
+    view.handle_key(KeyCode::Char('l'));
+    assert_eq!(view.selected_card(), None);
+
+    view.handle_key(KeyCode::Char('h'));
+    assert_eq!(view.selected_card().unwrap().name, "Gyro Boots");
+
+    assert_eq!(view.handle_key(KeyCode::Char('q')), Action::Quit);
+}
+
+#[test]
+fn test_view_and_edit() {
+    let mut view = BoardView::new(board());
+
+    view.handle_key(KeyCode::Enter);
+    assert_eq!(view.mode(), Mode::ViewCard);
+
+    let action = view.handle_key(KeyCode::Char('e'));
+    assert_eq!(
+        action,
+        Action::Edit(Box::new(Card::new("1", "Gyro Boots", "", None, "", None)))
+    );
+    assert_eq!(view.mode(), Mode::Browse);
+}
+
+#[test]
+fn test_move() {
+    let mut view = BoardView::new(board());
+
+    view.handle_key(KeyCode::Char('m'));
+    view.handle_key(KeyCode::Char('l'));
+    assert_eq!(view.mode(), Mode::MoveCard { target: 1 });
+
+    let action = view.handle_key(KeyCode::Enter);
+    assert_eq!(
+        action,
+        Action::Move {
+            card_id: CardId::new("1"),
+            list_id: ListId::new("shovel"),
+        }
+    );
+
+    let lists = view.board().lists.get().unwrap();
+    assert_eq!(lists[0].cards.get().unwrap().len(), 1);
+    assert_eq!(lists[1].cards.get().unwrap()[0].name, "Gyro Boots");
+    assert_eq!(view.selected_card().unwrap().name, "Propeller Rat");
+}
@@ -0,0 +1,176 @@
+use super::*;
+
+use chrono::{TimeZone, Utc};
+use colored::*;
+use views::{matrix, swimlanes, GroupBy};
+
+fn labelled(id: &str, name: &str, labels: &[&str]) -> Card {
+    let labels = labels.iter().map(|l| Label::new(l, l, "red")).collect();
+    Card::new(id, name, "", Some(labels), "", None)
+}
+
+fn priority_board() -> Board {
+    let mut closed = labelled("5", "Archived", &["urgent", "important"]);
+    closed.closed = true;
+
+    Board::new(
+        "B1",
+        "Priorities",
+        Some(vec![
+            List::new(
+                "L1",
+                "Todo",
+                Some(vec![
+                    labelled("1", "Fix outage", &["Urgent", "Important"]),
+                    labelled("2", "Plan roadmap", &["important"]),
+                    closed,
+                ]),
+            ),
+            List::new(
+                "L2",
+                "Doing",
+                Some(vec![
+                    labelled("3", "Reply to vendor", &["urgent"]),
+                    labelled("4", "Tidy desk", &[]),
+                ]),
+            ),
+        ]),
+        "",
+    )
+}
+
+#[test]
+fn test_matrix() {
+    let result = matrix(&priority_board(), "urgent", "important");
+
+    let names = |cards: &[Card]| cards.iter().map(|c| c.name.clone()).collect::<Vec<_>>();
+    assert_eq!(names(&result.do_first), vec!["Fix outage"]);
+    assert_eq!(names(&result.schedule), vec!["Plan roadmap"]);
+    assert_eq!(names(&result.delegate), vec!["Reply to vendor"]);
+    assert_eq!(names(&result.eliminate), vec!["Tidy desk"]);
+    assert_eq!(
+        result.simple_render(),
+        "Priorities: 1 do first, 1 schedule, 1 delegate, 1 eliminate"
+    );
+}
+
+#[test]
+fn test_matrix_render() {
+    let result = matrix(&priority_board(), "urgent", "important");
+
+    let expected = [
+        "Priorities".bold().to_string(),
+        format!("{} │ {}", "Do first         ".bold(), "Schedule".bold()),
+        String::from("* Fix outage      │ * Plan roadmap"),
+        String::from("──────────────────┼───────────────"),
+        format!("{} │ {}", "Delegate         ".bold(), "Eliminate".bold()),
+        String::from("* Reply to vendor │ * Tidy desk"),
+    ]
+    .join("\n");
+
+    assert_eq!(result.render(), expected);
+}
+
+fn lane_names(lanes: &views::Swimlanes) -> Vec<(String, Vec<String>)> {
+    lanes
+        .lanes
+        .iter()
+        .map(|l| {
+            let cards = l.cards.iter().map(|c| c.card.name.clone()).collect();
+            (l.name.clone(), cards)
+        })
+        .collect()
+}
+
+#[test]
+fn test_swimlanes_by_label() {
+    let result = swimlanes(&priority_board(), GroupBy::Label);
+
+    assert_eq!(
+        lane_names(&result),
+        vec![
+            (String::from("Urgent"), vec![String::from("Fix outage")]),
+            (String::from("Important"), vec![String::from("Fix outage")]),
+            (
+                String::from("important"),
+                vec![String::from("Plan roadmap")]
+            ),
+            (
+                String::from("urgent"),
+                vec![String::from("Reply to vendor")]
+            ),
+            (String::from("No label"), vec![String::from("Tidy desk")]),
+        ]
+    );
+}
+
+#[test]
+fn test_swimlanes_by_member() {
+    let mut assigned = Card::new("1", "Deploy", "", None, "", None);
+    assigned.id_members = vec![String::from("M1")];
+    let board = Board::new(
+        "B1",
+        "Team",
+        Some(vec![List::new(
+            "L1",
+            "Doing",
+            Some(vec![assigned, Card::new("2", "Triage", "", None, "", None)]),
+        )]),
+        "",
+    );
+
+    let members = vec![Member {
+        id: String::from("M1"),
+        full_name: String::from("Ada Lovelace"),
+        username: String::from("ada"),
+        #[cfg(feature = "extra-fields")]
+        extra: serde_json::Map::new(),
+    }];
+    let result = swimlanes(&board, GroupBy::Member).with_members(&members);
+
+    let expected = [
+        " Team \n======".bold().to_string(),
+        String::from(""),
+        "Ada Lovelace (1)".bold().to_string(),
+        format!("* Deploy {}", "(Doing)".dimmed()),
+        String::from(""),
+        "Unassigned (1)".bold().to_string(),
+        format!("* Triage {}", "(Doing)".dimmed()),
+    ]
+    .join("\n");
+    assert_eq!(result.render(), expected);
+    assert_eq!(result.simple_render(), "Team: 2 lanes");
+}
+
+#[test]
+fn test_swimlanes_by_due_week() {
+    let due = |y, m, d| Some(Utc.ymd(y, m, d).and_hms(12, 0, 0));
+    let board = Board::new(
+        "B1",
+        "Deadlines",
+        Some(vec![List::new(
+            "L1",
+            "Todo",
+            Some(vec![
+                Card::new("1", "Later", "", None, "", due(2021, 1, 4)),
+                Card::new("2", "Sometime", "", None, "", None),
+                Card::new("3", "Soon", "", None, "", due(2020, 12, 29)),
+                Card::new("4", "Sooner", "", None, "", due(2020, 12, 28)),
+            ]),
+        )]),
+        "",
+    );
+
+    let result = swimlanes(&board, GroupBy::DueWeek);
+    assert_eq!(
+        lane_names(&result),
+        vec![
+            (
+                String::from("2020-W53"),
+                vec![String::from("Soon"), String::from("Sooner")]
+            ),
+            (String::from("2021-W01"), vec![String::from("Later")]),
+            (String::from("No due date"), vec![String::from("Sometime")]),
+        ]
+    );
+}
@@ -0,0 +1,121 @@
+use super::*;
+
+use chrono::{TimeZone, Utc};
+use std::io::Read;
+use watch::{events, BoardWatcher, Event, EventType};
+
+fn board(lists: Vec<List>) -> Board {
+    Board::new("WATCHED", "Watched", Some(lists), "")
+}
+
+#[test]
+fn test_events() {
+    let old = board(vec![
+        List::new(
+            "L1",
+            "Doing",
+            Some(vec![
+                Card::new("A", "Dig", "", None, "", None),
+                Card::new("B", "Bounce", "", None, "", None),
+            ]),
+        ),
+        List::new("L2", "Done", Some(vec![])),
+    ]);
+    let new = board(vec![
+        List::new(
+            "L1",
+            "Doing",
+            Some(vec![Card::new("C", "Fish", "", None, "", None)]),
+        ),
+        List::new(
+            "L2",
+            "Done",
+            Some(vec![Card::new("A", "Dig deeper", "", None, "", None)]),
+        ),
+    ]);
+    let now = Utc.ymd(2020, 5, 1).and_hms(12, 0, 0);
+
+    let result = events(&old, &new, now);
+    assert_eq!(
+        result.iter().map(|e| e.event).collect::<Vec<EventType>>(),
+        vec![
+            EventType::CardCreated,
+            EventType::CardMoved,
+            EventType::CardRenamed,
+            EventType::CardRemoved,
+        ]
+    );
+    assert_eq!(
+        serde_json::to_string(&result[1]).unwrap(),
+        r#"{"event":"card_moved","card_id":"A","card_name":"Dig deeper","list":"Done","from_list":"Doing","timestamp":"2020-05-01T12:00:00Z"}"#
+    );
+    assert_eq!(
+        serde_json::to_string(&result[3]).unwrap(),
+        r#"{"event":"card_removed","card_id":"B","card_name":"Bounce","list":"Doing","timestamp":"2020-05-01T12:00:00Z"}"#
+    );
+}
+
+#[test]
+fn test_events_list_renamed() {
+    let old = board(vec![List::new(
+        "L1",
+        "Doing",
+        Some(vec![Card::new("A", "Dig", "", None, "", None)]),
+    )]);
+    let new = board(vec![List::new(
+        "L1",
+        "In progress",
+        Some(vec![Card::new("A", "Dig", "", None, "", None)]),
+    )]);
+
+    let now = Utc.ymd(2020, 5, 1).and_hms(12, 0, 0);
+    assert_eq!(events(&old, &new, now), vec![]);
+}
+
+fn mock_board(cards: serde_json::Value) -> mockito::Mock {
+    mockito::mock(
+        "GET",
+        "/1/boards/WATCHED/lists?key=some-key&token=some-token&fields=id%2Cname%2Cclosed%2CsoftLimit&cards=open",
+    )
+    .with_status(200)
+    .with_body(json!([{"id": "L1", "name": "Todo", "closed": false, "cards": cards}]).to_string())
+    .create()
+}
+
+#[test]
+fn test_poll_ndjson() -> Result<()> {
+    let _board = mockito::mock(
+        "GET",
+        "/1/boards/WATCHED?key=some-key&token=some-token&fields=id%2Cname%2Cclosed%2Curl%2Csubscribed",
+    )
+    .with_status(200)
+    .with_body(json!({"id": "WATCHED", "name": "Watched", "closed": false, "url": ""}).to_string())
+    .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let file = NamedTempFile::new()?;
+    let mut watcher = BoardWatcher::new("WATCHED").ndjson(file.reopen()?);
+
+    let lists = mock_board(json!([]));
+    assert_eq!(watcher.poll(&client)?, vec![]);
+    drop(lists);
+
+    let _lists = mock_board(json!([
+        {"id": "NEW", "name": "Fresh", "desc": "", "closed": false, "url": ""},
+    ]));
+    let result = watcher.poll(&client)?;
+    assert_eq!(result.len(), 1);
+
+    let mut contents = String::new();
+    file.reopen()?.read_to_string(&mut contents)?;
+    let lines = contents.lines().collect::<Vec<&str>>();
+    assert_eq!(lines.len(), 1);
+
+    let event: Event = serde_json::from_str(lines[0]).unwrap();
+    assert_eq!(event, result[0]);
+    assert_eq!(event.event, EventType::CardCreated);
+    assert_eq!(event.list, "Todo");
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use super::*;
+
+use chrono::{TimeZone, Utc};
+
+fn move_card_payload() -> String {
+    json!({
+        "action": {
+            "id": "ACTION",
+            "idMemberCreator": "MEMBER",
+            "type": "updateCard",
+            "date": "2020-05-01T12:00:00.000Z",
+            "data": {
+                "card": {"id": "CARD", "name": "Dig", "shortLink": "abc", "idShort": 4},
+                "listBefore": {"id": "L1", "name": "Doing"},
+                "listAfter": {"id": "L2", "name": "Done"},
+                "board": {"id": "BOARD", "name": "Shovel", "shortLink": "xyz"},
+                "old": {"idList": "L1"},
+            },
+            "display": {
+                "translationKey": "action_move_card_from_list_to_list",
+                "entities": {
+                    "card": {"type": "card", "id": "CARD", "shortLink": "abc", "text": "Dig"},
+                    "listBefore": {"type": "list", "id": "L1", "text": "Doing"},
+                    "memberCreator": {"type": "member", "id": "MEMBER", "username": "sk", "text": "Shovel Knight"},
+                },
+            },
+            "memberCreator": {"id": "MEMBER", "username": "sk", "fullName": "Shovel Knight"},
+        },
+        "model": {"id": "BOARD", "name": "Shovel", "url": "https://trello.com/b/xyz", "closed": false},
+    })
+    .to_string()
+}
+
+#[test]
+fn test_parse() -> Result<()> {
+    let payload = webhook::parse(&move_card_payload())?;
+    let action = &payload.action;
+
+    assert_eq!(action.action_type, "updateCard");
+    assert_eq!(action.date, Utc.ymd(2020, 5, 1).and_hms(12, 0, 0));
+
+    let card = action.data.card.as_ref().unwrap();
+    assert_eq!(card.short_link.as_deref(), Some("abc"));
+    assert_eq!(card.extra["idShort"], json!(4));
+    assert_eq!(
+        action.data.list_after.as_ref().unwrap().name.as_deref(),
+        Some("Done")
+    );
+    assert_eq!(action.data.old.as_ref().unwrap()["idList"], json!("L1"));
+
+    let display = action.display.as_ref().unwrap();
+    assert_eq!(
+        display.entities["memberCreator"].username.as_deref(),
+        Some("sk")
+    );
+    assert_eq!(
+        action.member_creator.as_ref().unwrap().full_name,
+        "Shovel Knight"
+    );
+
+    assert_eq!(payload.model.id, "BOARD");
+    assert_eq!(payload.model.extra["closed"], json!(false));
+    Ok(())
+}
+
+#[test]
+fn test_parse_invalid() {
+    match webhook::parse("{\"action\": {}}") {
+        Err(TrelloError::Deserialize { body, .. }) => assert_eq!(body, "{\"action\": {}}"),
+        other => panic!("Unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn test_verify() {
+    let body = br#"{"a":1}"#;
+    let url = "https://example.com/hook";
+    let signature = "rJvMe98iRMTqfHL6wRy3mKBcwE4=";
+
+    assert!(webhook::verify("secret", url, body, signature));
+    assert!(!webhook::verify("wrong", url, body, signature));
+    assert!(!webhook::verify(
+        "secret",
+        "https://example.com/other",
+        body,
+        signature
+    ));
+    assert!(!webhook::verify("secret", url, body, "not base64!"));
+}
+
+#[test]
+fn test_handle_request() -> Result<()> {
+    use hmac::{Hmac, Mac};
+
+    let body = move_card_payload();
+    let url = "https://example.com/hook";
+    let mut mac = Hmac::<sha1::Sha1>::new_from_slice(b"secret").unwrap();
+    mac.update(body.as_bytes());
+    mac.update(url.as_bytes());
+    let signature = base64::encode(mac.finalize().into_bytes());
+
+    let request = http::Request::post(url)
+        .header(webhook::SIGNATURE_HEADER, signature)
+        .body(body.clone())
+        .unwrap();
+    let payload = webhook::handle_request("secret", url, &request)?;
+    assert_eq!(payload.action.id, "ACTION");
+
+    let unsigned = http::Request::post(url).body(body).unwrap();
+    match webhook::handle_request("secret", url, &unsigned) {
+        Err(TrelloError::InvalidSignature) => {}
+        other => panic!("Unexpected result: {:?}", other),
+    }
+    Ok(())
+}
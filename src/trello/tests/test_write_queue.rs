@@ -0,0 +1,104 @@
+use super::*;
+
+use std::time::Duration;
+
+fn rename(card_id: &str, name: &str) -> Mutation {
+    Mutation::Rename {
+        card_id: CardId::new(card_id),
+        name: String::from(name),
+    }
+}
+
+fn apply(card_id: &str, label_id: &str) -> Mutation {
+    Mutation::ApplyLabel {
+        card_id: CardId::new(card_id),
+        label_id: LabelId::new(label_id),
+    }
+}
+
+fn remove(card_id: &str, label_id: &str) -> Mutation {
+    Mutation::RemoveLabel {
+        card_id: CardId::new(card_id),
+        label_id: LabelId::new(label_id),
+    }
+}
+
+#[test]
+fn test_coalesce() {
+    let mut queue = WriteQueue::new();
+    queue.push(rename("A", "first"));
+    queue.push(apply("A", "L1"));
+    queue.push(rename("B", "other"));
+    queue.push(rename("A", "second"));
+    queue.push(remove("A", "L1"));
+    queue.push(apply("A", "L2"));
+
+    assert_eq!(
+        queue.mutations(),
+        &[
+            rename("B", "other"),
+            rename("A", "second"),
+            remove("A", "L1"),
+            apply("A", "L2"),
+        ]
+    );
+}
+
+#[test]
+fn test_flush() -> Result<()> {
+    let _rename = mockito::mock("PUT", "/1/cards/QUEUED-1/?key=some-key&token=some-token")
+        .match_body("name=last")
+        .with_status(200)
+        .with_body(
+            json!({"id": "QUEUED-1", "name": "last", "desc": "", "closed": false, "url": ""})
+                .to_string(),
+        )
+        .expect(1)
+        .create();
+    let _move = mockito::mock("PUT", "/1/cards/QUEUED-2/?key=some-key&token=some-token")
+        .match_body("idList=DONE")
+        .with_status(200)
+        .with_body(json!({}).to_string())
+        .expect(1)
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let mut queue = WriteQueue::new().interval(Duration::from_millis(0));
+
+    queue.push(rename("QUEUED-1", "first"));
+    queue.push(Mutation::Move {
+        card_id: CardId::new("QUEUED-2"),
+        list_id: ListId::new("DONE"),
+    });
+    queue.push(rename("QUEUED-1", "last"));
+
+    let mut progress = vec![];
+    let mut record = |_: &str, sent: usize, total: usize| progress.push((sent, total));
+    let options = progress::RunOptions::new().progress(&mut record);
+    assert_eq!(queue.flush_with(&client, options)?, 2);
+    assert!(queue.is_empty());
+    assert_eq!(progress, vec![(0, 2), (1, 2), (2, 2)]);
+    _rename.assert();
+    _move.assert();
+    Ok(())
+}
+
+#[test]
+fn test_flush_error_keeps_remaining() -> Result<()> {
+    let _fail = mockito::mock("PUT", "/1/cards/QUEUED-FAIL/?key=some-key&token=some-token")
+        .with_status(500)
+        .create();
+
+    let config = ClientConfig::new(&mockito::server_url(), "some-token", "some-key");
+    let client = TrelloClient::new(config);
+
+    let mut queue = WriteQueue::new().interval(Duration::from_millis(0));
+    queue.push(rename("QUEUED-FAIL", "name"));
+    queue.push(rename("QUEUED-LATER", "name"));
+
+    assert!(queue.flush(&client).is_err());
+    assert_eq!(queue.len(), 2);
+    Ok(())
+}
@@ -0,0 +1,104 @@
+//! Compressed transfer of responses. Every request asks for a gzip or
+//! deflate compressed response, which is decompressed before it is handed
+//! to the caller, and the number of bytes transferred is recorded. Board
+//! exports with all their cards compress to a fraction of their size, which
+//! matters on metered or slow connections.
+use crate::response::BodySize;
+use crate::trello_error::TrelloError;
+
+use flate2::read::{DeflateDecoder, GzDecoder, ZlibDecoder};
+use reqwest::blocking::Response;
+use reqwest::header::{HeaderMap, CONTENT_ENCODING, CONTENT_LENGTH};
+use reqwest::{StatusCode, Version};
+use std::io::Read;
+
+type Result<T> = std::result::Result<T, TrelloError>;
+
+/// The value of the Accept-Encoding header sent with every request
+pub(crate) const ACCEPT_ENCODING: &str = "gzip, deflate";
+
+/// The response bodies received by a client since it was created, see
+/// `TrelloClient::transfer_stats`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TransferStats {
+    /// The number of responses received
+    pub responses: u64,
+    /// Bytes received over the network, i.e. compressed
+    pub transferred: u64,
+    /// Bytes after decompression
+    pub decoded: u64,
+}
+
+impl TransferStats {
+    pub(crate) fn record(&mut self, size: &BodySize) {
+        self.responses += 1;
+        self.transferred += size.transferred;
+        self.decoded += size.decoded;
+    }
+
+    /// The bytes compression saved from being transferred
+    pub fn saved(&self) -> u64 {
+        self.decoded.saturating_sub(self.transferred)
+    }
+}
+
+/// Creates a response with the given body, e.g. after reading the body of
+/// another response
+pub(crate) fn rebuild(
+    status: StatusCode,
+    version: Version,
+    headers: HeaderMap,
+    body: Vec<u8>,
+) -> Response {
+    let mut response = http::Response::new(body);
+    *response.status_mut() = status;
+    *response.version_mut() = version;
+    *response.headers_mut() = headers;
+    Response::from(response)
+}
+
+fn read_all<R: Read>(mut reader: R) -> std::io::Result<Vec<u8>> {
+    let mut body = vec![];
+    reader.read_to_end(&mut body)?;
+    Ok(body)
+}
+
+/// Reads the body of the response, decompressing it according to its
+/// Content-Encoding, and returns a response with the decompressed body
+/// along with the size of the body before and after decompression
+pub(crate) fn decode(response: Response) -> Result<(Response, BodySize)> {
+    let status = response.status();
+    let version = response.version();
+    let mut headers = response.headers().clone();
+    let raw = response.bytes()?;
+
+    let encoding = headers
+        .get(CONTENT_ENCODING)
+        .and_then(|e| e.to_str().ok())
+        .map(|e| e.trim().to_lowercase());
+    let body = match encoding.as_deref() {
+        Some("gzip") | Some("x-gzip") => Some(read_all(GzDecoder::new(&raw[..]))?),
+        // deflate is meant to be zlib wrapped, but some servers send raw
+        // deflate data
+        Some("deflate") => Some(
+            read_all(ZlibDecoder::new(&raw[..]))
+                .or_else(|_| read_all(DeflateDecoder::new(&raw[..])))?,
+        ),
+        _ => None,
+    };
+
+    let size = BodySize {
+        transferred: raw.len() as u64,
+        decoded: body.as_ref().map_or(raw.len(), Vec::len) as u64,
+    };
+    let body = match body {
+        Some(body) => {
+            headers.remove(CONTENT_ENCODING);
+            headers.remove(CONTENT_LENGTH);
+            body
+        }
+        None => raw.to_vec(),
+    };
+
+    Ok((rebuild(status, version, headers, body), size))
+}
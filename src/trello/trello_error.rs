@@ -1,13 +1,148 @@
+use crate::redact::redact;
+
+use chrono::{DateTime, Utc};
+use std::any::Any;
+#[cfg(any(feature = "http", feature = "wasm"))]
+use std::error::Error as StdError;
+#[cfg(any(feature = "http", feature = "wasm"))]
+use std::fmt;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
 pub enum TrelloError {
+    #[cfg(any(feature = "http", feature = "wasm"))]
     #[error("Reqwest error: {0}")]
-    Reqwest(#[from] reqwest::Error),
+    Reqwest(#[source] RequestError),
     #[error("url Parse error: {0}")]
     UrlParse(#[from] url::ParseError),
     #[error("IO Error: {0}")]
     Io(#[from] std::io::Error),
+    #[error("Unable to deserialize response: {source}")]
+    Deserialize {
+        source: serde_json::Error,
+        body: String,
+    },
+    #[error("Regex error: {0}")]
+    Regex(#[from] regex::Error),
+    #[error("CSV error: {0}")]
+    Csv(#[from] csv::Error),
     #[error("Card Parse Error: {0}")]
     CardParse(String),
+    #[error("Unable to parse date {0}")]
+    DateParse(String),
+    #[error("Modified by someone else since {expected} (last activity {actual})")]
+    Conflict {
+        expected: DateTime<Utc>,
+        actual: DateTime<Utc>,
+    },
+    #[error("Deletion not permitted: {0}")]
+    DeletionNotPermitted(String),
+    /// A request which would change something was made with a read only
+    /// client, see `TrelloClient::read_only`
+    #[error("Read only client, refusing to send {0}")]
+    ReadOnly(String),
+    /// A long operation was stopped through its `CancelToken`. Whatever was
+    /// completed before is kept, e.g. the mutations which were not sent
+    /// remain in the `WriteQueue`. Operations which build up a result give
+    /// what they had so far as `partial`, see `TrelloError::partial`.
+    #[error("Cancelled after {completed} completed steps")]
+    Cancelled {
+        completed: usize,
+        partial: Option<Box<dyn Any + Send + Sync>>,
+    },
+    #[error("Invalid header: {0}")]
+    InvalidHeader(String),
+    #[error("Unsupported Trello url: {}", redact(.0))]
+    UnsupportedUrl(String),
+    #[error("Not found: {0}")]
+    NotFound(String),
+    #[error("Ambiguous name: {0}")]
+    Ambiguous(String),
+    #[error("Invalid webhook signature")]
+    InvalidSignature,
+    #[error("Invalid environment: {0}")]
+    Environment(String),
+    #[error("Invalid API key")]
+    InvalidKey,
+    #[error("Invalid token: {0}")]
+    InvalidToken(String),
+    #[cfg(feature = "smtp")]
+    #[error("Email error: {0}")]
+    Email(String),
+    #[cfg(feature = "auth")]
+    #[error("Keyring error: {0}")]
+    Keyring(#[from] keyring::Error),
+}
+
+impl TrelloError {
+    /// The partial result of a cancelled operation, e.g. the `SyncReport` of
+    /// the boards `labels::sync_with` synced before it was cancelled
+    pub fn partial<T: Any>(&self) -> Option<&T> {
+        match self {
+            TrelloError::Cancelled {
+                partial: Some(partial),
+                ..
+            } => partial.downcast_ref(),
+            _ => None,
+        }
+    }
+
+    /// Attaches the partial result to a `TrelloError::Cancelled`, other
+    /// errors are returned as they are
+    #[cfg(feature = "http")]
+    pub(crate) fn with_partial<T: Any + Send + Sync>(self, result: T) -> TrelloError {
+        match self {
+            TrelloError::Cancelled { completed, .. } => TrelloError::Cancelled {
+                completed,
+                partial: Some(Box::new(result)),
+            },
+            e => e,
+        }
+    }
+}
+
+/// An error which occurred while making a request. Unlike the underlying
+/// `reqwest::Error`, credentials are redacted from the url of the request
+/// when the error is displayed or debugged.
+#[cfg(any(feature = "http", feature = "wasm"))]
+pub struct RequestError(reqwest::Error);
+
+#[cfg(any(feature = "http", feature = "wasm"))]
+impl RequestError {
+    /// The underlying error. Note that its url includes the credentials.
+    pub fn inner(&self) -> &reqwest::Error {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> reqwest::Error {
+        self.0
+    }
+}
+
+#[cfg(any(feature = "http", feature = "wasm"))]
+impl fmt::Display for RequestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&redact(&self.0.to_string()))
+    }
+}
+
+#[cfg(any(feature = "http", feature = "wasm"))]
+impl fmt::Debug for RequestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&redact(&format!("{:?}", self.0)))
+    }
+}
+
+#[cfg(any(feature = "http", feature = "wasm"))]
+impl StdError for RequestError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.0.source()
+    }
+}
+
+#[cfg(any(feature = "http", feature = "wasm"))]
+impl From<reqwest::Error> for TrelloError {
+    fn from(error: reqwest::Error) -> TrelloError {
+        TrelloError::Reqwest(RequestError(error))
+    }
 }
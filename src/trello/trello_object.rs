@@ -1,3 +1,4 @@
+use chrono::{DateTime, FixedOffset, Local, Utc};
 use std::fmt::Debug;
 
 pub trait TrelloObject: Debug {
@@ -8,12 +9,75 @@ pub trait TrelloObject: Debug {
     fn get_fields() -> &'static [&'static str];
 }
 
+/// How much detail is included when rendering an object
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Detail {
+    /// Only names, one line per object
+    Minimal,
+    Normal,
+    /// Everything which is available, including card descriptions within lists
+    Full,
+}
+
+/// Options controlling how objects are rendered. The default options
+/// produce the same output as `Renderable::render`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RenderOptions {
+    pub detail: Detail,
+    pub show_ids: bool,
+    pub show_urls: bool,
+    pub show_labels: bool,
+    /// Prefixes each card in a list with a short prefix of its id, at least
+    /// this many characters long and long enough to be unique within the
+    /// list (or board, when rendering a board).
+    pub id_prefix: Option<usize>,
+    /// The timezone dates are shown in, e.g. when rendering on behalf of a
+    /// user elsewhere. Defaults to the local timezone.
+    pub timezone: Option<FixedOffset>,
+    /// The time relative dates such as "due in 2 days" are measured from.
+    /// Defaults to the current time.
+    pub now: Option<DateTime<Utc>>,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        RenderOptions {
+            detail: Detail::Normal,
+            show_ids: false,
+            show_urls: false,
+            show_labels: true,
+            id_prefix: None,
+            timezone: None,
+            now: None,
+        }
+    }
+}
+
+impl RenderOptions {
+    /// Formats the date in the timezone of the options
+    pub fn format_date(&self, date: &DateTime<Utc>, format: &str) -> String {
+        match self.timezone {
+            Some(timezone) => date.with_timezone(&timezone).format(format).to_string(),
+            None => date.with_timezone(&Local).format(format).to_string(),
+        }
+    }
+
+    pub fn now(&self) -> DateTime<Utc> {
+        self.now.unwrap_or_else(Utc::now)
+    }
+}
+
 /// Provides the ability for an object to be rendered
 /// to the command line
 pub trait Renderable {
     /// Render aims to render as much detail as possible and
     /// can render to multiple lines
-    fn render(&self) -> String;
+    fn render(&self) -> String {
+        self.render_with(&RenderOptions::default())
+    }
+
+    /// Renders using the given options
+    fn render_with(&self, options: &RenderOptions) -> String;
 
     /// Simple render aims to output to a single line
     fn simple_render(&self) -> String;
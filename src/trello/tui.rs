@@ -0,0 +1,324 @@
+//! Interactive terminal browser for a board. Lists are displayed as columns
+//! which can be navigated with h/j/k/l (or the arrow keys). Pressing enter
+//! shows the selected card, `e` returns it to the caller for editing and `m`
+//! moves it to a different list.
+use crate::board::Board;
+use crate::card::Card;
+use crate::client::TrelloClient;
+use crate::ids::{CardId, ListId};
+use crate::list::List;
+use crate::trello_error::TrelloError;
+
+use ratatui::backend::CrosstermBackend;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::crossterm::execute;
+use ratatui::crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Text};
+use ratatui::widgets::{Block, Clear, List as ListWidget, ListItem, ListState, Paragraph, Wrap};
+use ratatui::{Frame, Terminal};
+use std::io;
+
+type Result<T> = std::result::Result<T, TrelloError>;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Mode {
+    Browse,
+    ViewCard,
+    /// Choosing the list to move the selected card to
+    MoveCard {
+        target: usize,
+    },
+}
+
+/// Actions the caller of `BoardView::handle_key` should carry out
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum Action {
+    None,
+    Quit,
+    Edit(Box<Card>),
+    Move { card_id: CardId, list_id: ListId },
+}
+
+/// State of the board browser, independent of the terminal it is drawn on
+#[derive(Debug)]
+pub struct BoardView {
+    board: Board,
+    column: usize,
+    row: usize,
+    mode: Mode,
+}
+
+impl BoardView {
+    /// Creates a view of the given board. The board is expected to have
+    /// its nested lists and cards retrieved.
+    pub fn new(board: Board) -> BoardView {
+        BoardView {
+            board,
+            column: 0,
+            row: 0,
+            mode: Mode::Browse,
+        }
+    }
+
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    fn lists(&self) -> &[List] {
+        self.board.lists.as_slice()
+    }
+
+    fn cards(&self, column: usize) -> &[Card] {
+        self.lists()
+            .get(column)
+            .and_then(|l| l.cards.get())
+            .unwrap_or(&[])
+    }
+
+    pub fn selected_card(&self) -> Option<&Card> {
+        self.cards(self.column).get(self.row)
+    }
+
+    fn select_column(&mut self, column: usize) {
+        self.column = column;
+        self.row = self.row.min(self.cards(column).len().saturating_sub(1));
+    }
+
+    /// Updates the view in response to a key press, returning any
+    /// action which should be carried out as a result.
+    pub fn handle_key(&mut self, key: KeyCode) -> Action {
+        let columns = self.lists().len();
+
+        match self.mode {
+            Mode::Browse => match key {
+                KeyCode::Char('q') | KeyCode::Esc => return Action::Quit,
+                KeyCode::Char('h') | KeyCode::Left if self.column > 0 => {
+                    self.select_column(self.column - 1)
+                }
+                KeyCode::Char('l') | KeyCode::Right if self.column + 1 < columns => {
+                    self.select_column(self.column + 1)
+                }
+                KeyCode::Char('j') | KeyCode::Down
+                    if self.row + 1 < self.cards(self.column).len() =>
+                {
+                    self.row += 1
+                }
+                KeyCode::Char('k') | KeyCode::Up if self.row > 0 => self.row -= 1,
+                KeyCode::Enter if self.selected_card().is_some() => self.mode = Mode::ViewCard,
+                KeyCode::Char('e') => {
+                    if let Some(card) = self.selected_card() {
+                        return Action::Edit(Box::new(card.clone()));
+                    }
+                }
+                KeyCode::Char('m') if self.selected_card().is_some() => {
+                    self.mode = Mode::MoveCard {
+                        target: self.column,
+                    }
+                }
+                _ => {}
+            },
+            Mode::ViewCard => match key {
+                KeyCode::Char('e') => {
+                    self.mode = Mode::Browse;
+                    if let Some(card) = self.selected_card() {
+                        return Action::Edit(Box::new(card.clone()));
+                    }
+                }
+                KeyCode::Char('q') | KeyCode::Esc | KeyCode::Enter => self.mode = Mode::Browse,
+                _ => {}
+            },
+            Mode::MoveCard { target } => match key {
+                KeyCode::Char('h') | KeyCode::Left if target > 0 => {
+                    self.mode = Mode::MoveCard { target: target - 1 }
+                }
+                KeyCode::Char('l') | KeyCode::Right if target + 1 < columns => {
+                    self.mode = Mode::MoveCard { target: target + 1 }
+                }
+                KeyCode::Enter => {
+                    self.mode = Mode::Browse;
+                    if target != self.column {
+                        return self.move_selected(target);
+                    }
+                }
+                KeyCode::Char('q') | KeyCode::Esc => self.mode = Mode::Browse,
+                _ => {}
+            },
+        }
+
+        Action::None
+    }
+
+    /// Moves the selected card to the end of the list at the target column
+    fn move_selected(&mut self, target: usize) -> Action {
+        let (column, row) = (self.column, self.row);
+        let lists = match self.board.lists.get_mut() {
+            Some(lists) => lists,
+            None => return Action::None,
+        };
+
+        let card = match lists[column].cards.get_mut() {
+            Some(cards) if row < cards.len() => cards.remove(row),
+            _ => return Action::None,
+        };
+        let action = Action::Move {
+            card_id: card.id.clone(),
+            list_id: lists[target].id.clone(),
+        };
+        lists[target].cards.get_or_insert_empty().push(card);
+
+        self.select_column(column);
+        action
+    }
+
+    pub fn draw(&self, frame: &mut Frame) {
+        let [body, footer] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+
+        let lists = self.lists();
+        let columns = Layout::horizontal(
+            lists
+                .iter()
+                .map(|_| Constraint::Ratio(1, lists.len() as u32)),
+        )
+        .split(body);
+
+        for (index, (list, area)) in lists.iter().zip(columns.iter()).enumerate() {
+            let highlighted = match self.mode {
+                Mode::MoveCard { target } => index == target,
+                _ => index == self.column,
+            };
+            let border_style = if highlighted {
+                Style::default().fg(Color::Green)
+            } else {
+                Style::default()
+            };
+
+            let items = self
+                .cards(index)
+                .iter()
+                .map(|c| ListItem::new(card_line(c)))
+                .collect::<Vec<ListItem>>();
+
+            let widget = ListWidget::new(items)
+                .block(
+                    Block::bordered()
+                        .title(list.name.as_str())
+                        .border_style(border_style),
+                )
+                .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+
+            let mut state = ListState::default();
+            if index == self.column {
+                state.select(Some(self.row));
+            }
+            frame.render_stateful_widget(widget, *area, &mut state);
+        }
+
+        let help = match self.mode {
+            Mode::Browse => "h/j/k/l: navigate  enter: view  e: edit  m: move  q: quit",
+            Mode::ViewCard => "e: edit  enter/q: back",
+            Mode::MoveCard { .. } => "h/l: choose list  enter: move  q: cancel",
+        };
+        frame.render_widget(
+            Line::from(help).style(Style::default().fg(Color::DarkGray)),
+            footer,
+        );
+
+        if self.mode == Mode::ViewCard {
+            if let Some(card) = self.selected_card() {
+                let area = centered(frame.area(), 60, 60);
+                let mut text = Text::from(card_line(card));
+                text.push_line("");
+                for line in card.desc.lines() {
+                    text.push_line(line.to_string());
+                }
+
+                frame.render_widget(Clear, area);
+                frame.render_widget(
+                    Paragraph::new(text)
+                        .wrap(Wrap { trim: false })
+                        .block(Block::bordered().title(card.name.as_str())),
+                    area,
+                );
+            }
+        }
+    }
+}
+
+fn card_line(card: &Card) -> String {
+    let mut line = card.name.clone();
+    if let Some(labels) = &card.labels {
+        for l in labels {
+            line.push_str(&format!(" [{}]", l.name));
+        }
+    }
+    line
+}
+
+/// Rect of the given percentage size centered within the area
+fn centered(area: Rect, width_percent: u16, height_percent: u16) -> Rect {
+    let width = area.width * width_percent / 100;
+    let height = area.height * height_percent / 100;
+
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+/// Opens the board browser in the terminal. Moves are uploaded to Trello
+/// as they happen. If the user chooses to edit a card, the browser is
+/// closed and the card is returned so the caller can edit it.
+pub fn run(client: &TrelloClient, board: &Board) -> Result<Option<Card>> {
+    let mut board = board.clone();
+    board.retrieve_nested(client)?;
+    let mut view = BoardView::new(board);
+
+    enable_raw_mode()?;
+    execute!(io::stdout(), EnterAlternateScreen)?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
+
+    let result = event_loop(client, &mut terminal, &mut view);
+
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
+
+    result
+}
+
+fn event_loop(
+    client: &TrelloClient,
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    view: &mut BoardView,
+) -> Result<Option<Card>> {
+    loop {
+        terminal.draw(|f| view.draw(f))?;
+
+        if let Event::Key(key) = event::read()? {
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+
+            match view.handle_key(key.code) {
+                Action::None => {}
+                Action::Quit => return Ok(None),
+                Action::Edit(card) => return Ok(Some(*card)),
+                Action::Move { card_id, list_id } => {
+                    debug!("Moving card {} to list {}", card_id, list_id);
+                    Card::move_to_list(client, &card_id, &list_id)?;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,291 @@
+//! Alternative presentations of a board, which regroup its cards without
+//! changing anything in Trello. The board must have its lists and cards
+//! retrieved beforehand, see `Board::retrieve_nested`.
+use crate::board::Board;
+use crate::card::Card;
+use crate::formatting::{pad, title};
+use crate::list::List;
+use crate::member::Member;
+use crate::trello_object::{RenderOptions, Renderable};
+
+use chrono::Datelike;
+use colored::*;
+use unicode_width::UnicodeWidthStr;
+
+fn open_cards(board: &Board) -> impl Iterator<Item = &Card> {
+    open_cards_with_lists(board).map(|(_, c)| c)
+}
+
+fn open_cards_with_lists(board: &Board) -> impl Iterator<Item = (&List, &Card)> {
+    board
+        .lists
+        .iter()
+        .filter(|l| !l.closed)
+        .flat_map(|l| l.cards.iter().map(move |c| (l, c)))
+        .filter(|(_, c)| !c.closed)
+}
+
+fn has_label(card: &Card, name: &str) -> bool {
+    card.labels
+        .iter()
+        .flatten()
+        .any(|l| l.name.eq_ignore_ascii_case(name))
+}
+
+/// The open cards of a board sorted into the quadrants of an Eisenhower
+/// matrix by their labels
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix {
+    pub name: String,
+    /// Urgent and important
+    pub do_first: Vec<Card>,
+    /// Important but not urgent
+    pub schedule: Vec<Card>,
+    /// Urgent but not important
+    pub delegate: Vec<Card>,
+    /// Neither urgent nor important
+    pub eliminate: Vec<Card>,
+}
+
+/// Sorts the open cards of the board into a priority matrix. Labels are
+/// matched by name, ignoring case.
+pub fn matrix(board: &Board, urgent_label: &str, important_label: &str) -> Matrix {
+    let mut result = Matrix {
+        name: board.name.clone(),
+        do_first: vec![],
+        schedule: vec![],
+        delegate: vec![],
+        eliminate: vec![],
+    };
+
+    for card in open_cards(board) {
+        let quadrant = match (
+            has_label(card, urgent_label),
+            has_label(card, important_label),
+        ) {
+            (true, true) => &mut result.do_first,
+            (false, true) => &mut result.schedule,
+            (true, false) => &mut result.delegate,
+            (false, false) => &mut result.eliminate,
+        };
+        quadrant.push(card.clone());
+    }
+
+    result
+}
+
+impl Matrix {
+    fn quadrant_lines(title: &str, cards: &[Card]) -> Vec<String> {
+        let mut lines = vec![String::from(title)];
+        lines.extend(cards.iter().map(|c| format!("* {}", c.name)));
+        lines
+    }
+}
+
+impl Renderable for Matrix {
+    /// Renders the quadrants as a 2x2 grid, with urgent cards on the left and
+    /// important cards at the top
+    fn render_with(&self, _options: &RenderOptions) -> String {
+        let rows = [
+            (
+                Matrix::quadrant_lines("Do first", &self.do_first),
+                Matrix::quadrant_lines("Schedule", &self.schedule),
+            ),
+            (
+                Matrix::quadrant_lines("Delegate", &self.delegate),
+                Matrix::quadrant_lines("Eliminate", &self.eliminate),
+            ),
+        ];
+
+        let width = |lines: &[String]| lines.iter().map(|l| l.width()).max().unwrap_or(0);
+        let left = rows.iter().map(|(l, _)| width(l)).max().unwrap_or(0);
+        let right = rows.iter().map(|(_, r)| width(r)).max().unwrap_or(0);
+
+        let mut result = vec![self.name.bold().to_string()];
+        for (index, (left_lines, right_lines)) in rows.iter().enumerate() {
+            if index > 0 {
+                result.push(format!(
+                    "{}┼{}",
+                    "─".repeat(left + 1),
+                    "─".repeat(right + 1)
+                ));
+            }
+            for line in 0..left_lines.len().max(right_lines.len()) {
+                let cell = |lines: &[String], width: usize| {
+                    let text = pad(lines.get(line).map_or("", |l| l.as_str()), width);
+                    if line == 0 {
+                        text.bold().to_string()
+                    } else {
+                        text
+                    }
+                };
+                result.push(
+                    format!("{} │ {}", cell(left_lines, left), cell(right_lines, 0))
+                        .trim_end()
+                        .to_string(),
+                );
+            }
+        }
+
+        result.join("\n")
+    }
+
+    fn simple_render(&self) -> String {
+        format!(
+            "{}: {} do first, {} schedule, {} delegate, {} eliminate",
+            self.name,
+            self.do_first.len(),
+            self.schedule.len(),
+            self.delegate.len(),
+            self.eliminate.len()
+        )
+    }
+}
+
+/// How cards are grouped into swimlanes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupBy {
+    /// One lane per label. Cards with several labels appear in each of their lanes.
+    Label,
+    /// One lane per assigned member (named by member id, see `Swimlanes::with_members`).
+    /// Cards with several members appear in each of their lanes.
+    Member,
+    /// One lane per ISO week of the due date, in chronological order
+    DueWeek,
+}
+
+impl GroupBy {
+    /// The name of the lane for cards which do not belong to any other lane
+    fn fallback_lane(self) -> &'static str {
+        match self {
+            GroupBy::Label => "No label",
+            GroupBy::Member => "Unassigned",
+            GroupBy::DueWeek => "No due date",
+        }
+    }
+
+    fn lane_names(self, card: &Card) -> Vec<String> {
+        match self {
+            GroupBy::Label => card
+                .labels
+                .iter()
+                .flatten()
+                .map(|l| l.name.clone())
+                .collect(),
+            GroupBy::Member => card.id_members.clone(),
+            GroupBy::DueWeek => card
+                .due
+                .map(|due| {
+                    let week = due.naive_utc().date().iso_week();
+                    vec![format!("{}-W{:02}", week.year(), week.week())]
+                })
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// A card in a swimlane along with the name of its list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LaneCard {
+    pub card: Card,
+    pub list_name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lane {
+    pub name: String,
+    pub cards: Vec<LaneCard>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Swimlanes {
+    pub name: String,
+    pub lanes: Vec<Lane>,
+}
+
+/// Regroups the open cards of the board into lanes across its lists. Lanes
+/// appear in the order they are first encountered (or chronologically when
+/// grouping by due week), followed by a lane for cards which belong to none.
+pub fn swimlanes(board: &Board, group_by: GroupBy) -> Swimlanes {
+    let mut lanes: Vec<Lane> = vec![];
+    let mut fallback = Lane {
+        name: String::from(group_by.fallback_lane()),
+        cards: vec![],
+    };
+
+    for (list, card) in open_cards_with_lists(board) {
+        let lane_card = LaneCard {
+            card: card.clone(),
+            list_name: list.name.clone(),
+        };
+
+        let names = group_by.lane_names(card);
+        if names.is_empty() {
+            fallback.cards.push(lane_card);
+            continue;
+        }
+
+        for name in names {
+            match lanes.iter_mut().find(|l| l.name == name) {
+                Some(lane) => lane.cards.push(lane_card.clone()),
+                None => lanes.push(Lane {
+                    name,
+                    cards: vec![lane_card.clone()],
+                }),
+            }
+        }
+    }
+
+    if group_by == GroupBy::DueWeek {
+        lanes.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+    if !fallback.cards.is_empty() {
+        lanes.push(fallback);
+    }
+
+    Swimlanes {
+        name: board.name.clone(),
+        lanes,
+    }
+}
+
+impl Swimlanes {
+    /// Replaces member ids in lane names with the full names of the members
+    pub fn with_members(mut self, members: &[Member]) -> Swimlanes {
+        for lane in &mut self.lanes {
+            if let Some(member) = members.iter().find(|m| m.id == lane.name) {
+                lane.name = member.full_name.clone();
+            }
+        }
+        self
+    }
+}
+
+impl Renderable for Swimlanes {
+    fn render_with(&self, options: &RenderOptions) -> String {
+        let mut result = vec![title(&self.name).bold().to_string()];
+
+        for lane in &self.lanes {
+            result.push(String::from(""));
+            result.push(
+                format!("{} ({})", lane.name, lane.cards.len())
+                    .bold()
+                    .to_string(),
+            );
+
+            for lane_card in &lane.cards {
+                result.push(format!(
+                    "* {} {}",
+                    lane_card.card.render_line(options),
+                    format!("({})", lane_card.list_name).dimmed()
+                ));
+            }
+        }
+
+        result.join("\n")
+    }
+
+    fn simple_render(&self) -> String {
+        format!("{}: {} lanes", self.name, self.lanes.len())
+    }
+}
@@ -0,0 +1,180 @@
+//! Polls a board for changes to its cards, optionally writing each change
+//! as a line of JSON (NDJSON) to a sink such as stdout or a log file.
+//!
+//! Each line has a stable schema, e.g.
+//! `{"event":"card_moved","card_id":"...","card_name":"...","list":"Done","from_list":"Doing","timestamp":"..."}`
+//! where `from_list` is only present for `card_moved` events.
+use crate::board::Board;
+#[cfg(feature = "http")]
+use crate::cancel::CancelToken;
+use crate::card::Card;
+#[cfg(feature = "http")]
+use crate::client::TrelloClient;
+use crate::ids::CardId;
+use crate::list::List;
+#[cfg(feature = "http")]
+use crate::progress::RunOptions;
+#[cfg(feature = "http")]
+use crate::trello_error::TrelloError;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "http")]
+use std::io::Write;
+#[cfg(feature = "http")]
+use std::time::Duration;
+
+#[cfg(feature = "http")]
+type Result<T> = std::result::Result<T, TrelloError>;
+
+#[derive(Deserialize, Serialize, Debug, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum EventType {
+    CardCreated,
+    /// The card was archived, deleted or moved to another board
+    CardRemoved,
+    CardMoved,
+    CardRenamed,
+}
+
+#[derive(Deserialize, Serialize, Debug, Eq, PartialEq, Clone)]
+pub struct Event {
+    pub event: EventType,
+    pub card_id: CardId,
+    pub card_name: String,
+    /// The list the card is in, or was last in when removed
+    pub list: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_list: Option<String>,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl Event {
+    fn new(event: EventType, card: &Card, list: &List, timestamp: DateTime<Utc>) -> Event {
+        Event {
+            event,
+            card_id: card.id.clone(),
+            card_name: card.name.clone(),
+            list: list.name.clone(),
+            from_list: None,
+            timestamp,
+        }
+    }
+}
+
+fn cards(board: &Board) -> Vec<(&Card, &List)> {
+    board
+        .lists
+        .iter()
+        .flat_map(|l| l.cards.iter().map(move |c| (c, l)))
+        .filter(|(c, _)| !c.closed)
+        .collect()
+}
+
+/// The card events between two snapshots of a board, matching cards and
+/// lists by id
+pub fn events(old: &Board, new: &Board, timestamp: DateTime<Utc>) -> Vec<Event> {
+    let old_cards = cards(old);
+    let new_cards = cards(new);
+    let mut result = vec![];
+
+    for (card, list) in &new_cards {
+        match old_cards.iter().find(|(c, _)| c.id == card.id) {
+            None => result.push(Event::new(EventType::CardCreated, card, list, timestamp)),
+            Some((previous, previous_list)) => {
+                if previous_list.id != list.id {
+                    let mut event = Event::new(EventType::CardMoved, card, list, timestamp);
+                    event.from_list = Some(previous_list.name.clone());
+                    result.push(event);
+                }
+                if previous.name != card.name {
+                    result.push(Event::new(EventType::CardRenamed, card, list, timestamp));
+                }
+            }
+        }
+    }
+
+    for (card, list) in &old_cards {
+        if !new_cards.iter().any(|(c, _)| c.id == card.id) {
+            result.push(Event::new(EventType::CardRemoved, card, list, timestamp));
+        }
+    }
+
+    result
+}
+
+#[cfg(feature = "http")]
+pub struct BoardWatcher {
+    board_id: String,
+    previous: Option<Board>,
+    sink: Option<Box<dyn Write>>,
+    cancel: CancelToken,
+}
+
+#[cfg(feature = "http")]
+impl BoardWatcher {
+    pub fn new(board_id: &str) -> BoardWatcher {
+        BoardWatcher {
+            board_id: String::from(board_id),
+            previous: None,
+            sink: None,
+            cancel: CancelToken::new(),
+        }
+    }
+
+    /// Stops polling with `TrelloError::Cancelled` once the token is
+    /// cancelled, without waiting for the end of the current interval
+    pub fn cancel_token(mut self, cancel: CancelToken) -> BoardWatcher {
+        self.cancel = cancel;
+        self
+    }
+
+    /// Writes every event as a line of JSON to the given sink, flushing
+    /// after each poll
+    pub fn ndjson<W: Write + 'static>(mut self, sink: W) -> BoardWatcher {
+        self.sink = Some(Box::new(sink));
+        self
+    }
+
+    /// Retrieves the board and returns the events since the previous poll.
+    /// The first poll only records the current state of the board.
+    pub fn poll(&mut self, client: &TrelloClient) -> Result<Vec<Event>> {
+        let mut board = Board::get(client, &self.board_id)?;
+        board.retrieve_nested_with(client, RunOptions::new().cancel(&self.cancel))?;
+
+        let events = match &self.previous {
+            Some(previous) => events(previous, &board, Utc::now()),
+            None => vec![],
+        };
+        self.previous = Some(board);
+
+        if let Some(sink) = &mut self.sink {
+            for event in &events {
+                serde_json::to_writer(&mut *sink, event).map_err(std::io::Error::from)?;
+                writeln!(sink)?;
+            }
+            sink.flush()?;
+        }
+
+        Ok(events)
+    }
+
+    /// Polls the board until cancelled, waiting `interval` between polls.
+    /// The number of completed polls is given by `TrelloError::Cancelled`.
+    pub fn run(&mut self, client: &TrelloClient, interval: Duration) -> Result<()> {
+        let mut polls = 0;
+        loop {
+            self.cancel.check(polls)?;
+            let events = self.poll(client).map_err(|e| match e {
+                TrelloError::Cancelled { .. } => TrelloError::Cancelled {
+                    completed: polls,
+                    partial: None,
+                },
+                e => e,
+            })?;
+            debug!("{} events on board {}", events.len(), self.board_id);
+            polls += 1;
+            self.cancel.sleep(interval);
+        }
+    }
+}
@@ -0,0 +1,154 @@
+//! Types for the payloads Trello POSTs to webhook callback urls, so that
+//! servers receiving these callbacks can use this crate as their model layer.
+//!
+//! https://developer.atlassian.com/cloud/trello/guides/rest-api/webhooks/
+use crate::trello_error::TrelloError;
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha1::Sha1;
+use std::collections::BTreeMap;
+
+type Result<T> = std::result::Result<T, TrelloError>;
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct WebhookPayload {
+    pub action: Action,
+    /// The object the webhook was registered for
+    pub model: Model,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Action {
+    pub id: String,
+    pub id_member_creator: String,
+    /// e.g. "createCard", "updateCard" or "commentCard"
+    #[serde(rename = "type")]
+    pub action_type: String,
+    pub date: DateTime<Utc>,
+    #[serde(default)]
+    pub data: ActionData,
+    pub display: Option<Display>,
+    pub member_creator: Option<ActionMember>,
+}
+
+/// The objects involved in an action. Which fields are present depends on
+/// the action type.
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ActionData {
+    pub board: Option<Entity>,
+    pub list: Option<Entity>,
+    pub card: Option<Entity>,
+    pub label: Option<Entity>,
+    pub checklist: Option<Entity>,
+    /// Set when a card moves between lists
+    pub list_before: Option<Entity>,
+    pub list_after: Option<Entity>,
+    /// The previous values of the fields changed by an update action
+    pub old: Option<serde_json::Map<String, serde_json::Value>>,
+    /// The text of a comment
+    pub text: Option<String>,
+}
+
+/// A reference to a board, list, card or other object within an action
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Entity {
+    pub id: String,
+    pub name: Option<String>,
+    pub short_link: Option<String>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Display {
+    /// e.g. "action_move_card_from_list_to_list"
+    pub translation_key: String,
+    #[serde(default)]
+    pub entities: BTreeMap<String, DisplayEntity>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DisplayEntity {
+    #[serde(rename = "type")]
+    pub entity_type: String,
+    pub id: Option<String>,
+    pub text: Option<String>,
+    pub short_link: Option<String>,
+    pub username: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ActionMember {
+    pub id: String,
+    pub username: String,
+    pub full_name: String,
+}
+
+/// The board, list, card or member the webhook watches
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Model {
+    pub id: String,
+    pub name: Option<String>,
+    pub url: Option<String>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Parses the body of a webhook callback request
+pub fn parse(body: &str) -> Result<WebhookPayload> {
+    serde_json::from_str(body).map_err(|source| TrelloError::Deserialize {
+        source,
+        body: String::from(body),
+    })
+}
+
+/// The header containing the signature of a webhook callback request
+pub const SIGNATURE_HEADER: &str = "X-Trello-Webhook";
+
+/// Checks the signature Trello sends in the `X-Trello-Webhook` header: the
+/// base64 encoded HMAC-SHA1 of the request body followed by the callback url
+/// the webhook was registered with, keyed by the application secret.
+pub fn verify(secret: &str, callback_url: &str, body: &[u8], header_signature: &str) -> bool {
+    let signature = match base64::decode(header_signature.trim()) {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
+
+    let mut mac =
+        Hmac::<Sha1>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(body);
+    mac.update(callback_url.as_bytes());
+
+    // constant time comparison
+    mac.verify_slice(&signature).is_ok()
+}
+
+/// Verifies and parses a webhook callback request, e.g. as received by a
+/// hyper or axum handler once its body has been collected
+pub fn handle_request<B: AsRef<[u8]>>(
+    secret: &str,
+    callback_url: &str,
+    request: &http::Request<B>,
+) -> Result<WebhookPayload> {
+    let body = request.body().as_ref();
+    let signature = request
+        .headers()
+        .get(SIGNATURE_HEADER)
+        .and_then(|s| s.to_str().ok())
+        .ok_or(TrelloError::InvalidSignature)?;
+
+    if !verify(secret, callback_url, body, signature) {
+        return Err(TrelloError::InvalidSignature);
+    }
+
+    parse(&String::from_utf8_lossy(body))
+}
@@ -0,0 +1,182 @@
+#[cfg(feature = "http")]
+use crate::card::{Card, CardPatch};
+#[cfg(feature = "http")]
+use crate::client::TrelloClient;
+use crate::ids::{CardId, LabelId, ListId};
+#[cfg(feature = "http")]
+use crate::label::Label;
+#[cfg(feature = "http")]
+use crate::progress::RunOptions;
+#[cfg(feature = "http")]
+use crate::trello_error::TrelloError;
+
+use std::time::Duration;
+
+#[cfg(feature = "http")]
+type Result<T> = std::result::Result<T, TrelloError>;
+
+#[cfg(feature = "http")]
+const FLUSH_PHASE: &str = "Sending changes";
+
+/// A change to a card which can be queued in a `WriteQueue`
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum Mutation {
+    ApplyLabel { card_id: CardId, label_id: LabelId },
+    RemoveLabel { card_id: CardId, label_id: LabelId },
+    Rename { card_id: CardId, name: String },
+    Move { card_id: CardId, list_id: ListId },
+}
+
+impl Mutation {
+    /// Whether a later `other` makes this mutation redundant
+    fn superseded_by(&self, other: &Mutation) -> bool {
+        use Mutation::*;
+
+        match (self, other) {
+            (Rename { card_id: a, .. }, Rename { card_id: b, .. }) => a == b,
+            (Move { card_id: a, .. }, Move { card_id: b, .. }) => a == b,
+            (
+                ApplyLabel {
+                    card_id: a,
+                    label_id: la,
+                }
+                | RemoveLabel {
+                    card_id: a,
+                    label_id: la,
+                },
+                ApplyLabel {
+                    card_id: b,
+                    label_id: lb,
+                }
+                | RemoveLabel {
+                    card_id: b,
+                    label_id: lb,
+                },
+            ) => a == b && la == lb,
+            _ => false,
+        }
+    }
+
+    #[cfg(feature = "http")]
+    fn send(&self, client: &TrelloClient) -> Result<()> {
+        match self {
+            Mutation::ApplyLabel { card_id, label_id } => {
+                Label::add_to_card(client, card_id, label_id)
+            }
+            Mutation::RemoveLabel { card_id, label_id } => {
+                Label::remove_from_card(client, card_id, label_id)
+            }
+            Mutation::Rename { card_id, name } => {
+                Card::patch(client, card_id, &CardPatch::new().name(name))?;
+                Ok(())
+            }
+            Mutation::Move { card_id, list_id } => Card::move_to_list(client, card_id, list_id),
+        }
+    }
+}
+
+/// Buffers card mutations so that bulk edits can be sent in one go. Redundant
+/// mutations are coalesced as they are pushed: the last rename or move of a
+/// card wins, as does the last apply or remove of the same label on a card.
+pub struct WriteQueue {
+    mutations: Vec<Mutation>,
+    interval: Duration,
+}
+
+impl Default for WriteQueue {
+    fn default() -> Self {
+        WriteQueue {
+            mutations: vec![],
+            // Trello allows 100 requests per 10 seconds for each token
+            interval: Duration::from_millis(100),
+        }
+    }
+}
+
+impl WriteQueue {
+    pub fn new() -> WriteQueue {
+        WriteQueue::default()
+    }
+
+    /// The minimum time to wait between requests when flushing
+    pub fn interval(mut self, interval: Duration) -> WriteQueue {
+        self.interval = interval;
+        self
+    }
+
+    pub fn push(&mut self, mutation: Mutation) {
+        self.mutations.retain(|m| !m.superseded_by(&mutation));
+        self.mutations.push(mutation);
+    }
+
+    pub fn mutations(&self) -> &[Mutation] {
+        &self.mutations
+    }
+
+    pub fn len(&self) -> usize {
+        self.mutations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.mutations.is_empty()
+    }
+
+    /// Sends the queued mutations in order, returning the number sent. On
+    /// error, the mutations which were not sent remain in the queue. When the
+    /// rate limit reported by Trello is exhausted, waits for the limit's
+    /// interval before continuing.
+    #[cfg(feature = "http")]
+    pub fn flush(&mut self, client: &TrelloClient) -> Result<usize> {
+        self.flush_with(client, RunOptions::new())
+    }
+
+    /// Like `flush`, reporting each mutation sent to the progress of the
+    /// options, and stopping with `TrelloError::Cancelled` before the next
+    /// mutation once cancelled
+    #[cfg(feature = "http")]
+    pub fn flush_with(&mut self, client: &TrelloClient, mut options: RunOptions) -> Result<usize> {
+        let total = self.mutations.len();
+        let mut sent = 0;
+        options.update(FLUSH_PHASE, sent, total);
+
+        let result = loop {
+            let mutation = match self.mutations.get(sent) {
+                Some(m) => m,
+                None => break Ok(sent),
+            };
+
+            if sent > 0 {
+                options.sleep(wait_time(client, self.interval));
+            }
+
+            if let Err(e) = options.check(sent).and_then(|()| mutation.send(client)) {
+                break Err(e);
+            }
+            sent += 1;
+            options.update(FLUSH_PHASE, sent, total);
+        };
+
+        options.finish();
+        self.mutations.drain(..sent);
+        result
+    }
+}
+
+#[cfg(feature = "http")]
+fn wait_time(client: &TrelloClient, interval: Duration) -> Duration {
+    let status = match client.rate_limit_status() {
+        Some(status) if status.remaining() == Some(0) => status,
+        _ => return interval,
+    };
+
+    let limit_interval = [status.api_key, status.api_token, status.member]
+        .iter()
+        .flatten()
+        .filter(|l| l.remaining == 0)
+        .map(|l| Duration::from_millis(l.interval_ms))
+        .max()
+        .unwrap_or(interval);
+
+    debug!("Rate limit reached, waiting {:?}", limit_interval);
+    limit_interval.max(interval)
+}